@@ -40,6 +40,7 @@ use tari_template_builtin::{
     ACCOUNT_NFT_TEMPLATE_ADDRESS,
     ACCOUNT_TEMPLATE_ADDRESS,
     FAUCET_TEMPLATE_ADDRESS,
+    MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
 };
 use tari_template_lib::models::TemplateAddress;
 
@@ -90,7 +91,7 @@ impl<TAddr: NodeAddressable> TemplateManager<TAddr> {
 
     fn load_builtin_templates() -> HashMap<TemplateAddress, Template> {
         // for now, we only load the "account" template
-        let mut builtin_templates = HashMap::with_capacity(3);
+        let mut builtin_templates = HashMap::with_capacity(4);
 
         // get the builtin WASM code of the account template
         let compiled_code = get_template_builtin(&ACCOUNT_TEMPLATE_ADDRESS);
@@ -108,6 +109,15 @@ impl<TAddr: NodeAddressable> TemplateManager<TAddr> {
         let template = Self::convert_code_to_template("XtrFaucet", FAUCET_TEMPLATE_ADDRESS, compiled_code.to_vec());
         builtin_templates.insert(FAUCET_TEMPLATE_ADDRESS, template);
 
+        // get the builtin WASM code of the multisig account template
+        let compiled_code = get_template_builtin(&MULTISIG_ACCOUNT_TEMPLATE_ADDRESS);
+        let template = Self::convert_code_to_template(
+            "MultisigAccount",
+            MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
+            compiled_code.to_vec(),
+        );
+        builtin_templates.insert(MULTISIG_ACCOUNT_TEMPLATE_ADDRESS, template);
+
         builtin_templates
     }
 
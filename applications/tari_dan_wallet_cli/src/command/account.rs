@@ -26,20 +26,34 @@ use std::{
     io,
     io::Read,
     path::PathBuf,
+    str::FromStr,
 };
 
 use anyhow::anyhow;
 use clap::{Args, Subcommand};
 use serde_json as json;
-use tari_template_lib::models::Amount;
-use tari_utilities::ByteArray;
+use tari_common_types::types::PublicKey;
+use tari_template_lib::{
+    args,
+    crypto::RistrettoPublicKeyBytes,
+    models::{Amount, ComponentAddress, NonFungibleAddress, ResourceAddress},
+};
+use tari_utilities::{hex::Hex, ByteArray};
 use tari_wallet_daemon_client::{
     types::{
         AccountInfo,
+        AccountsApproveRecoveryRequest,
+        AccountsCancelRecoveryRequest,
+        AccountsCompleteRecoveryRequest,
         AccountsCreateFreeTestCoinsRequest,
+        AccountsCreateMultisigRequest,
         AccountsCreateRequest,
         AccountsGetBalancesRequest,
+        AccountsInitiateRecoveryRequest,
+        AccountsInvokeMultisigRequest,
         AccountsInvokeRequest,
+        AccountsRestoreRequest,
+        AccountsSetGuardiansRequest,
         ClaimBurnRequest,
         RevealFundsRequest,
     },
@@ -49,6 +63,7 @@ use tari_wallet_daemon_client::{
 
 use crate::{
     command::transaction::{print_execution_results, summarize_finalize_result, CliArg},
+    from_hex::FromHex,
     table::Table,
     table_row,
 };
@@ -76,6 +91,15 @@ pub enum AccountsSubcommand {
     CreateFreeTestCoins(CreateFreeTestCoinsArgs),
     #[clap(alias = "default")]
     SetDefault(SetDefaultArgs),
+    CreateMultisig(CreateMultisigArgs),
+    MultisigProposeWithdrawal(MultisigProposeWithdrawalArgs),
+    MultisigApprove(MultisigApproveArgs),
+    SetGuardians(SetGuardiansArgs),
+    InitiateRecovery(InitiateRecoveryArgs),
+    ApproveRecovery(ApproveRecoveryArgs),
+    CancelRecovery(CancelRecoveryArgs),
+    CompleteRecovery(CompleteRecoveryArgs),
+    Restore(RestoreArgs),
 }
 
 #[derive(Debug, Args, Clone)]
@@ -90,6 +114,133 @@ pub struct CreateArgs {
     pub key_id: Option<u64>,
 }
 
+#[derive(Debug, Args, Clone)]
+pub struct CreateMultisigArgs {
+    /// The hex public key of an owner. Specify once for each owner.
+    #[clap(long = "owner", short = 'o', required = true)]
+    pub owners: Vec<FromHex<Vec<u8>>>,
+    /// The number of owners required to approve a proposal
+    #[clap(long, short = 't')]
+    pub threshold: u32,
+    /// Adds the multisig account to this wallet, owned by the key with this id. The key must be one of the owners.
+    #[clap(long, short = 'k', alias = "key")]
+    pub owner_key_id: Option<u64>,
+    #[clap(long, alias = "name")]
+    pub account_name: Option<String>,
+    /// The account that pays the fees. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub fee_account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct MultisigProposeWithdrawalArgs {
+    pub multisig_address: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: u64,
+    pub destination: ComponentAddress,
+    /// The last epoch in which the proposal can be approved
+    #[clap(long, short = 'e')]
+    pub expires_at_epoch: u64,
+    /// The owner account that signs the proposal and pays the fees. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct MultisigApproveArgs {
+    pub multisig_address: ComponentAddress,
+    pub proposal_id: u64,
+    /// The owner account that signs the approval and pays the fees. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct SetGuardiansArgs {
+    /// A guardian, either a hex public key or a non-fungible address (nft_...). Specify once for each guardian.
+    #[clap(long = "guardian", short = 'g', required = true)]
+    pub guardians: Vec<String>,
+    /// The number of guardians required to approve a recovery
+    #[clap(long, short = 't')]
+    pub threshold: u32,
+    /// The number of epochs that the owner has to cancel a recovery once the guardians have approved it
+    #[clap(long, short = 'd')]
+    pub recovery_delay_epochs: u64,
+    /// The account to set guardians for. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct InitiateRecoveryArgs {
+    /// The account being recovered
+    pub account_address: ComponentAddress,
+    /// The hex public key of the new owner
+    pub new_owner_public_key: FromHex<Vec<u8>>,
+    /// The guardian non-fungible held by the guardian account, if the guardian is not the account's public key
+    #[clap(long, short = 'b')]
+    pub guardian_badge: Option<String>,
+    /// The guardian account that signs the transaction and pays the fees. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub guardian_account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct ApproveRecoveryArgs {
+    /// The account being recovered
+    pub account_address: ComponentAddress,
+    /// The guardian non-fungible held by the guardian account, if the guardian is not the account's public key
+    #[clap(long, short = 'b')]
+    pub guardian_badge: Option<String>,
+    /// The guardian account that signs the transaction and pays the fees. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub guardian_account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct CancelRecoveryArgs {
+    /// The account with the pending recovery. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct CompleteRecoveryArgs {
+    /// The account being recovered
+    pub account_address: ComponentAddress,
+    /// Adds the recovered account to this wallet, owned by the key with this id
+    #[clap(long, short = 'k', alias = "key")]
+    pub new_owner_key_id: Option<u64>,
+    #[clap(long, alias = "name")]
+    pub account_name: Option<String>,
+    /// The account that pays the fees. The default account is used if not set.
+    #[clap(long, short = 'a')]
+    pub fee_account: Option<ComponentAddressOrName>,
+    #[clap(long, short = 'f')]
+    pub max_fee: Option<u64>,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct RestoreArgs {
+    /// The number of consecutive unused keys after which account discovery stops
+    #[clap(long, short = 'g')]
+    pub gap_limit: Option<u64>,
+}
+
 #[derive(Debug, Args, Clone)]
 pub struct SetDefaultArgs {
     pub account_name: ComponentAddressOrName,
@@ -169,6 +320,17 @@ impl AccountsSubcommand {
             AccountsSubcommand::RevealFunds(args) => handle_reveal_funds(args, &mut client).await?,
             AccountsSubcommand::CreateFreeTestCoins(args) => handle_create_free_test_coins(args, &mut client).await?,
             AccountsSubcommand::SetDefault(args) => handle_set_default(args, &mut client).await?,
+            AccountsSubcommand::CreateMultisig(args) => handle_create_multisig(args, &mut client).await?,
+            AccountsSubcommand::MultisigProposeWithdrawal(args) => {
+                handle_multisig_propose_withdrawal(args, &mut client).await?
+            },
+            AccountsSubcommand::MultisigApprove(args) => handle_multisig_approve(args, &mut client).await?,
+            AccountsSubcommand::SetGuardians(args) => handle_set_guardians(args, &mut client).await?,
+            AccountsSubcommand::InitiateRecovery(args) => handle_initiate_recovery(args, &mut client).await?,
+            AccountsSubcommand::ApproveRecovery(args) => handle_approve_recovery(args, &mut client).await?,
+            AccountsSubcommand::CancelRecovery(args) => handle_cancel_recovery(args, &mut client).await?,
+            AccountsSubcommand::CompleteRecovery(args) => handle_complete_recovery(args, &mut client).await?,
+            AccountsSubcommand::Restore(args) => handle_restore(args, &mut client).await?,
         }
         Ok(())
     }
@@ -229,6 +391,240 @@ async fn handle_invoke(
     Ok(())
 }
 
+async fn handle_create_multisig(
+    args: CreateMultisigArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    let owner_public_keys = args
+        .owners
+        .into_iter()
+        .map(|owner| PublicKey::from_canonical_bytes(&owner.into_inner()).map_err(anyhow::Error::msg))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    println!("Submitted new multisig account creation transaction...");
+    let resp = client
+        .create_multisig_account(AccountsCreateMultisigRequest {
+            fee_account: args.fee_account,
+            owner_public_keys,
+            threshold: args.threshold,
+            owner_key_id: args.owner_key_id,
+            account_name: args.account_name,
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Multisig account created");
+    println!("   address: {}", resp.address);
+    Ok(())
+}
+
+async fn handle_multisig_propose_withdrawal(
+    args: MultisigProposeWithdrawalArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    println!("Submitted multisig withdrawal proposal transaction...");
+    let resp = client
+        .invoke_multisig_method(AccountsInvokeMultisigRequest {
+            account: args.account,
+            multisig_address: args.multisig_address,
+            method: "propose_withdrawal".to_string(),
+            args: args![
+                args.resource_address,
+                Amount::try_from(args.amount)?,
+                args.destination,
+                args.expires_at_epoch
+            ],
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    let proposal_id = resp
+        .result
+        .map(|r| r.decode::<u64>())
+        .transpose()?
+        .ok_or_else(|| anyhow!("No proposal id returned"))?;
+
+    println!();
+    println!("✅ Withdrawal proposed");
+    println!("   proposal id: {}", proposal_id);
+    Ok(())
+}
+
+async fn handle_multisig_approve(
+    args: MultisigApproveArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    println!("Submitted multisig approval transaction...");
+    client
+        .invoke_multisig_method(AccountsInvokeMultisigRequest {
+            account: args.account,
+            multisig_address: args.multisig_address,
+            method: "approve".to_string(),
+            args: args![args.proposal_id],
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Proposal {} approved", args.proposal_id);
+    Ok(())
+}
+
+/// Parses a guardian given as either a non-fungible address or a hex public key
+fn parse_guardian(s: &str) -> Result<NonFungibleAddress, anyhow::Error> {
+    if s.starts_with("nft_") {
+        return parse_non_fungible_address(s);
+    }
+    let public_key = PublicKey::from_hex(s)
+        .map_err(|_| anyhow!("Guardian '{}' is not a valid public key or non-fungible address", s))?;
+    let public_key = RistrettoPublicKeyBytes::from_bytes(public_key.as_bytes())
+        .expect("RistrettoPublicKeyBytes::from_bytes is infallible for a valid public key");
+    Ok(NonFungibleAddress::from_public_key(public_key))
+}
+
+fn parse_non_fungible_address(s: &str) -> Result<NonFungibleAddress, anyhow::Error> {
+    NonFungibleAddress::from_str(s).map_err(|e| anyhow!("Invalid non-fungible address '{}': {}", s, e))
+}
+
+async fn handle_set_guardians(args: SetGuardiansArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let guardians = args
+        .guardians
+        .iter()
+        .map(|g| parse_guardian(g))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    println!("Submitted set guardians transaction...");
+    client
+        .set_account_guardians(AccountsSetGuardiansRequest {
+            account: args.account,
+            guardians,
+            threshold: args.threshold,
+            recovery_delay_epochs: args.recovery_delay_epochs,
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Guardians set");
+    Ok(())
+}
+
+async fn handle_initiate_recovery(
+    args: InitiateRecoveryArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    let new_owner_public_key =
+        PublicKey::from_canonical_bytes(&args.new_owner_public_key.into_inner()).map_err(anyhow::Error::msg)?;
+
+    println!("Submitted initiate recovery transaction...");
+    client
+        .initiate_account_recovery(AccountsInitiateRecoveryRequest {
+            guardian_account: args.guardian_account,
+            account_address: args.account_address,
+            new_owner_public_key,
+            guardian_badge: args
+                .guardian_badge
+                .as_deref()
+                .map(parse_non_fungible_address)
+                .transpose()?,
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Recovery of account {} initiated", args.account_address);
+    Ok(())
+}
+
+async fn handle_approve_recovery(
+    args: ApproveRecoveryArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    println!("Submitted approve recovery transaction...");
+    client
+        .approve_account_recovery(AccountsApproveRecoveryRequest {
+            guardian_account: args.guardian_account,
+            account_address: args.account_address,
+            guardian_badge: args
+                .guardian_badge
+                .as_deref()
+                .map(parse_non_fungible_address)
+                .transpose()?,
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Recovery of account {} approved", args.account_address);
+    Ok(())
+}
+
+async fn handle_cancel_recovery(
+    args: CancelRecoveryArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    println!("Submitted cancel recovery transaction...");
+    client
+        .cancel_account_recovery(AccountsCancelRecoveryRequest {
+            account: args.account,
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Recovery cancelled");
+    Ok(())
+}
+
+async fn handle_complete_recovery(
+    args: CompleteRecoveryArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<(), anyhow::Error> {
+    println!("Submitted complete recovery transaction...");
+    client
+        .complete_account_recovery(AccountsCompleteRecoveryRequest {
+            fee_account: args.fee_account,
+            account_address: args.account_address,
+            new_owner_key_id: args.new_owner_key_id,
+            account_name: args.account_name,
+            max_fee: args.max_fee.map(|f| f.try_into()).transpose()?,
+        })
+        .await?;
+
+    println!();
+    println!("✅ Account {} recovered", args.account_address);
+    Ok(())
+}
+
+async fn handle_restore(args: RestoreArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    println!("Discovering accounts owned by the wallet seed...");
+    let resp = client
+        .restore_accounts(AccountsRestoreRequest {
+            gap_limit: args.gap_limit,
+        })
+        .await?;
+
+    if resp.accounts.is_empty() {
+        println!("No accounts found");
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.enable_row_count();
+    table.set_titles(vec!["Address", "Public Key", "Default"]);
+    println!("Restored accounts:");
+    for AccountInfo { account, public_key } in resp.accounts {
+        table.add_row(table_row!(
+            account.address,
+            public_key,
+            if account.is_default { "✅" } else { "" }
+        ));
+    }
+    table.print_stdout();
+    Ok(())
+}
+
 async fn handle_get_balances(args: GetBalancesArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
     let resp = client
         .get_account_balances(AccountsGetBalancesRequest {
@@ -23,6 +23,7 @@
 use std::{
     collections::HashMap,
     convert::{TryFrom, TryInto},
+    env,
     fmt,
     fs,
     path::PathBuf,
@@ -33,10 +34,10 @@ use std::{
 use anyhow::anyhow;
 use clap::{Args, Subcommand};
 use tari_bor::decode_exact;
-use tari_common_types::types::PublicKey;
+use tari_common_types::types::{PrivateKey, PublicKey};
 use tari_dan_common_types::{Epoch, SubstateAddress, SubstateRequirement};
 use tari_dan_engine::abi::Type;
-use tari_dan_wallet_sdk::apis::confidential_transfer::ConfidentialTransferInputSelection;
+use tari_dan_wallet_sdk::{apis::confidential_transfer::ConfidentialTransferInputSelection, models::Account};
 use tari_engine_types::{
     commit_result::{FinalizeResult, RejectReason, TransactionResult},
     instruction::Instruction,
@@ -55,15 +56,20 @@ use tari_template_lib::{
 };
 use tari_transaction::{Transaction, TransactionId, UnsignedTransaction};
 use tari_transaction_manifest::{parse_manifest, ManifestValue};
-use tari_utilities::{hex::to_hex, ByteArray};
+use tari_utilities::{
+    hex::{to_hex, Hex},
+    ByteArray,
+};
 use tari_wallet_daemon_client::{
     types::{
         AccountGetResponse,
         AccountsTransferRequest,
         ConfidentialTransferRequest,
         TransactionGetResultRequest,
+        TransactionResolveInputsRequest,
         TransactionSubmitDryRunRequest,
         TransactionSubmitRequest,
+        TransactionSubmitSignedRequest,
         TransactionWaitResultRequest,
         TransactionWaitResultResponse,
     },
@@ -71,7 +77,7 @@ use tari_wallet_daemon_client::{
     WalletDaemonClient,
 };
 
-use crate::from_hex::FromHex;
+use crate::{from_hex::FromHex, offline_transaction::OfflineTransaction, prompt::Prompt};
 
 #[derive(Debug, Subcommand, Clone)]
 pub enum TransactionSubcommand {
@@ -80,6 +86,12 @@ pub enum TransactionSubcommand {
     SubmitManifest(SubmitManifestArgs),
     Send(SendArgs),
     ConfidentialTransfer(ConfidentialTransferArgs),
+    /// Exports an unsigned transaction to a file so that it can be signed offline
+    Export(ExportArgs),
+    /// Signs an exported transaction. This does not require a connection to the wallet daemon.
+    Sign(SignArgs),
+    /// Submits an exported transaction once all required signers have signed
+    SubmitSigned(SubmitSignedArgs),
 }
 
 #[derive(Debug, Args, Clone)]
@@ -95,6 +107,43 @@ pub struct SubmitArgs {
     pub common: CommonSubmitArgs,
 }
 
+#[derive(Debug, Args, Clone)]
+pub struct ExportArgs {
+    /// The file to export the transaction to
+    #[clap(long, short = 'o')]
+    pub output: PathBuf,
+    /// The hex public key of a signer that must sign the transaction. Specify once for each signer. If not set, the
+    /// owner of the fee account must sign.
+    #[clap(long = "signer")]
+    pub signers: Vec<FromHex<Vec<u8>>>,
+    #[clap(subcommand)]
+    pub instruction: CliInstruction,
+    #[clap(flatten)]
+    pub common: CommonSubmitArgs,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct SignArgs {
+    /// The exported transaction file. The signature is added to this file.
+    pub file: PathBuf,
+    /// A file containing the hex secret key to sign with. If no key source is given, the key is read from a prompt
+    /// that does not echo the input.
+    #[clap(long, conflicts_with = "secret_key_from_env")]
+    pub secret_key_file: Option<PathBuf>,
+    /// Read the hex secret key from the TARI_DAN_WALLET_SIGNING_KEY environment variable
+    #[clap(long)]
+    pub secret_key_from_env: bool,
+    /// Sign without asking for confirmation
+    #[clap(long, short = 'y')]
+    pub yes: bool,
+}
+
+#[derive(Debug, Args, Clone)]
+pub struct SubmitSignedArgs {
+    /// The exported transaction file
+    pub file: PathBuf,
+}
+
 #[derive(Debug, Args, Clone)]
 pub struct CommonSubmitArgs {
     /// Timeout in seconds
@@ -186,6 +235,9 @@ impl TransactionSubcommand {
             TransactionSubcommand::ConfidentialTransfer(args) => {
                 handle_confidential_transfer(args, &mut client).await?;
             },
+            TransactionSubcommand::Export(args) => handle_export(args, &mut client).await?,
+            TransactionSubcommand::Sign(args) => handle_sign(args)?,
+            TransactionSubcommand::SubmitSigned(args) => handle_submit_signed(args, &mut client).await?,
         }
         Ok(())
     }
@@ -211,6 +263,53 @@ async fn handle_get(args: GetArgs, client: &mut WalletDaemonClient) -> Result<()
 
 pub async fn handle_submit(args: SubmitArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
     let SubmitArgs { instruction, common } = args;
+    let fee_account = get_fee_account(&common, client).await?;
+    let transaction = build_instruction_transaction(instruction, &common, &fee_account.account, client).await?;
+    summarize_transaction(&transaction);
+
+    if common.dry_run {
+        println!("NOTE: Dry run is enabled. This transaction will not be processed by the network.");
+        println!();
+        let resp = client
+            .submit_transaction_dry_run(TransactionSubmitDryRunRequest {
+                transaction,
+                signing_key_index: None,
+                autofill_inputs: vec![],
+                detect_inputs: common.detect_inputs.unwrap_or(true),
+                proof_ids: vec![],
+            })
+            .await?;
+        wait_transaction_result(resp.transaction_id, client).await?;
+    } else {
+        let request = TransactionSubmitRequest {
+            transaction,
+            signing_key_index: None,
+            autofill_inputs: vec![],
+            detect_inputs: common.detect_inputs.unwrap_or(true),
+            proof_ids: vec![],
+        };
+        let resp = client.submit_transaction(&request).await?;
+        wait_transaction_result(resp.transaction_id, client).await?;
+    }
+    Ok(())
+}
+
+async fn get_fee_account(
+    common: &CommonSubmitArgs,
+    client: &mut WalletDaemonClient,
+) -> Result<AccountGetResponse, anyhow::Error> {
+    match common.fee_account.clone() {
+        Some(fee_account_name) => Ok(client.accounts_get(fee_account_name).await?),
+        None => Ok(client.accounts_get_default().await?),
+    }
+}
+
+async fn build_instruction_transaction(
+    instruction: CliInstruction,
+    common: &CommonSubmitArgs,
+    fee_account: &Account,
+    client: &mut WalletDaemonClient,
+) -> Result<UnsignedTransaction, anyhow::Error> {
     let instruction = match instruction {
         CliInstruction::CallFunction {
             template_address,
@@ -234,24 +333,17 @@ pub async fn handle_submit(args: SubmitArgs, client: &mut WalletDaemonClient) ->
         },
     };
 
-    let fee_account;
-    if let Some(fee_account_name) = common.fee_account.clone() {
-        fee_account = client.accounts_get(fee_account_name).await?.account;
-    } else {
-        fee_account = client.accounts_get_default().await?.account;
-    }
-
     let mut builder = Transaction::builder()
         .fee_transaction_pay_from_component(
             fee_account.address.as_component_address().unwrap(),
             Amount::try_from(common.max_fee.unwrap_or(1000))?,
         )
         .add_instruction(instruction)
-        .with_inputs(common.inputs)
+        .with_inputs(common.inputs.clone())
         .with_min_epoch(common.min_epoch.map(Epoch))
         .with_max_epoch(common.max_epoch.map(Epoch));
 
-    if let Some(dump_account) = common.dump_outputs_into {
+    if let Some(dump_account) = common.dump_outputs_into.clone() {
         let AccountGetResponse { account, .. } = client.accounts_get(dump_account).await?;
 
         builder = builder.put_last_instruction_output_on_workspace("bucket").call_method(
@@ -261,33 +353,114 @@ pub async fn handle_submit(args: SubmitArgs, client: &mut WalletDaemonClient) ->
         );
     }
 
-    let transaction = builder.build_unsigned_transaction();
-    summarize_transaction(&transaction);
+    Ok(builder.build_unsigned_transaction())
+}
 
-    if common.dry_run {
-        println!("NOTE: Dry run is enabled. This transaction will not be processed by the network.");
-        println!();
+async fn handle_export(args: ExportArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let ExportArgs {
+        output,
+        signers,
+        instruction,
+        common,
+    } = args;
+
+    let fee_account = get_fee_account(&common, client).await?;
+    let mut transaction = build_instruction_transaction(instruction, &common, &fee_account.account, client).await?;
+    // The inputs are signed, so they must be resolved before the transaction leaves the wallet
+    if common.detect_inputs.unwrap_or(true) {
         let resp = client
-            .submit_transaction_dry_run(TransactionSubmitDryRunRequest {
-                transaction,
-                signing_key_index: None,
-                autofill_inputs: vec![],
-                detect_inputs: common.detect_inputs.unwrap_or(true),
-                proof_ids: vec![],
+            .resolve_transaction_inputs(TransactionResolveInputsRequest {
+                transaction: transaction.clone(),
             })
             .await?;
-        wait_transaction_result(resp.transaction_id, client).await?;
+        transaction = Transaction::builder()
+            .with_unsigned_transaction(transaction)
+            .with_inputs(resp.inputs)
+            .build_unsigned_transaction();
+    }
+
+    let required_signers = if signers.is_empty() {
+        vec![fee_account.public_key]
     } else {
-        let request = TransactionSubmitRequest {
+        signers
+            .into_iter()
+            .map(|pk| PublicKey::from_canonical_bytes(&pk.into_inner()).map_err(anyhow::Error::msg))
+            .collect::<Result<_, _>>()?
+    };
+
+    let offline_transaction = OfflineTransaction::new(transaction, required_signers);
+    offline_transaction.write_to_file(&output)?;
+    for line in offline_transaction.summary() {
+        println!("{}", line);
+    }
+    println!();
+    println!("Required signers:");
+    for signer in &offline_transaction.required_signers {
+        println!("- {}", signer);
+    }
+    println!();
+    println!("✅ Transaction exported to {}", output.display());
+    Ok(())
+}
+
+fn handle_sign(args: SignArgs) -> Result<(), anyhow::Error> {
+    let secret_key = read_signing_key(&args)?;
+    let SignArgs { file, yes, .. } = args;
+    let mut offline_transaction = OfflineTransaction::read_from_file(&file)?;
+
+    for line in offline_transaction.summary() {
+        println!("{}", line);
+    }
+    println!();
+    let answer = Prompt::new("Sign this transaction? (y/N)")
+        .with_default("n")
+        .with_value(yes.then_some("y"))
+        .ask()?;
+    if !answer.eq_ignore_ascii_case("y") {
+        println!("Transaction not signed");
+        return Ok(());
+    }
+
+    let signer = offline_transaction.sign(&secret_key)?;
+    offline_transaction.write_to_file(&file)?;
+    println!("✅ Transaction signed by {}", signer);
+
+    let missing_signers = offline_transaction.missing_signers();
+    if missing_signers.is_empty() {
+        println!("All required signers have signed. The transaction can be submitted.");
+    } else {
+        println!("Waiting for signatures from:");
+        for signer in missing_signers {
+            println!("- {}", signer);
+        }
+    }
+    Ok(())
+}
+
+const SIGNING_KEY_ENV_VAR: &str = "TARI_DAN_WALLET_SIGNING_KEY";
+
+fn read_signing_key(args: &SignArgs) -> Result<PrivateKey, anyhow::Error> {
+    let secret_key = match args.secret_key_file {
+        Some(ref path) => fs::read_to_string(path).map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?,
+        None if args.secret_key_from_env => {
+            env::var(SIGNING_KEY_ENV_VAR).map_err(|_| anyhow!("{} is not set", SIGNING_KEY_ENV_VAR))?
+        },
+        None => rpassword::prompt_password_stdout("Secret key (hex): ")?,
+    };
+    PrivateKey::from_hex(secret_key.trim()).map_err(|e| anyhow!("Invalid secret key: {}", e))
+}
+
+async fn handle_submit_signed(args: SubmitSignedArgs, client: &mut WalletDaemonClient) -> Result<(), anyhow::Error> {
+    let offline_transaction = OfflineTransaction::read_from_file(&args.file)?;
+    let transaction = offline_transaction.into_transaction()?;
+
+    let resp = client
+        .submit_signed_transaction(TransactionSubmitSignedRequest {
             transaction,
-            signing_key_index: None,
             autofill_inputs: vec![],
-            detect_inputs: common.detect_inputs.unwrap_or(true),
-            proof_ids: vec![],
-        };
-        let resp = client.submit_transaction(&request).await?;
-        wait_transaction_result(resp.transaction_id, client).await?;
-    }
+        })
+        .await?;
+    wait_transaction_result(resp.transaction_id, client).await?;
     Ok(())
 }
 
@@ -24,6 +24,7 @@ pub mod cli;
 pub mod command;
 pub mod from_base64;
 pub mod from_hex;
+pub mod offline_transaction;
 pub mod prompt;
 #[macro_use]
 pub mod table;
@@ -0,0 +1,218 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{fs, path::Path};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use tari_common_types::types::{PrivateKey, PublicKey};
+use tari_crypto::keys::PublicKey as PublicKeyTrait;
+use tari_transaction::{Transaction, TransactionSignature, UnsignedTransaction};
+
+/// A transaction exported to a file so that it can be signed on another, possibly offline, machine. Signatures are
+/// accumulated in the file until every required signer has signed, after which the transaction can be submitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineTransaction {
+    /// The transaction to sign, including the inputs that were resolved when it was exported
+    pub transaction: UnsignedTransaction,
+    /// A human-readable description of the transaction. This is informational only, the summary that is shown when
+    /// signing is always generated from the transaction.
+    pub summary: Vec<String>,
+    /// The public keys that must sign the transaction before it can be submitted
+    pub required_signers: Vec<PublicKey>,
+    pub signatures: Vec<TransactionSignature>,
+}
+
+impl OfflineTransaction {
+    pub fn new(transaction: UnsignedTransaction, required_signers: Vec<PublicKey>) -> Self {
+        Self {
+            summary: summarize(&transaction),
+            transaction,
+            required_signers,
+            signatures: vec![],
+        }
+    }
+
+    /// Reads the transaction from a file, checking that all signatures in the file are valid
+    pub fn read_from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+        let offline_transaction: Self = serde_json::from_str(&contents)
+            .map_err(|e| anyhow!("{} is not a valid offline transaction: {}", path.display(), e))?;
+        offline_transaction
+            .verify_signatures()
+            .map_err(|e| anyhow!("{} in {}", e, path.display()))?;
+
+        Ok(offline_transaction)
+    }
+
+    /// Checks that every signature is a valid signature of the transaction
+    pub fn verify_signatures(&self) -> anyhow::Result<()> {
+        for signature in &self.signatures {
+            if !signature.verify(&self.transaction) {
+                return Err(anyhow!("Invalid signature from {}", signature.public_key()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_to_file<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents).map_err(|e| anyhow!("Failed to write {}: {}", path.display(), e))?;
+        Ok(())
+    }
+
+    /// Returns a summary of the transaction that is generated from the transaction, not read from the file
+    pub fn summary(&self) -> Vec<String> {
+        summarize(&self.transaction)
+    }
+
+    /// Adds a signature using the secret key. The key must belong to a required signer that has not yet signed.
+    pub fn sign(&mut self, secret_key: &PrivateKey) -> anyhow::Result<PublicKey> {
+        let public_key = PublicKey::from_secret_key(secret_key);
+        if !self.required_signers.contains(&public_key) {
+            return Err(anyhow!("{} is not a required signer of this transaction", public_key));
+        }
+        if self.has_signed(&public_key) {
+            return Err(anyhow!("{} has already signed this transaction", public_key));
+        }
+
+        self.signatures
+            .push(TransactionSignature::sign(secret_key, &self.transaction));
+        Ok(public_key)
+    }
+
+    /// Returns the required signers that have not yet signed the transaction
+    pub fn missing_signers(&self) -> Vec<&PublicKey> {
+        self.required_signers.iter().filter(|pk| !self.has_signed(pk)).collect()
+    }
+
+    fn has_signed(&self, public_key: &PublicKey) -> bool {
+        self.signatures.iter().any(|sig| sig.public_key() == public_key)
+    }
+
+    /// Builds the signed transaction. All required signers must have signed.
+    pub fn into_transaction(self) -> anyhow::Result<Transaction> {
+        let missing_signers = self.missing_signers();
+        if !missing_signers.is_empty() {
+            return Err(anyhow!(
+                "The transaction is missing {} signature(s) from: {}",
+                missing_signers.len(),
+                missing_signers
+                    .iter()
+                    .map(|pk| pk.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        Ok(Transaction::new(self.transaction, self.signatures))
+    }
+}
+
+fn summarize(transaction: &UnsignedTransaction) -> Vec<String> {
+    let mut summary = Vec::new();
+    summary.push("Inputs:".to_string());
+    if transaction.inputs().is_empty() {
+        summary.push("  None".to_string());
+    }
+    summary.extend(transaction.inputs().iter().map(|req| format!("- {}", req)));
+    summary.push("Fee instructions:".to_string());
+    summary.extend(transaction.fee_instructions().iter().map(|i| format!("- {}", i)));
+    summary.push("Instructions:".to_string());
+    summary.extend(transaction.instructions().iter().map(|i| format!("- {}", i)));
+    for (i, sub_intent) in transaction.sub_intents().iter().enumerate() {
+        summary.push(format!("Sub-intent {} ({}):", i, sub_intent.intent_id()));
+        summary.extend(sub_intent.instructions().iter().map(|i| format!("- {}", i)));
+    }
+    if let Some(min_epoch) = transaction.min_epoch() {
+        summary.push(format!("Min epoch: {}", min_epoch));
+    }
+    if let Some(max_epoch) = transaction.max_epoch() {
+        summary.push(format!("Max epoch: {}", max_epoch));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use tari_dan_common_types::Epoch;
+    use tari_template_lib::models::ComponentAddress;
+
+    use super::*;
+
+    fn new_transaction(max_epoch: u64) -> UnsignedTransaction {
+        Transaction::builder()
+            .call_method(ComponentAddress::from_array([1; 32]), "withdraw", vec![])
+            .with_max_epoch(Some(Epoch(max_epoch)))
+            .build_unsigned_transaction()
+    }
+
+    fn new_signer(n: u64) -> (PrivateKey, PublicKey) {
+        let secret_key = PrivateKey::from(n);
+        let public_key = PublicKey::from_secret_key(&secret_key);
+        (secret_key, public_key)
+    }
+
+    #[test]
+    fn it_accumulates_signatures_from_required_signers() {
+        let (secret1, public1) = new_signer(1);
+        let (secret2, public2) = new_signer(2);
+        let mut offline = OfflineTransaction::new(new_transaction(10), vec![public1.clone(), public2.clone()]);
+
+        assert_eq!(offline.sign(&secret1).unwrap(), public1);
+        assert_eq!(offline.missing_signers(), vec![&public2]);
+        offline.sign(&secret2).unwrap();
+        assert!(offline.missing_signers().is_empty());
+        offline.verify_signatures().unwrap();
+
+        let transaction = offline.into_transaction().unwrap();
+        assert_eq!(transaction.signatures().len(), 2);
+        assert!(transaction.verify_all_signatures());
+    }
+
+    #[test]
+    fn it_rejects_a_duplicate_signer() {
+        let (secret1, public1) = new_signer(1);
+        let mut offline = OfflineTransaction::new(new_transaction(10), vec![public1]);
+
+        offline.sign(&secret1).unwrap();
+        let err = offline.sign(&secret1).unwrap_err();
+        assert!(err.to_string().contains("has already signed"));
+        assert_eq!(offline.signatures.len(), 1);
+    }
+
+    #[test]
+    fn it_rejects_a_signer_that_is_not_required() {
+        let (_, public1) = new_signer(1);
+        let (secret2, _) = new_signer(2);
+        let mut offline = OfflineTransaction::new(new_transaction(10), vec![public1]);
+
+        let err = offline.sign(&secret2).unwrap_err();
+        assert!(err.to_string().contains("is not a required signer"));
+        assert!(offline.signatures.is_empty());
+    }
+
+    #[test]
+    fn it_detects_a_tampered_transaction() {
+        let (secret1, public1) = new_signer(1);
+        let mut offline = OfflineTransaction::new(new_transaction(10), vec![public1]);
+        offline.sign(&secret1).unwrap();
+
+        offline.transaction = new_transaction(11);
+        let err = offline.verify_signatures().unwrap_err();
+        assert!(err.to_string().contains("Invalid signature"));
+    }
+
+    #[test]
+    fn it_does_not_build_a_transaction_with_missing_signers() {
+        let (secret1, public1) = new_signer(1);
+        let (_, public2) = new_signer(2);
+        let mut offline = OfflineTransaction::new(new_transaction(10), vec![public1, public2.clone()]);
+        offline.sign(&secret1).unwrap();
+
+        let err = offline.into_transaction().unwrap_err();
+        assert!(err.to_string().contains(&public2.to_string()));
+    }
+}
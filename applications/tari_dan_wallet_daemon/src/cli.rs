@@ -42,6 +42,13 @@ pub struct Cli {
     pub indexer_node_json_rpc_url: Option<String>,
     #[clap(long)]
     pub derive_secret: Option<u64>,
+    /// Restore the wallet from seed words before starting. The words are read from a prompt that does not echo the
+    /// input. The wallet must not have any accounts.
+    #[clap(long)]
+    pub restore_seed_words: bool,
+    /// Print the wallet seed words to the terminal and exit
+    #[clap(long)]
+    pub print_seed_words: bool,
 }
 
 impl Cli {
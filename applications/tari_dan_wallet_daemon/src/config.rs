@@ -70,6 +70,9 @@ pub struct WalletDaemonConfig {
     /// utility. If this is not set, the value lookup table will be generated on the fly which will have a large
     /// performance cost when brute forcing high-value outputs.
     pub value_lookup_table_file: Option<PathBuf>,
+    /// The percentage added to the fee estimated by a dry run when the wallet sets the max fee of a transaction. This
+    /// allows for state changes between the dry run and the transaction being executed.
+    pub fee_estimate_margin_percentage: u32,
 }
 
 impl Default for WalletDaemonConfig {
@@ -85,6 +88,7 @@ impl Default for WalletDaemonConfig {
             jwt_secret_key: Some(create_secret()),
             http_ui_address: Some("127.0.0.1:5100".parse().unwrap()),
             value_lookup_table_file: None,
+            fee_estimate_margin_percentage: 20,
         }
     }
 }
@@ -17,7 +17,7 @@ use tari_dan_common_types::{optional::Optional, SubstateRequirement};
 use tari_dan_wallet_crypto::ConfidentialProofStatement;
 use tari_dan_wallet_sdk::{
     apis::{confidential_transfer::TransferParams, jwt::JrpcPermission, key_manager, substate::ValidatorScanResult},
-    models::NewAccountInfo,
+    models::{Account, NewAccountInfo},
     storage::WalletStore,
     DanWalletSdk,
 };
@@ -29,14 +29,15 @@ use tari_engine_types::{
     substate::{Substate, SubstateId},
 };
 use tari_key_manager::key_manager::DerivedKey;
-use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
+use tari_template_builtin::{ACCOUNT_TEMPLATE_ADDRESS, MULTISIG_ACCOUNT_TEMPLATE_ADDRESS};
 use tari_template_lib::{
     args,
     constants::{XTR_FAUCET_COMPONENT_ADDRESS, XTR_FAUCET_VAULT_ADDRESS},
-    models::{Amount, UnclaimedConfidentialOutputAddress},
+    crypto::RistrettoPublicKeyBytes,
+    models::{Amount, ComponentAddress, NonFungibleAddress, UnclaimedConfidentialOutputAddress},
     prelude::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
 };
-use tari_transaction::Transaction;
+use tari_transaction::{Transaction, TransactionBuilder};
 use tari_wallet_daemon_client::{
     types::{
         AccountGetDefaultRequest,
@@ -45,16 +46,28 @@ use tari_wallet_daemon_client::{
         AccountInfo,
         AccountSetDefaultRequest,
         AccountSetDefaultResponse,
+        AccountsApproveRecoveryRequest,
+        AccountsCancelRecoveryRequest,
+        AccountsCompleteRecoveryRequest,
         AccountsCreateFreeTestCoinsRequest,
         AccountsCreateFreeTestCoinsResponse,
+        AccountsCreateMultisigRequest,
+        AccountsCreateMultisigResponse,
         AccountsCreateRequest,
         AccountsCreateResponse,
         AccountsGetBalancesRequest,
         AccountsGetBalancesResponse,
+        AccountsInitiateRecoveryRequest,
+        AccountsInvokeMultisigRequest,
+        AccountsInvokeMultisigResponse,
         AccountsInvokeRequest,
         AccountsInvokeResponse,
         AccountsListRequest,
         AccountsListResponse,
+        AccountsRecoveryResponse,
+        AccountsRestoreRequest,
+        AccountsRestoreResponse,
+        AccountsSetGuardiansRequest,
         AccountsTransferRequest,
         AccountsTransferResponse,
         BalanceEntry,
@@ -249,6 +262,428 @@ pub async fn handle_invoke(
     })
 }
 
+pub async fn handle_create_multisig(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsCreateMultisigRequest,
+) -> Result<AccountsCreateMultisigResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    if req.threshold == 0 || req.threshold as usize > req.owner_public_keys.len() {
+        return Err(invalid_params(
+            "threshold",
+            Some(format!(
+                "must be between 1 and the number of owners ({})",
+                req.owner_public_keys.len()
+            )),
+        ));
+    }
+
+    let fee_account = get_account_or_default(req.fee_account, &sdk.accounts_api())?;
+    let signing_key = sdk
+        .key_manager_api()
+        .derive_key(key_manager::TRANSACTION_BRANCH, fee_account.key_index)?;
+    let inputs = sdk
+        .substate_api()
+        .locate_dependent_substates(&[fee_account.address.clone()])
+        .await?;
+
+    // The multisig account is added to the wallet if one of the wallet keys is an owner
+    let owner_key_index = req.owner_key_id.unwrap_or(fee_account.key_index);
+    let owner_key = sdk
+        .key_manager_api()
+        .derive_key(key_manager::TRANSACTION_BRANCH, owner_key_index)?;
+    let is_owner = req
+        .owner_public_keys
+        .contains(&PublicKey::from_secret_key(&owner_key.key));
+    if req.owner_key_id.is_some() && !is_owner {
+        return Err(invalid_params(
+            "owner_key_id",
+            Some(format!("key {} is not one of the owners", owner_key_index)),
+        ));
+    }
+    if let Some(name) = req.account_name.as_ref() {
+        if sdk.accounts_api().get_account_by_name(name).optional()?.is_some() {
+            return Err(anyhow!("Account name '{}' already exists", name));
+        }
+    }
+
+    info!(
+        target: LOG_TARGET,
+        "Creating {}-of-{} multisig account. Fees are paid using account '{}' {}",
+        req.threshold,
+        req.owner_public_keys.len(),
+        fee_account.name.as_deref().unwrap_or("<None>"),
+        fee_account.address
+    );
+
+    let transaction = Transaction::builder()
+        .fee_transaction_pay_from_component(
+            fee_account.address.as_component_address().unwrap(),
+            req.max_fee.unwrap_or(DEFAULT_FEE),
+        )
+        .create_multisig_account(req.owner_public_keys, req.threshold)
+        .with_inputs(inputs)
+        .sign(&signing_key.key)
+        .build();
+
+    let mut events = context.notifier().subscribe();
+    let tx_id = context
+        .transaction_service()
+        .submit_transaction(transaction, vec![])
+        .await?;
+
+    let event = wait_for_result(&mut events, tx_id).await?;
+    if let Some(reject) = event.finalize.result.reject() {
+        return Err(anyhow!("Create multisig account transaction rejected: {}", reject));
+    }
+    if let Some(reason) = event.finalize.reject() {
+        return Err(anyhow!("Create multisig account transaction failed: {}", reason));
+    }
+
+    let address = event
+        .finalize
+        .result
+        .accept()
+        .unwrap()
+        .up_iter()
+        .find(|(_, v)| {
+            v.version() == 0 &&
+                v.substate_value()
+                    .component()
+                    .is_some_and(|c| c.template_address == MULTISIG_ACCOUNT_TEMPLATE_ADDRESS)
+        })
+        .and_then(|(a, _)| a.as_component_address())
+        .ok_or_else(|| anyhow!("Finalize result did not UP any new multisig account component"))?;
+
+    if is_owner {
+        let account_address = SubstateId::from(address);
+        sdk.accounts_api()
+            .add_account(req.account_name.as_deref(), &account_address, owner_key_index, false)?;
+        context.account_monitor().refresh_account(account_address).await?;
+    }
+
+    Ok(AccountsCreateMultisigResponse {
+        address,
+        result: event.finalize,
+    })
+}
+
+pub async fn handle_invoke_multisig(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsInvokeMultisigRequest,
+) -> Result<AccountsInvokeMultisigResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    // The owner account signs the transaction so that the multisig account can identify the proposer/approver
+    let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+    let signing_key = sdk
+        .key_manager_api()
+        .derive_key(key_manager::TRANSACTION_BRANCH, account.key_index)?;
+
+    let inputs = sdk
+        .substate_api()
+        .locate_dependent_substates(&[account.address.clone(), req.multisig_address.into()])
+        .await?;
+
+    let transaction = Transaction::builder()
+        .fee_transaction_pay_from_component(
+            account.address.as_component_address().unwrap(),
+            req.max_fee.unwrap_or(DEFAULT_FEE),
+        )
+        .call_method(req.multisig_address, &req.method, req.args)
+        .with_inputs(inputs)
+        .sign(&signing_key.key)
+        .build();
+
+    let mut events = context.notifier().subscribe();
+    let tx_id = context
+        .transaction_service()
+        .submit_transaction(transaction, vec![])
+        .await?;
+
+    let mut finalized = wait_for_result(&mut events, tx_id).await?;
+    if let Some(reject) = finalized.finalize.result.reject() {
+        return Err(anyhow!("Fee transaction rejected: {}", reject));
+    }
+    if let Some(reject) = finalized.finalize.reject() {
+        return Err(anyhow!("Transaction rejected: {}", reject));
+    }
+
+    Ok(AccountsInvokeMultisigResponse {
+        result: finalized.finalize.execution_results.pop(),
+    })
+}
+
+pub async fn handle_set_guardians(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsSetGuardiansRequest,
+) -> Result<AccountsRecoveryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    if req.threshold == 0 || req.threshold as usize > req.guardians.len() {
+        return Err(invalid_params(
+            "threshold",
+            Some(format!(
+                "must be between 1 and the number of guardians ({})",
+                req.guardians.len()
+            )),
+        ));
+    }
+
+    let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+    let account_address = account.address.as_component_address().unwrap();
+    info!(
+        target: LOG_TARGET,
+        "Setting {}-of-{} guardians with a recovery delay of {} epochs on account {}",
+        req.threshold,
+        req.guardians.len(),
+        req.recovery_delay_epochs,
+        account.address
+    );
+
+    let builder = Transaction::builder()
+        .fee_transaction_pay_from_component(account_address, req.max_fee.unwrap_or(DEFAULT_FEE))
+        .call_method(account_address, "set_guardians", args![
+            req.guardians,
+            req.threshold,
+            req.recovery_delay_epochs
+        ]);
+    submit_recovery_transaction(context, &account, builder, &[]).await
+}
+
+pub async fn handle_initiate_recovery(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsInitiateRecoveryRequest,
+) -> Result<AccountsRecoveryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    let guardian_account = get_account_or_default(req.guardian_account, &sdk.accounts_api())?;
+    let guardian_address = guardian_account.address.as_component_address().unwrap();
+    let new_owner_key = RistrettoPublicKeyBytes::from_bytes(req.new_owner_public_key.as_bytes())
+        .expect("RistrettoPublicKeyBytes::from_bytes is infallible for a valid public key");
+    info!(
+        target: LOG_TARGET,
+        "Initiating recovery of account {} to new owner {} as guardian {}",
+        req.account_address,
+        req.new_owner_public_key,
+        guardian_account.address
+    );
+
+    let builder =
+        Transaction::builder().fee_transaction_pay_from_component(guardian_address, req.max_fee.unwrap_or(DEFAULT_FEE));
+    let builder = match req.guardian_badge {
+        Some(badge) => with_guardian_badge_proof(builder, guardian_address, &badge).call_method(
+            req.account_address,
+            "initiate_recovery_with_proof",
+            args![new_owner_key, Workspace(GUARDIAN_PROOF_LABEL)],
+        ),
+        None => builder.call_method(req.account_address, "initiate_recovery", args![new_owner_key]),
+    };
+    submit_recovery_transaction(context, &guardian_account, builder, &[req.account_address.into()]).await
+}
+
+pub async fn handle_approve_recovery(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsApproveRecoveryRequest,
+) -> Result<AccountsRecoveryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    let guardian_account = get_account_or_default(req.guardian_account, &sdk.accounts_api())?;
+    let guardian_address = guardian_account.address.as_component_address().unwrap();
+    info!(
+        target: LOG_TARGET,
+        "Approving recovery of account {} as guardian {}", req.account_address, guardian_account.address
+    );
+
+    let builder =
+        Transaction::builder().fee_transaction_pay_from_component(guardian_address, req.max_fee.unwrap_or(DEFAULT_FEE));
+    let builder = match req.guardian_badge {
+        Some(badge) => with_guardian_badge_proof(builder, guardian_address, &badge).call_method(
+            req.account_address,
+            "approve_recovery_with_proof",
+            args![Workspace(GUARDIAN_PROOF_LABEL)],
+        ),
+        None => builder.call_method(req.account_address, "approve_recovery", args![]),
+    };
+    submit_recovery_transaction(context, &guardian_account, builder, &[req.account_address.into()]).await
+}
+
+pub async fn handle_cancel_recovery(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsCancelRecoveryRequest,
+) -> Result<AccountsRecoveryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    let account = get_account_or_default(req.account, &sdk.accounts_api())?;
+    let account_address = account.address.as_component_address().unwrap();
+    info!(target: LOG_TARGET, "Cancelling recovery of account {}", account.address);
+
+    let builder = Transaction::builder()
+        .fee_transaction_pay_from_component(account_address, req.max_fee.unwrap_or(DEFAULT_FEE))
+        .call_method(account_address, "cancel_recovery", args![]);
+    submit_recovery_transaction(context, &account, builder, &[]).await
+}
+
+pub async fn handle_complete_recovery(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsCompleteRecoveryRequest,
+) -> Result<AccountsRecoveryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    if let Some(name) = req.account_name.as_ref() {
+        if sdk.accounts_api().get_account_by_name(name).optional()?.is_some() {
+            return Err(anyhow!("Account name '{}' already exists", name));
+        }
+    }
+
+    let fee_account = get_account_or_default(req.fee_account, &sdk.accounts_api())?;
+    info!(
+        target: LOG_TARGET,
+        "Completing recovery of account {}. Fees are paid using account {}", req.account_address, fee_account.address
+    );
+
+    let builder = Transaction::builder()
+        .fee_transaction_pay_from_component(
+            fee_account.address.as_component_address().unwrap(),
+            req.max_fee.unwrap_or(DEFAULT_FEE),
+        )
+        .call_method(req.account_address, "complete_recovery", args![]);
+    let response = submit_recovery_transaction(context, &fee_account, builder, &[req.account_address.into()]).await?;
+
+    if let Some(key_id) = req.new_owner_key_id {
+        let owner_key = sdk
+            .key_manager_api()
+            .derive_key(key_manager::TRANSACTION_BRANCH, key_id)?;
+        let owner_pk = PublicKey::from_secret_key(&owner_key.key);
+        let owner_pk_bytes = RistrettoPublicKeyBytes::from_bytes(owner_pk.as_bytes())
+            .expect("RistrettoPublicKeyBytes::from_bytes is infallible for a valid public key");
+        let expected_owner = NonFungibleAddress::from_public_key(owner_pk_bytes).to_string();
+        let new_owner = response
+            .result
+            .events
+            .iter()
+            .find(|e| e.topic() == "complete_recovery")
+            .and_then(|e| e.get_payload("new_owner"));
+        if new_owner.as_deref() != Some(expected_owner.as_str()) {
+            return Err(anyhow!(
+                "Account {} was recovered to {}, which is not owned by key {}",
+                req.account_address,
+                new_owner.as_deref().unwrap_or("<unknown>"),
+                key_id
+            ));
+        }
+
+        let account_address = SubstateId::from(req.account_address);
+        sdk.accounts_api()
+            .add_account(req.account_name.as_deref(), &account_address, key_id, false)?;
+        context.account_monitor().refresh_account(account_address).await?;
+    }
+
+    Ok(response)
+}
+
+/// The default number of consecutive unused keys after which account discovery stops
+const DEFAULT_RESTORE_GAP_LIMIT: u64 = 20;
+
+pub async fn handle_restore(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: AccountsRestoreRequest,
+) -> Result<AccountsRestoreResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api().check_auth(token, &[JrpcPermission::Admin])?;
+
+    let gap_limit = req.gap_limit.unwrap_or(DEFAULT_RESTORE_GAP_LIMIT);
+    let accounts = context.account_monitor().restore_accounts(gap_limit).await?;
+    info!(
+        target: LOG_TARGET,
+        "Restored {} account(s) from the wallet seed",
+        accounts.len()
+    );
+
+    let km = sdk.key_manager_api();
+    let accounts = accounts
+        .into_iter()
+        .map(|a| {
+            let key = km.derive_key(key_manager::TRANSACTION_BRANCH, a.key_index)?;
+            let pk = PublicKey::from_secret_key(&key.key);
+            Ok(AccountInfo {
+                account: a,
+                public_key: pk,
+            })
+        })
+        .collect::<Result<_, anyhow::Error>>()?;
+
+    Ok(AccountsRestoreResponse { accounts })
+}
+
+const GUARDIAN_PROOF_LABEL: &str = "guardian_proof";
+
+/// Adds instructions that put a proof of the guardian badge held by the guardian account on the workspace
+fn with_guardian_badge_proof(
+    builder: TransactionBuilder,
+    guardian_account: ComponentAddress,
+    badge: &NonFungibleAddress,
+) -> TransactionBuilder {
+    builder
+        .call_method(guardian_account, "create_proof_by_non_fungible_ids", args![
+            *badge.resource_address(),
+            vec![badge.id().clone()]
+        ])
+        .put_last_instruction_output_on_workspace(GUARDIAN_PROOF_LABEL)
+}
+
+/// Signs the transaction with the account's key, submits it and waits for it to be finalized
+async fn submit_recovery_transaction(
+    context: &HandlerContext,
+    signing_account: &Account,
+    builder: TransactionBuilder,
+    other_substates: &[SubstateId],
+) -> Result<AccountsRecoveryResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    let signing_key = sdk
+        .key_manager_api()
+        .derive_key(key_manager::TRANSACTION_BRANCH, signing_account.key_index)?;
+    let mut substates = vec![signing_account.address.clone()];
+    substates.extend_from_slice(other_substates);
+    let inputs = sdk.substate_api().locate_dependent_substates(&substates).await?;
+
+    let transaction = builder.with_inputs(inputs).sign(&signing_key.key).build();
+
+    let mut events = context.notifier().subscribe();
+    let transaction_id = context
+        .transaction_service()
+        .submit_transaction(transaction, vec![])
+        .await?;
+
+    let finalized = wait_for_result(&mut events, transaction_id).await?;
+    if let Some(reject) = finalized.finalize.result.reject() {
+        return Err(anyhow!("Fee transaction rejected: {}", reject));
+    }
+    if let Some(reject) = finalized.finalize.reject() {
+        return Err(anyhow!("Transaction rejected: {}", reject));
+    }
+
+    Ok(AccountsRecoveryResponse {
+        transaction_id,
+        result: finalized.finalize,
+    })
+}
+
 pub async fn handle_get_balances(
     context: &HandlerContext,
     token: Option<String>,
@@ -274,6 +709,7 @@ pub async fn handle_get_balances(
             balance: vault.revealed_balance,
             resource_type: vault.resource_type,
             confidential_balance: vault.confidential_balance,
+            time_locked_balance: vault.time_locked_balance,
             token_symbol: vault.token_symbol,
         })
     }
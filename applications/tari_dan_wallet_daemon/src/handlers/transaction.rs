@@ -10,7 +10,7 @@ use tari_dan_common_types::{optional::Optional, Epoch, SubstateRequirement};
 use tari_dan_wallet_sdk::apis::{jwt::JrpcPermission, key_manager};
 use tari_engine_types::{indexed_value::IndexedValue, instruction::Instruction, substate::SubstateId};
 use tari_template_lib::{args, args::Arg, models::Amount};
-use tari_transaction::Transaction;
+use tari_transaction::{Transaction, UnsignedTransaction};
 use tari_wallet_daemon_client::types::{
     AccountGetRequest,
     AccountGetResponse,
@@ -21,17 +21,21 @@ use tari_wallet_daemon_client::types::{
     TransactionGetResponse,
     TransactionGetResultRequest,
     TransactionGetResultResponse,
+    TransactionResolveInputsRequest,
+    TransactionResolveInputsResponse,
     TransactionSubmitDryRunRequest,
     TransactionSubmitDryRunResponse,
     TransactionSubmitRequest,
     TransactionSubmitResponse,
+    TransactionSubmitSignedRequest,
+    TransactionSubmitSignedResponse,
     TransactionWaitResultRequest,
     TransactionWaitResultResponse,
 };
 use tokio::time;
 
 use super::{accounts, context::HandlerContext};
-use crate::{handlers::HandlerError, services::WalletEvent};
+use crate::{handlers::HandlerError, services::WalletEvent, DEFAULT_FEE};
 
 const LOG_TARGET: &str = "tari::dan::wallet_daemon::handlers::transaction";
 
@@ -62,21 +66,52 @@ pub async fn handle_submit_instruction(
         name_or_address: req.fee_account,
     })
     .await?;
+    let fee_account_address = fee_account.address.as_component_address().unwrap();
+    let detect_inputs = req.override_inputs.unwrap_or_default();
 
-    let transaction = builder
-        .fee_transaction_pay_from_component(
-            fee_account.address.as_component_address().unwrap(),
-            req.max_fee.try_into()?,
-        )
+    let builder = builder
         .with_min_epoch(req.min_epoch.map(Epoch))
-        .with_max_epoch(req.max_epoch.map(Epoch))
+        .with_max_epoch(req.max_epoch.map(Epoch));
+
+    let max_fee = match req.max_fee {
+        Some(max_fee) => Amount::try_from(max_fee)?,
+        None => {
+            // Estimate the fee by dry-running the transaction with the default fee
+            let transaction = builder
+                .clone()
+                .fee_transaction_pay_from_component(fee_account_address, DEFAULT_FEE)
+                .build_unsigned_transaction();
+            let dry_run = handle_submit_dry_run(context, token.clone(), TransactionSubmitDryRunRequest {
+                transaction,
+                signing_key_index: Some(fee_account.key_index),
+                autofill_inputs: vec![],
+                detect_inputs,
+                proof_ids: vec![],
+            })
+            .await?;
+            // A rejected dry run does not give a usable fee estimate and the transaction would fail anyway
+            if let Some(reason) = dry_run.result.finalize.full_reject() {
+                return Err(anyhow!("Transaction dry run was rejected: {}", reason));
+            }
+            info!(
+                target: LOG_TARGET,
+                "Estimated fee {} (max fee {})",
+                dry_run.fee_breakdown.total_fees_charged,
+                dry_run.suggested_max_fee
+            );
+            dry_run.suggested_max_fee
+        },
+    };
+
+    let transaction = builder
+        .fee_transaction_pay_from_component(fee_account_address, max_fee)
         .build_unsigned_transaction();
 
     let request = TransactionSubmitRequest {
         transaction,
         signing_key_index: Some(fee_account.key_index),
         autofill_inputs: vec![],
-        detect_inputs: req.override_inputs.unwrap_or_default(),
+        detect_inputs,
         proof_ids: vec![],
     };
     handle_submit(context, token, request).await
@@ -99,14 +134,7 @@ pub async fn handle_submit(
     let autofill_inputs = req.autofill_inputs;
     let detected_inputs = if req.detect_inputs {
         // If we are not overriding inputs, we will use inputs that we know about in the local substate id db
-        let mut substates = get_referenced_substate_addresses(&req.transaction.instructions)?;
-        substates.extend(get_referenced_substate_addresses(&req.transaction.fee_instructions)?);
-        let substates = substates.into_iter().collect::<Vec<_>>();
-        let loaded_substates = sdk.substate_api().locate_dependent_substates(&substates).await?;
-        loaded_substates
-            .into_iter()
-            .chain(substates.into_iter().map(SubstateRequirement::unversioned))
-            .collect()
+        detect_inputs(context, &req.transaction).await?
     } else {
         vec![]
     };
@@ -143,6 +171,56 @@ pub async fn handle_submit(
     Ok(TransactionSubmitResponse { transaction_id })
 }
 
+pub async fn handle_resolve_inputs(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: TransactionResolveInputsRequest,
+) -> Result<TransactionResolveInputsResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api()
+        .check_auth(token, &[JrpcPermission::TransactionSend(None)])?;
+
+    let inputs = detect_inputs(context, &req.transaction).await?;
+    Ok(TransactionResolveInputsResponse { inputs })
+}
+
+pub async fn handle_submit_signed(
+    context: &HandlerContext,
+    token: Option<String>,
+    req: TransactionSubmitSignedRequest,
+) -> Result<TransactionSubmitSignedResponse, anyhow::Error> {
+    let sdk = context.wallet_sdk();
+    sdk.jwt_api()
+        .check_auth(token, &[JrpcPermission::TransactionSend(None)])?;
+
+    let transaction = req.transaction;
+    if !transaction.check_id() {
+        return Err(anyhow!(
+            "Transaction id {} does not match the transaction",
+            transaction.id()
+        ));
+    }
+    if !transaction.verify_all_signatures() {
+        return Err(anyhow!(
+            "Transaction {} has missing or invalid signatures",
+            transaction.id()
+        ));
+    }
+
+    info!(
+        target: LOG_TARGET,
+        "Submitted signed transaction with hash {}",
+        transaction.hash()
+    );
+
+    let transaction_id = context
+        .transaction_service()
+        .submit_transaction(transaction, req.autofill_inputs)
+        .await?;
+
+    Ok(TransactionSubmitSignedResponse { transaction_id })
+}
+
 pub async fn handle_submit_dry_run(
     context: &HandlerContext,
     token: Option<String>,
@@ -191,14 +269,27 @@ pub async fn handle_submit_dry_run(
         .await?;
 
     let json_result = json_encoding::encode_finalize_result_into_json(&exec_result.finalize)?;
+    let fee_breakdown = exec_result.finalize.fee_receipt.to_cost_breakdown();
+    let suggested_max_fee = add_fee_margin(
+        fee_breakdown.total_fees_charged,
+        context.config().fee_estimate_margin_percentage,
+    );
 
     Ok(TransactionSubmitDryRunResponse {
         transaction_id: exec_result.finalize.transaction_hash.into_array().into(),
         result: exec_result,
         json_result,
+        fee_breakdown,
+        suggested_max_fee,
     })
 }
 
+/// Adds a margin (as a percentage of the fee) to a fee estimate
+fn add_fee_margin(fee: Amount, margin_percentage: u32) -> Amount {
+    let margin = fee.value().saturating_mul(i64::from(margin_percentage)) / 100;
+    fee.saturating_add(Amount::new(margin))
+}
+
 pub async fn handle_get(
     context: &HandlerContext,
     token: Option<String>,
@@ -358,6 +449,26 @@ pub async fn handle_wait_result(
     }
 }
 
+/// Returns the versioned inputs that the wallet knows about for the substates referenced in the transaction, followed
+/// by the referenced substates without a version
+async fn detect_inputs(
+    context: &HandlerContext,
+    transaction: &UnsignedTransaction,
+) -> anyhow::Result<Vec<SubstateRequirement>> {
+    let mut substates = get_referenced_substate_addresses(&transaction.instructions)?;
+    substates.extend(get_referenced_substate_addresses(&transaction.fee_instructions)?);
+    let substates = substates.into_iter().collect::<Vec<_>>();
+    let loaded_substates = context
+        .wallet_sdk()
+        .substate_api()
+        .locate_dependent_substates(&substates)
+        .await?;
+    Ok(loaded_substates
+        .into_iter()
+        .chain(substates.into_iter().map(SubstateRequirement::unversioned))
+        .collect())
+}
+
 fn get_referenced_substate_addresses(instructions: &[Instruction]) -> anyhow::Result<HashSet<SubstateId>> {
     let mut substates = HashSet::new();
     for instruction in instructions {
@@ -5,7 +5,8 @@ use std::sync::{Arc, Mutex};
 
 use axum::async_trait;
 use reqwest::{IntoUrl, Url};
-use tari_dan_common_types::{optional::IsNotFoundError, substate_type::SubstateType, SubstateRequirement};
+use tari_common_types::types::PublicKey;
+use tari_dan_common_types::{optional::IsNotFoundError, substate_type::SubstateType, Epoch, SubstateRequirement};
 use tari_dan_wallet_sdk::network::{
     SubstateListItem,
     SubstateListResult,
@@ -19,6 +20,7 @@ use tari_indexer_client::{
     error::IndexerClientError,
     json_rpc_client::IndexerJsonRpcClient,
     types::{
+        GetAccountsByOwnerRequest,
         GetSubstateRequest,
         GetTransactionResultRequest,
         IndexerTransactionFinalizedResult,
@@ -27,7 +29,7 @@ use tari_indexer_client::{
         SubmitTransactionRequest,
     },
 };
-use tari_template_lib::models::TemplateAddress;
+use tari_template_lib::models::{ComponentAddress, TemplateAddress};
 use tari_transaction::{Transaction, TransactionId};
 use url::ParseError;
 
@@ -189,6 +191,30 @@ impl WalletNetworkInterface for IndexerJsonRpcNetworkInterface {
 
         Ok(resp.definition)
     }
+
+    async fn get_current_epoch(&self) -> Result<Epoch, Self::Error> {
+        let mut client = self.get_client()?;
+        let resp = client.get_epoch_manager_stats().await?;
+        Ok(resp.current_epoch)
+    }
+
+    async fn get_accounts_by_owner(
+        &self,
+        owner: &PublicKey,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ComponentAddress>, Self::Error> {
+        let mut client = self.get_client()?;
+        let resp = client
+            .get_accounts_by_owner(GetAccountsByOwnerRequest {
+                owner: owner.clone(),
+                offset,
+                limit,
+            })
+            .await?;
+
+        Ok(resp.accounts.into_iter().map(|a| a.component_address).collect())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -124,6 +124,8 @@ async fn handler(
             "submit_instruction" => call_handler(context, value, token, transaction::handle_submit_instruction).await,
             "submit" => call_handler(context, value, token, transaction::handle_submit).await,
             "submit_dry_run" => call_handler(context, value, token, transaction::handle_submit_dry_run).await,
+            "resolve_inputs" => call_handler(context, value, token, transaction::handle_resolve_inputs).await,
+            "submit_signed" => call_handler(context, value, token, transaction::handle_submit_signed).await,
             "get" => call_handler(context, value, token, transaction::handle_get).await,
             "get_result" => call_handler(context, value, token, transaction::handle_get_result).await,
             "wait_result" => call_handler(context, value, token, transaction::handle_wait_result).await,
@@ -137,6 +139,14 @@ async fn handler(
             "list" => call_handler(context, value, token, accounts::handle_list).await,
             "get_balances" => call_handler(context, value, token, accounts::handle_get_balances).await,
             "invoke" => call_handler(context, value, token, accounts::handle_invoke).await,
+            "create_multisig" => call_handler(context, value, token, accounts::handle_create_multisig).await,
+            "invoke_multisig" => call_handler(context, value, token, accounts::handle_invoke_multisig).await,
+            "set_guardians" => call_handler(context, value, token, accounts::handle_set_guardians).await,
+            "initiate_recovery" => call_handler(context, value, token, accounts::handle_initiate_recovery).await,
+            "approve_recovery" => call_handler(context, value, token, accounts::handle_approve_recovery).await,
+            "cancel_recovery" => call_handler(context, value, token, accounts::handle_cancel_recovery).await,
+            "complete_recovery" => call_handler(context, value, token, accounts::handle_complete_recovery).await,
+            "restore" => call_handler(context, value, token, accounts::handle_restore).await,
             "get" => call_handler(context, value, token, accounts::handle_get).await,
             "get_default" => call_handler(context, value, token, accounts::handle_get_default).await,
             "transfer" => call_handler(context, value, token, accounts::handle_transfer).await,
@@ -43,6 +43,7 @@ use tari_dan_wallet_sdk::{
     WalletSdkConfig,
 };
 use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
+use tari_key_manager::SeedWords;
 use tari_shutdown::ShutdownSignal;
 use tari_template_lib::models::Amount;
 use tokio::task;
@@ -124,8 +125,7 @@ pub async fn run_tari_dan_wallet_daemon(
 pub fn initialize_wallet_sdk(
     config: &ApplicationConfig,
 ) -> anyhow::Result<DanWalletSdk<SqliteWalletStore, IndexerJsonRpcNetworkInterface>> {
-    let store = SqliteWalletStore::try_open(config.common.base_path.join("data/wallet.sqlite"))?;
-    store.run_migrations()?;
+    let store = open_wallet_store(config)?;
 
     let sdk_config = WalletSdkConfig {
         // TODO: Configure
@@ -143,3 +143,17 @@ pub fn initialize_wallet_sdk(
     let wallet_sdk = DanWalletSdk::initialize(store, indexer, sdk_config)?;
     Ok(wallet_sdk)
 }
+
+/// Sets the wallet seed to the seed encoded in the seed words. The wallet must not have any accounts. Accounts owned by
+/// the seed are discovered by calling `accounts.restore` once the daemon is running.
+pub fn restore_wallet_seed(config: &ApplicationConfig, seed_words: &SeedWords) -> anyhow::Result<()> {
+    let store = open_wallet_store(config)?;
+    DanWalletSdk::<_, IndexerJsonRpcNetworkInterface>::restore_cipher_seed(&store, seed_words)?;
+    Ok(())
+}
+
+fn open_wallet_store(config: &ApplicationConfig) -> anyhow::Result<SqliteWalletStore> {
+    let store = SqliteWalletStore::try_open(config.common.base_path.join("data/wallet.sqlite"))?;
+    store.run_migrations()?;
+    Ok(store)
+}
@@ -20,13 +20,27 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{fs, panic, process};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    panic,
+    process,
+    str::FromStr,
+};
 
+use anyhow::anyhow;
 use tari_common::initialize_logging;
 use tari_crypto::{keys::PublicKey, ristretto::RistrettoPublicKey};
 use tari_dan_app_utilities::configuration::load_configuration;
-use tari_dan_wallet_daemon::{cli::Cli, config::ApplicationConfig, initialize_wallet_sdk, run_tari_dan_wallet_daemon};
+use tari_dan_wallet_daemon::{
+    cli::Cli,
+    config::ApplicationConfig,
+    initialize_wallet_sdk,
+    restore_wallet_seed,
+    run_tari_dan_wallet_daemon,
+};
 use tari_dan_wallet_sdk::apis::key_manager;
+use tari_key_manager::SeedWords;
 use tari_shutdown::Shutdown;
 
 #[tokio::main]
@@ -45,6 +59,13 @@ async fn main() -> Result<(), anyhow::Error> {
     let cfg = load_configuration(config_path, true, &cli, cli.common.network)?;
     let config = ApplicationConfig::load_from(&cfg)?;
 
+    if cli.restore_seed_words {
+        let seed_words = rpassword::prompt_password_stdout("Seed words: ")?;
+        let seed_words = SeedWords::from_str(seed_words.trim())?;
+        restore_wallet_seed(&config, &seed_words)?;
+        println!("Wallet seed restored. Call accounts.restore to discover the accounts owned by the seed.");
+    }
+
     if let Some(index) = cli.derive_secret {
         let sdk = initialize_wallet_sdk(&config)?;
         let secret = sdk
@@ -55,6 +76,19 @@ async fn main() -> Result<(), anyhow::Error> {
         return Ok(());
     }
 
+    if cli.print_seed_words {
+        // Don't write the seed words to a file or pipe where they may be persisted
+        if !io::stdout().is_terminal() {
+            return Err(anyhow!(
+                "Refusing to print the seed words because stdout is not a terminal"
+            ));
+        }
+        let sdk = initialize_wallet_sdk(&config)?;
+        let seed_words = sdk.get_seed_words()?;
+        println!("Seed words: {}", seed_words.join(" ").reveal());
+        return Ok(());
+    }
+
     // Remove the file if it was left behind by a previous run
     let _file = fs::remove_file(config.common.base_path.join("pid"));
 
@@ -10,10 +10,11 @@ use tari_dan_wallet_sdk::{
         accounts::AccountsApiError,
         confidential_outputs::ConfidentialOutputsApiError,
         non_fungible_tokens::NonFungibleTokensApiError,
+        restore::RestoreApiError,
         substate::{SubstateApiError, ValidatorScanResult},
         transaction::TransactionApiError,
     },
-    models::{NewAccountInfo, NonFungibleToken},
+    models::{Account, NewAccountInfo, NonFungibleToken},
     network::WalletNetworkInterface,
     storage::WalletStore,
     DanWalletSdk,
@@ -26,9 +27,9 @@ use tari_engine_types::{
     vault::Vault,
 };
 use tari_shutdown::ShutdownSignal;
-use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
+use tari_template_builtin::{ACCOUNT_TEMPLATE_ADDRESS, MULTISIG_ACCOUNT_TEMPLATE_ADDRESS};
 use tari_template_lib::{
-    models::{NonFungibleAddress, VaultId},
+    models::{Amount, NonFungibleAddress, VaultId},
     prelude::{NonFungibleId, ResourceAddress},
     resource::TOKEN_SYMBOL,
 };
@@ -113,6 +114,9 @@ where
             AccountMonitorRequest::RefreshAccount { account, reply } => {
                 let _ignore = reply.send(self.refresh_account(&account).await);
             },
+            AccountMonitorRequest::RestoreAccounts { gap_limit, reply } => {
+                let _ignore = reply.send(self.restore_accounts(gap_limit).await);
+            },
         }
     }
 
@@ -147,6 +151,22 @@ where
         Ok(())
     }
 
+    async fn restore_accounts(&self, gap_limit: u64) -> Result<Vec<Account>, AccountMonitorError> {
+        let accounts = self.wallet_sdk.restore_api().discover_accounts(gap_limit).await?;
+        for account in &accounts {
+            info!(
+                target: LOG_TARGET,
+                "👁️‍🗨️ Restoring vaults for account {}", account
+            );
+            // Loads the vaults, NFTs and any confidential outputs that can be decrypted with the account key
+            self.refresh_account(&account.address).await?;
+            self.notify.notify(AccountChangedEvent {
+                account_address: account.address.clone(),
+            });
+        }
+        Ok(accounts)
+    }
+
     async fn refresh_account(&self, account_address: &SubstateId) -> Result<bool, AccountMonitorError> {
         let substate_api = self.wallet_sdk.substate_api();
         let accounts_api = self.wallet_sdk.accounts_api();
@@ -311,9 +331,24 @@ where
                 details: "confidential balance overflowed Amount".to_string(),
             })?;
 
+        let time_locked_balance = if vault.time_locks().is_empty() {
+            Amount::zero()
+        } else {
+            let current_epoch = self
+                .wallet_sdk
+                .get_network_interface()
+                .get_current_epoch()
+                .await
+                .map_err(|e| AccountMonitorError::NetworkInterface(e.to_string()))?;
+            vault.time_locked_balance(current_epoch.as_u64())
+        };
+
         let vault_balance = accounts_api.get_vault_balance(&vault_addr)?;
-        if vault_balance.confidential != confidential_balance || vault_balance.revealed != balance {
-            accounts_api.update_vault_balance(&vault_addr, balance, confidential_balance)?;
+        if vault_balance.confidential != confidential_balance ||
+            vault_balance.revealed != balance ||
+            vault_balance.time_locked != time_locked_balance
+        {
+            accounts_api.update_vault_balance(&vault_addr, balance, confidential_balance, time_locked_balance)?;
             has_changed = true;
         }
 
@@ -563,6 +598,10 @@ enum AccountMonitorRequest {
         account: SubstateId,
         reply: Reply<Result<bool, AccountMonitorError>>,
     },
+    RestoreAccounts {
+        gap_limit: u64,
+        reply: Reply<Result<Vec<Account>, AccountMonitorError>>,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -582,6 +621,19 @@ impl AccountMonitorHandle {
             .map_err(|_| AccountMonitorError::ServiceShutdown)?;
         reply_rx.await.map_err(|_| AccountMonitorError::ServiceShutdown)?
     }
+
+    /// Discovers and adds the accounts owned by keys derived from the wallet seed, and loads their vaults
+    pub async fn restore_accounts(&self, gap_limit: u64) -> Result<Vec<Account>, AccountMonitorError> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(AccountMonitorRequest::RestoreAccounts {
+                gap_limit,
+                reply: reply_tx,
+            })
+            .await
+            .map_err(|_| AccountMonitorError::ServiceShutdown)?;
+        reply_rx.await.map_err(|_| AccountMonitorError::ServiceShutdown)?
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -596,12 +648,16 @@ pub enum AccountMonitorError {
     ConfidentialOutputs(#[from] ConfidentialOutputsApiError),
     #[error("Non Fungibles API error: {0}")]
     NonFungibleTokens(#[from] NonFungibleTokensApiError),
+    #[error("Restore API error: {0}")]
+    Restore(#[from] RestoreApiError),
     #[error("Failed to decode binary value: {0}")]
     DecodeValueFailed(#[from] IndexedValueError),
     #[error("Unexpected substate: {0}")]
     UnexpectedSubstate(String),
     #[error("Monitor service is not running")]
     ServiceShutdown,
+    #[error("Network interface error: {0}")]
+    NetworkInterface(String),
 
     #[error("Expected new account '{account_name}'to be created in transaction {tx_id}")]
     ExpectedNewAccount { tx_id: TransactionId, account_name: String },
@@ -635,6 +691,8 @@ fn find_new_account_address(diff: &SubstateDiff) -> Option<&SubstateId> {
 fn is_account(s: &Substate) -> bool {
     s.substate_value()
         .component()
-        .filter(|c| c.template_address == ACCOUNT_TEMPLATE_ADDRESS)
+        .filter(|c| {
+            c.template_address == ACCOUNT_TEMPLATE_ADDRESS || c.template_address == MULTISIG_ACCOUNT_TEMPLATE_ADDRESS
+        })
         .is_some()
 }
@@ -105,6 +105,7 @@ pub async fn spawn_services(
             },
             reachability_mode: config.indexer.p2p.reachability_mode.into(),
             announce: false,
+            peer_store_path: Some(config.indexer.peer_store_path()),
             ..Default::default()
         },
         seed_peers,
@@ -109,6 +109,10 @@ impl IndexerConfig {
         self.data_dir.join("state.db")
     }
 
+    pub fn peer_store_path(&self) -> PathBuf {
+        self.data_dir.join("peer_store.sqlite")
+    }
+
     pub fn set_base_path<P: AsRef<Path>>(&mut self, base_path: P) {
         if !self.identity_file.is_absolute() {
             self.identity_file = base_path.as_ref().join(&self.identity_file);
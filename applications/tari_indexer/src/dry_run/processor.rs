@@ -24,6 +24,7 @@ use std::{collections::HashMap, sync::Arc};
 
 use log::info;
 use tari_common::configuration::Network;
+use tari_crypto::tari_utilities::epoch_time::EpochTime;
 use tari_dan_app_utilities::{
     template_manager::implementation::TemplateManager,
     transaction_executor::{TariDanTransactionProcessor, TransactionExecutor as _},
@@ -32,7 +33,6 @@ use tari_dan_common_types::{Epoch, PeerAddress, SubstateAddress, SubstateRequire
 use tari_dan_engine::{fees::FeeTable, state_store::new_memory_store};
 use tari_engine_types::{
     commit_result::ExecuteResult,
-    instruction::Instruction,
     substate::{Substate, SubstateId},
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
@@ -42,6 +42,7 @@ use tari_indexer_lib::{
     substate_scanner::SubstateScanner,
     transaction_autofiller::TransactionAutofiller,
 };
+use tari_template_lib::models::BlockInfo;
 use tari_transaction::Transaction;
 use tari_validator_node_rpc::client::{
     SubstateResult,
@@ -134,6 +135,8 @@ where TSubstateCache: SubstateCache + 'static
                 per_byte_storage_cost: 1,
                 per_event_cost: 1,
                 per_log_cost: 1,
+                per_million_wasm_points_cost: 1,
+                per_wasm_memory_page_cost: 1,
             }
         } else {
             FeeTable::zero_rated()
@@ -230,23 +233,18 @@ where TSubstateCache: SubstateCache + 'static
             VirtualSubstateId::CurrentEpoch,
             VirtualSubstate::CurrentEpoch(epoch.as_u64()),
         );
+        // The indexer does not follow consensus blocks, so we estimate the block using the local clock
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentBlock,
+            VirtualSubstate::CurrentBlock(BlockInfo {
+                height: 0,
+                timestamp: EpochTime::now().as_u64(),
+                shard_group_start: 0,
+                shard_group_end: 0,
+            }),
+        );
 
-        let claim_instructions = transaction
-            .instructions()
-            .iter()
-            .chain(transaction.fee_instructions())
-            .filter_map(|instruction| {
-                if let Instruction::ClaimValidatorFees {
-                    epoch,
-                    validator_public_key,
-                } = instruction
-                {
-                    Some((Epoch(*epoch), validator_public_key.clone()))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
+        let claim_instructions = transaction.fee_claims().collect::<Vec<_>>();
         if !claim_instructions.is_empty() {
             for (epoch, public_key) in claim_instructions {
                 let vn = self
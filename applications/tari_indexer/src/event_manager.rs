@@ -20,8 +20,9 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use std::{collections::VecDeque, sync::Arc};
 
+use futures::{stream, Stream};
 use log::*;
 use tari_crypto::tari_utilities::message_format::MessageFormat;
 use tari_dan_app_utilities::substate_file_cache::SubstateFileCache;
@@ -29,29 +30,43 @@ use tari_dan_common_types::PeerAddress;
 use tari_engine_types::{events::Event, substate::SubstateId};
 use tari_epoch_manager::base_layer::EpochManagerHandle;
 use tari_indexer_lib::substate_scanner::SubstateScanner;
-use tari_template_lib::{
-    models::{Metadata, TemplateAddress},
-    Hash,
-};
+use tari_template_lib::models::{Metadata, TemplateAddress};
 use tari_transaction::TransactionId;
 use tari_validator_node_rpc::client::TariValidatorNodeRpcClientFactory;
+use tokio::sync::{broadcast, broadcast::error::RecvError};
 
-use crate::substate_storage_sqlite::{
-    models::events::NewEvent,
-    sqlite_substate_store_factory::{
-        SqliteSubstateStore,
-        SubstateStore,
-        SubstateStoreReadTransaction,
-        SubstateStoreWriteTransaction,
+use crate::{
+    event_scanner::EventFilter,
+    substate_storage_sqlite::{
+        models::events::NewEvent,
+        sqlite_substate_store_factory::{
+            SqliteSubstateStore,
+            SubstateStore,
+            SubstateStoreReadTransaction,
+            SubstateStoreWriteTransaction,
+        },
     },
 };
 
 const LOG_TARGET: &str = "tari::indexer::event_manager";
 
+/// The maximum number of stored events that are loaded at a time when a subscription catches up
+const EVENT_REPLAY_PAGE_SIZE: u32 = 100;
+
+/// An event that has been stored in the database by the event scanner
+#[derive(Debug, Clone)]
+pub struct PersistedEvent {
+    /// The database id of the event. Ids increase in the order that events are stored, so a subscriber can resume
+    /// from the last cursor it received.
+    pub cursor: i32,
+    pub event: Event,
+}
+
 pub struct EventManager {
     substate_store: SqliteSubstateStore,
     substate_scanner:
         Arc<SubstateScanner<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>>,
+    event_publisher: broadcast::Sender<PersistedEvent>,
 }
 
 impl EventManager {
@@ -60,13 +75,50 @@ impl EventManager {
         substate_scanner: Arc<
             SubstateScanner<EpochManagerHandle<PeerAddress>, TariValidatorNodeRpcClientFactory, SubstateFileCache>,
         >,
+        event_publisher: broadcast::Sender<PersistedEvent>,
     ) -> Self {
         Self {
             substate_store,
             substate_scanner,
+            event_publisher,
         }
     }
 
+    /// Streams the events that match the filter as they are stored by the event scanner. If a cursor is given, the
+    /// events stored after the cursor are streamed first, otherwise only events stored after subscribing are
+    /// streamed. The stream ends after the first error, after which the subscriber may resume from the last cursor
+    /// it received.
+    pub fn subscribe_events(
+        &self,
+        filter: EventFilter,
+        after: Option<i32>,
+    ) -> Result<impl Stream<Item = Result<PersistedEvent, anyhow::Error>>, anyhow::Error> {
+        // Subscribe before reading the latest event id so that no events are missed in between
+        let receiver = self.event_publisher.subscribe();
+        let (cursor, is_replaying) = match after {
+            Some(cursor) => (cursor, true),
+            None => {
+                let latest = self.substate_store.with_read_tx(|tx| tx.get_latest_event_id())?;
+                (latest.unwrap_or(0), false)
+            },
+        };
+
+        let state = EventStreamState {
+            substate_store: self.substate_store.clone(),
+            receiver,
+            filter,
+            cursor,
+            is_replaying,
+            pending: VecDeque::new(),
+            is_done: false,
+        };
+
+        Ok(stream::unfold(state, |mut state| async move {
+            let next = state.next_event().await?;
+            Some((next, state))
+        }))
+    }
+
     pub fn save_event_to_db(
         &self,
         substate_id: &SubstateId,
@@ -77,18 +129,30 @@ impl EventManager {
         version: u64,
         timestamp: u64,
     ) -> Result<(), anyhow::Error> {
-        self.substate_store.with_write_tx(|tx| {
+        let cursor = self.substate_store.with_write_tx(|tx| {
             let new_event = NewEvent {
                 substate_id: Some(substate_id.to_string()),
                 template_address: template_address.to_string(),
                 tx_hash: tx_hash.to_string(),
-                topic,
+                topic: topic.clone(),
                 payload: payload.to_json().expect("Failed to convert to JSON"),
                 version: version as i32,
                 timestamp: timestamp as i64,
             };
             tx.save_event(new_event)
         })?;
+
+        // Notify event subscribers once the event is committed. Sending fails if there are no subscribers.
+        let _ignore = self.event_publisher.send(PersistedEvent {
+            cursor,
+            event: Event::new(
+                Some(substate_id.clone()),
+                template_address,
+                tx_hash.into(),
+                topic,
+                payload.clone(),
+            ),
+        });
         Ok(())
     }
 
@@ -208,16 +272,257 @@ impl EventManager {
             .substate_store
             .with_read_tx(|tx| tx.get_events(substate_id, topic, offset, limit))?;
 
-        let mut events = vec![];
+        rows.into_iter().map(Event::try_from).collect()
+    }
+}
+
+struct EventStreamState {
+    substate_store: SqliteSubstateStore,
+    receiver: broadcast::Receiver<PersistedEvent>,
+    filter: EventFilter,
+    /// The id of the last stored event that the subscription has seen, whether or not it matched the filter
+    cursor: i32,
+    /// True if the subscription is loading stored events from the database instead of receiving published events
+    is_replaying: bool,
+    pending: VecDeque<PersistedEvent>,
+    is_done: bool,
+}
+
+impl EventStreamState {
+    async fn next_event(&mut self) -> Option<Result<PersistedEvent, anyhow::Error>> {
+        loop {
+            if self.is_done {
+                return None;
+            }
+
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event));
+            }
+
+            if self.is_replaying {
+                if let Err(err) = self.load_stored_events() {
+                    self.is_done = true;
+                    return Some(Err(err));
+                }
+                continue;
+            }
+
+            match self.receiver.recv().await {
+                Ok(event) => {
+                    // Skip events that have already been loaded from the database
+                    if event.cursor <= self.cursor {
+                        continue;
+                    }
+                    // The event scanner and the event manager publish events after committing them, so events can be
+                    // received out of order. Database writes are serialized, so every event up to this one is
+                    // committed and the events in the gap can be loaded from the database.
+                    if event.cursor > self.cursor + 1 {
+                        debug!(
+                            target: LOG_TARGET,
+                            "Received event {} after event {}. Loading missed events from the database",
+                            event.cursor,
+                            self.cursor
+                        );
+                        self.is_replaying = true;
+                        continue;
+                    }
+                    self.cursor = event.cursor;
+                    if self.filter.matches(&event.event) {
+                        return Some(Ok(event));
+                    }
+                },
+                Err(RecvError::Lagged(num_skipped)) => {
+                    warn!(
+                        target: LOG_TARGET,
+                        "Event subscription lagged by {} events. Loading missed events from the database",
+                        num_skipped
+                    );
+                    self.is_replaying = true;
+                },
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    fn load_stored_events(&mut self) -> Result<(), anyhow::Error> {
+        let cursor = self.cursor;
+        let rows = self
+            .substate_store
+            .with_read_tx(|tx| tx.get_events_after(cursor, EVENT_REPLAY_PAGE_SIZE))?;
+        if rows.len() < EVENT_REPLAY_PAGE_SIZE as usize {
+            self.is_replaying = false;
+        }
+
         for row in rows {
-            let substate_id = row.substate_id.map(|str| SubstateId::from_str(&str)).transpose()?;
-            let template_address = Hash::from_hex(&row.template_address)?;
-            let tx_hash = Hash::from_hex(&row.tx_hash)?;
-            let topic = row.topic;
-            let payload = Metadata::from(serde_json::from_str::<BTreeMap<String, String>>(row.payload.as_str())?);
-            events.push(Event::new(substate_id, template_address, tx_hash, topic, payload));
+            let cursor = row.id;
+            let event = Event::try_from(row)?;
+            self.cursor = cursor;
+            if self.filter.matches(&event) {
+                self.pending.push_back(PersistedEvent { cursor, event });
+            }
         }
 
-        Ok(events)
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tari_template_lib::Hash;
+
+    use super::*;
+
+    struct TestStore {
+        store: SqliteSubstateStore,
+        _temp: tempfile::TempDir,
+    }
+
+    impl TestStore {
+        fn new() -> Self {
+            let temp = tempfile::tempdir().unwrap();
+            let store = SqliteSubstateStore::try_create(temp.path().join("indexer.sqlite")).unwrap();
+            Self { store, _temp: temp }
+        }
+
+        fn save_event(&self, topic: &str) -> PersistedEvent {
+            let event = Event::new(
+                None,
+                TemplateAddress::default(),
+                Hash::default(),
+                topic.to_string(),
+                Metadata::new(),
+            );
+            let cursor = self
+                .store
+                .with_write_tx(|tx| {
+                    tx.save_event(NewEvent {
+                        substate_id: None,
+                        template_address: event.template_address().to_string(),
+                        tx_hash: event.tx_hash().to_string(),
+                        topic: event.topic(),
+                        payload: event.payload().to_json().unwrap(),
+                        version: 0,
+                        timestamp: 0,
+                    })
+                })
+                .unwrap();
+            PersistedEvent { cursor, event }
+        }
+
+        fn stream_state(
+            &self,
+            receiver: broadcast::Receiver<PersistedEvent>,
+            topic: &str,
+            cursor: i32,
+            is_replaying: bool,
+        ) -> EventStreamState {
+            EventStreamState {
+                substate_store: self.store.clone(),
+                receiver,
+                filter: EventFilter {
+                    topic: Some(topic.to_string()),
+                    ..Default::default()
+                },
+                cursor,
+                is_replaying,
+                pending: VecDeque::new(),
+                is_done: false,
+            }
+        }
+    }
+
+    async fn next_cursor(state: &mut EventStreamState) -> i32 {
+        state.next_event().await.unwrap().unwrap().cursor
+    }
+
+    #[tokio::test]
+    async fn it_replays_stored_events_after_the_cursor_then_receives_published_events() {
+        let test = TestStore::new();
+        let (sender, receiver) = broadcast::channel(10);
+        let first = test.save_event("a");
+        let second = test.save_event("a");
+        let _other = test.save_event("b");
+        let third = test.save_event("a");
+
+        let mut state = test.stream_state(receiver, "a", first.cursor, true);
+        assert_eq!(next_cursor(&mut state).await, second.cursor);
+        assert_eq!(next_cursor(&mut state).await, third.cursor);
+        assert!(!state.is_replaying);
+
+        // Events that were already replayed are skipped when they are published
+        let _ignore = sender.send(third.clone());
+        let fourth = test.save_event("a");
+        sender.send(fourth.clone()).unwrap();
+        let event = state.next_event().await.unwrap().unwrap();
+        assert_eq!(event.cursor, fourth.cursor);
+        assert_eq!(event.event, fourth.event);
+    }
+
+    #[tokio::test]
+    async fn it_skips_published_events_that_do_not_match_the_filter() {
+        let test = TestStore::new();
+        let (sender, receiver) = broadcast::channel(10);
+        let mut state = test.stream_state(receiver, "a", 0, false);
+
+        let other = test.save_event("b");
+        sender.send(other.clone()).unwrap();
+        let event = test.save_event("a");
+        sender.send(event.clone()).unwrap();
+
+        assert_eq!(next_cursor(&mut state).await, event.cursor);
+        assert_eq!(state.cursor, event.cursor);
+    }
+
+    #[tokio::test]
+    async fn it_loads_missed_events_from_the_database_when_lagging() {
+        let test = TestStore::new();
+        let (sender, receiver) = broadcast::channel(1);
+        let mut state = test.stream_state(receiver, "a", 0, false);
+
+        let events = (0..3).map(|_| test.save_event("a")).collect::<Vec<_>>();
+        for event in &events {
+            sender.send(event.clone()).unwrap();
+        }
+
+        for event in &events {
+            assert_eq!(next_cursor(&mut state).await, event.cursor);
+        }
+        assert!(!state.is_replaying);
+
+        // The last event is still buffered in the channel and is not returned twice
+        let next = test.save_event("a");
+        sender.send(next.clone()).unwrap();
+        assert_eq!(next_cursor(&mut state).await, next.cursor);
+    }
+
+    #[tokio::test]
+    async fn it_loads_events_from_the_database_when_they_are_published_out_of_order() {
+        let test = TestStore::new();
+        let (sender, receiver) = broadcast::channel(10);
+        let mut state = test.stream_state(receiver, "a", 0, false);
+
+        let first = test.save_event("a");
+        let second = test.save_event("a");
+        // The second event is published before the first, e.g. by another writer
+        sender.send(second.clone()).unwrap();
+        sender.send(first.clone()).unwrap();
+
+        assert_eq!(next_cursor(&mut state).await, first.cursor);
+        assert_eq!(next_cursor(&mut state).await, second.cursor);
+
+        // The late first event is not returned twice
+        let third = test.save_event("a");
+        sender.send(third.clone()).unwrap();
+        assert_eq!(next_cursor(&mut state).await, third.cursor);
+    }
+
+    #[tokio::test]
+    async fn it_ends_the_stream_when_the_publisher_is_dropped() {
+        let test = TestStore::new();
+        let (sender, receiver) = broadcast::channel(10);
+        let mut state = test.stream_state(receiver, "a", 0, false);
+
+        drop(sender);
+        assert!(state.next_event().await.is_none());
     }
 }
@@ -20,7 +20,11 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, str::FromStr};
+use std::{
+    collections::{BTreeSet, HashMap},
+    iter,
+    str::FromStr,
+};
 
 use anyhow::anyhow;
 use futures::StreamExt;
@@ -34,21 +38,31 @@ use tari_dan_p2p::proto::rpc::{GetTransactionResultRequest, PayloadResultStatus,
 use tari_dan_storage::consensus_models::{Block, BlockError, BlockId, Decision, TransactionRecord};
 use tari_engine_types::{
     commit_result::{ExecuteResult, TransactionResult},
-    events::Event,
+    component::ComponentHeader,
+    events::{Event, VAULT_DEPOSIT_TOPIC, VAULT_WITHDRAW_TOPIC},
+    indexed_value::IndexedWellKnownTypes,
     substate::{Substate, SubstateId, SubstateValue},
 };
 use tari_epoch_manager::EpochManagerReader;
-use tari_template_lib::models::{EntityId, TemplateAddress};
+use tari_indexer_client::types::TransferKind;
+use tari_template_lib::{
+    auth::{AccessRule, OwnerRule, RequireRule, RestrictedAccessRule, RuleRequirement},
+    models::{ComponentAddress, EntityId, NonFungibleAddress, TemplateAddress},
+};
 use tari_transaction::{Transaction, TransactionId};
 use tari_validator_node_rpc::client::{TariValidatorNodeRpcClientFactory, ValidatorNodeClientFactory};
+use tokio::sync::broadcast;
 
 use crate::{
     config::EventFilterConfig,
     event_data::EventData,
+    event_manager::PersistedEvent,
     substate_storage_sqlite::{
         models::{
             events::{NewEvent, NewScannedBlockId},
             substate::NewSubstate,
+            substate_owner::NewSubstateOwner,
+            transfer_ledger::{NewVaultBalance, NewVaultTransfer},
         },
         sqlite_substate_store_factory::{
             SqliteSubstateStore,
@@ -61,12 +75,62 @@ use crate::{
 
 const LOG_TARGET: &str = "tari::indexer::event_scanner";
 
+/// The name of the one-off data migration that indexes the owners of components stored before owners were indexed
+const SUBSTATE_OWNERS_BACKFILL: &str = "substate_owners_backfill";
+const SUBSTATE_OWNERS_BACKFILL_BATCH_SIZE: u32 = 100;
+
 #[derive(Default, Debug, Clone)]
 pub struct EventFilter {
     pub topic: Option<String>,
     pub entity_id: Option<EntityId>,
     pub substate_id: Option<SubstateId>,
     pub template_address: Option<TemplateAddress>,
+    pub payload_key: Option<String>,
+    /// Only applies if `payload_key` is set
+    pub payload_value: Option<String>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, event: &Event) -> bool {
+        let matches_topic = self.topic.as_ref().map_or(true, |t| *t == event.topic());
+        let matches_template = self
+            .template_address
+            .as_ref()
+            .map_or(true, |t| *t == event.template_address());
+
+        let matches_substate_id = match &self.substate_id {
+            Some(substate_id) => event.substate_id().map(|s| s == *substate_id).unwrap_or(false),
+            None => true,
+        };
+
+        let matches_entity_id = match &self.entity_id {
+            Some(entity_id) => event
+                .substate_id()
+                .map(|s| Self::entity_id_matches(&s, entity_id))
+                .unwrap_or(false),
+            None => true,
+        };
+
+        let matches_payload = match &self.payload_key {
+            Some(key) => match event.payload().get(key) {
+                Some(value) => self.payload_value.as_ref().map_or(true, |v| v == value),
+                None => false,
+            },
+            None => true,
+        };
+
+        matches_topic && matches_template && matches_substate_id && matches_entity_id && matches_payload
+    }
+
+    fn entity_id_matches(substate_id: &SubstateId, entity_id: &EntityId) -> bool {
+        match substate_id {
+            SubstateId::Component(c) => c.entity_id() == *entity_id,
+            SubstateId::Resource(r) => r.as_entity_id() == *entity_id,
+            SubstateId::Vault(v) => v.entity_id() == *entity_id,
+            // TODO: should all types of substate addresses expose the entity id?
+            _ => false,
+        }
+    }
 }
 
 impl TryFrom<EventFilterConfig> for EventFilter {
@@ -85,16 +149,26 @@ impl TryFrom<EventFilterConfig> for EventFilter {
             entity_id,
             substate_id,
             template_address,
+            payload_key: None,
+            payload_value: None,
         })
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct TransactionMetadata {
     pub transaction_id: TransactionId,
+    pub epoch: Epoch,
     pub timestamp: u64,
 }
 
+/// The events and up substates of a committed transaction
+#[derive(Debug, Clone, Default)]
+struct TransactionOutputs {
+    pub events: Vec<EventData>,
+    pub substates: HashMap<SubstateId, Substate>,
+}
+
 pub struct EventScanner {
     network: Network,
     sidechain_id: Option<RistrettoPublicKey>,
@@ -103,6 +177,7 @@ pub struct EventScanner {
     substate_store: SqliteSubstateStore,
     event_filters: Vec<EventFilter>,
     consensus_constants: ConsensusConstants,
+    event_publisher: broadcast::Sender<PersistedEvent>,
 }
 
 impl EventScanner {
@@ -114,6 +189,7 @@ impl EventScanner {
         substate_store: SqliteSubstateStore,
         event_filters: Vec<EventFilter>,
         consensus_constants: ConsensusConstants,
+        event_publisher: broadcast::Sender<PersistedEvent>,
     ) -> Self {
         Self {
             network,
@@ -123,6 +199,7 @@ impl EventScanner {
             substate_store,
             event_filters,
             consensus_constants,
+            event_publisher,
         }
     }
 
@@ -195,13 +272,20 @@ impl EventScanner {
             );
 
             for transaction in transactions {
-                // fetch all the events in the transaction
-                let events = self.get_events_for_transaction(transaction.transaction_id).await?;
-                event_count += events.len();
+                // fetch all the events and substates in the transaction
+                let outputs = self.get_transaction_outputs(transaction.transaction_id).await?;
+                event_count += outputs.events.len();
+
+                // owners and the vault ledger are indexed for all components, regardless of the event filters
+                Self::store_substate_owners_in_db(&self.substate_store, &outputs.substates)?;
+                Self::store_vault_ledger_in_db(&self.substate_store, &outputs, &transaction)?;
 
                 // only keep the events specified by the indexer filter
-                let filtered_events: Vec<EventData> =
-                    events.into_iter().filter(|ev| self.should_persist_event(ev)).collect();
+                let filtered_events: Vec<EventData> = outputs
+                    .events
+                    .into_iter()
+                    .filter(|ev| self.should_persist_event(ev))
+                    .collect();
                 info!(
                     target: LOG_TARGET,
                     "Filtered events in epoch {}: {}",
@@ -222,50 +306,9 @@ impl EventScanner {
     }
 
     fn should_persist_event(&self, event_data: &EventData) -> bool {
-        for filter in &self.event_filters {
-            if Self::event_matches_filter(filter, &event_data.event) {
-                return true;
-            }
-        }
-
-        false
-    }
-
-    fn event_matches_filter(filter: &EventFilter, event: &Event) -> bool {
-        let matches_topic = filter.topic.as_ref().map_or(true, |t| *t == event.topic());
-        let matches_template = filter
-            .template_address
-            .as_ref()
-            .map_or(true, |t| *t == event.template_address());
-
-        let matches_substate_id = match &filter.substate_id {
-            Some(substate_id) => event.substate_id().map(|s| s == *substate_id).unwrap_or(false),
-            None => true,
-        };
-
-        let matches_entity_id = match &filter.entity_id {
-            Some(entity_id) => event
-                .substate_id()
-                .map(|s| Self::entity_id_matches(&s, entity_id))
-                .unwrap_or(false),
-            None => true,
-        };
-
-        if matches_topic && matches_template && matches_substate_id && matches_entity_id {
-            return true;
-        }
-
-        false
-    }
-
-    fn entity_id_matches(substate_id: &SubstateId, entity_id: &EntityId) -> bool {
-        match substate_id {
-            SubstateId::Component(c) => c.entity_id() == *entity_id,
-            SubstateId::Resource(r) => r.as_entity_id() == *entity_id,
-            SubstateId::Vault(v) => v.entity_id() == *entity_id,
-            // TODO: should all types of substate addresses expose the entity id?
-            _ => false,
-        }
+        self.event_filters
+            .iter()
+            .any(|filter| filter.matches(&event_data.event))
     }
 
     async fn store_events_in_db(
@@ -274,6 +317,7 @@ impl EventScanner {
         transaction: TransactionMetadata,
     ) -> Result<(), anyhow::Error> {
         let mut tx = self.substate_store.create_write_tx()?;
+        let mut persisted_events = Vec::with_capacity(events_data.len());
 
         for data in events_data {
             let event_row = NewEvent {
@@ -304,7 +348,11 @@ impl EventScanner {
                 "Saving event: {:?}",
                 event_row
             );
-            tx.save_event(event_row)?;
+            let event_id = tx.save_event(event_row)?;
+            persisted_events.push(PersistedEvent {
+                cursor: event_id,
+                event: data.event.clone(),
+            });
 
             // store/update the related substate if any
             if let (Some(substate_id), Some(substate)) = (data.event.substate_id(), &data.substate) {
@@ -330,9 +378,188 @@ impl EventScanner {
 
         tx.commit()?;
 
+        // Notify event subscribers only once the events are committed. Sending fails if there are no subscribers.
+        for event in persisted_events {
+            let _ignore = self.event_publisher.send(event);
+        }
+
+        Ok(())
+    }
+
+    /// Indexes the owners of components that were stored before owners were indexed. This only runs once.
+    pub fn backfill_substate_owners(&self) -> Result<(), anyhow::Error> {
+        Self::backfill_substate_owners_in_db(&self.substate_store)
+    }
+
+    fn backfill_substate_owners_in_db(substate_store: &SqliteSubstateStore) -> Result<(), anyhow::Error> {
+        let is_completed =
+            substate_store.with_read_tx(|tx| tx.is_data_migration_completed(SUBSTATE_OWNERS_BACKFILL))?;
+        if is_completed {
+            return Ok(());
+        }
+
+        info!(target: LOG_TARGET, "Indexing the owners of previously stored components");
+        let mut last_id = 0;
+        let mut num_components = 0;
+        loop {
+            let rows = substate_store
+                .with_read_tx(|tx| tx.get_components_after(last_id, SUBSTATE_OWNERS_BACKFILL_BATCH_SIZE))?;
+            let Some(last) = rows.last() else {
+                break;
+            };
+            last_id = last.id;
+
+            let mut substates = HashMap::with_capacity(rows.len());
+            for row in rows {
+                let substate_id = SubstateId::from_str(&row.address)?;
+                let substate = serde_json::from_str::<Substate>(&row.data)?;
+                substates.insert(substate_id, substate);
+            }
+            num_components += substates.len();
+            Self::store_substate_owners_in_db(substate_store, &substates)?;
+        }
+
+        substate_store.with_write_tx(|tx| tx.set_data_migration_completed(SUBSTATE_OWNERS_BACKFILL))?;
+        info!(
+            target: LOG_TARGET,
+            "Indexed the owners of {} previously stored component(s)", num_components
+        );
+        Ok(())
+    }
+
+    fn store_substate_owners_in_db(
+        substate_store: &SqliteSubstateStore,
+        substates: &HashMap<SubstateId, Substate>,
+    ) -> Result<(), anyhow::Error> {
+        let mut tx = substate_store.create_write_tx()?;
+
+        for (substate_id, substate) in substates {
+            let (SubstateId::Component(component_address), SubstateValue::Component(component)) =
+                (substate_id, substate.substate_value())
+            else {
+                continue;
+            };
+            let owners = Self::extract_substate_owners(component_address, component, substate.version())?;
+            debug!(
+                target: LOG_TARGET,
+                "Indexing {} owner(s) of component {}",
+                owners.len(),
+                component_address
+            );
+            tx.set_component_owners(component_address, substate.version(), owners)?;
+        }
+
+        tx.commit()?;
         Ok(())
     }
 
+    /// Indexes the component and the vaults that it holds under each of the owner badges of the component
+    fn extract_substate_owners(
+        component_address: &ComponentAddress,
+        component: &ComponentHeader,
+        version: u32,
+    ) -> Result<Vec<NewSubstateOwner>, anyhow::Error> {
+        let owner_badges = get_owner_badges(component);
+        if owner_badges.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let indexed = IndexedWellKnownTypes::from_value(component.state())?;
+        let parent_component = component_address.to_string();
+        let substate_ids = iter::once((SubstateId::Component(*component_address), None)).chain(
+            indexed
+                .vault_ids()
+                .iter()
+                .map(|vault_id| (SubstateId::Vault(*vault_id), Some(parent_component.clone()))),
+        );
+
+        let owners = substate_ids
+            .flat_map(|(substate_id, parent_component)| {
+                owner_badges.iter().map(move |badge| NewSubstateOwner {
+                    substate_id: substate_id.to_string(),
+                    owner_badge: badge.to_string(),
+                    parent_component: parent_component.clone(),
+                    template_address: component.template_address.to_string(),
+                    version: i64::from(version),
+                })
+            })
+            .collect();
+
+        Ok(owners)
+    }
+
+    /// Saves the transfers and balances of the vaults changed by a transaction. Transfers are identified by the index
+    /// of their event in the transaction, so identical transfers in a transaction are each saved and rescanning a
+    /// transaction does not duplicate them.
+    fn store_vault_ledger_in_db(
+        substate_store: &SqliteSubstateStore,
+        outputs: &TransactionOutputs,
+        transaction: &TransactionMetadata,
+    ) -> Result<(), anyhow::Error> {
+        let mut tx = substate_store.create_write_tx()?;
+
+        for (event_index, data) in outputs.events.iter().enumerate() {
+            if let Some(transfer) = Self::extract_vault_transfer(&data.event, event_index, transaction)? {
+                tx.save_vault_transfer(transfer)?;
+            }
+        }
+
+        for (substate_id, substate) in &outputs.substates {
+            let (SubstateId::Vault(vault_id), SubstateValue::Vault(vault)) = (substate_id, substate.substate_value())
+            else {
+                continue;
+            };
+            tx.save_vault_balance(NewVaultBalance {
+                vault_id: vault_id.to_string(),
+                version: i64::from(substate.version()),
+                resource_address: vault.resource_address().to_string(),
+                balance: vault.balance().value(),
+                tx_hash: transaction.transaction_id.to_string(),
+                epoch: transaction.epoch.as_u64() as i64,
+                timestamp: transaction.timestamp as i64,
+            })?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Derives a transfer ledger entry from a vault deposit or withdraw event. The engine emits each of these events
+    /// for both the vault and the resource, so only the vault event is used.
+    fn extract_vault_transfer(
+        event: &Event,
+        event_index: usize,
+        transaction: &TransactionMetadata,
+    ) -> Result<Option<NewVaultTransfer>, anyhow::Error> {
+        let kind = match event.topic().as_str() {
+            VAULT_DEPOSIT_TOPIC => TransferKind::Deposit,
+            VAULT_WITHDRAW_TOPIC => TransferKind::Withdraw,
+            _ => return Ok(None),
+        };
+        let Some(SubstateId::Vault(vault_id)) = event.substate_id() else {
+            return Ok(None);
+        };
+
+        let get_payload_field = |key: &str| {
+            event
+                .get_payload(key)
+                .ok_or_else(|| anyhow!("Vault event {} is missing the payload field '{}'", event, key))
+        };
+        let amount = get_payload_field("amount")?.parse::<i64>()?;
+
+        Ok(Some(NewVaultTransfer {
+            vault_id: vault_id.to_string(),
+            resource_address: get_payload_field("resource_address")?,
+            resource_type: get_payload_field("resource_type")?,
+            kind: kind.to_string(),
+            amount,
+            tx_hash: transaction.transaction_id.to_string(),
+            epoch: transaction.epoch.as_u64() as i64,
+            timestamp: transaction.timestamp as i64,
+            event_index: i32::try_from(event_index)?,
+        }))
+    }
+
     fn extract_template_address_from_substate(substate: &Substate) -> Option<TemplateAddress> {
         match substate.substate_value() {
             SubstateValue::Component(c) => Some(c.template_address),
@@ -352,7 +579,10 @@ impl EventScanner {
         Ok(pretty_json)
     }
 
-    async fn get_events_for_transaction(&self, transaction_id: TransactionId) -> Result<Vec<EventData>, anyhow::Error> {
+    async fn get_transaction_outputs(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<TransactionOutputs, anyhow::Error> {
         let committee = self.get_all_vns().await?;
 
         for member in &committee {
@@ -361,11 +591,11 @@ impl EventScanner {
             match resp {
                 Ok(res) => {
                     if let Some(execute_result) = res {
-                        let events = self.extract_events_from_transaction_result(execute_result);
-                        return Ok(events);
+                        let outputs = self.extract_outputs_from_transaction_result(execute_result);
+                        return Ok(outputs);
                     } else {
                         // The transaction is not successful, so we don't return any events
-                        return Ok(vec![]);
+                        return Ok(TransactionOutputs::default());
                     }
                 },
                 Err(e) => {
@@ -384,7 +614,7 @@ impl EventScanner {
             target: LOG_TARGET,
             "We could not get transaction results from any of the vns",
         );
-        Ok(vec![])
+        Ok(TransactionOutputs::default())
     }
 
     async fn get_execute_result_from_vn(
@@ -418,7 +648,7 @@ impl EventScanner {
         }
     }
 
-    fn extract_events_from_transaction_result(&self, result: ExecuteResult) -> Vec<EventData> {
+    fn extract_outputs_from_transaction_result(&self, result: ExecuteResult) -> TransactionOutputs {
         if let TransactionResult::Accept(substate_diff) = result.finalize.result {
             let substates: HashMap<SubstateId, Substate> = substate_diff.into_up_iter().collect();
 
@@ -437,18 +667,22 @@ impl EventScanner {
                 })
                 .collect();
 
-            events
+            TransactionOutputs { events, substates }
         } else {
-            vec![]
+            TransactionOutputs::default()
         }
     }
 
     fn extract_transactions_from_blocks(&self, blocks: Vec<Block>) -> Vec<TransactionMetadata> {
         blocks
             .iter()
-            .flat_map(|b| b.all_committing_transactions_ids().map(|id| (id, b.timestamp())))
-            .map(|(transaction_id, timestamp)| TransactionMetadata {
+            .flat_map(|b| {
+                b.all_committing_transactions_ids()
+                    .map(|id| (id, b.epoch(), b.timestamp()))
+            })
+            .map(|(transaction_id, epoch, timestamp)| TransactionMetadata {
                 transaction_id: *transaction_id,
+                epoch,
                 timestamp,
             })
             .collect()
@@ -638,3 +872,435 @@ impl EventScanner {
         Ok(blocks)
     }
 }
+
+/// Returns the badges that can act as the owner of the component on their own. A public key owner is represented by
+/// its public identity badge. Badges that only satisfy an owner access rule together with other requirements, e.g. one
+/// of the badges of a multisig rule, are not owners.
+fn get_owner_badges(component: &ComponentHeader) -> BTreeSet<NonFungibleAddress> {
+    let mut badges = BTreeSet::new();
+    if let Some(owner_key) = component.owner_key {
+        badges.insert(NonFungibleAddress::from_public_key(owner_key));
+    }
+    match &component.owner_rule {
+        OwnerRule::ByPublicKey(public_key) => {
+            badges.insert(NonFungibleAddress::from_public_key(*public_key));
+        },
+        OwnerRule::ByAccessRule(AccessRule::Restricted(rule)) => badges.extend(restricted_rule_sufficient_badges(rule)),
+        OwnerRule::OwnedBySigner | OwnerRule::None | OwnerRule::ByAccessRule(_) => {},
+    }
+    badges
+}
+
+/// Returns the badges that satisfy the rule when presented alone
+fn restricted_rule_sufficient_badges(rule: &RestrictedAccessRule) -> BTreeSet<NonFungibleAddress> {
+    match rule {
+        RestrictedAccessRule::Require(rule) => require_rule_sufficient_badges(rule),
+        RestrictedAccessRule::AnyOf(rules) => rules.iter().flat_map(restricted_rule_sufficient_badges).collect(),
+        RestrictedAccessRule::AllOf(rules) => {
+            let mut rules = rules.iter().map(restricted_rule_sufficient_badges);
+            let Some(first) = rules.next() else {
+                return BTreeSet::new();
+            };
+            rules.fold(first, |badges, rule_badges| {
+                badges.intersection(&rule_badges).cloned().collect()
+            })
+        },
+    }
+}
+
+/// Returns the badges that satisfy the rule when presented alone
+fn require_rule_sufficient_badges(rule: &RequireRule) -> BTreeSet<NonFungibleAddress> {
+    match rule {
+        RequireRule::Require(requirement) => non_fungible_badges(iter::once(requirement)).collect(),
+        RequireRule::AnyOf(requirements) => non_fungible_badges(requirements).collect(),
+        // A badge is only sufficient if it is the only requirement
+        RequireRule::AllOf(requirements) => match requirements.split_first() {
+            Some((first, rest)) if rest.iter().all(|r| r == first) => non_fungible_badges(iter::once(first)).collect(),
+            _ => BTreeSet::new(),
+        },
+        RequireRule::MOfN {
+            threshold,
+            requirements,
+        } if *threshold <= 1 => non_fungible_badges(requirements).collect(),
+        RequireRule::MOfN { .. } => BTreeSet::new(),
+        RequireRule::Weighted {
+            threshold,
+            requirements,
+        } => {
+            // Only the first entry of a requirement contributes its weight
+            let mut seen = Vec::with_capacity(requirements.len());
+            let mut badges = BTreeSet::new();
+            for weighted in requirements {
+                if seen.contains(&&weighted.requirement) {
+                    continue;
+                }
+                seen.push(&weighted.requirement);
+                if weighted.weight >= *threshold {
+                    badges.extend(non_fungible_badges(iter::once(&weighted.requirement)));
+                }
+            }
+            badges
+        },
+    }
+}
+
+fn non_fungible_badges<'a, I: IntoIterator<Item = &'a RuleRequirement>>(
+    requirements: I,
+) -> impl Iterator<Item = NonFungibleAddress> + 'a
+where I::IntoIter: 'a {
+    requirements.into_iter().filter_map(|requirement| match requirement {
+        RuleRequirement::NonFungibleAddress(badge) => Some(badge.clone()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tari_engine_types::component::ComponentBody;
+    use tari_template_lib::{
+        auth::{ComponentAccessRules, WeightedRequirement},
+        crypto::RistrettoPublicKeyBytes,
+        models::{Metadata, NonFungibleId, ObjectKey, ResourceAddress, VaultId},
+        resource::ResourceType,
+        Hash,
+    };
+
+    use super::*;
+
+    fn component_address() -> ComponentAddress {
+        ComponentAddress::from_array([1; 32])
+    }
+
+    fn template_address() -> TemplateAddress {
+        TemplateAddress::from_array([2; 32])
+    }
+
+    fn new_event(substate_id: Option<SubstateId>) -> Event {
+        let mut payload = Metadata::new();
+        payload.insert("amount", "100");
+        Event::new(
+            substate_id,
+            template_address(),
+            Hash::default(),
+            "transfer".to_string(),
+            payload,
+        )
+    }
+
+    #[test]
+    fn it_matches_all_events_with_an_empty_filter() {
+        let filter = EventFilter::default();
+        assert!(filter.matches(&new_event(None)));
+        assert!(filter.matches(&new_event(Some(component_address().into()))));
+    }
+
+    #[test]
+    fn it_matches_the_topic_and_template() {
+        let event = new_event(None);
+
+        let filter = EventFilter {
+            topic: Some("transfer".to_string()),
+            template_address: Some(template_address()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&event));
+
+        let filter = EventFilter {
+            topic: Some("mint".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&event));
+
+        let filter = EventFilter {
+            template_address: Some(TemplateAddress::from_array([3; 32])),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&event));
+    }
+
+    #[test]
+    fn it_matches_the_substate_id_and_entity_id() {
+        let event = new_event(Some(component_address().into()));
+        let event_without_substate = new_event(None);
+
+        let filter = EventFilter {
+            substate_id: Some(component_address().into()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&event));
+        assert!(!filter.matches(&event_without_substate));
+
+        let filter = EventFilter {
+            substate_id: Some(ComponentAddress::from_array([4; 32]).into()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&event));
+
+        let filter = EventFilter {
+            entity_id: Some(component_address().entity_id()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&event));
+        assert!(!filter.matches(&event_without_substate));
+
+        let filter = EventFilter {
+            entity_id: Some(ComponentAddress::from_array([4; 32]).entity_id()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&event));
+    }
+
+    #[test]
+    fn it_matches_the_payload() {
+        let event = new_event(None);
+
+        let filter = EventFilter {
+            payload_key: Some("amount".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&event));
+
+        let filter = EventFilter {
+            payload_key: Some("amount".to_string()),
+            payload_value: Some("100".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&event));
+
+        let filter = EventFilter {
+            payload_key: Some("amount".to_string()),
+            payload_value: Some("99".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&event));
+
+        let filter = EventFilter {
+            payload_key: Some("recipient".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&event));
+
+        // The payload value is ignored if no payload key is given
+        let filter = EventFilter {
+            payload_value: Some("99".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&event));
+    }
+
+    fn new_vault_withdraw_event(vault_id: VaultId, resource_address: ResourceAddress, amount: i64) -> EventData {
+        let mut payload = Metadata::new();
+        payload.insert("vault_id", vault_id.to_string());
+        payload.insert("resource_address", resource_address.to_string());
+        payload.insert("resource_type", ResourceType::Fungible.to_string());
+        payload.insert("amount", amount.to_string());
+        EventData {
+            event: Event::new(
+                Some(SubstateId::Vault(vault_id)),
+                template_address(),
+                Hash::default(),
+                VAULT_WITHDRAW_TOPIC.to_string(),
+                payload,
+            ),
+            substate: None,
+        }
+    }
+
+    #[test]
+    fn it_stores_identical_vault_transfers_in_a_transaction_once() {
+        let temp = tempfile::tempdir().unwrap();
+        let store = SqliteSubstateStore::try_create(temp.path().join("indexer.sqlite")).unwrap();
+        let vault_id = VaultId::new(ObjectKey::from_array([5; ObjectKey::LENGTH]));
+        let resource_address = ResourceAddress::new(ObjectKey::from_array([6; ObjectKey::LENGTH]));
+        let withdraw = new_vault_withdraw_event(vault_id, resource_address, 10);
+        let outputs = TransactionOutputs {
+            // Two identical withdrawals in the same transaction, which are both transfers
+            events: vec![
+                withdraw.clone(),
+                EventData {
+                    event: new_event(None),
+                    substate: None,
+                },
+                withdraw,
+            ],
+            substates: HashMap::new(),
+        };
+        let transaction = TransactionMetadata {
+            transaction_id: TransactionId::new([7; 32]),
+            epoch: Epoch(1),
+            timestamp: 0,
+        };
+
+        EventScanner::store_vault_ledger_in_db(&store, &outputs, &transaction).unwrap();
+        // Rescanning the transaction does not duplicate the transfers
+        EventScanner::store_vault_ledger_in_db(&store, &outputs, &transaction).unwrap();
+
+        let transfers = store
+            .with_read_tx(|tx| tx.get_vault_transfers(&[vault_id], None, 0, 10))
+            .unwrap();
+        assert_eq!(transfers.len(), 2);
+        assert!(transfers
+            .iter()
+            .all(|t| t.amount == 10 && t.kind == TransferKind::Withdraw.to_string()));
+        let mut event_indexes = transfers.iter().map(|t| t.event_index).collect::<Vec<_>>();
+        event_indexes.sort_unstable();
+        assert_eq!(event_indexes, vec![0, 2]);
+    }
+
+    fn badge(n: u32) -> NonFungibleAddress {
+        NonFungibleAddress::new(
+            ResourceAddress::new(ObjectKey::from_array([9; ObjectKey::LENGTH])),
+            NonFungibleId::from_u32(n),
+        )
+    }
+
+    fn require(n: u32) -> RuleRequirement {
+        RuleRequirement::NonFungibleAddress(badge(n))
+    }
+
+    fn new_component(owner_rule: OwnerRule) -> ComponentHeader {
+        ComponentHeader {
+            template_address: template_address(),
+            module_name: "Test".to_string(),
+            owner_key: None,
+            owner_rule,
+            access_rules: ComponentAccessRules::new(),
+            entity_id: component_address().entity_id(),
+            body: ComponentBody {
+                state: tari_bor::Value::Null,
+            },
+        }
+    }
+
+    fn owner_badges(rule: RestrictedAccessRule) -> Vec<NonFungibleAddress> {
+        get_owner_badges(&new_component(OwnerRule::ByAccessRule(AccessRule::Restricted(rule))))
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn it_only_indexes_owner_badges_that_are_sufficient_on_their_own() {
+        let rule = RestrictedAccessRule::Require(RequireRule::Require(require(1)));
+        assert_eq!(owner_badges(rule), vec![badge(1)]);
+
+        let rule = RestrictedAccessRule::Require(RequireRule::AnyOf(vec![
+            require(1),
+            require(2),
+            RuleRequirement::ScopedToComponent(component_address()),
+        ]));
+        assert_eq!(owner_badges(rule), vec![badge(1), badge(2)]);
+
+        let rule = RestrictedAccessRule::Require(RequireRule::AllOf(vec![require(1), require(2)]));
+        assert!(owner_badges(rule).is_empty());
+
+        let rule = RestrictedAccessRule::Require(RequireRule::AllOf(vec![require(1), require(1)]));
+        assert_eq!(owner_badges(rule), vec![badge(1)]);
+
+        let rule = RestrictedAccessRule::Require(RequireRule::m_of_n(1, [require(1), require(2)]));
+        assert_eq!(owner_badges(rule), vec![badge(1), badge(2)]);
+
+        let rule = RestrictedAccessRule::Require(RequireRule::m_of_n(2, [require(1), require(2), require(3)]));
+        assert!(owner_badges(rule).is_empty());
+
+        let rule = RestrictedAccessRule::Require(RequireRule::Weighted {
+            threshold: 3,
+            requirements: vec![
+                WeightedRequirement::new(require(1), 3),
+                WeightedRequirement::new(require(2), 2),
+                WeightedRequirement::new(require(3), 1),
+                // Only the first entry of a requirement counts
+                WeightedRequirement::new(require(2), 5),
+            ],
+        });
+        assert_eq!(owner_badges(rule), vec![badge(1)]);
+
+        let rule = RestrictedAccessRule::AnyOf(vec![
+            RestrictedAccessRule::Require(RequireRule::Require(require(1))),
+            RestrictedAccessRule::Require(RequireRule::m_of_n(2, [require(2), require(3)])),
+        ]);
+        assert_eq!(owner_badges(rule), vec![badge(1)]);
+
+        let rule = RestrictedAccessRule::AllOf(vec![
+            RestrictedAccessRule::Require(RequireRule::AnyOf(vec![require(1), require(2)])),
+            RestrictedAccessRule::Require(RequireRule::AnyOf(vec![require(2), require(3)])),
+        ]);
+        assert_eq!(owner_badges(rule), vec![badge(2)]);
+
+        let rule = RestrictedAccessRule::AllOf(vec![
+            RestrictedAccessRule::Require(RequireRule::Require(require(1))),
+            RestrictedAccessRule::Require(RequireRule::Require(RuleRequirement::ScopedToComponent(
+                component_address(),
+            ))),
+        ]);
+        assert!(owner_badges(rule).is_empty());
+    }
+
+    #[test]
+    fn it_indexes_the_owner_key_and_public_key_owner() {
+        let public_key = RistrettoPublicKeyBytes::from_bytes(&[1; 32]).unwrap();
+        let mut component = new_component(OwnerRule::ByPublicKey(public_key));
+        assert_eq!(get_owner_badges(&component).into_iter().collect::<Vec<_>>(), vec![
+            NonFungibleAddress::from_public_key(public_key)
+        ]);
+
+        component.owner_key = Some(public_key);
+        component.owner_rule = OwnerRule::None;
+        assert_eq!(get_owner_badges(&component).into_iter().collect::<Vec<_>>(), vec![
+            NonFungibleAddress::from_public_key(public_key)
+        ]);
+
+        component.owner_key = None;
+        component.owner_rule = OwnerRule::ByAccessRule(AccessRule::AllowAll);
+        assert!(get_owner_badges(&component).is_empty());
+    }
+
+    fn new_component_substate(address: ComponentAddress, version: u32, owner: u32) -> NewSubstate {
+        let component = new_component(OwnerRule::ByAccessRule(AccessRule::Restricted(
+            RestrictedAccessRule::Require(RequireRule::Require(require(owner))),
+        )));
+        NewSubstate {
+            address: address.to_string(),
+            version: i64::from(version),
+            data: serde_json::to_string_pretty(&Substate::new(version, component)).unwrap(),
+            tx_hash: TransactionId::new([0; 32]).to_string(),
+            template_address: Some(template_address().to_string()),
+            module_name: Some("Test".to_string()),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn it_backfills_the_owners_of_stored_components_once() {
+        let temp = tempfile::tempdir().unwrap();
+        let store = SqliteSubstateStore::try_create(temp.path().join("indexer.sqlite")).unwrap();
+        let first = ComponentAddress::from_array([1; 32]);
+        let second = ComponentAddress::from_array([2; 32]);
+        let third = ComponentAddress::from_array([3; 32]);
+        store
+            .with_write_tx(|tx| {
+                tx.set_substate(new_component_substate(first, 0, 1))?;
+                tx.set_substate(new_component_substate(second, 3, 2))
+            })
+            .unwrap();
+
+        EventScanner::backfill_substate_owners_in_db(&store).unwrap();
+
+        let owned_components = |owner: u32| {
+            store
+                .with_read_tx(|tx| tx.get_components_by_owner(&badge(owner), &[template_address()], 0, 10))
+                .unwrap()
+                .into_iter()
+                .map(|row| (row.substate_id, row.version))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(owned_components(1), vec![(first.to_string(), 0)]);
+        assert_eq!(owned_components(2), vec![(second.to_string(), 3)]);
+
+        // Components stored after the backfill are indexed by the scanner, not by the backfill
+        store
+            .with_write_tx(|tx| tx.set_substate(new_component_substate(third, 0, 1)))
+            .unwrap();
+        EventScanner::backfill_substate_owners_in_db(&store).unwrap();
+        assert_eq!(owned_components(1), vec![(first.to_string(), 0)]);
+    }
+}
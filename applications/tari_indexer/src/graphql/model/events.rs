@@ -22,14 +22,19 @@
 
 use std::{collections::BTreeMap, str::FromStr, sync::Arc};
 
-use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema, SimpleObject};
+use async_graphql::{Context, InputObject, Object, SimpleObject, Subscription};
+use futures::{Stream, StreamExt};
 use log::*;
 use serde::{Deserialize, Serialize};
 use tari_engine_types::substate::SubstateId;
 use tari_template_lib::Hash;
 use tari_transaction::TransactionId;
 
-use crate::event_manager::EventManager;
+use crate::{
+    config::EventFilterConfig,
+    event_manager::{EventManager, PersistedEvent},
+    event_scanner::EventFilter,
+};
 
 const LOG_TARGET: &str = "tari::indexer::graphql::events";
 
@@ -55,7 +60,53 @@ impl Event {
     }
 }
 
-pub(crate) type EventSchema = Schema<EventQuery, EmptyMutation, EmptySubscription>;
+/// An event pushed to a subscriber, along with the cursor to resume the subscription from
+#[derive(SimpleObject, Clone, Debug)]
+pub struct EventNotification {
+    pub cursor: i32,
+    pub event: Event,
+}
+
+impl TryFrom<PersistedEvent> for EventNotification {
+    type Error = anyhow::Error;
+
+    fn try_from(value: PersistedEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            cursor: value.cursor,
+            event: Event::from_engine_event(value.event)?,
+        })
+    }
+}
+
+/// Same fields as the `event_filters` in the indexer config, with an additional payload key/value filter
+#[derive(InputObject, Clone, Debug, Default)]
+pub struct EventFilterInput {
+    pub topic: Option<String>,
+    pub entity_id: Option<String>,
+    pub substate_id: Option<String>,
+    pub template_address: Option<String>,
+    pub payload_key: Option<String>,
+    pub payload_value: Option<String>,
+}
+
+impl TryFrom<EventFilterInput> for EventFilter {
+    type Error = anyhow::Error;
+
+    fn try_from(input: EventFilterInput) -> Result<Self, Self::Error> {
+        let filter = EventFilter::try_from(EventFilterConfig {
+            topic: input.topic,
+            entity_id: input.entity_id,
+            substate_id: input.substate_id,
+            template_address: input.template_address,
+        })?;
+
+        Ok(Self {
+            payload_key: input.payload_key,
+            payload_value: input.payload_value,
+            ..filter
+        })
+    }
+}
 
 pub struct EventQuery;
 
@@ -198,3 +249,31 @@ impl EventQuery {
         })
     }
 }
+
+pub struct EventSubscription;
+
+#[Subscription]
+impl EventSubscription {
+    /// Streams the events that match the filter as they are stored by the indexer. Events stored after the `after`
+    /// cursor are streamed first, so a client can resume from the last cursor it received after reconnecting.
+    pub async fn events(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<EventFilterInput>,
+        after: Option<i32>,
+    ) -> Result<impl Stream<Item = async_graphql::Result<EventNotification>>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Subscribing to events. filter: {:?}, after: {:?}", filter, after
+        );
+        let filter = filter.map(EventFilter::try_from).transpose()?.unwrap_or_default();
+        let event_manager = ctx.data_unchecked::<Arc<EventManager>>();
+        let events = event_manager.subscribe_events(filter, after)?;
+
+        Ok(events.map(|result| {
+            result
+                .and_then(EventNotification::try_from)
+                .map_err(|e| async_graphql::Error::new(e.to_string()))
+        }))
+    }
+}
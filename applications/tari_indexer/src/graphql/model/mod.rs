@@ -21,3 +21,18 @@
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod events;
+pub mod owners;
+pub mod transfers;
+
+use async_graphql::{EmptyMutation, MergedObject, Schema};
+
+use crate::graphql::model::{
+    events::{EventQuery, EventSubscription},
+    owners::OwnerQuery,
+    transfers::TransferQuery,
+};
+
+#[derive(MergedObject)]
+pub struct QueryRoot(EventQuery, TransferQuery, OwnerQuery);
+
+pub(crate) type IndexerSchema = Schema<QueryRoot, EmptyMutation, EventSubscription>;
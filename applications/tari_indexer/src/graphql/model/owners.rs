@@ -0,0 +1,97 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::sync::Arc;
+
+use async_graphql::{Context, Object, SimpleObject};
+use log::*;
+use tari_common_types::types::PublicKey;
+use tari_crypto::tari_utilities::hex::Hex;
+use tari_indexer_client::types::{OwnedAccount, OwnedAsset};
+
+use crate::substate_manager::SubstateManager;
+
+const LOG_TARGET: &str = "tari::indexer::graphql::owners";
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct Account {
+    pub component_address: String,
+    pub template_address: String,
+    pub version: u32,
+}
+
+impl From<OwnedAccount> for Account {
+    fn from(account: OwnedAccount) -> Self {
+        Self {
+            component_address: account.component_address.to_string(),
+            template_address: account.template_address.to_string(),
+            version: account.version,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct Asset {
+    pub account: String,
+    pub vault_id: String,
+    pub resource_address: String,
+    pub resource_type: String,
+    pub balance: i64,
+    pub non_fungible_ids: Vec<String>,
+}
+
+impl From<OwnedAsset> for Asset {
+    fn from(asset: OwnedAsset) -> Self {
+        Self {
+            account: asset.account.to_string(),
+            vault_id: asset.vault_id.to_string(),
+            resource_address: asset.resource_address.to_string(),
+            resource_type: asset.resource_type.to_string(),
+            balance: asset.balance.value(),
+            non_fungible_ids: asset.non_fungible_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+pub struct OwnerQuery;
+
+#[Object]
+impl OwnerQuery {
+    /// Returns the account components owned by the public key
+    pub async fn get_accounts_by_owner(
+        &self,
+        ctx: &Context<'_>,
+        owner: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Account>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying accounts by owner. owner: {}, offset: {}, limit: {}", owner, offset, limit,
+        );
+        let owner = PublicKey::from_hex(&owner)?;
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let accounts = substate_manager.get_accounts_by_owner(&owner, offset, limit).await?;
+
+        Ok(accounts.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the vaults held by the accounts owned by the public key
+    pub async fn get_assets_by_owner(
+        &self,
+        ctx: &Context<'_>,
+        owner: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Asset>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying assets by owner. owner: {}, offset: {}, limit: {}", owner, offset, limit,
+        );
+        let owner = PublicKey::from_hex(&owner)?;
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let assets = substate_manager.get_assets_by_owner(&owner, offset, limit).await?;
+
+        Ok(assets.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,136 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{str::FromStr, sync::Arc};
+
+use async_graphql::{Context, Object, SimpleObject};
+use log::*;
+use tari_dan_common_types::Epoch;
+use tari_indexer_client::types::{TransferLedgerEntry, VaultBalanceSnapshot};
+use tari_template_lib::models::{ComponentAddress, ResourceAddress};
+
+use crate::substate_manager::SubstateManager;
+
+const LOG_TARGET: &str = "tari::indexer::graphql::transfers";
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct Transfer {
+    pub vault_id: String,
+    pub resource_address: String,
+    pub resource_type: String,
+    /// "Deposit" or "Withdraw"
+    pub kind: String,
+    pub amount: i64,
+    pub transaction_id: String,
+    pub epoch: u64,
+    pub timestamp: u64,
+}
+
+impl From<TransferLedgerEntry> for Transfer {
+    fn from(entry: TransferLedgerEntry) -> Self {
+        Self {
+            vault_id: entry.vault_id.to_string(),
+            resource_address: entry.resource_address.to_string(),
+            resource_type: entry.resource_type.to_string(),
+            kind: entry.kind.to_string(),
+            amount: entry.amount.value(),
+            transaction_id: entry.transaction_id.to_string(),
+            epoch: entry.epoch.as_u64(),
+            timestamp: entry.timestamp,
+        }
+    }
+}
+
+#[derive(SimpleObject, Clone, Debug)]
+pub struct VaultBalance {
+    pub vault_id: String,
+    pub resource_address: String,
+    pub version: u32,
+    pub balance: i64,
+    pub transaction_id: String,
+    pub epoch: u64,
+    pub timestamp: u64,
+}
+
+impl From<VaultBalanceSnapshot> for VaultBalance {
+    fn from(snapshot: VaultBalanceSnapshot) -> Self {
+        Self {
+            vault_id: snapshot.vault_id.to_string(),
+            resource_address: snapshot.resource_address.to_string(),
+            version: snapshot.version,
+            balance: snapshot.balance.value(),
+            transaction_id: snapshot.transaction_id.to_string(),
+            epoch: snapshot.epoch.as_u64(),
+            timestamp: snapshot.timestamp,
+        }
+    }
+}
+
+pub struct TransferQuery;
+
+#[Object]
+impl TransferQuery {
+    /// Returns the deposits into and withdrawals from the vaults of the account, most recent first
+    pub async fn get_transfer_history(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        resource_address: Option<String>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Transfer>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying transfer history. account: {}, resource_address: {:?}, offset: {}, limit: {}", account, resource_address, offset, limit,
+        );
+        let account = ComponentAddress::from_str(&account)?;
+        let resource_address = resource_address.map(|s| ResourceAddress::from_str(&s)).transpose()?;
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let transfers = substate_manager
+            .get_transfer_history(account, resource_address, offset, limit)
+            .await?;
+
+        Ok(transfers.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the balances of the vaults of the account after each change, most recent first
+    pub async fn get_balance_history(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        resource_address: Option<String>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VaultBalance>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying balance history. account: {}, resource_address: {:?}, offset: {}, limit: {}", account, resource_address, offset, limit,
+        );
+        let account = ComponentAddress::from_str(&account)?;
+        let resource_address = resource_address.map(|s| ResourceAddress::from_str(&s)).transpose()?;
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let balances = substate_manager
+            .get_balance_history(account, resource_address, offset, limit)
+            .await?;
+
+        Ok(balances.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the last known balance of each vault of the account at the end of the epoch
+    pub async fn get_balances_at_epoch(
+        &self,
+        ctx: &Context<'_>,
+        account: String,
+        epoch: u64,
+    ) -> Result<Vec<VaultBalance>, anyhow::Error> {
+        info!(
+            target: LOG_TARGET,
+            "Querying balances. account: {}, epoch: {}", account, epoch,
+        );
+        let account = ComponentAddress::from_str(&account)?;
+        let substate_manager = ctx.data_unchecked::<Arc<SubstateManager>>();
+        let balances = substate_manager.get_balances_at_epoch(account, Epoch(epoch)).await?;
+
+        Ok(balances.into_iter().map(Into::into).collect())
+    }
+}
@@ -49,8 +49,16 @@ use tari_indexer_client::types::{
     AddPeerRequest,
     AddPeerResponse,
     ConnectionDirection,
+    GetAccountsByOwnerRequest,
+    GetAccountsByOwnerResponse,
     GetAllVnsRequest,
     GetAllVnsResponse,
+    GetAssetsByOwnerRequest,
+    GetAssetsByOwnerResponse,
+    GetBalanceHistoryRequest,
+    GetBalanceHistoryResponse,
+    GetBalancesAtEpochRequest,
+    GetBalancesAtEpochResponse,
     GetCommsStatsResponse,
     GetConnectionsResponse,
     GetEpochManagerStatsResponse,
@@ -68,6 +76,8 @@ use tari_indexer_client::types::{
     GetTemplateDefinitionResponse,
     GetTransactionResultRequest,
     GetTransactionResultResponse,
+    GetTransferHistoryRequest,
+    GetTransferHistoryResponse,
     IndexerTransactionFinalizedResult,
     InspectSubstateRequest,
     InspectSubstateResponse,
@@ -469,6 +479,79 @@ impl JsonRpcHandlers {
         }))
     }
 
+    pub async fn get_transfer_history(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetTransferHistoryRequest = value.parse_params()?;
+
+        let transfers = self
+            .substate_manager
+            .get_transfer_history(request.account, request.resource_address, request.offset, request.limit)
+            .await
+            .map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetTransferHistoryResponse {
+            transfers,
+        }))
+    }
+
+    pub async fn get_balance_history(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetBalanceHistoryRequest = value.parse_params()?;
+
+        let balances = self
+            .substate_manager
+            .get_balance_history(request.account, request.resource_address, request.offset, request.limit)
+            .await
+            .map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetBalanceHistoryResponse {
+            balances,
+        }))
+    }
+
+    pub async fn get_balances_at_epoch(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetBalancesAtEpochRequest = value.parse_params()?;
+
+        let balances = self
+            .substate_manager
+            .get_balances_at_epoch(request.account, request.epoch)
+            .await
+            .map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetBalancesAtEpochResponse {
+            balances,
+        }))
+    }
+
+    pub async fn get_accounts_by_owner(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetAccountsByOwnerRequest = value.parse_params()?;
+
+        let accounts = self
+            .substate_manager
+            .get_accounts_by_owner(&request.owner, request.offset, request.limit)
+            .await
+            .map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetAccountsByOwnerResponse {
+            accounts,
+        }))
+    }
+
+    pub async fn get_assets_by_owner(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let request: GetAssetsByOwnerRequest = value.parse_params()?;
+
+        let assets = self
+            .substate_manager
+            .get_assets_by_owner(&request.owner, request.offset, request.limit)
+            .await
+            .map_err(|e| Self::internal_error(answer_id, e))?;
+
+        Ok(JsonRpcResponse::success(answer_id, GetAssetsByOwnerResponse { assets }))
+    }
+
     pub async fn submit_transaction(&self, value: JsonRpcExtractor) -> JrpcResult {
         let answer_id = value.get_answer_id();
         let request: SubmitTransactionRequest = value.parse_params()?;
@@ -70,6 +70,11 @@ async fn handler(Extension(handlers): Extension<Arc<JsonRpcHandlers>>, value: Js
         "get_non_fungible_collections" => handlers.get_non_fungible_collections(value).await,
         "get_non_fungible_count" => handlers.get_non_fungible_count(value).await,
         "get_non_fungibles" => handlers.get_non_fungibles(value).await,
+        "get_transfer_history" => handlers.get_transfer_history(value).await,
+        "get_balance_history" => handlers.get_balance_history(value).await,
+        "get_balances_at_epoch" => handlers.get_balances_at_epoch(value).await,
+        "get_accounts_by_owner" => handlers.get_accounts_by_owner(value).await,
+        "get_assets_by_owner" => handlers.get_assets_by_owner(value).await,
         "submit_transaction" => handlers.submit_transaction(value).await,
         "get_transaction_result" => handlers.get_transaction_result(value).await,
         "get_substate_transactions" => handlers.get_substate_transactions(value).await,
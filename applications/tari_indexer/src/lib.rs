@@ -59,7 +59,7 @@ use tari_epoch_manager::{EpochManagerEvent, EpochManagerReader};
 use tari_indexer_lib::substate_scanner::SubstateScanner;
 use tari_networking::NetworkingService;
 use tari_shutdown::ShutdownSignal;
-use tokio::{task, time};
+use tokio::{sync::broadcast, task, time};
 
 use crate::{
     bootstrap::{spawn_services, Services},
@@ -72,6 +72,8 @@ use crate::{
 };
 
 const LOG_TARGET: &str = "tari::indexer::app";
+/// The number of persisted events buffered for each GraphQL subscriber before it has to catch up from the database
+const EVENT_SUBSCRIPTION_BUFFER_SIZE: usize = 1000;
 
 #[allow(clippy::too_many_lines)]
 pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: ShutdownSignal) -> Result<(), ExitError> {
@@ -164,9 +166,11 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
     }
 
     // Run the event manager
+    let (event_publisher, _) = broadcast::channel(EVENT_SUBSCRIPTION_BUFFER_SIZE);
     let event_manager = Arc::new(EventManager::new(
         services.substate_store.clone(),
         dan_layer_scanner.clone(),
+        event_publisher.clone(),
     ));
 
     // Run the event scanner
@@ -186,7 +190,11 @@ pub async fn run_indexer(config: ApplicationConfig, mut shutdown_signal: Shutdow
         services.substate_store.clone(),
         event_filters,
         consensus_constants,
+        event_publisher,
     ));
+    if let Err(err) = event_scanner.backfill_substate_owners() {
+        error!(target: LOG_TARGET, "Failed to index the owners of stored components: {}", err);
+    }
 
     // Run the GraphQL API
     let graphql_address = config.indexer.graphql_address;
@@ -20,17 +20,32 @@
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{convert::TryInto, sync::Arc};
+use std::{convert::TryInto, str::FromStr, sync::Arc};
 
+use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
-use tari_common_types::types::FixedHash;
+use tari_common_types::types::{FixedHash, PublicKey};
+use tari_crypto::tari_utilities::ByteArray;
 use tari_dan_app_utilities::substate_file_cache::SubstateFileCache;
-use tari_dan_common_types::{substate_type::SubstateType, PeerAddress};
-use tari_engine_types::substate::{Substate, SubstateId};
+use tari_dan_common_types::{substate_type::SubstateType, Epoch, PeerAddress};
+use tari_engine_types::{
+    indexed_value::IndexedWellKnownTypes,
+    substate::{Substate, SubstateId},
+};
 use tari_epoch_manager::base_layer::EpochManagerHandle;
-use tari_indexer_client::types::ListSubstateItem;
+use tari_indexer_client::types::{
+    ListSubstateItem,
+    OwnedAccount,
+    OwnedAsset,
+    TransferLedgerEntry,
+    VaultBalanceSnapshot,
+};
 use tari_indexer_lib::{substate_scanner::SubstateScanner, NonFungibleSubstate};
-use tari_template_lib::models::TemplateAddress;
+use tari_template_builtin::{ACCOUNT_TEMPLATE_ADDRESS, MULTISIG_ACCOUNT_TEMPLATE_ADDRESS};
+use tari_template_lib::{
+    crypto::RistrettoPublicKeyBytes,
+    models::{ComponentAddress, NonFungibleAddress, ResourceAddress, TemplateAddress, VaultId},
+};
 use tari_transaction::TransactionId;
 use tari_validator_node_rpc::client::{SubstateResult, TariValidatorNodeRpcClientFactory};
 
@@ -40,6 +55,9 @@ use crate::substate_storage_sqlite::sqlite_substate_store_factory::{
     SubstateStoreReadTransaction,
 };
 
+/// The templates of the components that are considered accounts
+const ACCOUNT_TEMPLATES: &[TemplateAddress] = &[ACCOUNT_TEMPLATE_ADDRESS, MULTISIG_ACCOUNT_TEMPLATE_ADDRESS];
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SubstateResponse {
     pub address: SubstateId,
@@ -196,4 +214,121 @@ impl SubstateManager {
 
         Ok(non_fungibles)
     }
+
+    pub async fn get_transfer_history(
+        &self,
+        account: ComponentAddress,
+        resource_address: Option<ResourceAddress>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<TransferLedgerEntry>, anyhow::Error> {
+        let vault_ids = self.get_account_vault_ids(account).await?;
+        let mut tx = self.substate_store.create_read_tx()?;
+        let transfers = tx.get_vault_transfers(&vault_ids, resource_address, offset, limit)?;
+        transfers.into_iter().map(TryInto::try_into).collect()
+    }
+
+    pub async fn get_balance_history(
+        &self,
+        account: ComponentAddress,
+        resource_address: Option<ResourceAddress>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VaultBalanceSnapshot>, anyhow::Error> {
+        let vault_ids = self.get_account_vault_ids(account).await?;
+        let mut tx = self.substate_store.create_read_tx()?;
+        let balances = tx.get_vault_balance_history(&vault_ids, resource_address, offset, limit)?;
+        balances.into_iter().map(TryInto::try_into).collect()
+    }
+
+    pub async fn get_balances_at_epoch(
+        &self,
+        account: ComponentAddress,
+        epoch: Epoch,
+    ) -> Result<Vec<VaultBalanceSnapshot>, anyhow::Error> {
+        let vault_ids = self.get_account_vault_ids(account).await?;
+        let mut tx = self.substate_store.create_read_tx()?;
+        let mut balances = Vec::with_capacity(vault_ids.len());
+        for vault_id in &vault_ids {
+            if let Some(balance) = tx.get_vault_balance_at_epoch(vault_id, epoch)? {
+                balances.push(balance.try_into()?);
+            }
+        }
+        Ok(balances)
+    }
+
+    /// Returns the accounts that the public key can act as the owner of without any other badges
+    pub async fn get_accounts_by_owner(
+        &self,
+        owner: &PublicKey,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<OwnedAccount>, anyhow::Error> {
+        let owner_badge = Self::owner_badge(owner)?;
+        let mut tx = self.substate_store.create_read_tx()?;
+        let accounts = tx.get_components_by_owner(&owner_badge, ACCOUNT_TEMPLATES, offset, limit)?;
+        accounts.into_iter().map(TryInto::try_into).collect()
+    }
+
+    pub async fn get_assets_by_owner(
+        &self,
+        owner: &PublicKey,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<OwnedAsset>, anyhow::Error> {
+        let owner_badge = Self::owner_badge(owner)?;
+        let owned_vaults = {
+            let mut tx = self.substate_store.create_read_tx()?;
+            tx.get_vaults_by_owner(&owner_badge, ACCOUNT_TEMPLATES, offset, limit)?
+        };
+
+        let mut assets = Vec::with_capacity(owned_vaults.len());
+        for owned_vault in owned_vaults {
+            let account = owned_vault
+                .parent_component
+                .as_deref()
+                .ok_or_else(|| anyhow!("Vault {} has no parent component", owned_vault.substate_id))?;
+            let vault_id = VaultId::from_str(&owned_vault.substate_id)?;
+            let substate = self
+                .get_substate(&SubstateId::Vault(vault_id), None)
+                .await?
+                .ok_or_else(|| anyhow!("Vault {} not found", vault_id))?;
+            let vault = substate
+                .substate
+                .substate_value()
+                .vault()
+                .ok_or_else(|| anyhow!("Substate {} is not a vault", vault_id))?;
+            assets.push(OwnedAsset {
+                account: ComponentAddress::from_str(account)?,
+                vault_id,
+                resource_address: *vault.resource_address(),
+                resource_type: vault.resource_type(),
+                balance: vault.balance(),
+                non_fungible_ids: vault.get_non_fungible_ids().iter().cloned().collect(),
+            });
+        }
+
+        Ok(assets)
+    }
+
+    /// Owners are indexed by badge, a public key owner being represented by its public identity badge
+    fn owner_badge(owner: &PublicKey) -> Result<NonFungibleAddress, anyhow::Error> {
+        let public_key = RistrettoPublicKeyBytes::from_bytes(owner.as_bytes())
+            .map_err(|e| anyhow!("Invalid owner public key: {:?}", e))?;
+        Ok(NonFungibleAddress::from_public_key(public_key))
+    }
+
+    async fn get_account_vault_ids(&self, account: ComponentAddress) -> Result<Vec<VaultId>, anyhow::Error> {
+        let substate = self
+            .get_substate(&SubstateId::Component(account), None)
+            .await?
+            .ok_or_else(|| anyhow!("Account {} not found", account))?;
+        let component = substate
+            .substate
+            .substate_value()
+            .component()
+            .ok_or_else(|| anyhow!("Substate {} is not a component", account))?;
+        let indexed = IndexedWellKnownTypes::from_value(component.state())?;
+        Ok(indexed.vault_ids().to_vec())
+    }
 }
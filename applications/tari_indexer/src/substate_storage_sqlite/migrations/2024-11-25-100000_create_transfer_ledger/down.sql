@@ -0,0 +1,2 @@
+drop table vault_balances;
+drop table vault_transfers;
@@ -0,0 +1,41 @@
+-- Deposits and withdrawals derived from the vault events emitted by the engine
+create table vault_transfers
+(
+    id               integer not NULL primary key AUTOINCREMENT,
+    vault_id         text    not NULL,
+    resource_address text    not NULL,
+    resource_type    text    not NULL,
+    -- "Deposit" or "Withdraw"
+    kind             text    not NULL,
+    amount           bigint  not NULL,
+    tx_hash          text    not NULL,
+    epoch            bigint  not NULL,
+    timestamp        bigint  not NULL,
+    -- The index of the vault event in the events of the transaction
+    event_index      integer not NULL
+);
+
+-- A transaction may contain identical transfers, so transfers are identified by their event in the transaction
+create unique index vault_transfers_unique_event on vault_transfers (tx_hash, event_index);
+
+-- Index for faster retrieval of the transfer history of vaults
+create index vault_transfers_vault_id_index on vault_transfers (vault_id);
+
+-- The balance of a vault for each version of the vault substate
+create table vault_balances
+(
+    id               integer not NULL primary key AUTOINCREMENT,
+    vault_id         text    not NULL,
+    version          bigint  not NULL,
+    resource_address text    not NULL,
+    balance          bigint  not NULL,
+    tx_hash          text    not NULL,
+    epoch            bigint  not NULL,
+    timestamp        bigint  not NULL
+);
+
+-- There should only be one balance snapshot for each version of a vault
+create unique index vault_balances_unique_version on vault_balances (vault_id, version);
+
+-- Index for faster retrieval of the balance of a vault at an epoch
+create index vault_balances_vault_id_epoch_index on vault_balances (vault_id, epoch);
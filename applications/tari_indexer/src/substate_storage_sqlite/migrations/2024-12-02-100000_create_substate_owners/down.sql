@@ -0,0 +1 @@
+drop table substate_owners;
@@ -0,0 +1,23 @@
+-- The owner badges of components, derived from their owner rules, and of the vaults that the components hold
+create table substate_owners
+(
+    id               integer not NULL primary key AUTOINCREMENT,
+    substate_id      text    not NULL,
+    -- Owners by public key are represented by their public identity badge
+    owner_badge      text    not NULL,
+    -- The component that holds the substate. NULL for components.
+    parent_component text    NULL,
+    -- The template of the component, or of the parent component for vaults
+    template_address text    not NULL,
+    -- The version of the component, or of the parent component for vaults
+    version          bigint  not NULL
+);
+
+-- A substate should only be indexed once for each owner
+create unique index substate_owners_unique_owner on substate_owners (substate_id, owner_badge);
+
+-- Index for faster retrieval of the substates of an owner
+create index substate_owners_owner_badge_index on substate_owners (owner_badge);
+
+-- Index for faster removal of the vaults of a component when it is updated
+create index substate_owners_parent_component_index on substate_owners (parent_component);
@@ -0,0 +1,6 @@
+-- One-off data migrations that the indexer has completed, so that they are only run once
+create table data_migrations
+(
+    name         text      not NULL primary key,
+    completed_at timestamp not NULL default current_timestamp
+);
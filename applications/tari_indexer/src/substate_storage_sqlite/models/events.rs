@@ -103,6 +103,22 @@ impl TryFrom<EventData> for crate::graphql::model::events::Event {
     }
 }
 
+impl TryFrom<Event> for tari_engine_types::events::Event {
+    type Error = anyhow::Error;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        let substate_id = event
+            .substate_id
+            .map(|sub_id| SubstateId::from_str(&sub_id))
+            .transpose()?;
+        let template_address = Hash::from_hex(&event.template_address)?;
+        let tx_hash = Hash::from_hex(&event.tx_hash)?;
+        let payload = serde_json::from_str(event.payload.as_str())?;
+
+        Ok(Self::new(substate_id, template_address, tx_hash, event.topic, payload))
+    }
+}
+
 impl TryFrom<EventData> for tari_engine_types::events::Event {
     type Error = anyhow::Error;
 
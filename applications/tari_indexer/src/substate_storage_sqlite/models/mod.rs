@@ -23,3 +23,5 @@
 pub mod events;
 pub mod non_fungible_index;
 pub mod substate;
+pub mod substate_owner;
+pub mod transfer_ledger;
@@ -0,0 +1,42 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{convert::TryFrom, str::FromStr};
+
+use tari_indexer_client::types::OwnedAccount;
+use tari_template_lib::models::{ComponentAddress, TemplateAddress};
+
+use crate::substate_storage_sqlite::schema::*;
+
+#[derive(Debug, Identifiable, Queryable)]
+#[diesel(table_name = substate_owners)]
+pub struct SubstateOwner {
+    pub id: i32,
+    pub substate_id: String,
+    pub owner_badge: String,
+    pub parent_component: Option<String>,
+    pub template_address: String,
+    pub version: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = substate_owners)]
+pub struct NewSubstateOwner {
+    pub substate_id: String,
+    pub owner_badge: String,
+    pub parent_component: Option<String>,
+    pub template_address: String,
+    pub version: i64,
+}
+
+impl TryFrom<SubstateOwner> for OwnedAccount {
+    type Error = anyhow::Error;
+
+    fn try_from(row: SubstateOwner) -> Result<Self, Self::Error> {
+        Ok(Self {
+            component_address: ComponentAddress::from_str(&row.substate_id)?,
+            template_address: TemplateAddress::from_hex(&row.template_address)?,
+            version: row.version as u32,
+        })
+    }
+}
@@ -0,0 +1,114 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{convert::TryFrom, str::FromStr};
+
+use anyhow::anyhow;
+use tari_dan_common_types::Epoch;
+use tari_indexer_client::types::{TransferKind, TransferLedgerEntry, VaultBalanceSnapshot};
+use tari_template_lib::{
+    models::{Amount, ResourceAddress, VaultId},
+    resource::ResourceType,
+};
+use tari_transaction::TransactionId;
+
+use crate::substate_storage_sqlite::schema::*;
+
+#[derive(Debug, Identifiable, Queryable)]
+#[diesel(table_name = vault_transfers)]
+pub struct VaultTransfer {
+    pub id: i32,
+    pub vault_id: String,
+    pub resource_address: String,
+    pub resource_type: String,
+    pub kind: String,
+    pub amount: i64,
+    pub tx_hash: String,
+    pub epoch: i64,
+    pub timestamp: i64,
+    pub event_index: i32,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = vault_transfers)]
+pub struct NewVaultTransfer {
+    pub vault_id: String,
+    pub resource_address: String,
+    pub resource_type: String,
+    pub kind: String,
+    pub amount: i64,
+    pub tx_hash: String,
+    pub epoch: i64,
+    pub timestamp: i64,
+    pub event_index: i32,
+}
+
+#[derive(Debug, Identifiable, Queryable)]
+#[diesel(table_name = vault_balances)]
+pub struct VaultBalance {
+    pub id: i32,
+    pub vault_id: String,
+    pub version: i64,
+    pub resource_address: String,
+    pub balance: i64,
+    pub tx_hash: String,
+    pub epoch: i64,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = vault_balances)]
+pub struct NewVaultBalance {
+    pub vault_id: String,
+    pub version: i64,
+    pub resource_address: String,
+    pub balance: i64,
+    pub tx_hash: String,
+    pub epoch: i64,
+    pub timestamp: i64,
+}
+
+impl TryFrom<VaultTransfer> for TransferLedgerEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(row: VaultTransfer) -> Result<Self, Self::Error> {
+        let resource_type = match row.resource_type.as_str() {
+            "Fungible" => ResourceType::Fungible,
+            "NonFungible" => ResourceType::NonFungible,
+            "Confidential" => ResourceType::Confidential,
+            other => return Err(anyhow!("Invalid resource type '{}'", other)),
+        };
+        let kind = match row.kind.as_str() {
+            "Deposit" => TransferKind::Deposit,
+            "Withdraw" => TransferKind::Withdraw,
+            other => return Err(anyhow!("Invalid transfer kind '{}'", other)),
+        };
+
+        Ok(Self {
+            vault_id: VaultId::from_str(&row.vault_id)?,
+            resource_address: ResourceAddress::from_str(&row.resource_address)?,
+            resource_type,
+            kind,
+            amount: Amount::new(row.amount),
+            transaction_id: TransactionId::from_hex(&row.tx_hash)?,
+            epoch: Epoch(row.epoch as u64),
+            timestamp: row.timestamp as u64,
+        })
+    }
+}
+
+impl TryFrom<VaultBalance> for VaultBalanceSnapshot {
+    type Error = anyhow::Error;
+
+    fn try_from(row: VaultBalance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            vault_id: VaultId::from_str(&row.vault_id)?,
+            resource_address: ResourceAddress::from_str(&row.resource_address)?,
+            version: row.version as u32,
+            balance: Amount::new(row.balance),
+            transaction_id: TransactionId::from_hex(&row.tx_hash)?,
+            epoch: Epoch(row.epoch as u64),
+            timestamp: row.timestamp as u64,
+        })
+    }
+}
@@ -1,5 +1,12 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    data_migrations (name) {
+        name -> Text,
+        completed_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     event_payloads (id) {
         id -> Integer,
@@ -40,6 +47,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    substate_owners (id) {
+        id -> Integer,
+        substate_id -> Text,
+        owner_badge -> Text,
+        parent_component -> Nullable<Text>,
+        template_address -> Text,
+        version -> BigInt,
+    }
+}
+
 diesel::table! {
     substates (id) {
         id -> Integer,
@@ -53,12 +71,44 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    vault_balances (id) {
+        id -> Integer,
+        vault_id -> Text,
+        version -> BigInt,
+        resource_address -> Text,
+        balance -> BigInt,
+        tx_hash -> Text,
+        epoch -> BigInt,
+        timestamp -> BigInt,
+    }
+}
+
+diesel::table! {
+    vault_transfers (id) {
+        id -> Integer,
+        vault_id -> Text,
+        resource_address -> Text,
+        resource_type -> Text,
+        kind -> Text,
+        amount -> BigInt,
+        tx_hash -> Text,
+        epoch -> BigInt,
+        timestamp -> BigInt,
+        event_index -> Integer,
+    }
+}
+
 diesel::joinable!(event_payloads -> events (event_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    data_migrations,
     event_payloads,
     events,
     non_fungible_indexes,
     scanned_block_ids,
+    substate_owners,
     substates,
+    vault_balances,
+    vault_transfers,
 );
@@ -30,7 +30,7 @@ use std::{
 };
 
 use diesel::{
-    dsl::count,
+    dsl::{count, max},
     prelude::*,
     sql_query,
     sql_types::{Integer, Nullable, Text},
@@ -44,7 +44,7 @@ use tari_dan_storage::{consensus_models::BlockId, StorageError};
 use tari_dan_storage_sqlite::{error::SqliteStorageError, SqliteTransaction};
 use tari_engine_types::substate::SubstateId;
 use tari_indexer_client::types::ListSubstateItem;
-use tari_template_lib::models::TemplateAddress;
+use tari_template_lib::models::{ComponentAddress, NonFungibleAddress, ResourceAddress, TemplateAddress, VaultId};
 use tari_transaction::TransactionId;
 use thiserror::Error;
 
@@ -55,6 +55,8 @@ use super::models::{
 use crate::substate_storage_sqlite::models::{
     events::{Event, NewEventPayloadField, ScannedBlockId},
     substate::{NewSubstate, Substate},
+    substate_owner::{NewSubstateOwner, SubstateOwner},
+    transfer_ledger::{NewVaultBalance, NewVaultTransfer, VaultBalance, VaultTransfer},
 };
 
 const LOG_TARGET: &str = "tari::indexer::substate_storage_sqlite";
@@ -229,13 +231,58 @@ pub trait SubstateStoreReadTransaction {
         offset: u32,
         limit: u32,
     ) -> Result<Vec<Event>, StorageError>;
+    /// Returns up to `limit` events that were stored after the event with the given id, in the order that they were
+    /// stored
+    fn get_events_after(&mut self, event_id: i32, limit: u32) -> Result<Vec<Event>, StorageError>;
+    fn get_latest_event_id(&mut self) -> Result<Option<i32>, StorageError>;
     fn event_exists(&mut self, event: NewEvent) -> Result<bool, StorageError>;
     fn get_oldest_scanned_epoch(&mut self) -> Result<Option<Epoch>, StorageError>;
+    /// Returns the transfers of the given vaults, most recent first
+    fn get_vault_transfers(
+        &mut self,
+        vault_ids: &[VaultId],
+        resource_address: Option<ResourceAddress>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VaultTransfer>, StorageError>;
+    /// Returns the balance snapshots of the given vaults, most recent first
+    fn get_vault_balance_history(
+        &mut self,
+        vault_ids: &[VaultId],
+        resource_address: Option<ResourceAddress>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VaultBalance>, StorageError>;
+    /// Returns the latest balance snapshot of the vault at or before the given epoch
+    fn get_vault_balance_at_epoch(
+        &mut self,
+        vault_id: &VaultId,
+        epoch: Epoch,
+    ) -> Result<Option<VaultBalance>, StorageError>;
+    /// Returns the components of the given templates that are owned by the badge
+    fn get_components_by_owner(
+        &mut self,
+        owner_badge: &NonFungibleAddress,
+        template_addresses: &[TemplateAddress],
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SubstateOwner>, StorageError>;
+    /// Returns the vaults held by the components of the given templates that are owned by the badge
+    fn get_vaults_by_owner(
+        &mut self,
+        owner_badge: &NonFungibleAddress,
+        template_addresses: &[TemplateAddress],
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SubstateOwner>, StorageError>;
     fn get_last_scanned_block_id(
         &mut self,
         epoch: Epoch,
         shard_group: ShardGroup,
     ) -> Result<Option<BlockId>, StorageError>;
+    /// Returns up to `limit` component substates with a row id greater than `after_id`, ordered by row id
+    fn get_components_after(&mut self, after_id: i32, limit: u32) -> Result<Vec<Substate>, StorageError>;
+    fn is_data_migration_completed(&mut self, name: &str) -> Result<bool, StorageError>;
 }
 
 impl SubstateStoreReadTransaction for SqliteSubstateStoreReadTransaction<'_> {
@@ -581,6 +628,34 @@ impl SubstateStoreReadTransaction for SqliteSubstateStoreReadTransaction<'_> {
         Ok(events)
     }
 
+    fn get_events_after(&mut self, event_id: i32, limit: u32) -> Result<Vec<Event>, StorageError> {
+        use crate::substate_storage_sqlite::schema::events;
+
+        let events = events::table
+            .filter(events::id.gt(event_id))
+            .order_by(events::id.asc())
+            .limit(i64::from(limit))
+            .get_results::<Event>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_events_after: {}", e),
+            })?;
+
+        Ok(events)
+    }
+
+    fn get_latest_event_id(&mut self) -> Result<Option<i32>, StorageError> {
+        use crate::substate_storage_sqlite::schema::events;
+
+        let event_id = events::table
+            .select(max(events::id))
+            .first::<Option<i32>>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_latest_event_id: {}", e),
+            })?;
+
+        Ok(event_id)
+    }
+
     fn event_exists(&mut self, value: NewEvent) -> Result<bool, StorageError> {
         use crate::substate_storage_sqlite::schema::events;
 
@@ -604,6 +679,162 @@ impl SubstateStoreReadTransaction for SqliteSubstateStoreReadTransaction<'_> {
         Ok(exists)
     }
 
+    fn get_vault_transfers(
+        &mut self,
+        vault_ids: &[VaultId],
+        resource_address: Option<ResourceAddress>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VaultTransfer>, StorageError> {
+        use crate::substate_storage_sqlite::schema::vault_transfers;
+
+        let mut query = vault_transfers::table
+            .filter(vault_transfers::vault_id.eq_any(vault_ids.iter().map(|id| id.to_string())))
+            .into_boxed();
+
+        if let Some(resource_address) = resource_address {
+            query = query.filter(vault_transfers::resource_address.eq(resource_address.to_string()));
+        }
+
+        let transfers = query
+            .order_by(vault_transfers::id.desc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .get_results::<VaultTransfer>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_vault_transfers: {}", e),
+            })?;
+
+        Ok(transfers)
+    }
+
+    fn get_vault_balance_history(
+        &mut self,
+        vault_ids: &[VaultId],
+        resource_address: Option<ResourceAddress>,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<VaultBalance>, StorageError> {
+        use crate::substate_storage_sqlite::schema::vault_balances;
+
+        let mut query = vault_balances::table
+            .filter(vault_balances::vault_id.eq_any(vault_ids.iter().map(|id| id.to_string())))
+            .into_boxed();
+
+        if let Some(resource_address) = resource_address {
+            query = query.filter(vault_balances::resource_address.eq(resource_address.to_string()));
+        }
+
+        let balances = query
+            .order_by((vault_balances::epoch.desc(), vault_balances::id.desc()))
+            .offset(offset.into())
+            .limit(limit.into())
+            .get_results::<VaultBalance>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_vault_balance_history: {}", e),
+            })?;
+
+        Ok(balances)
+    }
+
+    fn get_vault_balance_at_epoch(
+        &mut self,
+        vault_id: &VaultId,
+        epoch: Epoch,
+    ) -> Result<Option<VaultBalance>, StorageError> {
+        use crate::substate_storage_sqlite::schema::vault_balances;
+
+        let balance = vault_balances::table
+            .filter(vault_balances::vault_id.eq(vault_id.to_string()))
+            .filter(vault_balances::epoch.le(epoch.as_u64() as i64))
+            .order_by(vault_balances::version.desc())
+            .first::<VaultBalance>(self.connection())
+            .optional()
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_vault_balance_at_epoch: {}", e),
+            })?;
+
+        Ok(balance)
+    }
+
+    fn get_components_by_owner(
+        &mut self,
+        owner_badge: &NonFungibleAddress,
+        template_addresses: &[TemplateAddress],
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SubstateOwner>, StorageError> {
+        use crate::substate_storage_sqlite::schema::substate_owners;
+
+        let components = substate_owners::table
+            .filter(substate_owners::owner_badge.eq(owner_badge.to_string()))
+            .filter(substate_owners::parent_component.is_null())
+            .filter(substate_owners::template_address.eq_any(template_addresses.iter().map(|t| t.to_string())))
+            .order_by(substate_owners::id.asc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .get_results::<SubstateOwner>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_components_by_owner: {}", e),
+            })?;
+
+        Ok(components)
+    }
+
+    fn get_vaults_by_owner(
+        &mut self,
+        owner_badge: &NonFungibleAddress,
+        template_addresses: &[TemplateAddress],
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SubstateOwner>, StorageError> {
+        use crate::substate_storage_sqlite::schema::substate_owners;
+
+        let vaults = substate_owners::table
+            .filter(substate_owners::owner_badge.eq(owner_badge.to_string()))
+            .filter(substate_owners::parent_component.is_not_null())
+            .filter(substate_owners::template_address.eq_any(template_addresses.iter().map(|t| t.to_string())))
+            .order_by(substate_owners::id.asc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .get_results::<SubstateOwner>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_vaults_by_owner: {}", e),
+            })?;
+
+        Ok(vaults)
+    }
+
+    fn get_components_after(&mut self, after_id: i32, limit: u32) -> Result<Vec<Substate>, StorageError> {
+        use crate::substate_storage_sqlite::schema::substates;
+
+        let components = substates::table
+            .filter(substates::id.gt(after_id))
+            .filter(substates::address.like(format!("{}_%", SubstateType::Component.as_prefix_str())))
+            .order_by(substates::id.asc())
+            .limit(limit.into())
+            .get_results::<Substate>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("get_components_after: {}", e),
+            })?;
+
+        Ok(components)
+    }
+
+    fn is_data_migration_completed(&mut self, name: &str) -> Result<bool, StorageError> {
+        use crate::substate_storage_sqlite::schema::data_migrations;
+
+        let count = data_migrations::table
+            .filter(data_migrations::name.eq(name))
+            .count()
+            .get_result::<i64>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("is_data_migration_completed: {}", e),
+            })?;
+
+        Ok(count > 0)
+    }
+
     fn get_oldest_scanned_epoch(&mut self) -> Result<Option<Epoch>, StorageError> {
         use crate::substate_storage_sqlite::schema::scanned_block_ids;
 
@@ -677,9 +908,23 @@ pub trait SubstateStoreWriteTransaction {
     fn clear_substates(&mut self) -> Result<(), StorageError>;
     #[allow(dead_code)]
     fn add_non_fungible_index(&mut self, new_nft_index: NewNonFungibleIndex) -> Result<(), StorageError>;
-    fn save_event(&mut self, new_event: NewEvent) -> Result<(), StorageError>;
+    /// Saves the event and returns its id
+    fn save_event(&mut self, new_event: NewEvent) -> Result<i32, StorageError>;
     fn save_scanned_block_id(&mut self, new_scanned_block_id: NewScannedBlockId) -> Result<(), StorageError>;
+    /// Saves a transfer derived from a vault event. Does nothing if the transfer for the event has already been saved.
+    fn save_vault_transfer(&mut self, new_transfer: NewVaultTransfer) -> Result<(), StorageError>;
+    /// Saves the balance of a version of a vault. Does nothing if the version has already been saved.
+    fn save_vault_balance(&mut self, new_balance: NewVaultBalance) -> Result<(), StorageError>;
+    /// Replaces the indexed owners of a component and of the vaults that it holds. Does nothing if a newer version of
+    /// the component has already been indexed.
+    fn set_component_owners(
+        &mut self,
+        component_address: &ComponentAddress,
+        version: u32,
+        owners: Vec<NewSubstateOwner>,
+    ) -> Result<(), StorageError>;
     fn delete_scanned_epochs_older_than(&mut self, epoch: Epoch) -> Result<(), StorageError>;
+    fn set_data_migration_completed(&mut self, name: &str) -> Result<(), StorageError>;
 }
 
 impl SubstateStoreWriteTransaction for SqliteSubstateStoreWriteTransaction<'_> {
@@ -774,7 +1019,7 @@ impl SubstateStoreWriteTransaction for SqliteSubstateStoreWriteTransaction<'_> {
         Ok(())
     }
 
-    fn save_event(&mut self, new_event: NewEvent) -> Result<(), StorageError> {
+    fn save_event(&mut self, new_event: NewEvent) -> Result<i32, StorageError> {
         use crate::substate_storage_sqlite::schema::{event_payloads, events};
 
         // Save the event into the database
@@ -819,6 +1064,90 @@ impl SubstateStoreWriteTransaction for SqliteSubstateStoreWriteTransaction<'_> {
             new_event.version,
         );
 
+        Ok(event_row.id)
+    }
+
+    fn save_vault_transfer(&mut self, new_transfer: NewVaultTransfer) -> Result<(), StorageError> {
+        use crate::substate_storage_sqlite::schema::vault_transfers;
+
+        diesel::insert_or_ignore_into(vault_transfers::table)
+            .values(&new_transfer)
+            .execute(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("save_vault_transfer: {}", e),
+            })?;
+
+        Ok(())
+    }
+
+    fn save_vault_balance(&mut self, new_balance: NewVaultBalance) -> Result<(), StorageError> {
+        use crate::substate_storage_sqlite::schema::vault_balances;
+
+        diesel::insert_or_ignore_into(vault_balances::table)
+            .values(&new_balance)
+            .execute(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("save_vault_balance: {}", e),
+            })?;
+
+        Ok(())
+    }
+
+    fn set_component_owners(
+        &mut self,
+        component_address: &ComponentAddress,
+        version: u32,
+        owners: Vec<NewSubstateOwner>,
+    ) -> Result<(), StorageError> {
+        use crate::substate_storage_sqlite::schema::substate_owners;
+
+        let component_id = component_address.to_string();
+        let indexed_version = substate_owners::table
+            .select(max(substate_owners::version))
+            .filter(substate_owners::substate_id.eq(&component_id))
+            .first::<Option<i64>>(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("set_component_owners: {}", e),
+            })?;
+        if indexed_version.is_some_and(|v| v > i64::from(version)) {
+            return Ok(());
+        }
+
+        diesel::delete(substate_owners::table)
+            .filter(
+                substate_owners::substate_id
+                    .eq(&component_id)
+                    .or(substate_owners::parent_component.eq(&component_id)),
+            )
+            .execute(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("set_component_owners: {}", e),
+            })?;
+
+        if owners.is_empty() {
+            return Ok(());
+        }
+
+        diesel::insert_or_ignore_into(substate_owners::table)
+            .values(&owners)
+            .execute(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("set_component_owners: {}", e),
+            })?;
+
+        Ok(())
+    }
+
+    fn set_data_migration_completed(&mut self, name: &str) -> Result<(), StorageError> {
+        use crate::substate_storage_sqlite::schema::data_migrations;
+
+        diesel::insert_or_ignore_into(data_migrations::table)
+            .values(data_migrations::name.eq(name))
+            .execute(self.connection())
+            .map_err(|e| StorageError::QueryError {
+                reason: format!("set_data_migration_completed: {}", e),
+            })?;
+
         Ok(())
     }
 
@@ -881,3 +1210,232 @@ impl Drop for SqliteSubstateStoreWriteTransaction<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tari_template_lib::models::{NonFungibleId, ObjectKey};
+
+    use super::*;
+
+    struct TestStore {
+        store: SqliteSubstateStore,
+        _temp: tempfile::TempDir,
+    }
+
+    impl TestStore {
+        fn new() -> Self {
+            let temp = tempfile::tempdir().unwrap();
+            let store = SqliteSubstateStore::try_create(temp.path().join("indexer.sqlite")).unwrap();
+            Self { store, _temp: temp }
+        }
+    }
+
+    fn vault_id(n: u8) -> VaultId {
+        VaultId::new(ObjectKey::from_array([n; ObjectKey::LENGTH]))
+    }
+
+    fn resource_address(n: u8) -> ResourceAddress {
+        ResourceAddress::new(ObjectKey::from_array([n; ObjectKey::LENGTH]))
+    }
+
+    fn new_transfer(vault: u8, resource: u8, amount: i64, tx: u8, event_index: i32) -> NewVaultTransfer {
+        NewVaultTransfer {
+            vault_id: vault_id(vault).to_string(),
+            resource_address: resource_address(resource).to_string(),
+            resource_type: "Fungible".to_string(),
+            kind: "Deposit".to_string(),
+            amount,
+            tx_hash: to_hex(&[tx; 32]),
+            epoch: 1,
+            timestamp: 0,
+            event_index,
+        }
+    }
+
+    fn new_balance(vault: u8, resource: u8, version: i64, balance: i64, epoch: i64) -> NewVaultBalance {
+        NewVaultBalance {
+            vault_id: vault_id(vault).to_string(),
+            version,
+            resource_address: resource_address(resource).to_string(),
+            balance,
+            tx_hash: to_hex(&[version as u8; 32]),
+            epoch,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn it_returns_vault_transfers_most_recent_first() {
+        let test = TestStore::new();
+        test.store
+            .with_write_tx(|tx| {
+                tx.save_vault_transfer(new_transfer(1, 1, 10, 1, 0))?;
+                tx.save_vault_transfer(new_transfer(2, 2, 20, 1, 1))?;
+                tx.save_vault_transfer(new_transfer(1, 1, 30, 2, 0))?;
+                tx.save_vault_transfer(new_transfer(3, 1, 40, 2, 1))?;
+                // A transfer is only saved once for each event
+                tx.save_vault_transfer(new_transfer(1, 1, 30, 2, 0))
+            })
+            .unwrap();
+
+        let amounts = |vault_ids: &[VaultId], resource: Option<ResourceAddress>, offset: u32, limit: u32| {
+            test.store
+                .with_read_tx(|tx| tx.get_vault_transfers(vault_ids, resource, offset, limit))
+                .unwrap()
+                .into_iter()
+                .map(|t| t.amount)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(amounts(&[vault_id(1)], None, 0, 10), vec![30, 10]);
+        assert_eq!(amounts(&[vault_id(1), vault_id(2)], None, 0, 10), vec![30, 20, 10]);
+        assert_eq!(amounts(&[vault_id(1), vault_id(2)], None, 0, 2), vec![30, 20]);
+        assert_eq!(amounts(&[vault_id(1), vault_id(2)], None, 2, 2), vec![10]);
+        assert_eq!(
+            amounts(&[vault_id(1), vault_id(2)], Some(resource_address(2)), 0, 10),
+            vec![20]
+        );
+        assert!(amounts(&[vault_id(4)], None, 0, 10).is_empty());
+    }
+
+    #[test]
+    fn it_returns_vault_balance_history_most_recent_first() {
+        let test = TestStore::new();
+        test.store
+            .with_write_tx(|tx| {
+                tx.save_vault_balance(new_balance(1, 1, 0, 100, 1))?;
+                tx.save_vault_balance(new_balance(2, 2, 0, 5, 2))?;
+                tx.save_vault_balance(new_balance(1, 1, 1, 150, 3))?;
+                tx.save_vault_balance(new_balance(1, 1, 2, 120, 3))?;
+                // A balance is only saved once for each version
+                tx.save_vault_balance(new_balance(1, 1, 2, 999, 4))
+            })
+            .unwrap();
+
+        let balances = |vault_ids: &[VaultId], resource: Option<ResourceAddress>, offset: u32, limit: u32| {
+            test.store
+                .with_read_tx(|tx| tx.get_vault_balance_history(vault_ids, resource, offset, limit))
+                .unwrap()
+                .into_iter()
+                .map(|b| b.balance)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(balances(&[vault_id(1)], None, 0, 10), vec![120, 150, 100]);
+        assert_eq!(balances(&[vault_id(1), vault_id(2)], None, 0, 10), vec![
+            120, 150, 5, 100
+        ]);
+        assert_eq!(balances(&[vault_id(1), vault_id(2)], None, 1, 2), vec![150, 5]);
+        assert_eq!(
+            balances(&[vault_id(1), vault_id(2)], Some(resource_address(2)), 0, 10),
+            vec![5]
+        );
+    }
+
+    #[test]
+    fn it_returns_the_vault_balance_at_an_epoch() {
+        let test = TestStore::new();
+        test.store
+            .with_write_tx(|tx| {
+                tx.save_vault_balance(new_balance(1, 1, 0, 100, 2))?;
+                tx.save_vault_balance(new_balance(1, 1, 1, 150, 4))?;
+                tx.save_vault_balance(new_balance(1, 1, 2, 120, 4))?;
+                tx.save_vault_balance(new_balance(2, 1, 0, 5, 1))
+            })
+            .unwrap();
+
+        let balance_at = |epoch: u64| {
+            test.store
+                .with_read_tx(|tx| tx.get_vault_balance_at_epoch(&vault_id(1), Epoch(epoch)))
+                .unwrap()
+                .map(|b| b.balance)
+        };
+
+        assert_eq!(balance_at(1), None);
+        assert_eq!(balance_at(2), Some(100));
+        assert_eq!(balance_at(3), Some(100));
+        // The latest version in the epoch is returned
+        assert_eq!(balance_at(4), Some(120));
+        assert_eq!(balance_at(10), Some(120));
+    }
+
+    fn owner_badge(n: u32) -> NonFungibleAddress {
+        NonFungibleAddress::new(resource_address(9), NonFungibleId::from_u32(n))
+    }
+
+    fn new_owners(component: ComponentAddress, vaults: &[u8], owner: u32, version: u32) -> Vec<NewSubstateOwner> {
+        let template_address = TemplateAddress::from_array([1; 32]).to_string();
+        let component = component.to_string();
+        std::iter::once((component.clone(), None))
+            .chain(
+                vaults
+                    .iter()
+                    .map(|n| (vault_id(*n).to_string(), Some(component.clone()))),
+            )
+            .map(|(substate_id, parent_component)| NewSubstateOwner {
+                substate_id,
+                owner_badge: owner_badge(owner).to_string(),
+                parent_component,
+                template_address: template_address.clone(),
+                version: i64::from(version),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_only_replaces_component_owners_with_a_newer_version() {
+        let test = TestStore::new();
+        let template_address = TemplateAddress::from_array([1; 32]);
+        let component = ComponentAddress::from_array([1; 32]);
+
+        let owned = |owner: u32| {
+            test.store
+                .with_read_tx(|tx| {
+                    let components = tx.get_components_by_owner(&owner_badge(owner), &[template_address], 0, 10)?;
+                    let vaults = tx.get_vaults_by_owner(&owner_badge(owner), &[template_address], 0, 10)?;
+                    Ok::<_, StorageError>((
+                        components.into_iter().map(|c| c.version).collect::<Vec<_>>(),
+                        vaults.into_iter().map(|v| v.substate_id).collect::<Vec<_>>(),
+                    ))
+                })
+                .unwrap()
+        };
+
+        test.store
+            .with_write_tx(|tx| tx.set_component_owners(&component, 2, new_owners(component, &[1, 2], 1, 2)))
+            .unwrap();
+        assert_eq!(
+            owned(1),
+            (vec![2], vec![vault_id(1).to_string(), vault_id(2).to_string()])
+        );
+
+        // An older version does not replace the owners
+        test.store
+            .with_write_tx(|tx| tx.set_component_owners(&component, 1, new_owners(component, &[3], 2, 1)))
+            .unwrap();
+        assert_eq!(
+            owned(1),
+            (vec![2], vec![vault_id(1).to_string(), vault_id(2).to_string()])
+        );
+        assert_eq!(owned(2), (vec![], vec![]));
+
+        // The same version replaces the owners e.g. when the backfill indexes a component again
+        test.store
+            .with_write_tx(|tx| tx.set_component_owners(&component, 2, new_owners(component, &[1], 1, 2)))
+            .unwrap();
+        assert_eq!(owned(1), (vec![2], vec![vault_id(1).to_string()]));
+
+        // A newer version replaces the owners and the vaults of the component
+        test.store
+            .with_write_tx(|tx| tx.set_component_owners(&component, 3, new_owners(component, &[3], 2, 3)))
+            .unwrap();
+        assert_eq!(owned(1), (vec![], vec![]));
+        assert_eq!(owned(2), (vec![3], vec![vault_id(3).to_string()]));
+
+        // A newer version without owners removes the owners
+        test.store
+            .with_write_tx(|tx| tx.set_component_owners(&component, 4, vec![]))
+            .unwrap();
+        assert_eq!(owned(2), (vec![], vec![]));
+    }
+}
@@ -113,7 +113,13 @@ use crate::{
         NopLogger,
     },
     substate_resolver::TariSubstateResolver,
-    transaction_validators::{FeeTransactionValidator, HasInputs, TemplateExistsValidator, TransactionValidationError},
+    transaction_validators::{
+        FeeTransactionValidator,
+        HasInputs,
+        InstructionNestingDepthValidator,
+        TemplateExistsValidator,
+        TransactionValidationError,
+    },
     validator::Validator,
     validator_registration_file::ValidatorRegistrationFile,
     virtual_substate::VirtualSubstateManager,
@@ -186,6 +192,7 @@ pub async fn spawn_services(
             },
             reachability_mode: config.validator_node.p2p.reachability_mode.into(),
             announce: true,
+            peer_store_path: Some(config.validator_node.peer_store_path()),
             ..Default::default()
         },
         seed_peers,
@@ -247,6 +254,8 @@ pub async fn spawn_services(
         per_byte_storage_cost: 1,
         per_event_cost: 1,
         per_log_cost: 1,
+        per_million_wasm_points_cost: 1,
+        per_wasm_memory_page_cost: 1,
     };
 
     // Consensus gossip
@@ -263,6 +272,7 @@ pub async fn spawn_services(
         rx_consensus_messages,
         rx_consensus_gossip_messages,
         loopback_receiver,
+        networking.clone(),
         message_logger.clone(),
     );
     let outbound_messaging = ConsensusOutboundMessaging::new(
@@ -300,6 +310,7 @@ pub async fn spawn_services(
         shutdown.clone(),
         transaction_executor,
         consensus_constants.clone(),
+        config.validator_node.max_execution_concurrency,
     )
     .await;
     handles.push(consensus_join_handle);
@@ -622,6 +633,7 @@ fn create_mempool_transaction_validator(
     template_manager: TemplateManager<PeerAddress>,
 ) -> impl Validator<Transaction, Context = (), Error = TransactionValidationError> {
     HasInputs::new()
+        .and_then(InstructionNestingDepthValidator)
         .and_then(TemplateExistsValidator::new(template_manager))
         .and_then(FeeTransactionValidator)
 }
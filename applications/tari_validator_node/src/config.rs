@@ -107,6 +107,9 @@ pub struct ValidatorNodeConfig {
     pub template_sidechain_id: Option<RistrettoPublicKey>,
     /// The burnt utxo sidechain id
     pub burnt_utxo_sidechain_id: Option<RistrettoPublicKey>,
+    /// The maximum number of independent transactions in a block to execute concurrently. 0 uses the number of
+    /// available CPUs and 1 disables parallel execution.
+    pub max_execution_concurrency: usize,
 }
 
 impl ValidatorNodeConfig {
@@ -114,6 +117,10 @@ impl ValidatorNodeConfig {
         self.data_dir.join("state.db")
     }
 
+    pub fn peer_store_path(&self) -> PathBuf {
+        self.data_dir.join("peer_store.sqlite")
+    }
+
     pub fn set_base_path<P: AsRef<Path>>(&mut self, base_path: P) {
         if !self.shard_key_file.is_absolute() {
             self.shard_key_file = base_path.as_ref().join(&self.shard_key_file);
@@ -150,6 +157,7 @@ impl Default for ValidatorNodeConfig {
             validator_node_sidechain_id: None,
             template_sidechain_id: None,
             burnt_utxo_sidechain_id: None,
+            max_execution_concurrency: 0,
         }
     }
 }
@@ -4,7 +4,7 @@
 use std::{collections::HashMap, sync::Arc};
 
 use log::info;
-use tari_consensus::traits::{BlockTransactionExecutor, BlockTransactionExecutorError};
+use tari_consensus::traits::{BlockExecutionContext, BlockTransactionExecutor, BlockTransactionExecutorError};
 use tari_dan_app_utilities::transaction_executor::TransactionExecutor;
 use tari_dan_common_types::{Epoch, SubstateRequirement};
 use tari_dan_engine::state_store::{memory::MemoryStateStore, new_memory_store, StateWriter};
@@ -13,6 +13,7 @@ use tari_engine_types::{
     substate::Substate,
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
+use tari_template_lib::models::BlockInfo;
 use tari_transaction::Transaction;
 
 use crate::{transaction_validators::TransactionValidationError, validator::Validator};
@@ -71,7 +72,7 @@ where
     fn execute(
         &self,
         transaction: Transaction,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         resolved_inputs: &HashMap<SubstateRequirement, Substate>,
     ) -> Result<ExecutedTransaction, BlockTransactionExecutorError> {
         let id = *transaction.id();
@@ -85,7 +86,16 @@ where
         let mut virtual_substates = VirtualSubstates::new();
         virtual_substates.insert(
             VirtualSubstateId::CurrentEpoch,
-            VirtualSubstate::CurrentEpoch(current_epoch.as_u64()),
+            VirtualSubstate::CurrentEpoch(context.epoch.as_u64()),
+        );
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentBlock,
+            VirtualSubstate::CurrentBlock(BlockInfo {
+                height: context.height.as_u64(),
+                timestamp: context.timestamp,
+                shard_group_start: context.shard_group.start().as_u32(),
+                shard_group_end: context.shard_group.end().as_u32(),
+            }),
         );
 
         // Execute the transaction and get the result
@@ -4,7 +4,11 @@
 use std::{collections::HashMap, str::FromStr};
 
 use prometheus::{core::Collector, IntCounter, IntGauge, IntGaugeVec, Opts, Registry};
-use tari_consensus::{hotstuff::HotStuffError, messages::HotstuffMessage, traits::hooks::ConsensusHooks};
+use tari_consensus::{
+    hotstuff::{BlockExecutionStats, HotStuffError},
+    messages::HotstuffMessage,
+    traits::hooks::ConsensusHooks,
+};
 use tari_dan_common_types::{NodeHeight, PeerAddress};
 use tari_dan_storage::{
     consensus_models::{Decision, QuorumDecision, TransactionAtom, ValidBlock},
@@ -37,6 +41,9 @@ pub struct PrometheusConsensusMetrics<S = SqliteStateStore<PeerAddress>> {
     transactions_ready_for_consensus: IntCounter,
     transactions_finalized_committed: IntCounter,
     transactions_finalized_aborted: IntCounter,
+    transactions_executed_parallel: IntCounter,
+    transactions_executed_sequential: IntCounter,
+    transactions_execution_discarded: IntCounter,
 }
 
 impl<S: StateStore> PrometheusConsensusMetrics<S> {
@@ -97,6 +104,24 @@ impl<S: StateStore> PrometheusConsensusMetrics<S> {
             )
             .unwrap()
             .register_at(registry),
+            transactions_executed_parallel: IntCounter::new(
+                "consensus_transactions_executed_parallel",
+                "Number of transactions executed in parallel with other transactions in the block",
+            )
+            .unwrap()
+            .register_at(registry),
+            transactions_executed_sequential: IntCounter::new(
+                "consensus_transactions_executed_sequential",
+                "Number of transactions executed sequentially",
+            )
+            .unwrap()
+            .register_at(registry),
+            transactions_execution_discarded: IntCounter::new(
+                "consensus_transactions_execution_discarded",
+                "Number of parallel executions discarded because the transaction inputs changed",
+            )
+            .unwrap()
+            .register_at(registry),
             _transactions_pool_size: IntGauge::new(
                 "consensus_transactions_pool_size",
                 "Number of transactions in pool",
@@ -151,6 +176,13 @@ impl<S: StateStore> ConsensusHooks for PrometheusConsensusMetrics<S> {
         }
     }
 
+    fn on_block_transactions_executed(&mut self, _block: &ValidBlock, stats: &BlockExecutionStats) {
+        self.transactions_executed_parallel.inc_by(stats.num_parallel as u64);
+        self.transactions_executed_sequential
+            .inc_by(stats.num_sequential as u64);
+        self.transactions_execution_discarded.inc_by(stats.num_discarded as u64);
+    }
+
     fn on_block_validation_failed<E: ToString>(&mut self, _err: &E) {
         self.blocks_validation_failed.inc();
     }
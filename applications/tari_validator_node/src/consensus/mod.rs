@@ -25,7 +25,7 @@ use tokio::{
 };
 
 use crate::{
-    consensus::{leader_selection::RoundRobinLeaderStrategy, spec::TariConsensusSpec},
+    consensus::spec::TariConsensusSpec,
     event_subscription::EventSubscription,
     p2p::services::messaging::{ConsensusInboundMessaging, ConsensusOutboundMessaging},
     transaction_validators::{
@@ -33,6 +33,7 @@ use crate::{
         EpochRangeValidator,
         FeeTransactionValidator,
         HasInputs,
+        InstructionNestingDepthValidator,
         TemplateExistsValidator,
         TransactionSignatureValidator,
         TransactionValidationError,
@@ -42,7 +43,6 @@ use crate::{
 
 mod block_transaction_executor;
 mod handle;
-mod leader_selection;
 #[cfg(feature = "metrics")]
 pub mod metrics;
 mod signature_service;
@@ -51,7 +51,7 @@ mod spec;
 pub use block_transaction_executor::*;
 pub use handle::*;
 pub use signature_service::*;
-use tari_consensus::consensus_constants::ConsensusConstants;
+use tari_consensus::{consensus_constants::ConsensusConstants, leader_selection::ConsensusLeaderStrategy};
 
 use crate::{p2p::NopLogger, transaction_validators::WithContext};
 
@@ -74,10 +74,11 @@ pub async fn spawn(
         ConsensusTransactionValidator,
     >,
     consensus_constants: ConsensusConstants,
+    max_execution_concurrency: usize,
 ) -> (JoinHandle<Result<(), anyhow::Error>>, ConsensusHandle) {
     let (tx_new_transaction, rx_new_transactions) = mpsc::channel(10);
 
-    let leader_strategy = RoundRobinLeaderStrategy::new();
+    let leader_strategy = ConsensusLeaderStrategy::new(consensus_constants.leader_selection);
     let transaction_pool = TransactionPool::new();
     let (tx_hotstuff_events, _) = broadcast::channel(100);
 
@@ -85,6 +86,7 @@ pub async fn spawn(
         network,
         sidechain_id,
         consensus_constants,
+        max_execution_concurrency,
     };
 
     let hotstuff_worker = HotstuffWorker::<TariConsensusSpec>::new(
@@ -132,6 +134,7 @@ pub fn create_transaction_validator(
         .map_context(
             |_| (),
             HasInputs::new()
+                .and_then(InstructionNestingDepthValidator)
                 .and_then(TransactionSignatureValidator)
                 .and_then(TemplateExistsValidator::new(template_manager)),
         )
@@ -3,7 +3,7 @@
 
 #[cfg(not(feature = "metrics"))]
 use tari_consensus::traits::hooks::NoopHooks;
-use tari_consensus::traits::ConsensusSpec;
+use tari_consensus::{leader_selection::ConsensusLeaderStrategy, traits::ConsensusSpec};
 use tari_dan_app_utilities::{
     template_manager::implementation::TemplateManager,
     transaction_executor::TariDanTransactionProcessor,
@@ -17,7 +17,6 @@ use tari_state_store_sqlite::SqliteStateStore;
 use crate::consensus::metrics::PrometheusConsensusMetrics;
 use crate::{
     consensus::{
-        leader_selection::RoundRobinLeaderStrategy,
         signature_service::TariSignatureService,
         ConsensusTransactionValidator,
         TariDanBlockTransactionExecutor,
@@ -39,7 +38,7 @@ impl ConsensusSpec for TariConsensusSpec {
     #[cfg(feature = "metrics")]
     type Hooks = PrometheusConsensusMetrics;
     type InboundMessaging = ConsensusInboundMessaging<NopLogger>;
-    type LeaderStrategy = RoundRobinLeaderStrategy;
+    type LeaderStrategy = ConsensusLeaderStrategy;
     type OutboundMessaging = ConsensusOutboundMessaging<NopLogger>;
     type SignatureService = TariSignatureService;
     type StateStore = SqliteStateStore<Self::Addr>;
@@ -26,7 +26,10 @@ use axum_jrpc::{
     JsonRpcExtractor,
     JsonRpcResponse,
 };
-use libp2p::swarm::dial_opts::{DialOpts, PeerCondition};
+use libp2p::{
+    swarm::dial_opts::{DialOpts, PeerCondition},
+    PeerId,
+};
 use log::*;
 use serde_json::{self as json, json};
 use tari_base_node_client::{grpc::GrpcBaseNodeClient, BaseNodeClient};
@@ -46,10 +49,13 @@ use tari_validator_node_client::types::{
     self,
     AddPeerRequest,
     AddPeerResponse,
+    BanPeerRequest,
+    BanPeerResponse,
     ConnectionDirection,
     DryRunTransactionFinalizeResult,
     GetAllVnsRequest,
     GetAllVnsResponse,
+    GetBannedPeersResponse,
     GetBlockRequest,
     GetBlockResponse,
     GetBlocksCountResponse,
@@ -88,6 +94,8 @@ use tari_validator_node_client::types::{
     SubmitTransactionResponse,
     SubstateStatus,
     TemplateMetadata,
+    UnbanPeerRequest,
+    UnbanPeerResponse,
 };
 
 use crate::{
@@ -664,6 +672,59 @@ impl JsonRpcHandlers {
         Ok(JsonRpcResponse::success(answer_id, AddPeerResponse {}))
     }
 
+    pub async fn ban_peer(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let BanPeerRequest {
+            peer_id,
+            reason,
+            ban_duration,
+        } = value.parse_params()?;
+        let peer_id = parse_peer_id(answer_id, &peer_id)?;
+
+        self.networking
+            .ban_peer(peer_id, reason, ban_duration)
+            .await
+            .map_err(internal_error(answer_id))?;
+
+        Ok(JsonRpcResponse::success(answer_id, BanPeerResponse {}))
+    }
+
+    pub async fn unban_peer(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let UnbanPeerRequest { peer_id } = value.parse_params()?;
+        let peer_id = parse_peer_id(answer_id, &peer_id)?;
+
+        let was_banned = self
+            .networking
+            .unban_peer(peer_id)
+            .await
+            .map_err(internal_error(answer_id))?;
+
+        Ok(JsonRpcResponse::success(answer_id, UnbanPeerResponse { was_banned }))
+    }
+
+    pub async fn get_banned_peers(&self, value: JsonRpcExtractor) -> JrpcResult {
+        let answer_id = value.get_answer_id();
+        let banned_peers = self
+            .networking
+            .get_banned_peers()
+            .await
+            .map_err(internal_error(answer_id))?;
+
+        let banned_peers = banned_peers
+            .into_iter()
+            .map(|banned| types::BannedPeer {
+                peer_id: banned.peer_id.to_string(),
+                reason: banned.reason,
+                banned_until: banned.banned_until.as_secs(),
+            })
+            .collect();
+
+        Ok(JsonRpcResponse::success(answer_id, GetBannedPeersResponse {
+            banned_peers,
+        }))
+    }
+
     pub async fn get_comms_stats(&self, value: JsonRpcExtractor) -> JrpcResult {
         let answer_id = value.get_answer_id();
         let peers = self
@@ -760,3 +821,16 @@ impl JsonRpcHandlers {
         }))
     }
 }
+
+fn parse_peer_id(answer_id: i64, peer_id: &str) -> Result<PeerId, JsonRpcResponse> {
+    peer_id.parse().map_err(|err| {
+        JsonRpcResponse::error(
+            answer_id,
+            JsonRpcError::new(
+                JsonRpcErrorReason::InvalidParams,
+                format!("Invalid peer id '{peer_id}': {err}"),
+                json::Value::Null,
+            ),
+        )
+    })
+}
@@ -97,6 +97,9 @@ async fn handler(Extension(handlers): Extension<Arc<JsonRpcHandlers>>, value: Js
         "add_peer" => handlers.add_peer(value).await,
         "get_comms_stats" => handlers.get_comms_stats(value).await,
         "get_connections" => handlers.get_connections(value).await,
+        "ban_peer" => handlers.ban_peer(value).await,
+        "unban_peer" => handlers.unban_peer(value).await,
+        "get_banned_peers" => handlers.get_banned_peers(value).await,
         method => Ok(value.method_not_found(method)),
     };
 
@@ -122,11 +122,19 @@ impl ConsensusGossipService<PeerAddress> {
     ) -> Result<(), ConsensusGossipError> {
         let (from, msg) = msg;
 
-        let (_, msg) = self
-            .codec
-            .decode_from(&mut msg.data.as_slice())
-            .await
-            .map_err(|e| ConsensusGossipError::InvalidMessage(e.into()))?;
+        let (_, msg) = match self.codec.decode_from(&mut msg.data.as_slice()).await {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                if let Err(err) = self
+                    .networking
+                    .report_invalid_message(from, format!("Invalid consensus gossip message: {e}"))
+                    .await
+                {
+                    error!(target: LOG_TARGET, "Failed to report invalid message from {from}: {err}");
+                }
+                return Err(ConsensusGossipError::InvalidMessage(e.into()));
+            },
+        };
 
         self.tx_consensus_gossip
             .send((from, msg))
@@ -83,7 +83,16 @@ impl MempoolGossip<PeerAddress> {
                 num_pending,
                 message_size: msg_len,
             })),
-            Err(e) => Some(Err(MempoolError::InvalidMessage(e.into()))),
+            Err(e) => {
+                if let Err(err) = self
+                    .networking
+                    .report_invalid_message(from, format!("Invalid mempool message: {e}"))
+                    .await
+                {
+                    error!(target: LOG_TARGET, "Failed to report invalid message from {from}: {err}");
+                }
+                Some(Err(MempoolError::InvalidMessage(e.into())))
+            },
         }
     }
 
@@ -3,18 +3,23 @@
 
 use async_trait::async_trait;
 use libp2p::PeerId;
+use log::*;
 use tari_consensus::{messages::HotstuffMessage, traits::InboundMessagingError};
 use tari_dan_common_types::PeerAddress;
-use tari_dan_p2p::proto;
+use tari_dan_p2p::{proto, TariMessagingSpec};
+use tari_networking::NetworkingHandle;
 use tokio::sync::mpsc;
 
 use crate::p2p::logging::MessageLogger;
 
+const LOG_TARGET: &str = "tari::validator_node::p2p::messaging::inbound";
+
 pub struct ConsensusInboundMessaging<TMsgLogger> {
     local_address: PeerAddress,
     rx_inbound_msg: mpsc::UnboundedReceiver<(PeerId, proto::consensus::HotStuffMessage)>,
     rx_gossip: mpsc::Receiver<(PeerId, proto::consensus::HotStuffMessage)>,
     rx_loopback: mpsc::UnboundedReceiver<HotstuffMessage>,
+    networking: NetworkingHandle<TariMessagingSpec>,
     msg_logger: TMsgLogger,
 }
 
@@ -24,6 +29,7 @@ impl<TMsgLogger: MessageLogger> ConsensusInboundMessaging<TMsgLogger> {
         rx_inbound_msg: mpsc::UnboundedReceiver<(PeerId, proto::consensus::HotStuffMessage)>,
         rx_gossip: mpsc::Receiver<(PeerId, proto::consensus::HotStuffMessage)>,
         rx_loopback: mpsc::UnboundedReceiver<HotstuffMessage>,
+        networking: NetworkingHandle<TariMessagingSpec>,
         msg_logger: TMsgLogger,
     ) -> Self {
         Self {
@@ -31,12 +37,13 @@ impl<TMsgLogger: MessageLogger> ConsensusInboundMessaging<TMsgLogger> {
             rx_inbound_msg,
             rx_gossip,
             rx_loopback,
+            networking,
             msg_logger,
         }
     }
 
-    fn handle_message(
-        &self,
+    async fn handle_message(
+        &mut self,
         from: PeerId,
         msg: proto::consensus::HotStuffMessage,
     ) -> Option<Result<(PeerAddress, HotstuffMessage), InboundMessagingError>> {
@@ -46,9 +53,17 @@ impl<TMsgLogger: MessageLogger> ConsensusInboundMessaging<TMsgLogger> {
                     .log_inbound_message(&from.to_string(), msg.as_type_str(), "", &msg);
                 Some(Ok((from.into(), msg)))
             },
-            Err(err) => Some(Err(InboundMessagingError::InvalidMessage {
-                reason: err.to_string(),
-            })),
+            Err(err) => {
+                let reason = err.to_string();
+                if let Err(err) = self
+                    .networking
+                    .report_invalid_message(from, format!("Invalid consensus message: {reason}"))
+                    .await
+                {
+                    error!(target: LOG_TARGET, "Failed to report invalid message from {from}: {err}");
+                }
+                Some(Err(InboundMessagingError::InvalidMessage { reason }))
+            },
         }
     }
 }
@@ -74,11 +89,11 @@ impl<TMsgLogger: MessageLogger + Send> tari_consensus::traits::InboundMessaging
             }),
             maybe_msg = self.rx_inbound_msg.recv() => {
                 let (from, msg) = maybe_msg?;
-                self.handle_message(from, msg)
+                self.handle_message(from, msg).await
             },
             maybe_msg = self.rx_gossip.recv() => {
                 let (from, msg) = maybe_msg?;
-                self.handle_message(from, msg)
+                self.handle_message(from, msg).await
             },
         }
     }
@@ -11,7 +11,6 @@ use tari_dan_common_types::{Epoch, SubstateAddress, SubstateRequirement};
 use tari_dan_engine::state_store::StateStoreError;
 use tari_dan_storage::{consensus_models::SubstateRecord, StateStore, StorageError};
 use tari_engine_types::{
-    instruction::Instruction,
     substate::{Substate, SubstateId},
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
@@ -244,28 +243,17 @@ where
         transaction: &Transaction,
         current_epoch: Epoch,
     ) -> Result<VirtualSubstates, Self::Error> {
-        let claim_epoch_and_public_key = transaction
-            .instructions()
-            .iter()
-            .chain(transaction.fee_instructions())
-            .filter_map(|instruction| {
-                if let Instruction::ClaimValidatorFees {
-                    epoch,
-                    validator_public_key,
-                } = instruction
-                {
-                    Some((Epoch(*epoch), validator_public_key.clone()))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<_>>();
+        let claim_epoch_and_public_key = transaction.fee_claims().collect::<Vec<_>>();
 
         let mut virtual_substates = VirtualSubstates::new();
         virtual_substates.insert(
             VirtualSubstateId::CurrentEpoch,
             VirtualSubstate::CurrentEpoch(current_epoch.as_u64()),
         );
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentBlock,
+            self.virtual_substate_manager.generate_current_block(current_epoch)?,
+        );
 
         if claim_epoch_and_public_key.is_empty() {
             return Ok(virtual_substates);
@@ -24,17 +24,13 @@ impl Validator<Transaction> for ClaimFeeTransactionValidator {
     type Error = TransactionValidationError;
 
     fn validate(&self, &current_epoch: &Epoch, transaction: &Transaction) -> Result<(), Self::Error> {
-        let mut claim_fees = transaction
-            .fee_instructions()
-            .iter()
-            .chain(transaction.instructions())
-            .filter_map(|i| {
-                if let Instruction::ClaimValidatorFees { epoch, .. } = i {
-                    Some(epoch)
-                } else {
-                    None
-                }
-            });
+        let mut claim_fees = transaction.all_instructions_iter().filter_map(|i| {
+            if let Instruction::ClaimValidatorFees { epoch, .. } = i {
+                Some(epoch)
+            } else {
+                None
+            }
+        });
 
         if let Some(&epoch) = claim_fees.find(|e| **e >= current_epoch.as_u64()) {
             warn!(
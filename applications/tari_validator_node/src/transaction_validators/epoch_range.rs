@@ -23,26 +23,39 @@ impl Validator<Transaction> for EpochRangeValidator {
     type Error = TransactionValidationError;
 
     fn validate(&self, &current_epoch: &Epoch, transaction: &Transaction) -> Result<(), TransactionValidationError> {
-        if let Some(min_epoch) = transaction.min_epoch() {
-            if current_epoch < min_epoch {
-                warn!(target: LOG_TARGET, "EpochRangeValidator - FAIL: Current epoch {current_epoch} less than minimum epoch {min_epoch}.");
-                return Err(TransactionValidationError::CurrentEpochLessThanMinimum {
-                    current_epoch,
-                    min_epoch,
-                });
-            }
+        check_epoch_range(current_epoch, transaction.min_epoch(), transaction.max_epoch())?;
+        for sub_intent in transaction.sub_intents() {
+            check_epoch_range(current_epoch, sub_intent.min_epoch(), sub_intent.max_epoch())?;
         }
 
-        if let Some(max_epoch) = transaction.max_epoch() {
-            if current_epoch > max_epoch {
-                warn!(target: LOG_TARGET, "EpochRangeValidator - FAIL: Current epoch {current_epoch} greater than maximum epoch {max_epoch}.");
-                return Err(TransactionValidationError::CurrentEpochGreaterThanMaximum {
-                    current_epoch,
-                    max_epoch,
-                });
-            }
+        Ok(())
+    }
+}
+
+fn check_epoch_range(
+    current_epoch: Epoch,
+    min_epoch: Option<Epoch>,
+    max_epoch: Option<Epoch>,
+) -> Result<(), TransactionValidationError> {
+    if let Some(min_epoch) = min_epoch {
+        if current_epoch < min_epoch {
+            warn!(target: LOG_TARGET, "EpochRangeValidator - FAIL: Current epoch {current_epoch} less than minimum epoch {min_epoch}.");
+            return Err(TransactionValidationError::CurrentEpochLessThanMinimum {
+                current_epoch,
+                min_epoch,
+            });
         }
+    }
 
-        Ok(())
+    if let Some(max_epoch) = max_epoch {
+        if current_epoch > max_epoch {
+            warn!(target: LOG_TARGET, "EpochRangeValidator - FAIL: Current epoch {current_epoch} greater than maximum epoch {max_epoch}.");
+            return Err(TransactionValidationError::CurrentEpochGreaterThanMaximum {
+                current_epoch,
+                max_epoch,
+            });
+        }
     }
+
+    Ok(())
 }
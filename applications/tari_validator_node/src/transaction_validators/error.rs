@@ -42,6 +42,15 @@ pub enum TransactionValidationError {
     InvalidSignature,
     #[error("Transaction {transaction_id} is not signed")]
     TransactionNotSigned { transaction_id: TransactionId },
+    #[error(
+        "Instructions in transaction {transaction_id} are nested {depth} levels deep which exceeds the maximum of \
+         {max_depth}"
+    )]
+    InstructionNestingTooDeep {
+        transaction_id: TransactionId,
+        depth: usize,
+        max_depth: usize,
+    },
     #[error("Network error: {0}")]
     NetworkingError(#[from] NetworkingError),
 }
@@ -5,6 +5,7 @@ mod claim_fee_instructions;
 mod epoch_range;
 mod fee;
 mod has_inputs;
+mod nesting_depth;
 mod signature;
 mod template_exists;
 
@@ -12,6 +13,7 @@ pub use claim_fee_instructions::*;
 pub use epoch_range::*;
 pub use fee::*;
 pub use has_inputs::*;
+pub use nesting_depth::*;
 pub use signature::*;
 pub use template_exists::*;
 
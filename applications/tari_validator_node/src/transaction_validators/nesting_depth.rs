@@ -0,0 +1,44 @@
+//    Copyright 2024 The Tari Project
+//    SPDX-License-Identifier: BSD-3-Clause
+
+use log::warn;
+use tari_engine_types::instruction::{Instruction, MAX_INSTRUCTION_NESTING_DEPTH};
+use tari_transaction::Transaction;
+
+use crate::{transaction_validators::TransactionValidationError, validator::Validator};
+
+const LOG_TARGET: &str = "tari::dan::mempool::validators::nesting_depth";
+
+/// Rejects transactions with control flow instructions that are nested deeper than the maximum depth
+#[derive(Debug)]
+pub struct InstructionNestingDepthValidator;
+
+impl Validator<Transaction> for InstructionNestingDepthValidator {
+    type Context = ();
+    type Error = TransactionValidationError;
+
+    fn validate(&self, _context: &(), transaction: &Transaction) -> Result<(), TransactionValidationError> {
+        let depth = transaction
+            .fee_instructions()
+            .iter()
+            .chain(transaction.sub_intents().iter().flat_map(|s| s.instructions()))
+            .chain(transaction.instructions())
+            .map(Instruction::nesting_depth)
+            .max()
+            .unwrap_or(0);
+
+        if depth > MAX_INSTRUCTION_NESTING_DEPTH {
+            warn!(
+                target: LOG_TARGET,
+                "InstructionNestingDepthValidator - FAIL: Instructions nested {} levels deep", depth
+            );
+            return Err(TransactionValidationError::InstructionNestingTooDeep {
+                transaction_id: *transaction.id(),
+                depth,
+                max_depth: MAX_INSTRUCTION_NESTING_DEPTH,
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -27,8 +27,7 @@ impl<TAddr: NodeAddressable> Validator<Transaction> for TemplateExistsValidator<
     type Error = TransactionValidationError;
 
     fn validate(&self, _context: &(), transaction: &Transaction) -> Result<(), TransactionValidationError> {
-        let instructions = transaction.instructions();
-        for instruction in instructions {
+        for instruction in transaction.all_instructions_iter() {
             match instruction {
                 Instruction::CallFunction { template_address, .. } => {
                     let template_exists = self.template_manager.template_exists(template_address);
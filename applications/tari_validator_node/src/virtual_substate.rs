@@ -4,13 +4,17 @@
 use log::*;
 use tari_common_types::types::PublicKey;
 use tari_dan_common_types::Epoch;
-use tari_dan_storage::{consensus_models::Block, StateStore, StorageError};
+use tari_dan_storage::{
+    consensus_models::{Block, LeafBlock},
+    StateStore,
+    StorageError,
+};
 use tari_engine_types::{
     fee_claim::FeeClaim,
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
 use tari_epoch_manager::EpochManagerReader;
-use tari_template_lib::models::Amount;
+use tari_template_lib::models::{Amount, BlockInfo};
 
 const LOG_TARGET: &str = "tari::dan::validator_node::virtual_substate";
 
@@ -38,6 +42,10 @@ where
     ) -> Result<VirtualSubstate, VirtualSubstateError> {
         match address {
             VirtualSubstateId::CurrentEpoch => self.generate_current_epoch().await,
+            VirtualSubstateId::CurrentBlock => {
+                let current_epoch = self.epoch_manager.current_epoch().await?;
+                self.generate_current_block(current_epoch)
+            },
             VirtualSubstateId::UnclaimedValidatorFee { epoch, address } => {
                 self.generate_validator_fee_claim(Epoch(*epoch), address)
             },
@@ -70,6 +78,18 @@ where
         Ok(VirtualSubstate::CurrentEpoch(current_epoch.as_u64()))
     }
 
+    /// Returns the block info for the current leaf block. This is only an approximation of the block that a transaction
+    /// will eventually be executed in, and is intended for dry-runs.
+    pub fn generate_current_block(&self, epoch: Epoch) -> Result<VirtualSubstate, VirtualSubstateError> {
+        let leaf_block = self.store.with_read_tx(|tx| LeafBlock::get(tx, epoch)?.get_block(tx))?;
+        Ok(VirtualSubstate::CurrentBlock(BlockInfo {
+            height: leaf_block.height().as_u64(),
+            timestamp: leaf_block.timestamp(),
+            shard_group_start: leaf_block.shard_group().start().as_u32(),
+            shard_group_end: leaf_block.shard_group().end().as_u32(),
+        }))
+    }
+
     fn generate_validator_fee_claim(
         &self,
         epoch: Epoch,
@@ -3,11 +3,14 @@
 export * from "./types/AbortReason";
 export * from "./types/AccessRule";
 export * from "./types/Account";
+export * from "./types/AggregatedValidatorSignature";
 export * from "./types/Amount";
 export * from "./types/ArgDef";
 export * from "./types/Arg";
+export * from "./types/Assertion";
 export * from "./types/AuthHook";
 export * from "./types/Block";
+export * from "./types/BlockHeader";
 export * from "./types/BucketId";
 export * from "./types/Claims";
 export * from "./types/Command";
@@ -29,6 +32,9 @@ export * from "./types/Decision";
 export * from "./types/ElgamalVerifiableBalance";
 export * from "./types/EntityId";
 export * from "./types/Epoch";
+export * from "./types/EquivocationEvidence";
+export * from "./types/EquivocationKind";
+export * from "./types/EquivocationStatement";
 export * from "./types/Event";
 export * from "./types/Evidence";
 export * from "./types/ExecutedTransaction";
@@ -51,6 +57,7 @@ export * from "./types/JrpcPermissions";
 export * from "./types/JrpcPermission";
 export * from "./types/LeaderFee";
 export * from "./types/LockFlag";
+export * from "./types/LockSchedule";
 export * from "./types/LogEntry";
 export * from "./types/LogLevel";
 export * from "./types/Metadata";
@@ -86,6 +93,7 @@ export * from "./types/ShardEvidence";
 export * from "./types/ShardGroupEvidence";
 export * from "./types/ShardGroup";
 export * from "./types/Shard";
+export * from "./types/SubIntent";
 export * from "./types/SubstateAddress";
 export * from "./types/SubstateDestroyed";
 export * from "./types/SubstateDiff";
@@ -100,6 +108,7 @@ export * from "./types/SubstateValue";
 export * from "./types/SuspendNodeAtom";
 export * from "./types/TemplateDef";
 export * from "./types/TemplateDefV1";
+export * from "./types/TimeLockedAsset";
 export * from "./types/TransactionAtom";
 export * from "./types/TransactionPoolRecord";
 export * from "./types/TransactionPoolStage";
@@ -115,10 +124,12 @@ export * from "./types/UnclaimedConfidentialOutput";
 export * from "./types/UnsignedTransaction";
 export * from "./types/ValidatorSignature";
 export * from "./types/VaultId";
+export * from "./types/VaultTimeLock";
 export * from "./types/Vault";
 export * from "./types/VersionedSubstateIdLockIntent";
 export * from "./types/VersionedSubstateId";
 export * from "./types/ViewableBalanceProof";
+export * from "./types/WeightedRequirement";
 export * from "./base-node-client";
 export * from "./tari-indexer-client";
 export * from "./validator-node-client";
@@ -0,0 +1,5 @@
+export interface AggregatedValidatorSignature {
+    signers: string;
+    public_nonces: Array<string>;
+    signature: string;
+}
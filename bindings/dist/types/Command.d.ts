@@ -1,3 +1,4 @@
+import type { EquivocationEvidence } from "./EquivocationEvidence";
 import type { ForeignProposalAtom } from "./ForeignProposalAtom";
 import type { MintConfidentialOutputAtom } from "./MintConfidentialOutputAtom";
 import type { ResumeNodeAtom } from "./ResumeNodeAtom";
@@ -27,4 +28,6 @@ export type Command = {
     SuspendNode: SuspendNodeAtom;
 } | {
     ResumeNode: ResumeNodeAtom;
+} | {
+    Equivocation: EquivocationEvidence;
 } | "EndEpoch";
@@ -0,0 +1 @@
+export type EquivocationKind = "DoubleVote" | "DoubleProposal";
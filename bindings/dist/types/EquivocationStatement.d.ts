@@ -0,0 +1,11 @@
+import type { BlockHeader } from "./BlockHeader";
+import type { QuorumDecision } from "./QuorumDecision";
+export interface EquivocationStatement {
+    block_id: string;
+    header: BlockHeader;
+    decision: QuorumDecision | null;
+    signature: {
+        public_nonce: string;
+        signature: string;
+    };
+}
@@ -1 +1 @@
-export type FeeSource = "Initial" | "RuntimeCall" | "Storage" | "Events" | "Logs";
+export type FeeSource = "Initial" | "RuntimeCall" | "Storage" | "Events" | "Logs" | "WasmCompute";
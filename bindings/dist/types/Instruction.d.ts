@@ -1,5 +1,6 @@
 import type { Amount } from "./Amount";
 import type { Arg } from "./Arg";
+import type { Assertion } from "./Assertion";
 import type { ComponentAccessRules } from "./ComponentAccessRules";
 import type { ComponentAddress } from "./ComponentAddress";
 import type { ConfidentialClaim } from "./ConfidentialClaim";
@@ -49,4 +50,25 @@ export type Instruction = {
         resource_address: ResourceAddress;
         min_amount: Amount;
     };
+} | {
+    Assert: {
+        assertion: Assertion;
+    };
+} | {
+    If: {
+        condition: Assertion;
+        then_instructions: Array<Instruction>;
+        else_instructions: Array<Instruction>;
+    };
+} | {
+    ForEach: {
+        key: Array<number>;
+        item_key: Array<number>;
+        max_iterations: number;
+        instructions: Array<Instruction>;
+    };
+} | {
+    SponsorFee: {
+        component_address: ComponentAddress;
+    };
 };
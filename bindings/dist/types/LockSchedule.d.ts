@@ -0,0 +1,8 @@
+export type LockSchedule = {
+    UntilEpoch: number;
+} | {
+    LinearVesting: {
+        start_epoch: number;
+        end_epoch: number;
+    };
+};
@@ -1,8 +1,19 @@
 import type { RuleRequirement } from "./RuleRequirement";
+import type { WeightedRequirement } from "./WeightedRequirement";
 export type RequireRule = {
     Require: RuleRequirement;
 } | {
     AnyOf: Array<RuleRequirement>;
 } | {
     AllOf: Array<RuleRequirement>;
+} | {
+    MOfN: {
+        threshold: number;
+        requirements: Array<RuleRequirement>;
+    };
+} | {
+    Weighted: {
+        threshold: number;
+        requirements: Array<WeightedRequirement>;
+    };
 };
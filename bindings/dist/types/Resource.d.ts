@@ -13,4 +13,5 @@ export interface Resource {
     total_supply: Amount;
     view_key: string | null;
     auth_hook: AuthHook | null;
+    is_paused: boolean;
 }
@@ -0,0 +1,11 @@
+import type { Amount } from "./Amount";
+import type { NonFungibleId } from "./NonFungibleId";
+export type TimeLockedAsset = {
+    Fungible: {
+        amount: Amount;
+    };
+} | {
+    NonFungible: {
+        ids: Array<NonFungibleId>;
+    };
+};
@@ -1,5 +1,6 @@
 import type { Epoch } from "./Epoch";
 import type { Instruction } from "./Instruction";
+import type { SubIntent } from "./SubIntent";
 import type { SubstateRequirement } from "./SubstateRequirement";
 import type { TransactionSignature } from "./TransactionSignature";
 import type { VersionedSubstateId } from "./VersionedSubstateId";
@@ -10,6 +11,7 @@ export interface Transaction {
     inputs: Array<SubstateRequirement>;
     min_epoch: Epoch | null;
     max_epoch: Epoch | null;
+    sub_intents: Array<SubIntent>;
     signatures: Array<TransactionSignature>;
     filled_inputs: Array<VersionedSubstateId>;
 }
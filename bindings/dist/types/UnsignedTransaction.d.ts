@@ -1,5 +1,6 @@
 import type { Epoch } from "./Epoch";
 import type { Instruction } from "./Instruction";
+import type { SubIntent } from "./SubIntent";
 import type { SubstateRequirement } from "./SubstateRequirement";
 export interface UnsignedTransaction {
     fee_instructions: Array<Instruction>;
@@ -7,4 +8,5 @@ export interface UnsignedTransaction {
     inputs: Array<SubstateRequirement>;
     min_epoch: Epoch | null;
     max_epoch: Epoch | null;
+    sub_intents: Array<SubIntent>;
 }
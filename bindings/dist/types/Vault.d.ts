@@ -1,4 +1,7 @@
 import type { ResourceContainer } from "./ResourceContainer";
+import type { VaultTimeLock } from "./VaultTimeLock";
 export interface Vault {
     resource_container: ResourceContainer;
+    time_locks: Array<VaultTimeLock>;
+    is_frozen: boolean;
 }
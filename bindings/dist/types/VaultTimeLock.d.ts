@@ -0,0 +1,6 @@
+import type { LockSchedule } from "./LockSchedule";
+import type { TimeLockedAsset } from "./TimeLockedAsset";
+export interface VaultTimeLock {
+    asset: TimeLockedAsset;
+    schedule: LockSchedule;
+}
@@ -0,0 +1,5 @@
+import type { RuleRequirement } from "./RuleRequirement";
+export interface WeightedRequirement {
+    requirement: RuleRequirement;
+    weight: number;
+}
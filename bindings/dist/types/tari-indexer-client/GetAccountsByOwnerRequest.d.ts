@@ -0,0 +1,5 @@
+export interface GetAccountsByOwnerRequest {
+    owner: string;
+    offset: number;
+    limit: number;
+}
@@ -0,0 +1,4 @@
+import type { OwnedAccount } from "./OwnedAccount";
+export interface GetAccountsByOwnerResponse {
+    accounts: Array<OwnedAccount>;
+}
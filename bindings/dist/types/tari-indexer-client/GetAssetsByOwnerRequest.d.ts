@@ -0,0 +1,5 @@
+export interface GetAssetsByOwnerRequest {
+    owner: string;
+    offset: number;
+    limit: number;
+}
@@ -0,0 +1,4 @@
+import type { OwnedAsset } from "./OwnedAsset";
+export interface GetAssetsByOwnerResponse {
+    assets: Array<OwnedAsset>;
+}
@@ -0,0 +1,4 @@
+import type { VaultBalanceSnapshot } from "./VaultBalanceSnapshot";
+export interface GetBalanceHistoryResponse {
+    balances: Array<VaultBalanceSnapshot>;
+}
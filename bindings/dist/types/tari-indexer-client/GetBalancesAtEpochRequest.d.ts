@@ -0,0 +1,6 @@
+import type { ComponentAddress } from "../ComponentAddress";
+import type { Epoch } from "../Epoch";
+export interface GetBalancesAtEpochRequest {
+    account: ComponentAddress;
+    epoch: Epoch;
+}
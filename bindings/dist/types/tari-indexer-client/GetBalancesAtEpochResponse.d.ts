@@ -0,0 +1,4 @@
+import type { VaultBalanceSnapshot } from "./VaultBalanceSnapshot";
+export interface GetBalancesAtEpochResponse {
+    balances: Array<VaultBalanceSnapshot>;
+}
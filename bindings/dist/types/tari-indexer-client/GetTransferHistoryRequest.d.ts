@@ -0,0 +1,7 @@
+import type { ComponentAddress } from "../ComponentAddress";
+export interface GetTransferHistoryRequest {
+    account: ComponentAddress;
+    resource_address: string | null;
+    offset: number;
+    limit: number;
+}
@@ -0,0 +1,4 @@
+import type { TransferLedgerEntry } from "./TransferLedgerEntry";
+export interface GetTransferHistoryResponse {
+    transfers: Array<TransferLedgerEntry>;
+}
@@ -0,0 +1,6 @@
+import type { ComponentAddress } from "../ComponentAddress";
+export interface OwnedAccount {
+    component_address: ComponentAddress;
+    template_address: string;
+    version: number;
+}
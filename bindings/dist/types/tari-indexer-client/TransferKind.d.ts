@@ -0,0 +1 @@
+export type TransferKind = "Deposit" | "Withdraw";
@@ -0,0 +1,16 @@
+import type { Amount } from "../Amount";
+import type { Epoch } from "../Epoch";
+import type { ResourceAddress } from "../ResourceAddress";
+import type { ResourceType } from "../ResourceType";
+import type { TransferKind } from "./TransferKind";
+import type { VaultId } from "../VaultId";
+export interface TransferLedgerEntry {
+    vault_id: VaultId;
+    resource_address: ResourceAddress;
+    resource_type: ResourceType;
+    kind: TransferKind;
+    amount: Amount;
+    transaction_id: string;
+    epoch: Epoch;
+    timestamp: number;
+}
@@ -0,0 +1,8 @@
+export interface VNBanPeerRequest {
+    peer_id: string;
+    reason: string;
+    ban_duration: {
+        secs: number;
+        nanos: number;
+    } | null;
+}
@@ -0,0 +1 @@
+export type VNBanPeerResponse = Record<string, never>;
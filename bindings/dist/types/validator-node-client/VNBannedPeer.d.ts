@@ -0,0 +1,5 @@
+export interface VNBannedPeer {
+    peer_id: string;
+    reason: string;
+    banned_until: number;
+}
@@ -0,0 +1,4 @@
+import type { VNBannedPeer } from "./VNBannedPeer";
+export interface VNGetBannedPeersResponse {
+    banned_peers: Array<VNBannedPeer>;
+}
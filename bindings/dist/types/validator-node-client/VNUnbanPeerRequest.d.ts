@@ -0,0 +1,3 @@
+export interface VNUnbanPeerRequest {
+    peer_id: string;
+}
@@ -0,0 +1,3 @@
+export interface VNUnbanPeerResponse {
+    was_banned: boolean;
+}
@@ -0,0 +1,6 @@
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+export interface AccountsCancelRecoveryRequest {
+    account: ComponentAddressOrName | null;
+    max_fee: Amount | null;
+}
@@ -0,0 +1,10 @@
+import type { Amount } from "../Amount";
+import type { ComponentAddress } from "../ComponentAddress";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+export interface AccountsCompleteRecoveryRequest {
+    fee_account: ComponentAddressOrName | null;
+    account_address: ComponentAddress;
+    new_owner_key_id: bigint | null;
+    account_name: string | null;
+    max_fee: Amount | null;
+}
@@ -0,0 +1,10 @@
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+export interface AccountsCreateMultisigRequest {
+    fee_account: ComponentAddressOrName | null;
+    owner_public_keys: Array<string>;
+    threshold: number;
+    owner_key_id: number | null;
+    account_name: string | null;
+    max_fee: Amount | null;
+}
@@ -0,0 +1,6 @@
+import type { ComponentAddress } from "../ComponentAddress";
+import type { FinalizeResult } from "../FinalizeResult";
+export interface AccountsCreateMultisigResponse {
+    address: ComponentAddress;
+    result: FinalizeResult;
+}
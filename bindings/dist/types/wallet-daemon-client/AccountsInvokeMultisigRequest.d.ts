@@ -0,0 +1,11 @@
+import type { Amount } from "../Amount";
+import type { Arg } from "../Arg";
+import type { ComponentAddress } from "../ComponentAddress";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+export interface AccountsInvokeMultisigRequest {
+    account: ComponentAddressOrName | null;
+    multisig_address: ComponentAddress;
+    method: string;
+    args: Array<Arg>;
+    max_fee: Amount | null;
+}
@@ -0,0 +1,4 @@
+import type { InstructionResult } from "../InstructionResult";
+export interface AccountsInvokeMultisigResponse {
+    result: InstructionResult | null;
+}
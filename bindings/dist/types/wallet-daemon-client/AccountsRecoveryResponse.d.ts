@@ -0,0 +1,5 @@
+import type { FinalizeResult } from "../FinalizeResult";
+export interface AccountsRecoveryResponse {
+    transaction_id: string;
+    result: FinalizeResult;
+}
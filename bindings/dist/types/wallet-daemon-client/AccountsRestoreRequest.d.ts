@@ -0,0 +1,3 @@
+export interface AccountsRestoreRequest {
+    gap_limit: number | null;
+}
@@ -0,0 +1,4 @@
+import type { AccountInfo } from "./AccountInfo";
+export interface AccountsRestoreResponse {
+    accounts: Array<AccountInfo>;
+}
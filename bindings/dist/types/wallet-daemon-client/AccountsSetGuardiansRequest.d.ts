@@ -0,0 +1,10 @@
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+import type { NonFungibleAddress } from "../NonFungibleAddress";
+export interface AccountsSetGuardiansRequest {
+    account: ComponentAddressOrName | null;
+    guardians: Array<NonFungibleAddress>;
+    threshold: number;
+    recovery_delay_epochs: bigint;
+    max_fee: Amount | null;
+}
@@ -5,7 +5,7 @@ export interface CallInstructionRequest {
     instructions: Array<Instruction>;
     fee_account: ComponentAddressOrName;
     dump_outputs_into: ComponentAddressOrName | null;
-    max_fee: number;
+    max_fee: number | null;
     inputs: Array<SubstateRequirement>;
     override_inputs: boolean | null;
     new_outputs: number | null;
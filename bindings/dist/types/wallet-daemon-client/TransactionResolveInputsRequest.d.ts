@@ -0,0 +1,4 @@
+import type { UnsignedTransaction } from "../UnsignedTransaction";
+export interface TransactionResolveInputsRequest {
+    transaction: UnsignedTransaction;
+}
@@ -0,0 +1,4 @@
+import type { SubstateRequirement } from "../SubstateRequirement";
+export interface TransactionResolveInputsResponse {
+    inputs: Array<SubstateRequirement>;
+}
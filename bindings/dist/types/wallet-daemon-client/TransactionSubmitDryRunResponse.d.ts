@@ -1,6 +1,10 @@
+import type { Amount } from "../Amount";
 import type { ExecuteResult } from "../ExecuteResult";
+import type { FeeCostBreakdown } from "../FeeCostBreakdown";
 export interface TransactionSubmitDryRunResponse {
     transaction_id: string;
     result: ExecuteResult;
     json_result: Array<any>;
+    fee_breakdown: FeeCostBreakdown;
+    suggested_max_fee: Amount;
 }
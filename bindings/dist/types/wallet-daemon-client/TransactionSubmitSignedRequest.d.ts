@@ -0,0 +1,6 @@
+import type { SubstateRequirement } from "../SubstateRequirement";
+import type { Transaction } from "../Transaction";
+export interface TransactionSubmitSignedRequest {
+    transaction: Transaction;
+    autofill_inputs: Array<SubstateRequirement>;
+}
@@ -0,0 +1,3 @@
+export interface TransactionSubmitSignedResponse {
+    transaction_id: string;
+}
@@ -56,3 +56,9 @@ export * from "./types/validator-node-client/VNArgDef";
 export * from "./types/validator-node-client/GetEpochManagerStatsResponse";
 export * from "./types/validator-node-client/GetBlockResponse";
 export * from "./types/validator-node-client/VNCommitteeShardInfo";
+export * from "./types/validator-node-client/VNBanPeerRequest";
+export * from "./types/validator-node-client/VNBanPeerResponse";
+export * from "./types/validator-node-client/VNUnbanPeerRequest";
+export * from "./types/validator-node-client/VNUnbanPeerResponse";
+export * from "./types/validator-node-client/VNBannedPeer";
+export * from "./types/validator-node-client/VNGetBannedPeersResponse";
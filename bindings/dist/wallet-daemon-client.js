@@ -89,3 +89,19 @@ export * from "./types/wallet-daemon-client/ConfidentialTransferRequest";
 export * from "./types/wallet-daemon-client/AccountsCreateResponse";
 export * from "./types/wallet-daemon-client/TransactionWaitResultResponse";
 export * from "./types/wallet-daemon-client/AccountGetRequest";
+export * from "./types/wallet-daemon-client/AccountsCreateMultisigRequest";
+export * from "./types/wallet-daemon-client/AccountsCreateMultisigResponse";
+export * from "./types/wallet-daemon-client/AccountsInvokeMultisigRequest";
+export * from "./types/wallet-daemon-client/AccountsInvokeMultisigResponse";
+export * from "./types/wallet-daemon-client/AccountsSetGuardiansRequest";
+export * from "./types/wallet-daemon-client/AccountsInitiateRecoveryRequest";
+export * from "./types/wallet-daemon-client/AccountsApproveRecoveryRequest";
+export * from "./types/wallet-daemon-client/AccountsCancelRecoveryRequest";
+export * from "./types/wallet-daemon-client/AccountsCompleteRecoveryRequest";
+export * from "./types/wallet-daemon-client/AccountsRecoveryResponse";
+export * from "./types/wallet-daemon-client/AccountsRestoreRequest";
+export * from "./types/wallet-daemon-client/AccountsRestoreResponse";
+export * from "./types/wallet-daemon-client/TransactionResolveInputsRequest";
+export * from "./types/wallet-daemon-client/TransactionResolveInputsResponse";
+export * from "./types/wallet-daemon-client/TransactionSubmitSignedRequest";
+export * from "./types/wallet-daemon-client/TransactionSubmitSignedResponse";
@@ -35,3 +35,18 @@ export * from "./types/tari-indexer-client/IndexerConnectionDirection";
 export * from "./types/tari-indexer-client/IndexerGetIdentityResponse";
 export * from "./types/tari-indexer-client/IndexerGetTransactionResultRequest";
 export * from "./types/tari-indexer-client/GetNonFungibleCountRequest";
+export * from "./types/tari-indexer-client/GetTransferHistoryRequest";
+export * from "./types/tari-indexer-client/GetTransferHistoryResponse";
+export * from "./types/tari-indexer-client/TransferLedgerEntry";
+export * from "./types/tari-indexer-client/TransferKind";
+export * from "./types/tari-indexer-client/GetBalanceHistoryRequest";
+export * from "./types/tari-indexer-client/GetBalanceHistoryResponse";
+export * from "./types/tari-indexer-client/GetBalancesAtEpochRequest";
+export * from "./types/tari-indexer-client/GetBalancesAtEpochResponse";
+export * from "./types/tari-indexer-client/VaultBalanceSnapshot";
+export * from "./types/tari-indexer-client/GetAccountsByOwnerRequest";
+export * from "./types/tari-indexer-client/GetAccountsByOwnerResponse";
+export * from "./types/tari-indexer-client/OwnedAccount";
+export * from "./types/tari-indexer-client/GetAssetsByOwnerRequest";
+export * from "./types/tari-indexer-client/GetAssetsByOwnerResponse";
+export * from "./types/tari-indexer-client/OwnedAsset";
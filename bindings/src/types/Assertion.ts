@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "./Amount";
+import type { ComponentAddress } from "./ComponentAddress";
+import type { NonFungibleId } from "./NonFungibleId";
+import type { VaultId } from "./VaultId";
+
+export type Assertion =
+  | { VaultBalanceAtLeast: { vault_id: VaultId; min_amount: Amount } }
+  | { VaultBalanceAtMost: { vault_id: VaultId; max_amount: Amount } }
+  | { BucketContainsNonFungible: { key: Array<number>; non_fungible_id: NonFungibleId } }
+  | { WorkspaceValueEquals: { key: Array<number>; value: Array<number> } }
+  | { ComponentStateEquals: { component_address: ComponentAddress; path: string; value: Array<number> } };
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EquivocationEvidence } from "./EquivocationEvidence";
 import type { ForeignProposalAtom } from "./ForeignProposalAtom";
 import type { MintConfidentialOutputAtom } from "./MintConfidentialOutputAtom";
 import type { ResumeNodeAtom } from "./ResumeNodeAtom";
@@ -18,4 +19,5 @@ export type Command =
   | { MintConfidentialOutput: MintConfidentialOutputAtom }
   | { SuspendNode: SuspendNodeAtom }
   | { ResumeNode: ResumeNodeAtom }
+  | { Equivocation: EquivocationEvidence }
   | "EndEpoch";
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Epoch } from "./Epoch";
+import type { EquivocationKind } from "./EquivocationKind";
+import type { EquivocationStatement } from "./EquivocationStatement";
+import type { NodeHeight } from "./NodeHeight";
+import type { ShardGroup } from "./ShardGroup";
+
+export interface EquivocationEvidence {
+  kind: EquivocationKind;
+  epoch: Epoch;
+  shard_group: ShardGroup;
+  height: NodeHeight;
+  validator_public_key: string;
+  first: EquivocationStatement;
+  second: EquivocationStatement;
+}
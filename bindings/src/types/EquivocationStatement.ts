@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlockHeader } from "./BlockHeader";
+import type { QuorumDecision } from "./QuorumDecision";
+
+export interface EquivocationStatement {
+  block_id: string;
+  header: BlockHeader;
+  decision: QuorumDecision | null;
+  signature: { public_nonce: string; signature: string };
+}
@@ -3,4 +3,6 @@ import type { FeeSource } from "./FeeSource";
 
 export interface FeeBreakdown {
   breakdown: Record<FeeSource, bigint>;
+  by_instruction: Record<number, bigint>;
+  by_component: Record<string, bigint>;
 }
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Amount } from "./Amount";
 import type { Arg } from "./Arg";
+import type { Assertion } from "./Assertion";
 import type { ComponentAccessRules } from "./ComponentAccessRules";
 import type { ComponentAddress } from "./ComponentAddress";
 import type { ConfidentialClaim } from "./ConfidentialClaim";
@@ -24,4 +25,15 @@ export type Instruction =
   | { ClaimBurn: { claim: ConfidentialClaim } }
   | { ClaimValidatorFees: { epoch: number; validator_public_key: string } }
   | "DropAllProofsInWorkspace"
-  | { AssertBucketContains: { key: Array<number>; resource_address: ResourceAddress; min_amount: Amount } };
+  | { AssertBucketContains: { key: Array<number>; resource_address: ResourceAddress; min_amount: Amount } }
+  | { Assert: { assertion: Assertion } }
+  | { If: { condition: Assertion; then_instructions: Array<Instruction>; else_instructions: Array<Instruction> } }
+  | {
+      ForEach: {
+        key: Array<number>;
+        item_key: Array<number>;
+        max_iterations: number;
+        instructions: Array<Instruction>;
+      };
+    }
+  | { SponsorFee: { component_address: ComponentAddress } };
@@ -1,9 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { AggregatedValidatorSignature } from "./AggregatedValidatorSignature";
 import type { Epoch } from "./Epoch";
 import type { NodeHeight } from "./NodeHeight";
 import type { QuorumDecision } from "./QuorumDecision";
 import type { ShardGroup } from "./ShardGroup";
-import type { ValidatorSignature } from "./ValidatorSignature";
 
 export interface QuorumCertificate {
   qc_id: string;
@@ -11,7 +11,7 @@ export interface QuorumCertificate {
   block_height: NodeHeight;
   epoch: Epoch;
   shard_group: ShardGroup;
-  signatures: Array<ValidatorSignature>;
+  signature: AggregatedValidatorSignature;
   leaf_hashes: Array<string>;
   decision: QuorumDecision;
   is_shares_processed: boolean;
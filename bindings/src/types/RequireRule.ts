@@ -1,7 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { RuleRequirement } from "./RuleRequirement";
+import type { WeightedRequirement } from "./WeightedRequirement";
 
 export type RequireRule =
   | { Require: RuleRequirement }
   | { AnyOf: Array<RuleRequirement> }
-  | { AllOf: Array<RuleRequirement> };
+  | { AllOf: Array<RuleRequirement> }
+  | { MOfN: { threshold: number; requirements: Array<RuleRequirement> } }
+  | { Weighted: { threshold: number; requirements: Array<WeightedRequirement> } };
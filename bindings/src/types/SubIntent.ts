@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Epoch } from "./Epoch";
+import type { Instruction } from "./Instruction";
+import type { TransactionSignature } from "./TransactionSignature";
+
+export interface SubIntent {
+  intent_id: string;
+  instructions: Array<Instruction>;
+  min_epoch: Epoch | null;
+  max_epoch: Epoch | null;
+  signatures: Array<TransactionSignature>;
+}
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "./Amount";
+import type { NonFungibleId } from "./NonFungibleId";
+
+export type TimeLockedAsset = { Fungible: { amount: Amount } } | { NonFungible: { ids: Array<NonFungibleId> } };
@@ -1,6 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Epoch } from "./Epoch";
 import type { Instruction } from "./Instruction";
+import type { SubIntent } from "./SubIntent";
 import type { SubstateRequirement } from "./SubstateRequirement";
 import type { TransactionSignature } from "./TransactionSignature";
 import type { VersionedSubstateId } from "./VersionedSubstateId";
@@ -12,6 +13,7 @@ export interface Transaction {
   inputs: Array<SubstateRequirement>;
   min_epoch: Epoch | null;
   max_epoch: Epoch | null;
+  sub_intents: Array<SubIntent>;
   signatures: Array<TransactionSignature>;
   filled_inputs: Array<VersionedSubstateId>;
 }
@@ -1,6 +1,9 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { ResourceContainer } from "./ResourceContainer";
+import type { VaultTimeLock } from "./VaultTimeLock";
 
 export interface Vault {
   resource_container: ResourceContainer;
+  time_locks: Array<VaultTimeLock>;
+  is_frozen: boolean;
 }
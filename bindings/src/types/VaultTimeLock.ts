@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LockSchedule } from "./LockSchedule";
+import type { TimeLockedAsset } from "./TimeLockedAsset";
+
+export interface VaultTimeLock {
+  asset: TimeLockedAsset;
+  schedule: LockSchedule;
+}
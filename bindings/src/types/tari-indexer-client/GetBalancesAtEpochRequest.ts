@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComponentAddress } from "../ComponentAddress";
+import type { Epoch } from "../Epoch";
+
+export interface GetBalancesAtEpochRequest {
+  account: ComponentAddress;
+  epoch: Epoch;
+}
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComponentAddress } from "../ComponentAddress";
+
+export interface GetTransferHistoryRequest {
+  account: ComponentAddress;
+  resource_address: string | null;
+  offset: number;
+  limit: number;
+}
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddress } from "../ComponentAddress";
+import type { NonFungibleId } from "../NonFungibleId";
+import type { ResourceAddress } from "../ResourceAddress";
+import type { ResourceType } from "../ResourceType";
+import type { VaultId } from "../VaultId";
+
+export interface OwnedAsset {
+  account: ComponentAddress;
+  vault_id: VaultId;
+  resource_address: ResourceAddress;
+  resource_type: ResourceType;
+  balance: Amount;
+  non_fungible_ids: Array<NonFungibleId>;
+}
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { Epoch } from "../Epoch";
+import type { ResourceAddress } from "../ResourceAddress";
+import type { VaultId } from "../VaultId";
+
+export interface VaultBalanceSnapshot {
+  vault_id: VaultId;
+  resource_address: ResourceAddress;
+  version: number;
+  balance: Amount;
+  transaction_id: string;
+  epoch: Epoch;
+  timestamp: number;
+}
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface VNBanPeerRequest {
+  peer_id: string;
+  reason: string;
+  ban_duration: { secs: number; nanos: number } | null;
+}
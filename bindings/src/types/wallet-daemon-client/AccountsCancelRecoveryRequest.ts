@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+
+export interface AccountsCancelRecoveryRequest {
+  account: ComponentAddressOrName | null;
+  max_fee: Amount | null;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+
+export interface AccountsCreateMultisigRequest {
+  fee_account: ComponentAddressOrName | null;
+  owner_public_keys: Array<string>;
+  threshold: number;
+  owner_key_id: number | null;
+  account_name: string | null;
+  max_fee: Amount | null;
+}
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ComponentAddress } from "../ComponentAddress";
+import type { FinalizeResult } from "../FinalizeResult";
+
+export interface AccountsCreateMultisigResponse {
+  address: ComponentAddress;
+  result: FinalizeResult;
+}
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddress } from "../ComponentAddress";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+import type { NonFungibleAddress } from "../NonFungibleAddress";
+
+export interface AccountsInitiateRecoveryRequest {
+  guardian_account: ComponentAddressOrName | null;
+  account_address: ComponentAddress;
+  new_owner_public_key: string;
+  guardian_badge: NonFungibleAddress | null;
+  max_fee: Amount | null;
+}
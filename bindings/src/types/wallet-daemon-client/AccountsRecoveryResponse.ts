@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FinalizeResult } from "../FinalizeResult";
+
+export interface AccountsRecoveryResponse {
+  transaction_id: string;
+  result: FinalizeResult;
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
+import type { ComponentAddressOrName } from "./ComponentAddressOrName";
+import type { NonFungibleAddress } from "../NonFungibleAddress";
+
+export interface AccountsSetGuardiansRequest {
+  account: ComponentAddressOrName | null;
+  guardians: Array<NonFungibleAddress>;
+  threshold: number;
+  recovery_delay_epochs: bigint;
+  max_fee: Amount | null;
+}
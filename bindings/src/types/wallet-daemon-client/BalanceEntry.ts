@@ -10,5 +10,6 @@ export interface BalanceEntry {
   balance: Amount;
   resource_type: ResourceType;
   confidential_balance: Amount;
+  time_locked_balance: Amount;
   token_symbol: string | null;
 }
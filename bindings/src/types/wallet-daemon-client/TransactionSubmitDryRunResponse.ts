@@ -1,8 +1,12 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Amount } from "../Amount";
 import type { ExecuteResult } from "../ExecuteResult";
+import type { FeeCostBreakdown } from "../FeeCostBreakdown";
 
 export interface TransactionSubmitDryRunResponse {
   transaction_id: string;
   result: ExecuteResult;
   json_result: Array<any>;
+  fee_breakdown: FeeCostBreakdown;
+  suggested_max_fee: Amount;
 }
@@ -31,6 +31,14 @@ use crate::{
     types::{
         AddPeerRequest,
         AddPeerResponse,
+        GetAccountsByOwnerRequest,
+        GetAccountsByOwnerResponse,
+        GetAssetsByOwnerRequest,
+        GetAssetsByOwnerResponse,
+        GetBalanceHistoryRequest,
+        GetBalanceHistoryResponse,
+        GetBalancesAtEpochRequest,
+        GetBalancesAtEpochResponse,
         GetEpochManagerStatsResponse,
         GetNonFungiblesRequest,
         GetNonFungiblesResponse,
@@ -40,6 +48,8 @@ use crate::{
         GetTemplateDefinitionResponse,
         GetTransactionResultRequest,
         GetTransactionResultResponse,
+        GetTransferHistoryRequest,
+        GetTransferHistoryResponse,
         ListSubstatesRequest,
         ListSubstatesResponse,
         SubmitTransactionRequest,
@@ -135,6 +145,41 @@ impl IndexerJsonRpcClient {
         self.send_request("get_non_fungibles", req).await
     }
 
+    pub async fn get_transfer_history(
+        &mut self,
+        req: GetTransferHistoryRequest,
+    ) -> Result<GetTransferHistoryResponse, IndexerClientError> {
+        self.send_request("get_transfer_history", req).await
+    }
+
+    pub async fn get_balance_history(
+        &mut self,
+        req: GetBalanceHistoryRequest,
+    ) -> Result<GetBalanceHistoryResponse, IndexerClientError> {
+        self.send_request("get_balance_history", req).await
+    }
+
+    pub async fn get_balances_at_epoch(
+        &mut self,
+        req: GetBalancesAtEpochRequest,
+    ) -> Result<GetBalancesAtEpochResponse, IndexerClientError> {
+        self.send_request("get_balances_at_epoch", req).await
+    }
+
+    pub async fn get_accounts_by_owner(
+        &mut self,
+        req: GetAccountsByOwnerRequest,
+    ) -> Result<GetAccountsByOwnerResponse, IndexerClientError> {
+        self.send_request("get_accounts_by_owner", req).await
+    }
+
+    pub async fn get_assets_by_owner(
+        &mut self,
+        req: GetAssetsByOwnerRequest,
+    ) -> Result<GetAssetsByOwnerResponse, IndexerClientError> {
+        self.send_request("get_assets_by_owner", req).await
+    }
+
     pub async fn get_epoch_manager_stats(&mut self) -> Result<GetEpochManagerStatsResponse, IndexerClientError> {
         self.send_request("get_epoch_manager_stats", ()).await
     }
@@ -1,7 +1,7 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use std::{sync::Arc, time::Duration};
+use std::{fmt::Display, sync::Arc, time::Duration};
 
 use multiaddr::Multiaddr;
 use serde::{Deserialize, Serialize};
@@ -18,6 +18,10 @@ use tari_engine_types::{
     TemplateAddress,
 };
 use tari_template_abi::TemplateDef;
+use tari_template_lib::{
+    models::{Amount, ComponentAddress, NonFungibleId, ResourceAddress, VaultId},
+    resource::ResourceType,
+};
 use tari_transaction::{Transaction, TransactionId};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
@@ -530,3 +534,218 @@ pub struct GetTemplateDefinitionResponse {
     pub name: String,
     pub definition: TemplateDef,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetTransferHistoryRequest {
+    pub account: ComponentAddress,
+    #[serde(default, with = "serde_tools::string::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub resource_address: Option<ResourceAddress>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetTransferHistoryResponse {
+    /// Transfers into and out of the account vaults, most recent first
+    pub transfers: Vec<TransferLedgerEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct TransferLedgerEntry {
+    pub vault_id: VaultId,
+    #[serde(with = "serde_tools::string")]
+    pub resource_address: ResourceAddress,
+    pub resource_type: ResourceType,
+    pub kind: TransferKind,
+    /// The revealed amount that was transferred
+    pub amount: Amount,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    pub epoch: Epoch,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub enum TransferKind {
+    Deposit,
+    Withdraw,
+}
+
+impl Display for TransferKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetBalanceHistoryRequest {
+    pub account: ComponentAddress,
+    #[serde(default, with = "serde_tools::string::option")]
+    #[cfg_attr(feature = "ts", ts(type = "string | null"))]
+    pub resource_address: Option<ResourceAddress>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetBalanceHistoryResponse {
+    /// Balances of the account vaults after each change, most recent first
+    pub balances: Vec<VaultBalanceSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetBalancesAtEpochRequest {
+    pub account: ComponentAddress,
+    pub epoch: Epoch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetBalancesAtEpochResponse {
+    /// The last known balance of each account vault at the end of the epoch. Vaults that had no recorded changes by
+    /// the epoch are omitted.
+    pub balances: Vec<VaultBalanceSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct VaultBalanceSnapshot {
+    pub vault_id: VaultId,
+    #[serde(with = "serde_tools::string")]
+    pub resource_address: ResourceAddress,
+    pub version: u32,
+    /// The revealed balance of the vault
+    pub balance: Amount,
+    /// The transaction that produced this version of the vault
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    pub epoch: Epoch,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAccountsByOwnerRequest {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub owner: PublicKey,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAccountsByOwnerResponse {
+    /// The account components that the public key owns, either directly or as one of the badges in the owner rule
+    pub accounts: Vec<OwnedAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct OwnedAccount {
+    pub component_address: ComponentAddress,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    #[serde(with = "serde_tools::string")]
+    pub template_address: TemplateAddress,
+    /// The version of the component at which the owner was indexed
+    pub version: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAssetsByOwnerRequest {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub owner: PublicKey,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct GetAssetsByOwnerResponse {
+    /// The vaults held by the accounts that the public key owns
+    pub assets: Vec<OwnedAsset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/tari-indexer-client/")
+)]
+pub struct OwnedAsset {
+    pub account: ComponentAddress,
+    pub vault_id: VaultId,
+    #[serde(with = "serde_tools::string")]
+    pub resource_address: ResourceAddress,
+    pub resource_type: ResourceType,
+    /// The revealed balance of the vault
+    pub balance: Amount,
+    /// The non-fungibles held in the vault. Empty for fungible resources.
+    pub non_fungible_ids: Vec<NonFungibleId>,
+}
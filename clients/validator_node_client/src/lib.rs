@@ -145,6 +145,21 @@ impl ValidatorNodeClient {
         self.send_request("add_peer", request).await
     }
 
+    pub async fn ban_peer(&mut self, request: BanPeerRequest) -> Result<BanPeerResponse, ValidatorNodeClientError> {
+        self.send_request("ban_peer", request).await
+    }
+
+    pub async fn unban_peer(
+        &mut self,
+        request: UnbanPeerRequest,
+    ) -> Result<UnbanPeerResponse, ValidatorNodeClientError> {
+        self.send_request("unban_peer", request).await
+    }
+
+    pub async fn get_banned_peers(&mut self) -> Result<GetBannedPeersResponse, ValidatorNodeClientError> {
+        self.send_request("get_banned_peers", json!({})).await
+    }
+
     pub async fn get_blocks_count(&mut self) -> Result<GetBlocksCountResponse, ValidatorNodeClientError> {
         self.send_request("get_blocks_count", json!({})).await
     }
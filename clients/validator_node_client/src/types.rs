@@ -648,6 +648,96 @@ pub struct AddPeerRequest {
 )]
 pub struct AddPeerResponse {}
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNBanPeerRequest"
+    )
+)]
+pub struct BanPeerRequest {
+    pub peer_id: String,
+    pub reason: String,
+    /// The duration of the ban. If None, the node's default ban duration is used.
+    #[cfg_attr(feature = "ts", ts(type = "{secs: number, nanos: number} | null"))]
+    pub ban_duration: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNBanPeerResponse"
+    )
+)]
+pub struct BanPeerResponse {}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNUnbanPeerRequest"
+    )
+)]
+pub struct UnbanPeerRequest {
+    pub peer_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNUnbanPeerResponse"
+    )
+)]
+pub struct UnbanPeerResponse {
+    pub was_banned: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNBannedPeer"
+    )
+)]
+pub struct BannedPeer {
+    pub peer_id: String,
+    pub reason: String,
+    /// The time (seconds since the unix epoch) that the ban expires
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub banned_until: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(
+        export,
+        export_to = "../../bindings/src/types/validator-node-client/",
+        rename = "VNGetBannedPeersResponse"
+    )
+)]
+pub struct GetBannedPeersResponse {
+    pub banned_peers: Vec<BannedPeer>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -79,14 +79,26 @@ use crate::{
         AccountGetResponse,
         AccountSetDefaultRequest,
         AccountSetDefaultResponse,
+        AccountsApproveRecoveryRequest,
+        AccountsCancelRecoveryRequest,
+        AccountsCompleteRecoveryRequest,
+        AccountsCreateMultisigRequest,
+        AccountsCreateMultisigResponse,
         AccountsCreateRequest,
         AccountsCreateResponse,
         AccountsGetBalancesRequest,
         AccountsGetBalancesResponse,
+        AccountsInitiateRecoveryRequest,
+        AccountsInvokeMultisigRequest,
+        AccountsInvokeMultisigResponse,
         AccountsInvokeRequest,
         AccountsInvokeResponse,
         AccountsListRequest,
         AccountsListResponse,
+        AccountsRecoveryResponse,
+        AccountsRestoreRequest,
+        AccountsRestoreResponse,
+        AccountsSetGuardiansRequest,
         AuthGetAllJwtRequest,
         AuthGetAllJwtResponse,
         AuthRevokeTokenRequest,
@@ -114,10 +126,14 @@ use crate::{
         TransactionGetResponse,
         TransactionGetResultRequest,
         TransactionGetResultResponse,
+        TransactionResolveInputsRequest,
+        TransactionResolveInputsResponse,
         TransactionSubmitDryRunRequest,
         TransactionSubmitDryRunResponse,
         TransactionSubmitRequest,
         TransactionSubmitResponse,
+        TransactionSubmitSignedRequest,
+        TransactionSubmitSignedResponse,
         TransactionWaitResultRequest,
         TransactionWaitResultResponse,
     },
@@ -282,6 +298,20 @@ impl WalletDaemonClient {
         self.send_request("transactions.submit_dry_run", request.borrow()).await
     }
 
+    pub async fn resolve_transaction_inputs<T: Borrow<TransactionResolveInputsRequest>>(
+        &mut self,
+        request: T,
+    ) -> Result<TransactionResolveInputsResponse, WalletDaemonClientError> {
+        self.send_request("transactions.resolve_inputs", request.borrow()).await
+    }
+
+    pub async fn submit_signed_transaction<T: Borrow<TransactionSubmitSignedRequest>>(
+        &mut self,
+        request: T,
+    ) -> Result<TransactionSubmitSignedResponse, WalletDaemonClientError> {
+        self.send_request("transactions.submit_signed", request.borrow()).await
+    }
+
     pub async fn create_account<T: Borrow<AccountsCreateRequest>>(
         &mut self,
         request: T,
@@ -296,6 +326,62 @@ impl WalletDaemonClient {
         self.send_request("accounts.invoke", req.borrow()).await
     }
 
+    pub async fn create_multisig_account<T: Borrow<AccountsCreateMultisigRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsCreateMultisigResponse, WalletDaemonClientError> {
+        self.send_request("accounts.create_multisig", req.borrow()).await
+    }
+
+    pub async fn invoke_multisig_method<T: Borrow<AccountsInvokeMultisigRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsInvokeMultisigResponse, WalletDaemonClientError> {
+        self.send_request("accounts.invoke_multisig", req.borrow()).await
+    }
+
+    pub async fn set_account_guardians<T: Borrow<AccountsSetGuardiansRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsRecoveryResponse, WalletDaemonClientError> {
+        self.send_request("accounts.set_guardians", req.borrow()).await
+    }
+
+    pub async fn initiate_account_recovery<T: Borrow<AccountsInitiateRecoveryRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsRecoveryResponse, WalletDaemonClientError> {
+        self.send_request("accounts.initiate_recovery", req.borrow()).await
+    }
+
+    pub async fn approve_account_recovery<T: Borrow<AccountsApproveRecoveryRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsRecoveryResponse, WalletDaemonClientError> {
+        self.send_request("accounts.approve_recovery", req.borrow()).await
+    }
+
+    pub async fn cancel_account_recovery<T: Borrow<AccountsCancelRecoveryRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsRecoveryResponse, WalletDaemonClientError> {
+        self.send_request("accounts.cancel_recovery", req.borrow()).await
+    }
+
+    pub async fn complete_account_recovery<T: Borrow<AccountsCompleteRecoveryRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsRecoveryResponse, WalletDaemonClientError> {
+        self.send_request("accounts.complete_recovery", req.borrow()).await
+    }
+
+    pub async fn restore_accounts<T: Borrow<AccountsRestoreRequest>>(
+        &mut self,
+        req: T,
+    ) -> Result<AccountsRestoreResponse, WalletDaemonClientError> {
+        self.send_request("accounts.restore", req.borrow()).await
+    }
+
     pub async fn get_account_balances<T: Borrow<AccountsGetBalancesRequest>>(
         &mut self,
         request: T,
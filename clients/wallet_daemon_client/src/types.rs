@@ -36,6 +36,7 @@ use tari_dan_wallet_sdk::{
 };
 use tari_engine_types::{
     commit_result::{ExecuteResult, FinalizeResult},
+    fees::FeeCostBreakdown,
     instruction::Instruction,
     instruction_result::InstructionResult,
     serde_with,
@@ -46,7 +47,7 @@ use tari_template_abi::TemplateDef;
 use tari_template_lib::{
     args::Arg,
     auth::ComponentAccessRules,
-    models::{Amount, ConfidentialOutputStatement, NonFungibleId, ResourceAddress, VaultId},
+    models::{Amount, ConfidentialOutputStatement, NonFungibleAddress, NonFungibleId, ResourceAddress, VaultId},
     prelude::{ComponentAddress, ConfidentialWithdrawProof, ResourceType},
 };
 use tari_transaction::{Transaction, TransactionId, UnsignedTransaction};
@@ -70,8 +71,10 @@ pub struct CallInstructionRequest {
     pub fee_account: ComponentAddressOrName,
     #[serde(default, deserialize_with = "opt_string_or_struct")]
     pub dump_outputs_into: Option<ComponentAddressOrName>,
-    #[cfg_attr(feature = "ts", ts(type = "number"))]
-    pub max_fee: u64,
+    /// The maximum fee to pay for the transaction. If not provided, the fee is estimated using a dry run.
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub max_fee: Option<u64>,
     #[serde(default)]
     pub inputs: Vec<SubstateRequirement>,
     #[serde(default)]
@@ -144,6 +147,53 @@ pub struct TransactionSubmitDryRunResponse {
     pub result: ExecuteResult,
     #[cfg_attr(feature = "ts", ts(type = "Array<any>"))]
     pub json_result: Vec<serde_json::Value>,
+    pub fee_breakdown: FeeCostBreakdown,
+    /// The fees charged in the dry run plus the wallet's configured margin
+    pub suggested_max_fee: Amount,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionResolveInputsRequest {
+    pub transaction: UnsignedTransaction,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionResolveInputsResponse {
+    /// The inputs of the transaction that are known to the wallet, at their latest known version
+    pub inputs: Vec<SubstateRequirement>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionSubmitSignedRequest {
+    /// A transaction that has been signed outside of the wallet
+    pub transaction: Transaction,
+    pub autofill_inputs: Vec<SubstateRequirement>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct TransactionSubmitSignedResponse {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -402,6 +452,182 @@ pub struct AccountsInvokeResponse {
     pub result: Option<InstructionResult>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsCreateMultisigRequest {
+    /// The account that pays the fees for creating the multisig account. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub fee_account: Option<ComponentAddressOrName>,
+    #[cfg_attr(feature = "ts", ts(type = "Array<string>"))]
+    pub owner_public_keys: Vec<PublicKey>,
+    pub threshold: u32,
+    /// The wallet key that is one of the owners. The multisig account is added to this wallet, owned by this key. The
+    /// key of the fee account is used if not set, and the account is only added if that key is one of the owners.
+    pub owner_key_id: Option<u64>,
+    pub account_name: Option<String>,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsCreateMultisigResponse {
+    pub address: ComponentAddress,
+    pub result: FinalizeResult,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsInvokeMultisigRequest {
+    /// The owner account that signs the transaction and pays the fees. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub account: Option<ComponentAddressOrName>,
+    pub multisig_address: ComponentAddress,
+    pub method: String,
+    pub args: Vec<Arg>,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsInvokeMultisigResponse {
+    pub result: Option<InstructionResult>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsSetGuardiansRequest {
+    /// The account to set guardians for. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub account: Option<ComponentAddressOrName>,
+    /// Public key tokens or non-fungibles held by the guardians
+    pub guardians: Vec<NonFungibleAddress>,
+    pub threshold: u32,
+    pub recovery_delay_epochs: u64,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsInitiateRecoveryRequest {
+    /// The guardian account that signs the transaction and pays the fees. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub guardian_account: Option<ComponentAddressOrName>,
+    /// The account being recovered
+    pub account_address: ComponentAddress,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub new_owner_public_key: PublicKey,
+    /// If the guardian is a non-fungible held by the guardian account, rather than the account's public key
+    pub guardian_badge: Option<NonFungibleAddress>,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsApproveRecoveryRequest {
+    /// The guardian account that signs the transaction and pays the fees. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub guardian_account: Option<ComponentAddressOrName>,
+    /// The account being recovered
+    pub account_address: ComponentAddress,
+    /// If the guardian is a non-fungible held by the guardian account, rather than the account's public key
+    pub guardian_badge: Option<NonFungibleAddress>,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsCancelRecoveryRequest {
+    /// The account with the pending recovery. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub account: Option<ComponentAddressOrName>,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsCompleteRecoveryRequest {
+    /// The account that pays the fees. The default account is used if not set.
+    #[serde(default, deserialize_with = "opt_string_or_struct")]
+    pub fee_account: Option<ComponentAddressOrName>,
+    /// The account being recovered
+    pub account_address: ComponentAddress,
+    /// If set, the recovered account is added to this wallet, owned by the key with this index. The key must be the
+    /// new owner key of the recovery.
+    pub new_owner_key_id: Option<u64>,
+    pub account_name: Option<String>,
+    pub max_fee: Option<Amount>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsRecoveryResponse {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub transaction_id: TransactionId,
+    pub result: FinalizeResult,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsRestoreRequest {
+    /// The number of consecutive unused keys after which account discovery stops. Defaults to 20.
+    #[cfg_attr(feature = "ts", ts(type = "number | null"))]
+    pub gap_limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(TS),
+    ts(export, export_to = "../../bindings/src/types/wallet-daemon-client/")
+)]
+pub struct AccountsRestoreResponse {
+    pub accounts: Vec<AccountInfo>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[cfg_attr(
     feature = "ts",
@@ -476,6 +702,8 @@ pub struct BalanceEntry {
     pub balance: Amount,
     pub resource_type: ResourceType,
     pub confidential_balance: Amount,
+    /// The portion of the revealed balance that is time locked and cannot currently be withdrawn
+    pub time_locked_balance: Amount,
     pub token_symbol: Option<String>,
 }
 
@@ -483,6 +711,9 @@ impl BalanceEntry {
     pub fn to_balance_string(&self) -> String {
         let symbol = self.token_symbol.as_deref().unwrap_or_default();
         match self.resource_type {
+            ResourceType::Fungible if !self.time_locked_balance.is_zero() => {
+                format!("{} {} ({} time locked)", self.balance, symbol, self.time_locked_balance)
+            },
             ResourceType::Fungible => {
                 format!("{} {}", self.balance, symbol)
             },
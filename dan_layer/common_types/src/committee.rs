@@ -5,7 +5,7 @@ use std::{borrow::Borrow, cmp, ops::RangeInclusive};
 
 use rand::{rngs::OsRng, seq::SliceRandom};
 use serde::{Deserialize, Serialize};
-use tari_common_types::types::PublicKey;
+use tari_common_types::types::{FixedHash, PublicKey};
 use tari_engine_types::substate::SubstateId;
 
 use crate::{shard::Shard, Epoch, NumPreshards, ShardGroup, SubstateAddress};
@@ -20,6 +20,10 @@ pub struct Committee<TAddr> {
     // TODO: not pub
     #[cfg_attr(feature = "ts", ts(type = "Array<[TAddr, string]>"))]
     pub members: Vec<(TAddr, PublicKey)>,
+    /// Entropy that is unique to the epoch of the committee, used to seed leader selection
+    #[serde(skip)]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    seed: FixedHash,
 }
 
 impl<TAddr: PartialEq> Committee<TAddr> {
@@ -32,7 +36,19 @@ impl<TAddr: PartialEq> Committee<TAddr> {
     }
 
     pub fn new(members: Vec<(TAddr, PublicKey)>) -> Self {
-        Self { members }
+        Self {
+            members,
+            seed: FixedHash::zero(),
+        }
+    }
+
+    pub fn with_seed(mut self, seed: FixedHash) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn seed(&self) -> &FixedHash {
+        &self.seed
     }
 
     pub fn members(&self) -> impl Iterator<Item = &TAddr> + '_ {
@@ -36,6 +36,10 @@ pub fn block_hasher() -> TariHasher {
     dan_hasher("Block")
 }
 
+pub fn block_commands_hasher() -> TariHasher {
+    dan_hasher("BlockCommands")
+}
+
 pub fn quorum_certificate_hasher() -> TariHasher {
     dan_hasher("QuorumCertificate")
 }
@@ -52,6 +56,18 @@ pub fn vote_signature_hasher() -> TariHasher {
     dan_hasher("VoteSignature")
 }
 
+pub fn equivocation_evidence_hasher() -> TariHasher {
+    dan_hasher("EquivocationEvidence")
+}
+
+pub fn leader_selection_hasher() -> TariHasher {
+    dan_hasher("LeaderSelection")
+}
+
+pub fn aggregated_signature_hasher() -> TariHasher {
+    dan_hasher("AggregatedSignature")
+}
+
 fn dan_hasher(label: &'static str) -> TariHasher {
     tari_hasher::<TariDanConsensusHashDomain>(label)
 }
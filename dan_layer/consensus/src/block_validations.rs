@@ -1,6 +1,8 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
+use std::collections::HashSet;
+
 use tari_common::configuration::Network;
 use tari_crypto::{ristretto::RistrettoPublicKey, tari_utilities::ByteArray};
 use tari_dan_common_types::{
@@ -8,7 +10,7 @@ use tari_dan_common_types::{
     DerivableFromPublicKey,
     ExtraFieldKey,
 };
-use tari_dan_storage::consensus_models::Block;
+use tari_dan_storage::consensus_models::{Block, QuorumCertificate};
 use tari_epoch_manager::EpochManagerReader;
 
 use crate::{
@@ -177,11 +179,48 @@ pub fn check_quorum_certificate<TConsensusSpec: ConsensusSpec>(
         .into());
     }
 
-    if qc.signatures().is_empty() {
+    if qc.is_legacy() {
+        return check_legacy_quorum_certificate::<TConsensusSpec>(qc, committee, committee_info, vote_signing_service);
+    }
+
+    if qc.signature().is_empty() {
+        return Err(ProposalValidationError::QuorumWasNotReached { qc: *qc.id() }.into());
+    }
+
+    if qc.signature().signers(committee).is_none() {
+        return Err(ProposalValidationError::QCInvalidSigners {
+            qc: *qc.id(),
+            details: format!(
+                "signer bitmap does not refer to members of committee {} ({} members)",
+                committee_info.shard_group(),
+                committee.len()
+            ),
+        }
+        .into());
+    }
+
+    if committee_info.quorum_threshold() >
+        u32::try_from(qc.signature().num_signers()).map_err(|_| ProposalValidationError::QCConversionError)?
+    {
         return Err(ProposalValidationError::QuorumWasNotReached { qc: *qc.id() }.into());
     }
 
-    for signature in qc.signatures() {
+    let message = vote_signing_service.create_message(qc.block_id(), &qc.decision());
+    if !qc.signature().verify(committee, message) {
+        return Err(ProposalValidationError::QCInvalidSignature { qc: *qc.id() }.into());
+    }
+    Ok(())
+}
+
+/// Checks a QC that was created before signatures were aggregated by verifying each signature individually.
+fn check_legacy_quorum_certificate<TConsensusSpec: ConsensusSpec>(
+    qc: &QuorumCertificate,
+    committee: &Committee<TConsensusSpec::Addr>,
+    committee_info: &CommitteeInfo,
+    vote_signing_service: &TConsensusSpec::SignatureService,
+) -> Result<(), HotStuffError> {
+    let mut signers = HashSet::with_capacity(qc.legacy_signatures().len());
+    for signature in qc.legacy_signatures() {
         if !committee.contains_public_key(signature.public_key()) {
             return Err(ProposalValidationError::ValidatorNotInCommittee {
                 validator: signature.public_key().to_string(),
@@ -193,17 +232,24 @@ pub fn check_quorum_certificate<TConsensusSpec: ConsensusSpec>(
             }
             .into());
         }
+        if !signers.insert(signature.public_key()) {
+            return Err(ProposalValidationError::QCInvalidSigners {
+                qc: *qc.id(),
+                details: format!("validator {} signed more than once", signature.public_key()),
+            }
+            .into());
+        }
     }
 
-    for sign in qc.signatures() {
-        let message = vote_signing_service.create_message(qc.block_id(), &qc.decision());
-        if !sign.verify(message) {
+    let message = vote_signing_service.create_message(qc.block_id(), &qc.decision());
+    for signature in qc.legacy_signatures() {
+        if !signature.verify(&message) {
             return Err(ProposalValidationError::QCInvalidSignature { qc: *qc.id() }.into());
         }
     }
 
     if committee_info.quorum_threshold() >
-        u32::try_from(qc.signatures().len()).map_err(|_| ProposalValidationError::QCConversionError)?
+        u32::try_from(signers.len()).map_err(|_| ProposalValidationError::QCConversionError)?
     {
         return Err(ProposalValidationError::QuorumWasNotReached { qc: *qc.id() }.into());
     }
@@ -46,6 +46,23 @@ pub struct ConsensusConstants {
     /// Maximum number of validator nodes to be activated in an epoch.
     /// This is to give enough time to the network to catch up with new validator nodes and do syncing.
     pub max_vns_per_epoch_activated: u64,
+    /// The maximum amount of time that a proposed block timestamp may be ahead of the local clock. Block timestamps
+    /// are exposed to templates, so validators will not vote for blocks that are too far in the future.
+    pub max_block_time_drift: Duration,
+    /// The strategy used to select the leader for each height
+    pub leader_selection: LeaderSelection,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderSelection {
+    /// Leaders take turns in committee order
+    RoundRobin,
+    /// Every member leads once per round of `committee size` heights, in an order that is shuffled each round
+    Randomized,
+    /// As with `Randomized`, but a leader that has missed `missed_proposal_threshold` or more proposals in a row in
+    /// the current epoch is skipped when determining the next leader. At most `f` leaders are skipped in a row,
+    /// and no leaders are skipped in every fourth round so that they can recover.
+    Reputation { missed_proposal_threshold: u64 },
 }
 
 impl ConsensusConstants {
@@ -62,6 +79,8 @@ impl ConsensusConstants {
             max_block_size: 500,
             fee_exhaust_divisor: 20, // 5%
             max_vns_per_epoch_activated: 50,
+            max_block_time_drift: Duration::from_secs(30),
+            leader_selection: LeaderSelection::RoundRobin,
         }
     }
 }
@@ -18,6 +18,7 @@ use tari_dan_storage::{
         BlockId,
         BlockTransactionExecution,
         BurntUtxo,
+        EquivocationEvidence,
         ForeignProposal,
         HighQc,
         LeafBlock,
@@ -45,7 +46,7 @@ use tari_dan_storage::{
 use tari_engine_types::substate::SubstateId;
 use tari_transaction::TransactionId;
 
-use crate::tracing::TraceTimer;
+use crate::{hotstuff::transaction_manager::BlockExecutionStats, tracing::TraceTimer};
 
 const LOG_TARGET: &str = "tari::dan::consensus::block_change_set";
 
@@ -56,6 +57,7 @@ const MEM_MAX_TRANSACTION_CHANGE_SIZE: usize = 1000;
 const MEM_MAX_PROPOSED_FOREIGN_PROPOSALS_SIZE: usize = 1000;
 const MEM_MAX_PROPOSED_UTXO_MINTS_SIZE: usize = 1000;
 const MEM_MAX_SUSPEND_CHANGE_SIZE: usize = 10;
+const MEM_MAX_PROPOSED_EQUIVOCATIONS_SIZE: usize = 10;
 
 #[derive(Debug, Clone)]
 pub struct BlockDecision {
@@ -66,6 +68,7 @@ pub struct BlockDecision {
     pub finalized_transactions: Vec<Vec<TransactionPoolRecord>>,
     pub end_of_epoch: Option<Epoch>,
     pub high_qc: HighQc,
+    pub execution_stats: BlockExecutionStats,
 }
 
 impl BlockDecision {
@@ -84,6 +87,7 @@ pub struct ProposedBlockChangeSet {
     transaction_changes: IndexMap<TransactionId, TransactionChangeSet>,
     proposed_foreign_proposals: Vec<BlockId>,
     proposed_utxo_mints: Vec<SubstateId>,
+    proposed_equivocations: Vec<EquivocationEvidence>,
     no_vote_reason: Option<NoVoteReason>,
     suspend_nodes: Vec<PublicKey>,
     resume_nodes: Vec<PublicKey>,
@@ -100,6 +104,7 @@ impl ProposedBlockChangeSet {
             state_tree_diffs: IndexMap::new(),
             proposed_foreign_proposals: Vec::new(),
             proposed_utxo_mints: Vec::new(),
+            proposed_equivocations: Vec::new(),
             no_vote_reason: None,
             suspend_nodes: Vec::new(),
             resume_nodes: Vec::new(),
@@ -181,6 +186,11 @@ impl ProposedBlockChangeSet {
             );
             self.proposed_utxo_mints.shrink_to(MEM_MAX_PROPOSED_UTXO_MINTS_SIZE);
         }
+        self.proposed_equivocations.clear();
+        if self.proposed_equivocations.capacity() > MEM_MAX_PROPOSED_EQUIVOCATIONS_SIZE {
+            self.proposed_equivocations
+                .shrink_to(MEM_MAX_PROPOSED_EQUIVOCATIONS_SIZE);
+        }
         self.suspend_nodes.clear();
         if self.suspend_nodes.capacity() > MEM_MAX_SUSPEND_CHANGE_SIZE {
             self.suspend_nodes.shrink_to(MEM_MAX_SUSPEND_CHANGE_SIZE);
@@ -222,6 +232,11 @@ impl ProposedBlockChangeSet {
         self
     }
 
+    pub fn set_equivocation_proposed_in(&mut self, evidence: EquivocationEvidence) -> &mut Self {
+        self.proposed_equivocations.push(evidence);
+        self
+    }
+
     pub fn apply_transaction_update(&self, tx_rec_mut: &mut TransactionPoolRecord) {
         if let Some(update) = self.transaction_changes.get(tx_rec_mut.transaction_id()) {
             update.apply_update(tx_rec_mut);
@@ -394,6 +409,12 @@ impl ProposedBlockChangeSet {
             BurntUtxo::set_proposed_in_block(tx, mint, &self.block.block_id)?
         }
 
+        for evidence in &self.proposed_equivocations {
+            // The leader may have detected the equivocation, in which case we may not have the evidence yet
+            evidence.save(tx)?;
+            evidence.set_proposed_in_block(tx, &self.block.block_id)?;
+        }
+
         for node in &self.suspend_nodes {
             ValidatorConsensusStats::suspend_node(tx, node, self.block.block_id)?
         }
@@ -435,6 +456,13 @@ impl Display for ProposedBlockChangeSet {
         if !self.proposed_utxo_mints.is_empty() {
             write!(f, " ProposedUtxoMints: {} mint(s), ", self.proposed_utxo_mints.len())?;
         }
+        if !self.proposed_equivocations.is_empty() {
+            write!(
+                f,
+                " ProposedEquivocations: {} evidence(s), ",
+                self.proposed_equivocations.len()
+            )?;
+        }
         write!(f, ")")
     }
 }
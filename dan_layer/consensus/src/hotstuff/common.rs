@@ -9,7 +9,7 @@ use std::{
 use indexmap::IndexMap;
 use log::*;
 use tari_common::configuration::Network;
-use tari_common_types::types::FixedHash;
+use tari_common_types::types::{FixedHash, PublicKey};
 use tari_dan_common_types::{
     committee::{Committee, CommitteeInfo},
     shard::Shard,
@@ -47,6 +47,10 @@ use crate::{
 
 const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::common";
 
+/// Leaders with a poor reputation are not skipped in every this many rounds of `committee size` heights. This gives
+/// them a chance to propose a block and recover their reputation.
+const REPUTATION_PROBATION_ROUNDS: u64 = 4;
+
 /// Calculates the dummy block required to reach the new height and returns the last dummy block (parent for next
 /// proposal). Generates dummy blocks from from_height to new_height _exclusive_.
 pub fn calculate_last_dummy_block<TAddr: NodeAddressable, TLeaderStrategy: LeaderStrategy<TAddr>>(
@@ -318,11 +322,41 @@ pub(crate) fn get_next_block_height_and_leader<
     height: NodeHeight,
 ) -> Result<(NodeHeight, &'a TAddr, usize), HotStuffError> {
     let mut num_skipped = 0;
+    let mut num_skipped_for_reputation = 0;
     let mut next_height = height;
     let (mut leader_addr, mut leader_pk) = leader_strategy.get_leader_for_next_height(committee, next_height);
 
-    while ValidatorConsensusStats::is_node_suspended(tx, block_id, leader_pk)? {
-        debug!(target: LOG_TARGET, "Validator {} suspended for next height {}. Checking next validator", leader_addr, next_height + NodeHeight(1));
+    // Reputation is evaluated against the chain ending at block_id, so that all validators extending the same block
+    // agree on the next leader, regardless of how far each of them has committed
+    let reputation = match leader_strategy.missed_proposal_skip_threshold() {
+        Some(threshold) => {
+            let window = usize::try_from(threshold)
+                .unwrap_or(usize::MAX)
+                .saturating_mul(committee.len());
+            Some((threshold, tx.blocks_get_parent_chain(block_id, window)?))
+        },
+        None => None,
+    };
+
+    loop {
+        if ValidatorConsensusStats::is_node_suspended(tx, block_id, leader_pk)? {
+            debug!(target: LOG_TARGET, "Validator {} suspended for next height {}. Checking next validator", leader_addr, next_height + NodeHeight(1));
+        } else if num_skipped_for_reputation < committee.max_failures() &&
+            reputation.as_ref().is_some_and(|(threshold, recent_blocks)| {
+                has_poor_reputation(
+                    recent_blocks,
+                    *threshold,
+                    committee.len(),
+                    next_height + NodeHeight(1),
+                    leader_pk,
+                )
+            })
+        {
+            debug!(target: LOG_TARGET, "Validator {} has missed too many proposals to lead next height {}. Checking next validator", leader_addr, next_height + NodeHeight(1));
+            num_skipped_for_reputation += 1;
+        } else {
+            break;
+        }
         next_height += NodeHeight(1);
         num_skipped += 1;
         let (addr, pk) = leader_strategy.get_leader_for_next_height(committee, next_height);
@@ -333,3 +367,33 @@ pub(crate) fn get_next_block_height_and_leader<
 
     Ok((next_height, leader_addr, num_skipped))
 }
+
+/// Returns true if the validator missed at least `threshold` proposals (dummy blocks) in `recent_blocks` since it last
+/// proposed a block in the same epoch. `recent_blocks` must be ordered from highest to lowest.
+fn has_poor_reputation(
+    recent_blocks: &[Block],
+    threshold: u64,
+    committee_size: usize,
+    height: NodeHeight,
+    public_key: &PublicKey,
+) -> bool {
+    let round = height.as_u64() / (committee_size.max(1) as u64);
+    if round % REPUTATION_PROBATION_ROUNDS == 0 {
+        return false;
+    }
+
+    let Some(epoch) = recent_blocks.first().map(|b| b.epoch()) else {
+        return false;
+    };
+    let mut missed_proposals = 0;
+    for block in recent_blocks.iter().take_while(|b| b.epoch() == epoch) {
+        if block.proposed_by() != public_key {
+            continue;
+        }
+        if !block.is_dummy() {
+            break;
+        }
+        missed_proposals += 1;
+    }
+    missed_proposals >= threshold
+}
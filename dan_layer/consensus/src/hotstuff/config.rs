@@ -11,4 +11,7 @@ pub struct HotstuffConfig {
     pub network: Network,
     pub sidechain_id: Option<RistrettoPublicKey>,
     pub consensus_constants: ConsensusConstants,
+    /// The maximum number of independent transactions in a block that are executed concurrently. 0 uses the number of
+    /// available CPUs and 1 executes all transactions sequentially.
+    pub max_execution_concurrency: usize,
 }
@@ -5,7 +5,15 @@ use tari_common_types::types::FixedHash;
 use tari_crypto::ristretto::RistrettoPublicKey;
 use tari_dan_common_types::{Epoch, NodeHeight, VersionedSubstateIdError};
 use tari_dan_storage::{
-    consensus_models::{BlockError, BlockId, LeafBlock, LockedBlock, QcId, TransactionPoolError},
+    consensus_models::{
+        AggregatedSignatureError,
+        BlockError,
+        BlockId,
+        LeafBlock,
+        LockedBlock,
+        QcId,
+        TransactionPoolError,
+    },
     StorageError,
 };
 use tari_epoch_manager::EpochManagerError;
@@ -56,6 +64,8 @@ pub enum HotStuffError {
     InvalidVoteSignature { signer_public_key: String },
     #[error("Invalid vote {signer_public_key} (unauthenticated): {details}")]
     InvalidVote { signer_public_key: String, details: String },
+    #[error("Invalid equivocation evidence from {from}: {details}")]
+    InvalidEquivocationEvidence { from: String, details: String },
     #[error("Transaction pool error: {0}")]
     TransactionPoolError(#[from] TransactionPoolError),
     #[error("Transaction {transaction_id} does not exist")]
@@ -106,6 +116,8 @@ pub enum HotStuffError {
     },
     #[error("Block building error: {0}")]
     BlockBuildingError(#[from] BlockError),
+    #[error("Aggregated signature error: {0}")]
+    AggregatedSignatureError(#[from] AggregatedSignatureError),
 }
 
 impl From<EpochManagerError> for HotStuffError {
@@ -190,6 +202,8 @@ pub enum ProposalValidationError {
     InvalidSignature { block_id: BlockId, height: NodeHeight },
     #[error("QC has invalid signature: {qc}")]
     QCInvalidSignature { qc: QcId },
+    #[error("QC {qc} has invalid signers: {details}")]
+    QCInvalidSigners { qc: QcId, details: String },
     #[error("Quorum was not reached: {qc}")]
     QuorumWasNotReached { qc: QcId },
     #[error("Invalid network in block {block_id}: expected {expected_network}, given {block_network}")]
@@ -389,6 +389,7 @@ pub fn process_foreign_block<TTx: StateStoreReadTransaction>(
             Command::ForeignProposal(_) |
             Command::SuspendNode(_) |
             Command::ResumeNode(_) |
+            Command::Equivocation(_) |
             Command::MintConfidentialOutput(_) => {
                 // Disregard
                 continue;
@@ -12,6 +12,7 @@ mod on_leader_timeout;
 mod on_next_sync_view;
 mod on_propose;
 mod on_ready_to_vote_on_local_block;
+mod on_receive_equivocation;
 mod on_receive_foreign_proposal;
 mod on_receive_local_proposal;
 mod on_receive_new_transaction;
@@ -28,7 +29,7 @@ mod pacemaker;
 mod pacemaker_handle;
 mod state_machine;
 pub mod substate_store;
-mod transaction_manager;
+pub mod transaction_manager;
 mod vote_collector;
 mod worker;
 
@@ -38,4 +39,5 @@ pub use current_view::*;
 pub use error::*;
 pub use event::*;
 pub use state_machine::*;
+pub use transaction_manager::BlockExecutionStats;
 pub use worker::*;
@@ -28,6 +28,7 @@ use tari_dan_storage::{
         BurntUtxo,
         Command,
         Decision,
+        EquivocationEvidence,
         ForeignProposal,
         ForeignSendCounters,
         HighQc,
@@ -73,7 +74,13 @@ use crate::{
     },
     messages::{HotstuffMessage, ProposalMessage},
     tracing::TraceTimer,
-    traits::{ConsensusSpec, OutboundMessaging, ValidatorSignatureService, WriteableSubstateStore},
+    traits::{
+        BlockExecutionContext,
+        ConsensusSpec,
+        OutboundMessaging,
+        ValidatorSignatureService,
+        WriteableSubstateStore,
+    },
 };
 
 const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::on_local_propose";
@@ -260,6 +267,7 @@ where TConsensusSpec: ConsensusSpec
         start_of_chain_id: &LeafBlock,
         mut tx_rec: TransactionPoolRecord,
         local_committee_info: &CommitteeInfo,
+        execution_context: &BlockExecutionContext,
         substate_store: &mut PendingSubstateStore<TConsensusSpec::StateStore>,
         executed_transactions: &mut HashMap<TransactionId, TransactionExecution>,
         lock_conflicts: &mut TransactionLockConflicts,
@@ -269,6 +277,7 @@ where TConsensusSpec: ConsensusSpec
                 start_of_chain_id,
                 &mut tx_rec,
                 local_committee_info,
+                execution_context,
                 substate_store,
                 executed_transactions,
                 lock_conflicts,
@@ -281,6 +290,7 @@ where TConsensusSpec: ConsensusSpec
                 tx,
                 start_of_chain_id,
                 local_committee_info,
+                execution_context,
                 &mut tx_rec,
                 substate_store,
                 executed_transactions,
@@ -399,6 +409,20 @@ where TConsensusSpec: ConsensusSpec
             high_qc_certificate.as_leaf_block()
         };
 
+        // Templates may use the block timestamp as a time source, so it must never go backwards
+        let now = EpochTime::now().as_u64();
+        let timestamp = if high_qc_certificate.is_zero() {
+            now
+        } else {
+            now.max(high_qc_certificate.get_block(tx)?.timestamp())
+        };
+        let execution_context = BlockExecutionContext {
+            epoch,
+            height: next_height,
+            timestamp,
+            shard_group: local_committee_info.shard_group(),
+        };
+
         let mut total_leader_fee = 0;
 
         let foreign_proposals = if propose_epoch_end {
@@ -485,12 +509,33 @@ where TConsensusSpec: ConsensusSpec
 
         let suspend_nodes_len = suspend_nodes.len();
 
+        let equivocations = if dont_propose_transactions || propose_epoch_end {
+            vec![]
+        } else {
+            max_block_size
+                .checked_sub(foreign_proposals.len() * 4 + burnt_utxos.len() + suspend_nodes_len)
+                .filter(|n| *n > 0)
+                .map(|size| EquivocationEvidence::get_all_unproposed(tx, epoch, start_of_chain_block.block_id(), size))
+                .transpose()?
+                .unwrap_or_default()
+        };
+
+        if !equivocations.is_empty() {
+            debug!(
+                target: LOG_TARGET,
+                "🌿 Found {} equivocation(s) to report in next block",
+                equivocations.len()
+            )
+        }
+
         let batch = if dont_propose_transactions || propose_epoch_end {
             vec![]
         } else {
             max_block_size
                 // Each foreign proposal is "heavier" than a transaction command
-                .checked_sub(foreign_proposals.len() * 4 + burnt_utxos.len() + suspend_nodes.len())
+                .checked_sub(
+                    foreign_proposals.len() * 4 + burnt_utxos.len() + suspend_nodes_len + equivocations.len(),
+                )
                 .filter(|n| *n > 0)
                 .map(|size| self.transaction_pool.get_batch_for_next_block(tx, size, start_of_chain_block.block_id()))
                 .transpose()?
@@ -518,7 +563,8 @@ where TConsensusSpec: ConsensusSpec
                         resume_nodes
                             .into_iter()
                             .map(|public_key| Command::ResumeNode(ResumeNodeAtom { public_key })),
-                    ),
+                    )
+                    .chain(equivocations.into_iter().map(Command::Equivocation)),
             )
         };
 
@@ -581,6 +627,7 @@ where TConsensusSpec: ConsensusSpec
                 &start_of_chain_block,
                 transaction,
                 local_committee_info,
+                &execution_context,
                 &mut substate_store,
                 &mut executed_transactions,
                 &mut lock_conflicts,
@@ -653,7 +700,7 @@ where TConsensusSpec: ConsensusSpec
             total_leader_fee,
             foreign_indexes,
             None,
-            EpochTime::now().as_u64(),
+            timestamp,
             base_layer_block_height,
             base_layer_block_hash,
             None,
@@ -676,6 +723,7 @@ where TConsensusSpec: ConsensusSpec
         parent_block: &LeafBlock,
         tx_rec: &mut TransactionPoolRecord,
         local_committee_info: &CommitteeInfo,
+        execution_context: &BlockExecutionContext,
         substate_store: &mut PendingSubstateStore<TConsensusSpec::StateStore>,
         executed_transactions: &mut HashMap<TransactionId, TransactionExecution>,
         lock_conflicts: &mut TransactionLockConflicts,
@@ -691,7 +739,7 @@ where TConsensusSpec: ConsensusSpec
             .prepare(
                 substate_store,
                 local_committee_info,
-                parent_block.epoch(),
+                execution_context,
                 *tx_rec.transaction_id(),
                 parent_block.block_id(),
             )
@@ -836,6 +884,7 @@ where TConsensusSpec: ConsensusSpec
         tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
         parent_block: &LeafBlock,
         local_committee_info: &CommitteeInfo,
+        execution_context: &BlockExecutionContext,
         tx_rec: &mut TransactionPoolRecord,
         substate_store: &mut PendingSubstateStore<TConsensusSpec::StateStore>,
         executed_transactions: &mut HashMap<TransactionId, TransactionExecution>,
@@ -846,7 +895,7 @@ where TConsensusSpec: ConsensusSpec
         }
 
         let mut execution =
-            self.execute_transaction(tx, &parent_block.block_id, parent_block.epoch, tx_rec.transaction_id())?;
+            self.execute_transaction(tx, &parent_block.block_id, execution_context, tx_rec.transaction_id())?;
 
         // Try to lock all local outputs
         let local_outputs = execution
@@ -947,7 +996,7 @@ where TConsensusSpec: ConsensusSpec
         &self,
         tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
         parent_block_id: &BlockId,
-        current_epoch: Epoch,
+        execution_context: &BlockExecutionContext,
         transaction_id: &TransactionId,
     ) -> Result<TransactionExecution, HotStuffError> {
         let transaction = TransactionRecord::get(tx, transaction_id)?;
@@ -973,7 +1022,7 @@ where TConsensusSpec: ConsensusSpec
 
         let executed = self
             .transaction_manager
-            .execute(current_epoch, pledged)
+            .execute(execution_context, pledged)
             .map_err(|e| HotStuffError::TransactionExecutorError(e.to_string()))?;
 
         Ok(executed.into_execution())
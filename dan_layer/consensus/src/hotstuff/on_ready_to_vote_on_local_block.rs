@@ -4,11 +4,10 @@
 use std::{collections::HashMap, num::NonZeroU64};
 
 use log::*;
-use tari_crypto::ristretto::RistrettoPublicKey;
+use tari_crypto::{ristretto::RistrettoPublicKey, tari_utilities::epoch_time::EpochTime};
 use tari_dan_common_types::{
-    committee::CommitteeInfo,
+    committee::{Committee, CommitteeInfo},
     optional::Optional,
-    Epoch,
     ShardGroup,
     ToSubstateAddress,
     VersionedSubstateId,
@@ -22,6 +21,7 @@ use tari_dan_storage::{
         BlockTransactionExecution,
         Command,
         Decision,
+        EquivocationEvidence,
         ForeignProposalAtom,
         ForeignProposalStatus,
         HighQc,
@@ -57,6 +57,7 @@ use crate::{
         foreign_proposal_processor::process_foreign_block,
         substate_store::{PendingSubstateStore, ShardedStateTree},
         transaction_manager::{
+            BlockExecutionStats,
             ConsensusTransactionManager,
             LocalPreparedTransaction,
             PledgedTransaction,
@@ -66,7 +67,7 @@ use crate::{
         ProposalValidationError,
     },
     tracing::TraceTimer,
-    traits::{ConsensusSpec, WriteableSubstateStore},
+    traits::{BlockExecutionContext, ConsensusSpec, WriteableSubstateStore},
 };
 
 const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::on_ready_to_vote_on_local_block";
@@ -106,6 +107,7 @@ where TConsensusSpec: ConsensusSpec
         &mut self,
         tx: &mut <TConsensusSpec::StateStore as StateStore>::WriteTransaction<'_>,
         valid_block: &ValidBlock,
+        local_committee: &Committee<TConsensusSpec::Addr>,
         local_committee_info: &CommitteeInfo,
         can_propose_epoch_end: bool,
         foreign_committee_infos: HashMap<ShardGroup, CommitteeInfo>,
@@ -119,6 +121,7 @@ where TConsensusSpec: ConsensusSpec
             valid_block,
         );
 
+        let mut execution_stats = BlockExecutionStats::default();
         if self.should_vote(tx, valid_block.block())? {
             let mut justified_block = valid_block.justify().get_block(&**tx)?;
             // This comes before decide so that all evidence can be in place before LocalPrepare and LocalAccept
@@ -130,10 +133,12 @@ where TConsensusSpec: ConsensusSpec
             self.decide_what_to_vote(
                 tx,
                 valid_block.block(),
+                local_committee,
                 local_committee_info,
                 can_propose_epoch_end,
                 &foreign_committee_infos,
                 change_set,
+                &mut execution_stats,
             )?;
         } else {
             change_set.no_vote(NoVoteReason::AlreadyVotedAtHeight);
@@ -155,7 +160,8 @@ where TConsensusSpec: ConsensusSpec
                     self.on_lock_block(tx, block)
                 },
                 |tx, last_exec, commit_block| {
-                    let committed = self.on_commit(tx, last_exec, commit_block, local_committee_info)?;
+                    let committed =
+                        self.on_commit(tx, last_exec, commit_block, local_committee, local_committee_info)?;
                     if commit_block.is_epoch_end() {
                         end_of_epoch = Some(commit_block.epoch());
                     }
@@ -191,6 +197,7 @@ where TConsensusSpec: ConsensusSpec
             finalized_transactions,
             end_of_epoch,
             high_qc,
+            execution_stats,
         })
     }
 
@@ -294,15 +301,27 @@ where TConsensusSpec: ConsensusSpec
         &self,
         tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
         block: &Block,
+        local_committee: &Committee<TConsensusSpec::Addr>,
         local_committee_info: &CommitteeInfo,
         can_propose_epoch_end: bool,
         foreign_committee_infos: &HashMap<ShardGroup, CommitteeInfo>,
         proposed_block_change_set: &mut ProposedBlockChangeSet,
+        execution_stats: &mut BlockExecutionStats,
     ) -> Result<(), HotStuffError> {
+        if let Some(reason) = self.check_block_timestamp(tx, block)? {
+            proposed_block_change_set.no_vote(reason);
+            return Ok(());
+        }
+
         // Store used for transactions that have inputs without specific versions.
         // It lives through the entire block so multiple transactions can be sequenced together in the same block
         let mut substate_store =
             PendingSubstateStore::new(tx, *block.parent(), self.config.consensus_constants.num_preshards);
+        // Execute independent transactions in parallel. The results are applied in block order as each command is
+        // evaluated below.
+        self.transaction_manager
+            .pre_execute_block(&mut substate_store, local_committee_info, block)
+            .map_err(|e| HotStuffError::TransactionExecutorError(e.to_string()))?;
         let mut total_leader_fee = 0;
         let locked_block = LockedBlock::get(tx, block.epoch())?;
         let mut suspended_in_this_block_count = 0u64;
@@ -518,6 +537,19 @@ where TConsensusSpec: ConsensusSpec
                     );
                     proposed_block_change_set.add_resume_node(atom.public_key.clone());
                 },
+                Command::Equivocation(evidence) => {
+                    if let Some(reason) = self.evaluate_equivocation_command(
+                        tx,
+                        block,
+                        evidence,
+                        local_committee,
+                        local_committee_info,
+                        proposed_block_change_set,
+                    )? {
+                        proposed_block_change_set.no_vote(reason);
+                        return Ok(());
+                    }
+                },
                 Command::EndEpoch => {
                     if !can_propose_epoch_end {
                         warn!(
@@ -543,6 +575,8 @@ where TConsensusSpec: ConsensusSpec
             }
         }
 
+        *execution_stats = substate_store.pre_executed().stats();
+
         if total_leader_fee != block.total_leader_fee() {
             warn!(
                 target: LOG_TARGET,
@@ -633,7 +667,7 @@ where TConsensusSpec: ConsensusSpec
             .prepare(
                 substate_store,
                 local_committee_info,
-                block.epoch(),
+                &BlockExecutionContext::from_block(block),
                 *atom.id(),
                 block.id(),
             )
@@ -813,7 +847,7 @@ where TConsensusSpec: ConsensusSpec
             .prepare(
                 substate_store,
                 local_committee_info,
-                block.epoch(),
+                &BlockExecutionContext::from_block(block),
                 *atom.id(),
                 block.id(),
             )
@@ -1037,7 +1071,8 @@ where TConsensusSpec: ConsensusSpec
                 );
                 return Ok(Some(NoVoteReason::NotAllForeignInputPledges));
             }
-            let execution = self.execute_transaction(tx, block.id(), block.epoch(), transaction)?;
+            let execution =
+                self.execute_transaction(tx, block.id(), &BlockExecutionContext::from_block(block), transaction)?;
             let mut execution = execution.into_transaction_execution();
 
             // TODO: check the diff is valid against the provided input evidence (correct locks etc).
@@ -1664,11 +1699,99 @@ where TConsensusSpec: ConsensusSpec
         Ok(None)
     }
 
+    fn evaluate_equivocation_command(
+        &self,
+        tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
+        block: &Block,
+        evidence: &EquivocationEvidence,
+        local_committee: &Committee<TConsensusSpec::Addr>,
+        local_committee_info: &CommitteeInfo,
+        proposed_block_change_set: &mut ProposedBlockChangeSet,
+    ) -> Result<Option<NoVoteReason>, HotStuffError> {
+        if evidence.epoch != block.epoch() ||
+            evidence.shard_group != local_committee_info.shard_group() ||
+            !evidence.is_valid()
+        {
+            warn!(
+                target: LOG_TARGET,
+                "❌ NO VOTE: Invalid equivocation evidence {} in block {}",
+                evidence,
+                block.id()
+            );
+            return Ok(Some(NoVoteReason::InvalidEquivocationEvidence));
+        }
+
+        if !local_committee.contains_public_key(&evidence.validator_public_key) {
+            warn!(
+                target: LOG_TARGET,
+                "❌ NO VOTE: Equivocation evidence {} in block {} is for validator {} that is not in the local committee",
+                evidence,
+                block.id(),
+                evidence.validator_public_key
+            );
+            return Ok(Some(NoVoteReason::InvalidEquivocationEvidence));
+        }
+
+        if evidence.is_proposed_in_chain(tx, block.parent())? {
+            warn!(
+                target: LOG_TARGET,
+                "❌ NO VOTE: Equivocation evidence {} has already been proposed",
+                evidence,
+            );
+            return Ok(Some(NoVoteReason::EquivocationAlreadyProposed));
+        }
+
+        info!(
+            target: LOG_TARGET,
+            "⚖️ Equivocation evidence {} proposed in block {}",
+            evidence,
+            block.id()
+        );
+        proposed_block_change_set.set_equivocation_proposed_in(evidence.clone());
+
+        Ok(None)
+    }
+
+    /// Block timestamps are provided to templates as the current time, so they must not decrease and must not be too
+    /// far ahead of our local clock.
+    fn check_block_timestamp(
+        &self,
+        tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
+        block: &Block,
+    ) -> Result<Option<NoVoteReason>, HotStuffError> {
+        let min = if block.justify().is_zero() {
+            0
+        } else {
+            block.justify().get_block(tx)?.timestamp()
+        };
+        let max = EpochTime::now()
+            .as_u64()
+            .saturating_add(self.config.consensus_constants.max_block_time_drift.as_secs());
+
+        if block.timestamp() < min || block.timestamp() > max {
+            warn!(
+                target: LOG_TARGET,
+                "❌ NO VOTE: block {} has timestamp {} which is not within [{}, {}]",
+                block,
+                block.timestamp(),
+                min,
+                max,
+            );
+            return Ok(Some(NoVoteReason::InvalidBlockTimestamp {
+                timestamp: block.timestamp(),
+                min,
+                max,
+            }));
+        }
+
+        Ok(None)
+    }
+
     fn execute_transaction(
         &self,
         tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
         block_id: &BlockId,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         transaction: TransactionRecord,
     ) -> Result<BlockTransactionExecution, HotStuffError> {
         info!(
@@ -1688,7 +1811,7 @@ where TConsensusSpec: ConsensusSpec
 
         let executed = self
             .transaction_manager
-            .execute(current_epoch, pledged)
+            .execute(context, pledged)
             .map_err(|e| HotStuffError::TransactionExecutorError(e.to_string()))?;
 
         Ok(executed.into_execution().for_block(*block_id))
@@ -1699,9 +1822,10 @@ where TConsensusSpec: ConsensusSpec
         tx: &mut <TConsensusSpec::StateStore as StateStore>::WriteTransaction<'_>,
         last_executed: &LastExecuted,
         block: &Block,
+        local_committee: &Committee<TConsensusSpec::Addr>,
         local_committee_info: &CommitteeInfo,
     ) -> Result<Vec<TransactionPoolRecord>, HotStuffError> {
-        let committed_transactions = self.finalize_block(tx, block, local_committee_info)?;
+        let committed_transactions = self.finalize_block(tx, block, local_committee, local_committee_info)?;
         debug!(
             target: LOG_TARGET,
             "✅ COMMIT block {}, last executed height = {}",
@@ -1749,6 +1873,7 @@ where TConsensusSpec: ConsensusSpec
         &self,
         tx: &mut <TConsensusSpec::StateStore as StateStore>::WriteTransaction<'_>,
         block: &Block,
+        local_committee: &Committee<TConsensusSpec::Addr>,
         local_committee_info: &CommitteeInfo,
     ) -> Result<Vec<TransactionPoolRecord>, HotStuffError> {
         if block.is_dummy() {
@@ -1778,6 +1903,10 @@ where TConsensusSpec: ConsensusSpec
             atom.delete_suspended_node(tx)?;
         }
 
+        for evidence in block.all_equivocations() {
+            evidence.set_committed_in_block(tx, block.id())?;
+        }
+
         // NOTE: this must happen before we commit the substate diff because the state transitions use this version
         let pending = block.remove_pending_tree_diff_and_return(tx)?;
         let mut state_tree = ShardedStateTree::new(tx);
@@ -1814,7 +1943,19 @@ where TConsensusSpec: ConsensusSpec
             );
         }
 
-        block.justify().update_participation_shares(tx)?;
+        // The signer bitmap can only be resolved against the committee that signed the QC. local_committee is the
+        // committee for the block's epoch, so a justify from a previous epoch cannot be counted.
+        if block.justify().epoch() == block.epoch() {
+            block.justify().update_participation_shares(tx, local_committee)?;
+        } else {
+            debug!(
+                target: LOG_TARGET,
+                "Not counting participation shares for {} from epoch {} in block {}",
+                block.justify(),
+                block.justify().epoch(),
+                block,
+            );
+        }
         block.clear_leader_failure_count(tx)?;
 
         Ok(finalized_transactions)
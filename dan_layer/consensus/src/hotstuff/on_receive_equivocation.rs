@@ -0,0 +1,110 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use log::*;
+use tari_dan_common_types::{committee::CommitteeInfo, optional::Optional, Epoch};
+use tari_dan_storage::StateStore;
+use tari_epoch_manager::EpochManagerReader;
+
+use crate::{
+    hotstuff::error::HotStuffError,
+    messages::EquivocationMessage,
+    tracing::TraceTimer,
+    traits::ConsensusSpec,
+};
+
+const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::on_receive_equivocation";
+
+/// Handles equivocation evidence gossiped by other members of the local committee. Valid evidence is stored so that it
+/// can be included in a block by the next leader.
+pub struct OnReceiveEquivocationHandler<TConsensusSpec: ConsensusSpec> {
+    store: TConsensusSpec::StateStore,
+    epoch_manager: TConsensusSpec::EpochManager,
+}
+
+impl<TConsensusSpec> OnReceiveEquivocationHandler<TConsensusSpec>
+where TConsensusSpec: ConsensusSpec
+{
+    pub fn new(store: TConsensusSpec::StateStore, epoch_manager: TConsensusSpec::EpochManager) -> Self {
+        Self { store, epoch_manager }
+    }
+
+    pub async fn handle(
+        &self,
+        from: TConsensusSpec::Addr,
+        current_epoch: Epoch,
+        msg: EquivocationMessage,
+        local_committee_info: &CommitteeInfo,
+    ) -> Result<(), HotStuffError> {
+        let _timer = TraceTimer::debug(LOG_TARGET, "OnReceiveEquivocation");
+        let EquivocationMessage { evidence } = msg;
+
+        if evidence.epoch != current_epoch {
+            return Err(HotStuffError::InvalidEquivocationEvidence {
+                from: from.to_string(),
+                details: format!(
+                    "Evidence is for epoch {} but the current epoch is {}",
+                    evidence.epoch, current_epoch
+                ),
+            });
+        }
+
+        if evidence.shard_group != local_committee_info.shard_group() {
+            return Err(HotStuffError::InvalidEquivocationEvidence {
+                from: from.to_string(),
+                details: format!(
+                    "Evidence is for shard group {} but the local shard group is {}",
+                    evidence.shard_group,
+                    local_committee_info.shard_group()
+                ),
+            });
+        }
+
+        // The evidence carries the headers of both blocks, so it can be verified without having either block
+        if !evidence.is_valid() {
+            return Err(HotStuffError::InvalidEquivocationEvidence {
+                from: from.to_string(),
+                details: format!("Invalid headers or signatures for evidence {}", evidence),
+            });
+        }
+
+        // The equivocating validator must be a member of the local committee
+        let vn = self
+            .epoch_manager
+            .get_validator_node_by_public_key(evidence.epoch, evidence.validator_public_key.clone())
+            .await
+            .optional()?;
+        let is_committee_member = vn.map_or(false, |vn| {
+            local_committee_info.includes_substate_address(&vn.shard_key)
+        });
+        if !is_committee_member {
+            return Err(HotStuffError::InvalidEquivocationEvidence {
+                from: from.to_string(),
+                details: format!(
+                    "Validator {} is not a member of the local committee",
+                    evidence.validator_public_key
+                ),
+            });
+        }
+
+        let is_new = self.store.with_write_tx(|tx| evidence.save(tx))?;
+        if is_new {
+            warn!(
+                target: LOG_TARGET,
+                "⚠️ Received equivocation evidence {} from {}: {}",
+                evidence.id(),
+                from,
+                evidence
+            );
+        } else {
+            debug!(
+                target: LOG_TARGET,
+                "Equivocation evidence {} from {} already known",
+                evidence.id(),
+                from
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ use tari_dan_common_types::{
 use tari_dan_storage::{
     consensus_models::{
         Block,
+        EquivocationEvidence,
         ForeignProposal,
         HighQc,
         LastSentVote,
@@ -45,7 +46,14 @@ use crate::{
         HotstuffEvent,
         ProposalValidationError,
     },
-    messages::{ForeignProposalMessage, HotstuffMessage, NewViewMessage, ProposalMessage, VoteMessage},
+    messages::{
+        EquivocationMessage,
+        ForeignProposalMessage,
+        HotstuffMessage,
+        NewViewMessage,
+        ProposalMessage,
+        VoteMessage,
+    },
     tracing::TraceTimer,
     traits::{
         hooks::ConsensusHooks,
@@ -173,7 +181,7 @@ impl<TConsensusSpec: ConsensusSpec> OnReceiveLocalProposalHandler<TConsensusSpec
             foreign_committees.insert(shard_group, foreign_committee_info);
         }
 
-        self.store.with_write_tx(|tx| {
+        let new_equivocations = self.store.with_write_tx(|tx| {
             for foreign_proposal in foreign_proposals {
                 if foreign_proposal.exists(&**tx)? {
                     // This is expected behaviour, we may receive the same foreign proposal multiple times
@@ -195,11 +203,26 @@ impl<TConsensusSpec: ConsensusSpec> OnReceiveLocalProposalHandler<TConsensusSpec
                 )?;
             }
 
+            let new_equivocations = Self::check_for_double_proposal(tx, valid_block.block())?;
             self.save_block(tx, &valid_block)?;
             info!(target: LOG_TARGET, "✅ Block {} is valid and persisted.", valid_block);
-            Ok::<_, HotStuffError>(())
+            Ok::<_, HotStuffError>(new_equivocations)
         })?;
 
+        for evidence in new_equivocations {
+            // Share the evidence with the rest of the committee so that the next leader can include it in a block
+            if let Err(err) = self
+                .outbound_messaging
+                .multicast(
+                    local_committee_info.shard_group(),
+                    HotstuffMessage::Equivocation(EquivocationMessage { evidence }),
+                )
+                .await
+            {
+                warn!(target: LOG_TARGET, "Failed to multicast equivocation evidence: {}", err);
+            }
+        }
+
         let result = self
             .process_block(
                 current_epoch,
@@ -251,6 +274,7 @@ impl<TConsensusSpec: ConsensusSpec> OnReceiveLocalProposalHandler<TConsensusSpec
                 .unwrap_or_else(|| ProposedBlockChangeSet::new(valid_block.block().as_leaf_block()));
 
             let store = self.store.clone();
+            let local_committee = local_committee.clone();
 
             // Task closure
             move || {
@@ -258,6 +282,7 @@ impl<TConsensusSpec: ConsensusSpec> OnReceiveLocalProposalHandler<TConsensusSpec
                     let decision = on_ready_to_vote_on_local_block.handle(
                         tx,
                         &valid_block,
+                        &local_committee,
                         &local_committee_info,
                         can_propose_epoch_end,
                         foreign_committees,
@@ -310,6 +335,8 @@ impl<TConsensusSpec: ConsensusSpec> OnReceiveLocalProposalHandler<TConsensusSpec
 
         self.hooks
             .on_local_block_decide(&valid_block, block_decision.quorum_decision);
+        self.hooks
+            .on_block_transactions_executed(&valid_block, &block_decision.execution_stats);
         for t in block_decision.finalized_transactions.into_iter().flatten() {
             self.hooks.on_transaction_finalized(&t.into_current_transaction_atom());
         }
@@ -503,6 +530,45 @@ impl<TConsensusSpec: ConsensusSpec> OnReceiveLocalProposalHandler<TConsensusSpec
         Ok(())
     }
 
+    /// Checks if the proposer has proposed another block at the same height as the given block. Returns any new
+    /// equivocation evidence that was recorded.
+    fn check_for_double_proposal(
+        tx: &mut <TConsensusSpec::StateStore as StateStore>::WriteTransaction<'_>,
+        block: &Block,
+    ) -> Result<Vec<EquivocationEvidence>, HotStuffError> {
+        let mut new_equivocations = Vec::new();
+        if block.is_dummy() {
+            return Ok(new_equivocations);
+        }
+
+        for block_id in tx.blocks_get_all_ids_by_height(block.epoch(), block.height())? {
+            if block_id == *block.id() {
+                continue;
+            }
+            let other = Block::get(&**tx, &block_id)?;
+            if other.is_dummy() || other.proposed_by() != block.proposed_by() {
+                continue;
+            }
+            let Some(evidence) = EquivocationEvidence::double_proposal(&other, block) else {
+                continue;
+            };
+            if evidence.save(tx)? {
+                warn!(
+                    target: LOG_TARGET,
+                    "⚠️ Leader {} proposed blocks {} and {} at height {}. Recorded equivocation evidence {}",
+                    block.proposed_by(),
+                    other.id(),
+                    block.id(),
+                    block.height(),
+                    evidence.id()
+                );
+                new_equivocations.push(evidence);
+            }
+        }
+
+        Ok(new_equivocations)
+    }
+
     fn validate_block(
         &self,
         tx: &<TConsensusSpec::StateStore as StateStore>::ReadTransaction<'_>,
@@ -26,7 +26,7 @@ use tari_transaction::TransactionId;
 
 use super::error::SubstateStoreError;
 use crate::{
-    hotstuff::substate_store::LockFailedError,
+    hotstuff::{substate_store::LockFailedError, transaction_manager::PreExecutedTransactions},
     traits::{ReadableSubstateStore, WriteableSubstateStore},
 };
 
@@ -43,6 +43,8 @@ pub struct PendingSubstateStore<'a, 'tx, TStore: StateStore + 'a + 'tx> {
     new_locks: IndexMap<SubstateId, Vec<SubstateLock>>,
     parent_block: BlockId,
     num_preshards: NumPreshards,
+    /// Transactions executed in parallel ahead of the sequential evaluation of the block
+    pre_executed: PreExecutedTransactions,
 }
 
 impl<'a, 'tx, TStore: StateStore + 'a> PendingSubstateStore<'a, 'tx, TStore> {
@@ -55,12 +57,21 @@ impl<'a, 'tx, TStore: StateStore + 'a> PendingSubstateStore<'a, 'tx, TStore> {
             new_locks: IndexMap::new(),
             parent_block,
             num_preshards,
+            pre_executed: PreExecutedTransactions::default(),
         }
     }
 
     pub fn read_transaction(&self) -> &'a TStore::ReadTransaction<'tx> {
         self.store
     }
+
+    pub fn pre_executed(&self) -> &PreExecutedTransactions {
+        &self.pre_executed
+    }
+
+    pub fn pre_executed_mut(&mut self) -> &mut PreExecutedTransactions {
+        &mut self.pre_executed
+    }
 }
 
 impl<'store, 'tx, TStore: StateStore + 'store + 'tx> ReadableSubstateStore
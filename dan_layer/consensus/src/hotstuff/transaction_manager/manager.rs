@@ -4,20 +4,22 @@
 use std::{
     collections::{HashMap, HashSet},
     marker::PhantomData,
+    sync::Arc,
 };
 
 use indexmap::IndexMap;
 use log::*;
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
 use tari_dan_common_types::{
     committee::CommitteeInfo,
     optional::{IsNotFoundError, Optional},
-    Epoch,
     SubstateRequirement,
     ToSubstateAddress,
     VersionedSubstateId,
 };
 use tari_dan_storage::{
     consensus_models::{
+        Block,
         BlockId,
         BlockTransactionExecution,
         Decision,
@@ -35,11 +37,11 @@ use tari_engine_types::{
 };
 use tari_transaction::{Transaction, TransactionId};
 
-use super::{PledgedTransaction, PreparedTransaction};
+use super::{ConflictGraph, PledgedTransaction, PreExecutedTransaction, PreparedTransaction};
 use crate::{
     hotstuff::substate_store::{LockStatus, PendingSubstateStore},
     tracing::TraceTimer,
-    traits::{BlockTransactionExecutor, BlockTransactionExecutorError},
+    traits::{BlockExecutionContext, BlockTransactionExecutor, BlockTransactionExecutorError},
 };
 
 const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::block_transaction_executor";
@@ -47,15 +49,34 @@ const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::block_transaction_exec
 #[derive(Debug, Clone)]
 pub struct ConsensusTransactionManager<TExecutor, TStateStore> {
     executor: TExecutor,
+    /// Thread pool used to execute independent transactions concurrently. None if execution is sequential.
+    thread_pool: Option<Arc<ThreadPool>>,
     _store: PhantomData<TStateStore>,
 }
 
 impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
     ConsensusTransactionManager<TExecutor, TStateStore>
 {
-    pub fn new(executor: TExecutor) -> Self {
+    /// Creates a new transaction manager. Up to `max_concurrency` transactions in a block are executed concurrently,
+    /// where 0 uses the number of available CPUs and 1 executes all transactions sequentially.
+    pub fn new(executor: TExecutor, max_concurrency: usize) -> Self {
+        let thread_pool = if max_concurrency == 1 {
+            None
+        } else {
+            ThreadPoolBuilder::new()
+                .num_threads(max_concurrency)
+                .thread_name(|i| format!("tx-executor-{i}"))
+                .build()
+                .map(Arc::new)
+                .map_err(|err| {
+                    warn!(target: LOG_TARGET, "⚠️ Failed to create transaction executor thread pool: {err}. Transactions will be executed sequentially");
+                })
+                .ok()
+        };
+
         Self {
             executor,
+            thread_pool,
             _store: PhantomData,
         }
     }
@@ -94,7 +115,7 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
 
     pub fn execute(
         &self,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         pledged_transaction: PledgedTransaction,
     ) -> Result<ExecutedTransaction, BlockTransactionExecutorError> {
         let resolved_inputs = pledged_transaction
@@ -114,7 +135,7 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
             .collect();
         let executed = self.executor.execute(
             pledged_transaction.transaction.into_transaction(),
-            current_epoch,
+            context,
             &resolved_inputs,
         )?;
 
@@ -125,7 +146,7 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
         &self,
         store: &mut PendingSubstateStore<TStateStore>,
         transaction: Transaction,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         resolved_inputs: &HashMap<SubstateRequirement, Substate>,
         block_id: &BlockId,
     ) -> Result<TransactionExecution, BlockTransactionExecutorError> {
@@ -142,7 +163,16 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
             return Ok(execution.into_transaction_execution());
         }
 
-        let executed = self.executor.execute(transaction, current_epoch, resolved_inputs)?;
+        // Use the result of parallel execution if it was executed with the same inputs
+        if let Some(executed) = store
+            .pre_executed_mut()
+            .take_if_matches(transaction.id(), resolved_inputs)
+        {
+            return Ok(executed.into_execution());
+        }
+
+        store.pre_executed_mut().record_sequential_execution();
+        let executed = self.executor.execute(transaction, context, resolved_inputs)?;
 
         Ok(executed.into_execution())
     }
@@ -152,7 +182,7 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
         &self,
         store: &mut PendingSubstateStore<TStateStore>,
         local_committee_info: &CommitteeInfo,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         transaction_id: TransactionId,
         block_id: &BlockId,
     ) -> Result<PreparedTransaction, BlockTransactionExecutorError> {
@@ -218,13 +248,8 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
             // CASE: All inputs are local and we can execute the transaction.
             //       Outputs may or may not be local
             let local_inputs = store.get_many(local_versions.iter().map(|(req, v)| (req.clone(), *v)))?;
-            let mut execution = self.execute_or_fetch(
-                store,
-                transaction.into_transaction(),
-                current_epoch,
-                &local_inputs,
-                block_id,
-            )?;
+            let mut execution =
+                self.execute_or_fetch(store, transaction.into_transaction(), context, &local_inputs, block_id)?;
 
             // local-only transaction can be determined if we've executed the transaction
             let is_local_only = local_committee_info
@@ -307,3 +332,113 @@ impl<TStateStore: StateStore, TExecutor: BlockTransactionExecutor<TStateStore>>
         }
     }
 }
+
+impl<TStateStore, TExecutor> ConsensusTransactionManager<TExecutor, TStateStore>
+where
+    TStateStore: StateStore,
+    TExecutor: BlockTransactionExecutor<TStateStore> + Sync,
+{
+    /// Executes the LocalOnly and Prepare transactions in the block that do not conflict with any earlier transaction
+    /// in the block concurrently against the parent block's state. The results are held in the pending store and used
+    /// when each transaction is prepared, provided that the inputs resolved at that point in the block are the same.
+    /// Otherwise, the transaction is executed again sequentially, so the result is always the same as sequential
+    /// execution.
+    pub fn pre_execute_block(
+        &self,
+        store: &mut PendingSubstateStore<TStateStore>,
+        local_committee_info: &CommitteeInfo,
+        block: &Block,
+    ) -> Result<(), BlockTransactionExecutorError> {
+        let Some(thread_pool) = self.thread_pool.as_ref() else {
+            return Ok(());
+        };
+
+        let mut transactions = Vec::new();
+        for atom in block
+            .commands()
+            .iter()
+            .filter_map(|cmd| cmd.local_only().or_else(|| cmd.prepare()))
+        {
+            // Missing transactions are handled when the command is evaluated
+            if let Some(transaction) = TransactionRecord::get(store.read_transaction(), atom.id()).optional()? {
+                transactions.push(transaction);
+            }
+        }
+        if transactions.len() < 2 {
+            return Ok(());
+        }
+
+        let _timer = TraceTimer::info(LOG_TARGET, "pre_execute_block").with_iterations(transactions.len());
+        let graph = ConflictGraph::new(
+            transactions
+                .iter()
+                .map(|t| t.transaction().all_inputs_substate_ids_iter()),
+        );
+
+        let mut batch = Vec::new();
+        for index in graph.independent() {
+            let transaction = transactions[index].transaction();
+            // Might have been executed already in on propose
+            if BlockTransactionExecution::get_pending_for_block(store.read_transaction(), transaction.id(), block.id())
+                .optional()?
+                .is_some()
+            {
+                continue;
+            }
+            // Resolution errors are reported when the transaction is prepared
+            let Ok((local_versions, non_local_inputs)) =
+                self.resolve_local_versions(store, local_committee_info, transaction)
+            else {
+                continue;
+            };
+            if local_versions.is_empty() || !non_local_inputs.is_empty() {
+                continue;
+            }
+            let Ok(inputs) = store.get_many(local_versions.iter().map(|(req, v)| (req.clone(), *v))) else {
+                continue;
+            };
+            batch.push((transaction.clone(), local_versions, inputs));
+        }
+
+        info!(
+            target: LOG_TARGET,
+            "👨‍🔧 PRE-EXECUTE: {} of {} transaction(s) in block {} are independent and will be executed in parallel",
+            batch.len(),
+            graph.len(),
+            block,
+        );
+        if batch.len() < 2 {
+            return Ok(());
+        }
+
+        let context = BlockExecutionContext::from_block(block);
+        let executor = &self.executor;
+        let results = thread_pool.install(|| {
+            batch
+                .into_par_iter()
+                .map(|(transaction, local_versions, inputs)| {
+                    let id = *transaction.id();
+                    let result = executor.execute(transaction, &context, &inputs);
+                    (id, local_versions, result)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        for (id, local_versions, result) in results {
+            match result {
+                Ok(executed) => {
+                    store.pre_executed_mut().insert(
+                        id,
+                        PreExecutedTransaction::new(local_versions.into_iter().collect(), executed),
+                    );
+                },
+                Err(err) => {
+                    // The transaction will be executed sequentially and the error reported then
+                    warn!(target: LOG_TARGET, "⚠️ PRE-EXECUTE: transaction {id} failed to execute: {err}");
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -5,9 +5,11 @@ mod manager;
 pub use manager::*;
 
 mod lock_deps;
+mod parallel;
 mod pledged;
 mod prepared;
 
 pub use lock_deps::*;
+pub use parallel::*;
 pub use pledged::*;
 pub use prepared::*;
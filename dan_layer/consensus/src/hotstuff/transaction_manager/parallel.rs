@@ -0,0 +1,204 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::HashMap;
+
+use tari_dan_common_types::SubstateRequirement;
+use tari_dan_storage::consensus_models::ExecutedTransaction;
+use tari_engine_types::substate::{Substate, SubstateId};
+use tari_transaction::TransactionId;
+
+/// Conflicts between the transactions of a block, based on their declared inputs. Two transactions conflict if they
+/// share an input and at least one of them may write to it. Read-only substates never conflict.
+#[derive(Debug, Clone, Default)]
+pub struct ConflictGraph {
+    /// For each transaction, the indexes of the earlier transactions that it conflicts with
+    conflicts: Vec<Vec<usize>>,
+}
+
+impl ConflictGraph {
+    /// Builds the conflict graph for a list of transactions in block order, given the input substate ids of each
+    /// transaction.
+    pub fn new<'a, I, T>(transactions: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoIterator<Item = &'a SubstateId>,
+    {
+        // Substate id -> (transaction index, is_write) for every transaction that has the substate as an input
+        let mut accesses = HashMap::<&SubstateId, Vec<(usize, bool)>>::new();
+        let mut conflicts = Vec::new();
+        for (index, inputs) in transactions.into_iter().enumerate() {
+            let mut tx_conflicts = Vec::new();
+            for id in inputs {
+                let is_write = !id.is_read_only();
+                let prev_accesses = accesses.entry(id).or_default();
+                tx_conflicts.extend(
+                    prev_accesses
+                        .iter()
+                        .filter(|(_, prev_is_write)| is_write || *prev_is_write)
+                        .map(|(prev, _)| *prev),
+                );
+                prev_accesses.push((index, is_write));
+            }
+            tx_conflicts.sort_unstable();
+            tx_conflicts.dedup();
+            conflicts.push(tx_conflicts);
+        }
+
+        Self { conflicts }
+    }
+
+    /// Returns the indexes of the earlier transactions that the transaction at `index` conflicts with.
+    pub fn conflicts_of(&self, index: usize) -> &[usize] {
+        self.conflicts.get(index).map(|c| c.as_slice()).unwrap_or(&[])
+    }
+
+    /// Returns the indexes of the transactions that do not conflict with any earlier transaction. These transactions
+    /// see the same input state whether they are executed before or after the transactions that precede them, so
+    /// they can be executed concurrently against the parent block's state.
+    pub fn independent(&self) -> impl Iterator<Item = usize> + '_ {
+        self.conflicts
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_empty())
+            .map(|(i, _)| i)
+    }
+
+    pub fn len(&self) -> usize {
+        self.conflicts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+/// A transaction executed ahead of time against the parent block's state.
+#[derive(Debug, Clone)]
+pub struct PreExecutedTransaction {
+    /// The version of each input that the transaction was executed with
+    input_versions: HashMap<SubstateRequirement, u32>,
+    executed: ExecutedTransaction,
+}
+
+impl PreExecutedTransaction {
+    pub fn new(input_versions: HashMap<SubstateRequirement, u32>, executed: ExecutedTransaction) -> Self {
+        Self {
+            input_versions,
+            executed,
+        }
+    }
+
+    /// Returns true if the transaction was executed with exactly the given inputs. Substate versions are immutable, so
+    /// the result of execution is the same as executing with the given inputs.
+    pub fn matches_inputs(&self, resolved_inputs: &HashMap<SubstateRequirement, Substate>) -> bool {
+        self.input_versions.len() == resolved_inputs.len() &&
+            resolved_inputs
+                .iter()
+                .all(|(req, substate)| self.input_versions.get(req) == Some(&substate.version()))
+    }
+
+    pub fn into_executed(self) -> ExecutedTransaction {
+        self.executed
+    }
+}
+
+/// Transactions that were executed in parallel before the commands of a block are evaluated. Results are consumed in
+/// block order as each transaction is prepared, so they are applied to the pending state exactly as if they had been
+/// executed sequentially.
+#[derive(Debug, Clone, Default)]
+pub struct PreExecutedTransactions {
+    transactions: HashMap<TransactionId, PreExecutedTransaction>,
+    stats: BlockExecutionStats,
+}
+
+impl PreExecutedTransactions {
+    pub fn insert(&mut self, transaction_id: TransactionId, transaction: PreExecutedTransaction) {
+        self.stats.num_pre_executed += 1;
+        self.transactions.insert(transaction_id, transaction);
+    }
+
+    /// Removes and returns the pre-executed result for the transaction if it was executed with the given inputs.
+    pub fn take_if_matches(
+        &mut self,
+        transaction_id: &TransactionId,
+        resolved_inputs: &HashMap<SubstateRequirement, Substate>,
+    ) -> Option<ExecutedTransaction> {
+        let transaction = self.transactions.remove(transaction_id)?;
+        if transaction.matches_inputs(resolved_inputs) {
+            self.stats.num_parallel += 1;
+            Some(transaction.into_executed())
+        } else {
+            self.stats.num_discarded += 1;
+            None
+        }
+    }
+
+    pub fn record_sequential_execution(&mut self) {
+        self.stats.num_sequential += 1;
+    }
+
+    pub fn stats(&self) -> BlockExecutionStats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
+/// Transaction execution statistics for a block
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockExecutionStats {
+    /// Number of transactions that were executed on the thread pool before evaluating the block
+    pub num_pre_executed: usize,
+    /// Number of pre-executed results that were used
+    pub num_parallel: usize,
+    /// Number of pre-executed results that were discarded because their inputs changed
+    pub num_discarded: usize,
+    /// Number of transactions that were executed sequentially
+    pub num_sequential: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn component(n: u8) -> SubstateId {
+        SubstateId::from_str(&format!("component_{}", hex_repeat(n))).unwrap()
+    }
+
+    fn resource(n: u8) -> SubstateId {
+        SubstateId::from_str(&format!("resource_{}", hex_repeat(n))).unwrap()
+    }
+
+    fn hex_repeat(n: u8) -> String {
+        format!("{n:02x}").repeat(32)
+    }
+
+    #[test]
+    fn it_finds_independent_transactions() {
+        let transactions = [
+            vec![component(1), resource(1)],
+            vec![component(2), resource(1)],
+            vec![component(1), component(3)],
+            vec![component(4)],
+            vec![component(3), component(5)],
+        ];
+        let graph = ConflictGraph::new(transactions.iter().map(|inputs| inputs.iter()));
+
+        assert_eq!(graph.len(), 5);
+        // Sharing a read-only resource is not a conflict
+        assert!(graph.conflicts_of(1).is_empty());
+        assert_eq!(graph.conflicts_of(2), [0]);
+        // Conflicts with a transaction that itself conflicts
+        assert_eq!(graph.conflicts_of(4), [2]);
+        assert_eq!(graph.independent().collect::<Vec<_>>(), [0, 1, 3]);
+    }
+}
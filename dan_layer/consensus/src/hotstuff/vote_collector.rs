@@ -4,9 +4,21 @@
 use log::*;
 use tari_common::configuration::Network;
 use tari_common_types::types::FixedHash;
-use tari_dan_common_types::{committee::CommitteeInfo, optional::Optional, Epoch};
+use tari_dan_common_types::{
+    committee::{Committee, CommitteeInfo},
+    optional::Optional,
+    Epoch,
+};
 use tari_dan_storage::{
-    consensus_models::{Block, HighQc, QuorumCertificate, QuorumDecision, ValidatorSignature, Vote},
+    consensus_models::{
+        AggregatedValidatorSignature,
+        Block,
+        EquivocationEvidence,
+        HighQc,
+        QuorumCertificate,
+        QuorumDecision,
+        Vote,
+    },
     global::models::ValidatorNode,
     StateStore,
 };
@@ -14,9 +26,9 @@ use tari_epoch_manager::EpochManagerReader;
 
 use crate::{
     hotstuff::error::HotStuffError,
-    messages::VoteMessage,
+    messages::{EquivocationMessage, HotstuffMessage, VoteMessage},
     tracing::TraceTimer,
-    traits::{ConsensusSpec, VoteSignatureService},
+    traits::{ConsensusSpec, OutboundMessaging, VoteSignatureService},
 };
 
 const LOG_TARGET: &str = "tari::dan::consensus::hotstuff::on_receive_vote";
@@ -27,6 +39,7 @@ pub struct VoteCollector<TConsensusSpec: ConsensusSpec> {
     store: TConsensusSpec::StateStore,
     epoch_manager: TConsensusSpec::EpochManager,
     vote_signature_service: TConsensusSpec::SignatureService,
+    outbound_messaging: TConsensusSpec::OutboundMessaging,
 }
 
 impl<TConsensusSpec> VoteCollector<TConsensusSpec>
@@ -37,12 +50,14 @@ where TConsensusSpec: ConsensusSpec
         store: TConsensusSpec::StateStore,
         epoch_manager: TConsensusSpec::EpochManager,
         vote_signature_service: TConsensusSpec::SignatureService,
+        outbound_messaging: TConsensusSpec::OutboundMessaging,
     ) -> Self {
         Self {
             network,
             store,
             epoch_manager,
             vote_signature_service,
+            outbound_messaging,
         }
     }
 
@@ -62,7 +77,25 @@ where TConsensusSpec: ConsensusSpec
 
         self.validate_vote_message(current_epoch, &message)?;
         let sender_vn = self.check_eligibility(from, &message, local_committee_info).await?;
-        let maybe_qc = self.collect_vote(message, local_committee_info, sender_vn)?;
+        // The QC signer bitmap is indexed by position in the local committee
+        let local_committee = self.epoch_manager.get_local_committee(message.epoch).await?;
+        let (maybe_qc, new_equivocations) =
+            self.collect_vote(message, &local_committee, local_committee_info, sender_vn)?;
+        for evidence in new_equivocations {
+            // Only the leader collects votes, so the evidence is shared with the rest of the committee to ensure that
+            // the next leader can include it in a block
+            if let Err(err) = self
+                .outbound_messaging
+                .clone()
+                .multicast(
+                    local_committee_info.shard_group(),
+                    HotstuffMessage::Equivocation(EquivocationMessage { evidence }),
+                )
+                .await
+            {
+                warn!(target: LOG_TARGET, "Failed to multicast equivocation evidence: {}", err);
+            }
+        }
         if let Some((ref qc, ref high_qc)) = maybe_qc {
             if qc.id() == high_qc.qc_id() {
                 info!(target: LOG_TARGET, "🔥 New HIGH {}", qc);
@@ -109,20 +142,22 @@ where TConsensusSpec: ConsensusSpec
     fn collect_vote(
         &self,
         message: VoteMessage,
+        local_committee: &Committee<TConsensusSpec::Addr>,
         local_committee_info: &CommitteeInfo,
         sender_vn: ValidatorNode<TConsensusSpec::Addr>,
-    ) -> Result<Option<(QuorumCertificate, HighQc)>, HotStuffError> {
-        self.store.with_write_tx(|tx| {
+    ) -> Result<(Option<(QuorumCertificate, HighQc)>, Vec<EquivocationEvidence>), HotStuffError> {
+        let mut new_equivocations = Vec::new();
+        let maybe_qc = self.store.with_write_tx(|tx| {
             let sender_leaf_hash = sender_vn.get_node_hash(self.network);
 
-            let exists = Vote {
+            let vote = Vote {
                 epoch: message.epoch,
                 block_id: message.block_id,
                 decision: message.decision,
                 sender_leaf_hash,
                 signature: message.signature,
-            }
-            .save(tx)?;
+            };
+            let exists = vote.save(tx)?;
 
             if exists {
                 warn!(
@@ -134,6 +169,8 @@ where TConsensusSpec: ConsensusSpec
                 return Ok(None);
             }
 
+            new_equivocations = Self::check_for_double_vote(tx, &vote)?;
+
             let count = Vote::count_for_block(&**tx, &message.block_id)?;
             // We only generate the next high qc once when we have a quorum of votes. Any subsequent votes are not
             // included in the QC.
@@ -196,8 +233,10 @@ where TConsensusSpec: ConsensusSpec
                 leaf_hashes.push(vote.sender_leaf_hash);
             }
 
+            let message = self.vote_signature_service.create_message(block.id(), &quorum_decision);
+            let signature = AggregatedValidatorSignature::aggregate(local_committee, message, &signatures)?;
             let vote_data = VoteData {
-                signatures,
+                signature,
                 leaf_hashes,
                 quorum_decision,
                 block,
@@ -205,8 +244,51 @@ where TConsensusSpec: ConsensusSpec
             let new_qc = create_qc(vote_data);
             let high_qc = new_qc.update_high_qc(tx)?;
 
-            Ok(Some((new_qc, high_qc)))
-        })
+            Ok::<_, HotStuffError>(Some((new_qc, high_qc)))
+        })?;
+
+        Ok((maybe_qc, new_equivocations))
+    }
+
+    /// Checks if the sender has voted for another block at the same height as this vote. If so, equivocation
+    /// evidence is recorded so that it can be included in a block. This is only possible if we have the block
+    /// that was voted for. Returns any new equivocation evidence that was recorded.
+    fn check_for_double_vote(
+        tx: &mut <TConsensusSpec::StateStore as StateStore>::WriteTransaction<'_>,
+        vote: &Vote,
+    ) -> Result<Vec<EquivocationEvidence>, HotStuffError> {
+        let mut new_equivocations = Vec::new();
+        let Some(block) = Block::get(&**tx, &vote.block_id).optional()? else {
+            return Ok(new_equivocations);
+        };
+
+        for block_id in tx.blocks_get_all_ids_by_height(block.epoch(), block.height())? {
+            if block_id == vote.block_id {
+                continue;
+            }
+            let Some(other_vote) =
+                Vote::get_by_block_and_sender(&**tx, &block_id, &vote.sender_leaf_hash).optional()?
+            else {
+                continue;
+            };
+
+            let other_block = Block::get(&**tx, &block_id)?;
+            let evidence = EquivocationEvidence::double_vote(&other_vote, &other_block, vote, &block);
+            if evidence.save(tx)? {
+                warn!(
+                    target: LOG_TARGET,
+                    "⚠️ Validator {} voted for blocks {} and {} at height {}. Recorded equivocation evidence {}",
+                    vote.signature.public_key,
+                    other_vote.block_id,
+                    vote.block_id,
+                    block.height(),
+                    evidence.id()
+                );
+                new_equivocations.push(evidence);
+            }
+        }
+
+        Ok(new_equivocations)
     }
 
     fn calculate_threshold_decision(votes: &[Vote], local_committee_info: &CommitteeInfo) -> Option<QuorumDecision> {
@@ -255,7 +337,7 @@ where TConsensusSpec: ConsensusSpec
 
 fn create_qc(vote_data: VoteData) -> QuorumCertificate {
     let VoteData {
-        signatures,
+        signature,
         leaf_hashes,
         quorum_decision,
         block,
@@ -265,14 +347,14 @@ fn create_qc(vote_data: VoteData) -> QuorumCertificate {
         block.height(),
         block.epoch(),
         block.shard_group(),
-        signatures,
+        signature,
         leaf_hashes,
         quorum_decision,
     )
 }
 
 struct VoteData {
-    signatures: Vec<ValidatorSignature>,
+    signature: AggregatedValidatorSignature,
     leaf_hashes: Vec<FixedHash>,
     quorum_decision: QuorumDecision,
     block: Block,
@@ -18,6 +18,7 @@ use tari_dan_storage::{
         Block,
         BlockDiff,
         BurntUtxo,
+        EquivocationEvidence,
         ForeignProposal,
         HighQc,
         LeafBlock,
@@ -46,6 +47,7 @@ use crate::{
         on_message_validate::{MessageValidationResult, OnMessageValidate},
         on_next_sync_view::OnNextSyncViewHandler,
         on_propose::OnPropose,
+        on_receive_equivocation::OnReceiveEquivocationHandler,
         on_receive_foreign_proposal::OnReceiveForeignProposalHandler,
         on_receive_local_proposal::OnReceiveLocalProposalHandler,
         on_receive_new_view::OnReceiveNewViewHandler,
@@ -82,6 +84,7 @@ pub struct HotstuffWorker<TConsensusSpec: ConsensusSpec> {
     on_receive_new_view: OnReceiveNewViewHandler<TConsensusSpec>,
     on_receive_request_missing_txs: OnReceiveRequestMissingTransactions<TConsensusSpec>,
     on_receive_new_transaction: OnReceiveNewTransaction<TConsensusSpec>,
+    on_receive_equivocation: OnReceiveEquivocationHandler<TConsensusSpec>,
     on_message_validate: OnMessageValidate<TConsensusSpec>,
     on_propose: OnPropose<TConsensusSpec>,
     on_sync_request: OnSyncRequest<TConsensusSpec>,
@@ -121,8 +124,10 @@ impl<TConsensusSpec: ConsensusSpec> HotstuffWorker<TConsensusSpec> {
             state_store.clone(),
             epoch_manager.clone(),
             signing_service.clone(),
+            outbound_messaging.clone(),
         );
-        let transaction_manager = ConsensusTransactionManager::new(transaction_executor.clone());
+        let transaction_manager =
+            ConsensusTransactionManager::new(transaction_executor.clone(), config.max_execution_concurrency);
 
         Self {
             local_validator_addr: local_validator_addr.clone(),
@@ -185,6 +190,7 @@ impl<TConsensusSpec: ConsensusSpec> HotstuffWorker<TConsensusSpec> {
                 transaction_executor.clone(),
                 tx_missing_transactions,
             ),
+            on_receive_equivocation: OnReceiveEquivocationHandler::new(state_store.clone(), epoch_manager.clone()),
             on_propose: OnPropose::new(
                 config,
                 state_store.clone(),
@@ -646,9 +652,10 @@ impl<TConsensusSpec: ConsensusSpec> HotstuffWorker<TConsensusSpec> {
         );
 
         let propose_now = self.state_store.with_read_tx(|tx| {
-            // Propose quickly if there are UTXOs to mint or transactions to propose
+            // Propose quickly if there are UTXOs to mint, equivocations to report or transactions to propose
             let propose_now = ForeignProposal::has_unconfirmed(tx, epoch)? ||
                 BurntUtxo::has_unproposed(tx)? ||
+                EquivocationEvidence::has_unproposed(tx, epoch, leaf_block.block_id())? ||
                 self.transaction_pool.has_uncommitted_transactions(tx)?;
 
             Ok::<_, HotStuffError>(propose_now)
@@ -825,6 +832,12 @@ impl<TConsensusSpec: ConsensusSpec> HotstuffWorker<TConsensusSpec> {
                     .process_requested(current_epoch, from, msg, local_committee_info)
                     .await,
             ),
+            HotstuffMessage::Equivocation(msg) => log_err(
+                "on_receive_equivocation",
+                self.on_receive_equivocation
+                    .handle(from, current_epoch, msg, local_committee_info)
+                    .await,
+            ),
             HotstuffMessage::CatchUpSyncRequest(msg) => {
                 self.on_sync_request
                     .handle(from, *local_committee_info, current_epoch, msg);
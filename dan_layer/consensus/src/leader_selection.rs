@@ -0,0 +1,208 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_common_types::{committee::Committee, hashing::leader_selection_hasher, NodeAddressable, NodeHeight};
+
+use crate::{consensus_constants::LeaderSelection, traits::LeaderStrategy};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundRobinLeaderStrategy;
+
+impl RoundRobinLeaderStrategy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<TAddr: NodeAddressable> LeaderStrategy<TAddr> for RoundRobinLeaderStrategy {
+    fn calculate_leader(&self, committee: &Committee<TAddr>, height: NodeHeight) -> u32 {
+        (height.as_u64() % committee.members.len() as u64) as u32
+    }
+}
+
+/// Selects leaders from a permutation of the committee that is shuffled for every round of `committee size` heights.
+/// Each member leads exactly once per round, but the order cannot be predicted before the epoch starts. The permutation
+/// is seeded from the committee seed (the base layer block hash at the start of the epoch), the committee public keys
+/// and the round, so all members of the committee agree on it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomizedLeaderStrategy;
+
+impl RandomizedLeaderStrategy {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn permutation<TAddr>(committee: &Committee<TAddr>, round: u64) -> Vec<u32> {
+        let seed = leader_selection_hasher()
+            .chain(committee.seed())
+            .chain(&committee.members.iter().map(|(_, pk)| pk).collect::<Vec<_>>())
+            .chain(&round)
+            .result();
+
+        let len = committee.members.len() as u32;
+        let mut permutation = (0..len).collect::<Vec<_>>();
+        // Fisher-Yates shuffle using the seed as the source of randomness
+        for i in (1..len).rev() {
+            let hash = leader_selection_hasher().chain(&seed).chain(&i).result();
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&hash.as_slice()[..8]);
+            let j = u64::from_le_bytes(buf) % (u64::from(i) + 1);
+            permutation.swap(i as usize, j as usize);
+        }
+        permutation
+    }
+}
+
+impl<TAddr: NodeAddressable> LeaderStrategy<TAddr> for RandomizedLeaderStrategy {
+    fn calculate_leader(&self, committee: &Committee<TAddr>, height: NodeHeight) -> u32 {
+        let len = committee.members.len() as u64;
+        let round = height.as_u64() / len;
+        let position = height.as_u64() % len;
+        Self::permutation(committee, round)[position as usize]
+    }
+}
+
+/// The leader strategy configured by [LeaderSelection] in the consensus constants.
+#[derive(Debug, Clone, Copy)]
+pub struct ConsensusLeaderStrategy {
+    selection: LeaderSelection,
+}
+
+impl ConsensusLeaderStrategy {
+    pub fn new(selection: LeaderSelection) -> Self {
+        Self { selection }
+    }
+
+    pub fn selection(&self) -> LeaderSelection {
+        self.selection
+    }
+}
+
+impl<TAddr: NodeAddressable> LeaderStrategy<TAddr> for ConsensusLeaderStrategy {
+    fn calculate_leader(&self, committee: &Committee<TAddr>, height: NodeHeight) -> u32 {
+        match self.selection {
+            LeaderSelection::RoundRobin => RoundRobinLeaderStrategy.calculate_leader(committee, height),
+            LeaderSelection::Randomized | LeaderSelection::Reputation { .. } => {
+                RandomizedLeaderStrategy.calculate_leader(committee, height)
+            },
+        }
+    }
+
+    fn missed_proposal_skip_threshold(&self) -> Option<u64> {
+        match self.selection {
+            LeaderSelection::RoundRobin | LeaderSelection::Randomized => None,
+            LeaderSelection::Reputation {
+                missed_proposal_threshold,
+            } => Some(missed_proposal_threshold),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use tari_common_types::types::{FixedHash, PublicKey};
+
+    use super::*;
+
+    fn new_member(seed: &'static str) -> (String, PublicKey) {
+        (seed.to_string(), PublicKey::new_generator(seed).unwrap())
+    }
+
+    fn new_committee() -> Committee<String> {
+        Committee::from_iter([
+            new_member("1"),
+            new_member("2"),
+            new_member("3"),
+            new_member("4"),
+            new_member("5"),
+        ])
+    }
+
+    #[test]
+    fn it_selects_leader_based_on_height() {
+        let strategy = RoundRobinLeaderStrategy::new();
+        let committee = Committee::from_iter([new_member("1"), new_member("2"), new_member("3")]);
+
+        let (addr, _) = strategy.get_leader(&committee, NodeHeight(1));
+        assert_eq!(addr, "2");
+        let (addr, _) = strategy.get_leader(&committee, NodeHeight(2));
+        assert_eq!(addr, "3");
+        let (addr, _) = strategy.get_leader(&committee, NodeHeight(3));
+        assert_eq!(addr, "1");
+    }
+
+    #[test]
+    fn it_selects_every_member_once_per_round() {
+        let strategy = RandomizedLeaderStrategy::new();
+        let committee = new_committee();
+
+        let mut rounds = HashSet::new();
+        for round in 0..10u64 {
+            let leaders = (0..5)
+                .map(|i| strategy.calculate_leader(&committee, NodeHeight(round * 5 + i)))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                leaders.iter().collect::<HashSet<_>>().len(),
+                5,
+                "Round {round}: {leaders:?}"
+            );
+            rounds.insert(leaders);
+        }
+        // The order is shuffled between rounds
+        assert!(rounds.len() > 1);
+    }
+
+    #[test]
+    fn it_selects_the_same_leaders_for_the_same_committee() {
+        let strategy = RandomizedLeaderStrategy::new();
+        let committee = new_committee();
+        let other = new_committee();
+
+        for height in 0..20 {
+            assert_eq!(
+                strategy.get_leader(&committee, NodeHeight(height)),
+                strategy.get_leader(&other, NodeHeight(height))
+            );
+        }
+    }
+
+    #[test]
+    fn it_selects_different_leaders_for_different_epoch_seeds() {
+        let strategy = RandomizedLeaderStrategy::new();
+        let committee = new_committee();
+        let other = new_committee().with_seed(FixedHash::from([1u8; 32]));
+
+        let leaders = |committee: &Committee<String>| {
+            (0..20)
+                .map(|height| strategy.calculate_leader(committee, NodeHeight(height)))
+                .collect::<Vec<_>>()
+        };
+        assert_ne!(leaders(&committee), leaders(&other));
+    }
+
+    #[test]
+    fn it_only_skips_leaders_for_the_reputation_strategy() {
+        let committee = new_committee();
+        let randomized = ConsensusLeaderStrategy::new(LeaderSelection::Randomized);
+        let reputation = ConsensusLeaderStrategy::new(LeaderSelection::Reputation {
+            missed_proposal_threshold: 3,
+        });
+
+        assert_eq!(
+            LeaderStrategy::<String>::missed_proposal_skip_threshold(&randomized),
+            None
+        );
+        assert_eq!(
+            LeaderStrategy::<String>::missed_proposal_skip_threshold(&reputation),
+            Some(3)
+        );
+        for height in 0..10 {
+            assert_eq!(
+                randomized.calculate_leader(&committee, NodeHeight(height)),
+                reputation.calculate_leader(&committee, NodeHeight(height))
+            );
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use serde::Serialize;
+use tari_dan_storage::consensus_models::EquivocationEvidence;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EquivocationMessage {
+    pub evidence: EquivocationEvidence,
+}
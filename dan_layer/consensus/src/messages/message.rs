@@ -6,7 +6,14 @@ use std::fmt::Display;
 use serde::Serialize;
 use tari_dan_common_types::Epoch;
 
-use super::{ForeignProposalMessage, MissingTransactionsResponse, NewViewMessage, ProposalMessage, VoteMessage};
+use super::{
+    EquivocationMessage,
+    ForeignProposalMessage,
+    MissingTransactionsResponse,
+    NewViewMessage,
+    ProposalMessage,
+    VoteMessage,
+};
 use crate::messages::{MissingTransactionsRequest, SyncRequestMessage, SyncResponseMessage};
 
 // Serialize is implemented for the message logger
@@ -19,6 +26,7 @@ pub enum HotstuffMessage {
     MissingTransactionsRequest(MissingTransactionsRequest),
     MissingTransactionsResponse(MissingTransactionsResponse),
     CatchUpSyncRequest(SyncRequestMessage),
+    Equivocation(EquivocationMessage),
     // TODO: remove unused
     SyncResponse(SyncResponseMessage),
 }
@@ -33,6 +41,7 @@ impl HotstuffMessage {
             HotstuffMessage::MissingTransactionsRequest(_) => "MissingTransactionsRequest",
             HotstuffMessage::MissingTransactionsResponse(_) => "MissingTransactionsResponse",
             HotstuffMessage::CatchUpSyncRequest(_) => "CatchUpSyncRequest",
+            HotstuffMessage::Equivocation(_) => "Equivocation",
             HotstuffMessage::SyncResponse(_) => "SyncResponse",
         }
     }
@@ -46,6 +55,7 @@ impl HotstuffMessage {
             Self::MissingTransactionsRequest(msg) => msg.epoch,
             Self::MissingTransactionsResponse(msg) => msg.epoch,
             Self::CatchUpSyncRequest(msg) => msg.high_qc.epoch(),
+            Self::Equivocation(msg) => msg.evidence.epoch,
             Self::SyncResponse(msg) => msg.epoch,
         }
     }
@@ -101,6 +111,7 @@ impl Display for HotstuffMessage {
                 msg.epoch
             ),
             HotstuffMessage::CatchUpSyncRequest(msg) => write!(f, "SyncRequest({})", msg.high_qc),
+            HotstuffMessage::Equivocation(msg) => write!(f, "Equivocation({})", msg.evidence),
             HotstuffMessage::SyncResponse(msg) => write!(f, "SyncResponse({} block(s))", msg.blocks.len()),
         }
     }
@@ -20,3 +20,6 @@ pub use requested_transaction::*;
 
 mod sync;
 pub use sync::*;
+
+mod equivocation;
+pub use equivocation::*;
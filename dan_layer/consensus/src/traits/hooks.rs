@@ -5,10 +5,14 @@ use tari_dan_common_types::NodeHeight;
 use tari_dan_storage::consensus_models::{QuorumDecision, TransactionAtom, ValidBlock};
 use tari_transaction::TransactionId;
 
-use crate::{hotstuff::HotStuffError, messages::HotstuffMessage};
+use crate::{
+    hotstuff::{BlockExecutionStats, HotStuffError},
+    messages::HotstuffMessage,
+};
 
 pub trait ConsensusHooks {
     fn on_local_block_decide(&mut self, block: &ValidBlock, decision: Option<QuorumDecision>);
+    fn on_block_transactions_executed(&mut self, block: &ValidBlock, stats: &BlockExecutionStats);
 
     fn on_block_validation_failed<E: ToString>(&mut self, err: &E);
     fn on_message_received(&mut self, message: &HotstuffMessage);
@@ -44,6 +48,12 @@ impl<T: ConsensusHooks> ConsensusHooks for OptionalHooks<T> {
         }
     }
 
+    fn on_block_transactions_executed(&mut self, block: &ValidBlock, stats: &BlockExecutionStats) {
+        if let Some(inner) = self.inner.as_mut() {
+            inner.on_block_transactions_executed(block, stats);
+        }
+    }
+
     fn on_block_validation_failed<E: ToString>(&mut self, err: &E) {
         if let Some(inner) = self.inner.as_mut() {
             inner.on_block_validation_failed(err);
@@ -105,6 +115,8 @@ pub struct NoopHooks;
 impl ConsensusHooks for NoopHooks {
     fn on_local_block_decide(&mut self, _block: &ValidBlock, _decision: Option<QuorumDecision>) {}
 
+    fn on_block_transactions_executed(&mut self, _block: &ValidBlock, _stats: &BlockExecutionStats) {}
+
     fn on_block_validation_failed<E: ToString>(&mut self, _: &E) {}
 
     fn on_message_received(&mut self, _message: &HotstuffMessage) {}
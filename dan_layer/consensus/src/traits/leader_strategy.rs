@@ -26,6 +26,13 @@ use tari_dan_common_types::{committee::Committee, NodeHeight};
 pub trait LeaderStrategy<TAddr> {
     fn calculate_leader(&self, committee: &Committee<TAddr>, height: NodeHeight) -> u32;
 
+    /// If Some, a leader that missed at least this many proposals in a row in the current epoch is skipped when
+    /// determining the next leader. Skipping is a local liveness optimisation, the leader for a given height is always
+    /// the one returned by `calculate_leader`.
+    fn missed_proposal_skip_threshold(&self) -> Option<u64> {
+        None
+    }
+
     fn is_leader(&self, validator_addr: &TAddr, committee: &Committee<TAddr>, height: NodeHeight) -> bool
     where TAddr: PartialEq {
         let position = self.calculate_leader(committee, height);
@@ -3,8 +3,12 @@
 
 use std::collections::HashMap;
 
-use tari_dan_common_types::{optional::IsNotFoundError, Epoch, SubstateRequirement};
-use tari_dan_storage::{consensus_models::ExecutedTransaction, StateStore, StorageError};
+use tari_dan_common_types::{optional::IsNotFoundError, Epoch, NodeHeight, ShardGroup, SubstateRequirement};
+use tari_dan_storage::{
+    consensus_models::{Block, ExecutedTransaction},
+    StateStore,
+    StorageError,
+};
 use tari_engine_types::substate::Substate;
 use tari_transaction::Transaction;
 
@@ -47,6 +51,28 @@ impl IsNotFoundError for BlockTransactionExecutorError {
     }
 }
 
+/// Consensus-agreed values about the block in which a transaction is executed. Every member of the committee MUST
+/// provide the same context to the executor for a given block, otherwise execution results will differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockExecutionContext {
+    pub epoch: Epoch,
+    pub height: NodeHeight,
+    /// The proposer timestamp of the block in seconds since the unix epoch
+    pub timestamp: u64,
+    pub shard_group: ShardGroup,
+}
+
+impl BlockExecutionContext {
+    pub fn from_block(block: &Block) -> Self {
+        Self {
+            epoch: block.epoch(),
+            height: block.height(),
+            timestamp: block.timestamp(),
+            shard_group: block.shard_group(),
+        }
+    }
+}
+
 pub trait BlockTransactionExecutor<TStateStore: StateStore> {
     fn validate(
         &self,
@@ -58,7 +84,7 @@ pub trait BlockTransactionExecutor<TStateStore: StateStore> {
     fn execute(
         &self,
         transaction: Transaction,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         resolved_inputs: &HashMap<SubstateRequirement, Substate>,
     ) -> Result<ExecutedTransaction, BlockTransactionExecutorError>;
 }
@@ -11,7 +11,7 @@
 use std::time::Duration;
 
 use tari_common_types::types::PrivateKey;
-use tari_consensus::hotstuff::HotStuffError;
+use tari_consensus::{consensus_constants::LeaderSelection, hotstuff::HotStuffError};
 use tari_dan_common_types::{optional::Optional, Epoch, LockIntent, NodeHeight, SubstateRequirement};
 use tari_dan_storage::{
     consensus_models::{
@@ -33,6 +33,7 @@ use crate::support::{
     build_transaction_from,
     helpers,
     logging::setup_logger,
+    AdversaryBehaviour,
     ExecuteSpec,
     Test,
     TestAddress,
@@ -976,6 +977,106 @@ async fn leader_failure_node_goes_down() {
     test.assert_clean_shutdown_except(&[failure_node]).await;
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn leader_failure_node_goes_down_with_reputation_leader_selection() {
+    setup_logger();
+    let failure_node = TestAddress::new("4");
+
+    let mut test = Test::builder()
+        // Allow enough time for leader failures
+        .with_test_timeout(Duration::from_secs(60))
+        .modify_consensus_constants(|config_mut| {
+            // Prevent suspends so that the node is only skipped because of its missed proposals
+            config_mut.missed_proposal_suspend_threshold = 10;
+            config_mut.pacemaker_block_time = Duration::from_secs(2);
+            config_mut.leader_selection = LeaderSelection::Reputation {
+                missed_proposal_threshold: 1,
+            };
+        })
+        .add_committee(0, vec!["1", "2", "3", "4", "5"])
+        .add_failure_node(failure_node.clone())
+        .start()
+        .await;
+
+    for _ in 0..10 {
+        test.send_transaction_to_all(Decision::Commit, 1, 2, 1).await;
+    }
+
+    log::info!("😴 {failure_node} is offline");
+    test.network()
+        .go_offline(TestVnDestination::Address(failure_node.clone()))
+        .await;
+
+    test.start_epoch(Epoch(1)).await;
+
+    loop {
+        let (_, _, _, committed_height) = test.on_block_committed().await;
+
+        if committed_height == NodeHeight(1) {
+            test.send_transaction_to_all(Decision::Commit, 1, 2, 1).await;
+            test.wait_for_pool_count(TestVnDestination::All, 1).await;
+        }
+
+        if test.validators_iter().filter(|vn| vn.address != failure_node).all(|v| {
+            let c = v.get_transaction_pool_count();
+            log::info!("{} has {} transactions in pool", v.address, c);
+            c == 0
+        }) {
+            break;
+        }
+
+        if committed_height > NodeHeight(50) {
+            panic!("Not all transaction committed after {} blocks", committed_height);
+        }
+    }
+
+    test.assert_all_validators_at_same_height_except(&[failure_node.clone()])
+        .await;
+
+    test.validators_iter()
+        .filter(|vn| vn.address != failure_node)
+        .for_each(|v| {
+            assert!(v.has_committed_substates(), "Validator {} did not commit", v.address);
+        });
+
+    log::info!("total messages sent: {}", test.network().total_messages_sent());
+    test.assert_clean_shutdown_except(&[failure_node]).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn multi_validator_propose_blocks_with_randomized_leader_selection() {
+    setup_logger();
+    let mut test = Test::builder()
+        .modify_consensus_constants(|config_mut| {
+            config_mut.leader_selection = LeaderSelection::Randomized;
+        })
+        .add_committee(0, vec!["1", "2", "3", "4", "5"])
+        .start()
+        .await;
+
+    let (tx1, _, _) = test.send_transaction_to_all(Decision::Commit, 1, 5, 1).await;
+    test.start_epoch(Epoch(1)).await;
+
+    loop {
+        let (_, _, _, committed_height) = test.on_block_committed().await;
+
+        if test.is_transaction_pool_empty() {
+            break;
+        }
+
+        if committed_height > NodeHeight(20) {
+            panic!("Not all transaction committed after {} blocks", committed_height);
+        }
+    }
+
+    test.assert_all_validators_at_same_height().await;
+    test.assert_all_validators_committed();
+    test.assert_all_validators_have_decision(tx1.id(), Decision::Commit)
+        .await;
+
+    test.assert_clean_shutdown().await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn leader_failure_node_goes_down_and_gets_suspended() {
     setup_logger();
@@ -1295,3 +1396,214 @@ async fn multi_shard_unversioned_input_conflict() {
     test.assert_clean_shutdown().await;
     log::info!("total messages sent: {}", test.network().total_messages_sent());
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn equivocating_leader_does_not_break_safety() {
+    setup_logger();
+    let mut test = Test::builder()
+        // Allow enough time for leader failures
+        .with_test_timeout(Duration::from_secs(60))
+        .modify_consensus_constants(|config_mut| {
+            // Prevent suspends
+            config_mut.missed_proposal_suspend_threshold = 10;
+            config_mut.pacemaker_block_time = Duration::from_secs(2);
+        })
+        .add_committee(0, vec!["1", "2", "3", "4"])
+        .add_adversary("4", AdversaryBehaviour::EquivocatingLeader)
+        .start()
+        .await;
+    let adversary = TestAddress::new("4");
+
+    for _ in 0..10 {
+        test.send_transaction_to_all(Decision::Commit, 1, 2, 1).await;
+    }
+
+    test.start_epoch(Epoch(1)).await;
+
+    loop {
+        let (_, _, _, committed_height) = test.on_block_committed().await;
+
+        if test
+            .validators_iter()
+            .filter(|vn| vn.address != adversary)
+            .all(|v| v.get_transaction_pool_count() == 0)
+        {
+            break;
+        }
+
+        if committed_height > NodeHeight(50) {
+            panic!("Not all transaction committed after {} blocks", committed_height);
+        }
+    }
+
+    // The honest validators detect the equivocation and commit the evidence against the adversary
+    let (_, adversary_public_key) = helpers::derive_keypair_from_address(&adversary);
+    loop {
+        let has_committed_evidence = test.validators_iter().filter(|vn| vn.address != adversary).all(|v| {
+            v.state_store()
+                .with_read_tx(|tx| tx.equivocation_evidence_get_all_committed(Epoch(1)))
+                .unwrap()
+                .iter()
+                .any(|evidence| evidence.validator_public_key == adversary_public_key)
+        });
+        if has_committed_evidence {
+            break;
+        }
+
+        let (_, _, _, committed_height) = test.on_block_committed().await;
+        if committed_height > NodeHeight(60) {
+            panic!("Equivocation evidence not committed after {} blocks", committed_height);
+        }
+    }
+
+    // The honest validators never commit conflicting blocks
+    test.assert_all_validators_at_same_height_except(&[adversary.clone()])
+        .await;
+
+    log::info!("total messages sent: {}", test.network().total_messages_sent());
+    test.assert_clean_shutdown_except(&[adversary]).await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn withheld_votes_do_not_prevent_progress() {
+    setup_logger();
+    let mut test = Test::builder()
+        .with_test_timeout(Duration::from_secs(60))
+        .add_committee(0, vec!["1", "2", "3", "4"])
+        .add_adversary("2", AdversaryBehaviour::WithholdVotes)
+        // A slow link to the next leader
+        .with_link_delay("3", "4", Duration::from_millis(10)..=Duration::from_millis(200))
+        .with_seed(0x5eed)
+        .start()
+        .await;
+
+    for _ in 0..10 {
+        test.send_transaction_to_all(Decision::Commit, 1, 2, 1).await;
+    }
+
+    test.start_epoch(Epoch(1)).await;
+
+    loop {
+        let (_, _, _, committed_height) = test.on_block_committed().await;
+
+        if test.is_transaction_pool_empty() {
+            break;
+        }
+
+        if committed_height > NodeHeight(30) {
+            panic!("Not all transaction committed after {} blocks", committed_height);
+        }
+    }
+
+    test.assert_all_validators_at_same_height().await;
+    test.assert_all_validators_committed();
+
+    log::info!("total messages sent: {}", test.network().total_messages_sent());
+    test.assert_clean_shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn invalid_foreign_proposals_are_rejected() {
+    setup_logger();
+    let mut test = Test::builder()
+        .with_test_timeout(Duration::from_secs(60))
+        .add_committee(0, vec!["1", "2", "3"])
+        .add_committee(1, vec!["4", "5", "6"])
+        .add_adversary("1", AdversaryBehaviour::InvalidForeignProposals)
+        .add_adversary("4", AdversaryBehaviour::InvalidForeignProposals)
+        .start()
+        .await;
+
+    for _ in 0..10 {
+        test.send_transaction_to_all(Decision::Commit, 100, 2, 1).await;
+    }
+
+    test.start_epoch(Epoch(1)).await;
+
+    loop {
+        test.on_block_committed().await;
+
+        if test.is_transaction_pool_empty() {
+            break;
+        }
+
+        let leaf1 = test.get_validator(&TestAddress::new("2")).get_leaf_block();
+        let leaf2 = test.get_validator(&TestAddress::new("5")).get_leaf_block();
+        if leaf1.height > NodeHeight(40) || leaf2.height > NodeHeight(40) {
+            panic!(
+                "Not all transaction committed after {}/{} blocks",
+                leaf1.height, leaf2.height
+            );
+        }
+    }
+
+    // The honest members of each committee delivered valid foreign proposals
+    test.assert_all_validators_at_same_height().await;
+    test.assert_all_validators_committed();
+
+    log::info!("total messages sent: {}", test.network().total_messages_sent());
+    test.assert_clean_shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn network_partition_heals() {
+    setup_logger();
+    let mut test = Test::builder()
+        .with_test_timeout(Duration::from_secs(60))
+        .modify_consensus_constants(|config_mut| {
+            // Prevent suspends
+            config_mut.missed_proposal_suspend_threshold = 20;
+            config_mut.pacemaker_block_time = Duration::from_secs(1);
+        })
+        .add_committee(0, vec!["1", "2", "3", "4"])
+        .start()
+        .await;
+
+    for _ in 0..5 {
+        test.send_transaction_to_all(Decision::Commit, 1, 2, 1).await;
+    }
+
+    // Neither side of the partition has a quorum
+    test.network()
+        .partition([vec![TestAddress::new("1"), TestAddress::new("2")], vec![
+            TestAddress::new("3"),
+            TestAddress::new("4"),
+        ]]);
+    test.start_epoch(Epoch(1)).await;
+
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    test.with_all_validators(|v| {
+        assert_eq!(
+            v.get_transaction_pool_count(),
+            5,
+            "Validator {} finalized transactions while partitioned",
+            v.address
+        );
+    });
+
+    log::info!("🩹 Healing partition");
+    // A slow link between the halves of the partition remains after healing
+    test.network().heal().set_link_delay(
+        TestAddress::new("2"),
+        TestAddress::new("3"),
+        Duration::from_millis(10)..=Duration::from_millis(200),
+    );
+
+    loop {
+        let (_, _, _, committed_height) = test.on_block_committed().await;
+
+        if test.is_transaction_pool_empty() {
+            break;
+        }
+
+        if committed_height > NodeHeight(50) {
+            panic!("Not all transaction committed after {} blocks", committed_height);
+        }
+    }
+
+    test.assert_all_validators_at_same_height().await;
+    test.assert_all_validators_committed();
+
+    log::info!("total messages filtered: {}", test.network().total_messages_filtered());
+    test.assert_clean_shutdown().await;
+}
@@ -6,3 +6,5 @@ mod consensus;
 mod substate_store;
 #[cfg(test)]
 mod support;
+#[cfg(test)]
+mod transaction_manager;
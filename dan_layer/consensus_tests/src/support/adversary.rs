@@ -0,0 +1,141 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use rand::rngs::OsRng;
+use tari_common_types::types::PrivateKey;
+use tari_consensus::messages::{ForeignProposalMessage, HotstuffMessage, NewViewMessage, ProposalMessage};
+use tari_dan_storage::consensus_models::{Block, ValidatorSchnorrSignature};
+
+use crate::support::TestAddress;
+
+/// Malicious behaviours that a validator can be configured with using [crate::support::ValidatorBuilder].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdversaryBehaviour {
+    /// When leader, sends a conflicting (validly signed) proposal to half of the committee
+    EquivocatingLeader,
+    /// Never sends votes to the leader, including the last vote in a NewView message
+    WithholdVotes,
+    /// Tampers with the blocks in foreign proposals so that they fail validation in the foreign committee
+    InvalidForeignProposals,
+}
+
+/// Applies adversary behaviours to the outbound messages of a validator. A validator with no behaviours is honest and
+/// sends all messages unchanged.
+#[derive(Debug, Clone)]
+pub struct Adversary {
+    address: TestAddress,
+    secret_key: PrivateKey,
+    behaviours: Vec<AdversaryBehaviour>,
+}
+
+impl Adversary {
+    pub fn new(address: TestAddress, secret_key: PrivateKey, behaviours: Vec<AdversaryBehaviour>) -> Self {
+        Self {
+            address,
+            secret_key,
+            behaviours,
+        }
+    }
+
+    pub fn is_honest(&self) -> bool {
+        self.behaviours.is_empty()
+    }
+
+    pub fn has_behaviour(&self, behaviour: AdversaryBehaviour) -> bool {
+        self.behaviours.contains(&behaviour)
+    }
+
+    /// Returns the message to send to a single peer in place of the given message, or None if the message is
+    /// withheld.
+    pub fn on_send(&self, message: HotstuffMessage) -> Option<HotstuffMessage> {
+        match message {
+            HotstuffMessage::Vote(_) if self.has_behaviour(AdversaryBehaviour::WithholdVotes) => {
+                log::info!("😈 Withholding vote");
+                None
+            },
+            HotstuffMessage::NewView(msg) if self.has_behaviour(AdversaryBehaviour::WithholdVotes) => {
+                Some(HotstuffMessage::NewView(NewViewMessage { last_vote: None, ..msg }))
+            },
+            msg => Some(msg),
+        }
+    }
+
+    /// Returns the messages to multicast in place of the given message, each with the peers that should receive it.
+    pub fn on_multicast(
+        &self,
+        peers: Vec<TestAddress>,
+        message: HotstuffMessage,
+    ) -> Vec<(Vec<TestAddress>, HotstuffMessage)> {
+        match message {
+            HotstuffMessage::Proposal(msg) if self.has_behaviour(AdversaryBehaviour::EquivocatingLeader) => {
+                let conflicting = self.create_conflicting_block(&msg.block);
+                log::info!("😈 Equivocating on proposal {} with {}", msg.block, conflicting);
+                // Split the rest of the committee deterministically so that the honest validators disagree on the block
+                // at this height. The adversary keeps the original block for itself, so with enough validators in the
+                // first half (e.g. 2 of 3 honest validators when n = 4) the original block can still reach a quorum
+                // while the conflicting block cannot.
+                let mut peers = peers;
+                peers.retain(|peer| *peer != self.address);
+                peers.sort();
+                let (mut first, second) = peers.into_iter().enumerate().fold(
+                    (Vec::new(), Vec::new()),
+                    |(mut first, mut second), (i, peer)| {
+                        if i % 2 == 0 {
+                            first.push(peer);
+                        } else {
+                            second.push(peer);
+                        }
+                        (first, second)
+                    },
+                );
+                first.push(self.address.clone());
+                let conflicting_msg = HotstuffMessage::Proposal(ProposalMessage {
+                    block: conflicting,
+                    foreign_proposals: msg.foreign_proposals.clone(),
+                });
+                vec![(first, HotstuffMessage::Proposal(msg)), (second, conflicting_msg)]
+            },
+            HotstuffMessage::ForeignProposal(msg)
+                if self.has_behaviour(AdversaryBehaviour::InvalidForeignProposals) =>
+            {
+                log::info!("😈 Sending invalid foreign proposal for {}", msg.block);
+                // The justify QC no longer certifies the tampered block
+                let block = self.create_conflicting_block(&msg.block);
+                vec![(
+                    peers,
+                    HotstuffMessage::ForeignProposal(ForeignProposalMessage { block, ..msg }),
+                )]
+            },
+            msg => match self.on_send(msg) {
+                Some(msg) => vec![(peers, msg)],
+                None => vec![],
+            },
+        }
+    }
+
+    /// Creates a block at the same height with the same parent and commands as the given block, signed by this
+    /// validator.
+    fn create_conflicting_block(&self, block: &Block) -> Block {
+        let mut conflicting = Block::new(
+            block.network(),
+            *block.parent(),
+            block.justify().clone(),
+            block.height(),
+            block.epoch(),
+            block.shard_group(),
+            block.proposed_by().clone(),
+            block.commands().clone(),
+            *block.merkle_root(),
+            block.total_leader_fee(),
+            block.foreign_indexes().clone(),
+            None,
+            block.timestamp() + 1,
+            block.base_layer_block_height(),
+            *block.base_layer_block_hash(),
+            block.extra_data().cloned(),
+        );
+        let signature = ValidatorSchnorrSignature::sign(&self.secret_key, conflicting.id(), &mut OsRng).unwrap();
+        conflicting.set_signature(signature);
+        conflicting
+    }
+}
@@ -0,0 +1,83 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+    time::Duration,
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::support::TestAddress;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    Now,
+    Delay(Duration),
+    Drop,
+}
+
+/// Network faults applied to messages between validators. Delays are drawn from an RNG seeded by the test, so a test
+/// that sends the same messages in the same order is subjected to the same faults.
+#[derive(Debug, Clone)]
+pub struct FaultScheduler {
+    seed: u64,
+    rng: StdRng,
+    link_delays: HashMap<(TestAddress, TestAddress), RangeInclusive<Duration>>,
+    partitions: Vec<HashSet<TestAddress>>,
+}
+
+impl FaultScheduler {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            link_delays: HashMap::new(),
+            partitions: Vec::new(),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Delays every message sent from `from` to `to` by a random duration in the given range.
+    pub fn set_link_delay(&mut self, from: TestAddress, to: TestAddress, delay: RangeInclusive<Duration>) {
+        self.link_delays.insert((from, to), delay);
+    }
+
+    /// Partitions the network into the given groups. Messages between validators in different groups are dropped.
+    /// Validators that are not in any group can communicate with all validators.
+    pub fn partition<I: IntoIterator<Item = Vec<TestAddress>>>(&mut self, groups: I) {
+        self.partitions = groups.into_iter().map(|g| g.into_iter().collect()).collect();
+    }
+
+    /// Removes all partitions
+    pub fn heal(&mut self) {
+        self.partitions.clear();
+    }
+
+    pub fn is_partitioned(&self, from: &TestAddress, to: &TestAddress) -> bool {
+        let from_group = self.partitions.iter().position(|g| g.contains(from));
+        let to_group = self.partitions.iter().position(|g| g.contains(to));
+        match (from_group, to_group) {
+            (Some(from_group), Some(to_group)) => from_group != to_group,
+            _ => false,
+        }
+    }
+
+    /// Decides how a message from `from` to `to` is delivered.
+    pub fn schedule(&mut self, from: &TestAddress, to: &TestAddress) -> Delivery {
+        if from == to {
+            return Delivery::Now;
+        }
+        if self.is_partitioned(from, to) {
+            return Delivery::Drop;
+        }
+        match self.link_delays.get(&(from.clone(), to.clone())) {
+            Some(delay) => Delivery::Delay(self.rng.gen_range(delay.clone())),
+            None => Delivery::Now,
+        }
+    }
+}
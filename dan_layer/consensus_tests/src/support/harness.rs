@@ -4,6 +4,7 @@
 use std::{
     collections::{hash_map, HashMap, HashSet},
     fmt::Display,
+    ops::RangeInclusive,
     time::Duration,
 };
 
@@ -12,8 +13,9 @@ use itertools::Itertools;
 use log::info;
 use tari_common::configuration::Network;
 use tari_consensus::{
-    consensus_constants::ConsensusConstants,
+    consensus_constants::{ConsensusConstants, LeaderSelection},
     hotstuff::{HotstuffConfig, HotstuffEvent},
+    leader_selection::ConsensusLeaderStrategy,
 };
 use tari_dan_common_types::{
     committee::Committee,
@@ -46,19 +48,20 @@ use super::{
 };
 use crate::support::{
     address::TestAddress,
+    adversary::AdversaryBehaviour,
     epoch_manager::TestEpochManager,
     executions_store::ExecuteSpec,
+    faults::FaultScheduler,
     helpers::make_test_component,
     network::{spawn_network, TestNetwork, TestVnDestination},
     validator::Validator,
-    RoundRobinLeaderStrategy,
     ValidatorChannels,
 };
 
 pub struct Test {
     validators: HashMap<TestAddress, Validator>,
     network: TestNetwork,
-    _leader_strategy: RoundRobinLeaderStrategy,
+    _leader_strategy: ConsensusLeaderStrategy,
     epoch_manager: TestEpochManager,
     num_committees: u32,
     shutdown: Shutdown,
@@ -507,6 +510,9 @@ pub struct TestBuilder {
     debug_sql_file: Option<String>,
     message_filter: Option<MessageFilter>,
     failure_nodes: Vec<TestAddress>,
+    adversaries: HashMap<TestAddress, Vec<AdversaryBehaviour>>,
+    seed: u64,
+    link_delays: Vec<(TestAddress, TestAddress, RangeInclusive<Duration>)>,
     config: HotstuffConfig,
 }
 
@@ -519,6 +525,9 @@ impl TestBuilder {
             debug_sql_file: None,
             message_filter: None,
             failure_nodes: Vec::new(),
+            adversaries: HashMap::new(),
+            seed: 0,
+            link_delays: Vec::new(),
             config: HotstuffConfig {
                 network: Network::LocalNet,
                 sidechain_id: None,
@@ -534,7 +543,10 @@ impl TestBuilder {
                     max_block_size: 500,
                     fee_exhaust_divisor: 20,
                     max_vns_per_epoch_activated: 5,
+                    max_block_time_drift: Duration::from_secs(30),
+                    leader_selection: LeaderSelection::RoundRobin,
                 },
+                max_execution_concurrency: 2,
             },
         }
     }
@@ -581,6 +593,24 @@ impl TestBuilder {
         self
     }
 
+    /// Configures the node to behave maliciously. The behaviours are applied to all messages sent by the node.
+    pub fn add_adversary<T: Into<TestAddress>>(mut self, node: T, behaviour: AdversaryBehaviour) -> Self {
+        self.adversaries.entry(node.into()).or_default().push(behaviour);
+        self
+    }
+
+    /// Seeds the scheduler that decides network faults such as link delays
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Delays every message sent from `from` to `to` by a random duration in the given range.
+    pub fn with_link_delay<T: Into<TestAddress>>(mut self, from: T, to: T, delay: RangeInclusive<Duration>) -> Self {
+        self.link_delays.push((from.into(), to.into(), delay));
+        self
+    }
+
     pub fn with_message_filter(mut self, message_filter: MessageFilter) -> Self {
         self.message_filter = Some(message_filter);
         self
@@ -592,11 +622,12 @@ impl TestBuilder {
     }
 
     async fn build_validators(
-        leader_strategy: &RoundRobinLeaderStrategy,
+        leader_strategy: &ConsensusLeaderStrategy,
         epoch_manager: &TestEpochManager,
         sql_address: String,
         config: HotstuffConfig,
         failure_nodes: &[TestAddress],
+        adversaries: &HashMap<TestAddress, Vec<AdversaryBehaviour>>,
         shutdown_signal: ShutdownSignal,
     ) -> (Vec<ValidatorChannels>, HashMap<TestAddress, Validator>) {
         let num_committees = epoch_manager.get_num_committees(Epoch(0)).await.unwrap();
@@ -625,6 +656,7 @@ impl TestBuilder {
                     .with_epoch_manager(epoch_manager.clone_for(address.clone(), pk, shard_addr))
                     .with_leader_strategy(*leader_strategy)
                     .with_num_committees(num_committees)
+                    .with_adversary_behaviours(adversaries.get(&address).into_iter().flatten().copied())
                     .spawn(shutdown_signal.clone());
                 (channels, (address, validator))
             })
@@ -648,7 +680,7 @@ impl TestBuilder {
         let committees = build_committees(self.committees);
         let num_committees = u32::try_from(committees.len()).expect("WAAAY too many committees");
 
-        let leader_strategy = RoundRobinLeaderStrategy::new();
+        let leader_strategy = ConsensusLeaderStrategy::new(self.config.consensus_constants.leader_selection);
         let (tx_epoch_events, _) = broadcast::channel(10);
         let epoch_manager = TestEpochManager::new(tx_epoch_events);
         epoch_manager.add_committees(committees).await;
@@ -659,10 +691,15 @@ impl TestBuilder {
             self.sql_address,
             self.config,
             &self.failure_nodes,
+            &self.adversaries,
             shutdown.to_signal(),
         )
         .await;
-        let network = spawn_network(channels, shutdown.to_signal(), self.message_filter);
+        let mut faults = FaultScheduler::new(self.seed);
+        for (from, to, delay) in self.link_delays {
+            faults.set_link_delay(from, to, delay);
+        }
+        let network = spawn_network(channels, shutdown.to_signal(), self.message_filter, faults);
 
         Test {
             validators,
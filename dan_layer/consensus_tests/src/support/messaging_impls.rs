@@ -11,7 +11,7 @@ use tari_epoch_manager::EpochManagerReader;
 use tokio::sync::mpsc;
 
 use super::epoch_manager::TestEpochManager;
-use crate::support::TestAddress;
+use crate::support::{adversary::Adversary, TestAddress};
 
 #[derive(Debug, Clone)]
 pub struct TestOutboundMessaging {
@@ -19,6 +19,7 @@ pub struct TestOutboundMessaging {
     tx_leader: mpsc::Sender<(TestAddress, HotstuffMessage)>,
     tx_broadcast: mpsc::Sender<(Vec<TestAddress>, HotstuffMessage)>,
     loopback_sender: mpsc::Sender<HotstuffMessage>,
+    adversary: Adversary,
 }
 
 impl TestOutboundMessaging {
@@ -26,6 +27,7 @@ impl TestOutboundMessaging {
         epoch_manager: TestEpochManager,
         tx_leader: mpsc::Sender<(TestAddress, HotstuffMessage)>,
         tx_broadcast: mpsc::Sender<(Vec<TestAddress>, HotstuffMessage)>,
+        adversary: Adversary,
     ) -> (Self, mpsc::Receiver<HotstuffMessage>) {
         let (loopback_sender, loopback_receiver) = mpsc::channel(100);
         (
@@ -34,6 +36,7 @@ impl TestOutboundMessaging {
                 tx_leader,
                 tx_broadcast,
                 loopback_sender,
+                adversary,
             },
             loopback_receiver,
         )
@@ -58,8 +61,11 @@ impl OutboundMessaging for TestOutboundMessaging {
         to: Self::Addr,
         message: T,
     ) -> Result<(), OutboundMessagingError> {
+        let Some(message) = self.adversary.on_send(message.into()) else {
+            return Ok(());
+        };
         self.tx_leader
-            .send((to, message.into()))
+            .send((to, message))
             .await
             .map_err(|_| OutboundMessagingError::FailedToEnqueueMessage {
                 reason: "leader channel closed".to_string(),
@@ -85,11 +91,14 @@ impl OutboundMessaging for TestOutboundMessaging {
             .flat_map(|c| c.addresses().cloned())
             .collect();
 
-        self.tx_broadcast.send((peers, message.into())).await.map_err(|_| {
-            OutboundMessagingError::FailedToEnqueueMessage {
-                reason: "broadcast channel closed".to_string(),
-            }
-        })
+        for (peers, message) in self.adversary.on_multicast(peers, message.into()) {
+            self.tx_broadcast.send((peers, message)).await.map_err(|_| {
+                OutboundMessagingError::FailedToEnqueueMessage {
+                    reason: "broadcast channel closed".to_string(),
+                }
+            })?;
+        }
+        Ok(())
     }
 }
 
@@ -7,11 +7,12 @@
 pub const TEST_NUM_PRESHARDS: NumPreshards = NumPreshards::P64;
 
 mod address;
+mod adversary;
 mod epoch_manager;
 mod executions_store;
+mod faults;
 mod harness;
 pub mod helpers;
-mod leader_strategy;
 pub mod logging;
 mod messaging_impls;
 mod network;
@@ -23,9 +24,9 @@ mod transaction_executor;
 mod validator;
 
 pub use address::*;
-pub use executions_store::ExecuteSpec;
+pub use adversary::AdversaryBehaviour;
+pub use executions_store::{ExecuteSpec, TestExecutionSpecStore};
 pub use harness::*;
-pub use leader_strategy::*;
 pub use network::*;
 pub use spec::*;
 use tari_dan_common_types::NumPreshards;
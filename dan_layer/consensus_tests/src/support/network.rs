@@ -2,8 +2,10 @@
 //    SPDX-License-Identifier: BSD-3-Clause
 
 use std::{
-    collections::HashMap,
-    sync::{atomic::AtomicUsize, Arc},
+    collections::{BTreeMap, HashMap},
+    ops::RangeInclusive,
+    sync::{atomic::AtomicUsize, Arc, Mutex},
+    time::Duration,
 };
 
 use futures::{stream::FuturesUnordered, FutureExt, StreamExt};
@@ -21,11 +23,13 @@ use tokio::{
         RwLock,
     },
     task,
+    time::{sleep_until, Instant},
 };
 
 use crate::support::{
     address::TestAddress,
     committee_number_to_shard_group,
+    faults::{Delivery, FaultScheduler},
     Validator,
     ValidatorChannels,
     TEST_NUM_PRESHARDS,
@@ -37,6 +41,7 @@ pub fn spawn_network(
     channels: Vec<ValidatorChannels>,
     shutdown_signal: ShutdownSignal,
     message_filter: Option<MessageFilter>,
+    faults: FaultScheduler,
 ) -> TestNetwork {
     let tx_new_transactions = channels
         .iter()
@@ -67,6 +72,8 @@ pub fn spawn_network(
     let num_filtered_messages = Arc::new(AtomicUsize::new(0));
 
     let offline_destinations = Arc::new(RwLock::new(Vec::new()));
+    log::info!("🎲 Network fault scheduler seed: {}", faults.seed());
+    let faults = Arc::new(Mutex::new(faults));
 
     let network_task_handle = TestNetworkWorker {
         network_status,
@@ -80,6 +87,9 @@ pub fn spawn_network(
         num_filtered_messages: num_filtered_messages.clone(),
         transaction_store: Arc::new(Default::default()),
         offline_destinations: offline_destinations.clone(),
+        faults: faults.clone(),
+        delayed_messages: BTreeMap::new(),
+        num_delayed_messages: 0,
         shutdown_signal,
         message_filter,
     }
@@ -90,6 +100,7 @@ pub fn spawn_network(
         tx_new_transaction,
         network_status: tx_network_status,
         offline_destinations,
+        faults,
         num_sent_messages,
         num_filtered_messages,
         _on_message: rx_on_message,
@@ -113,6 +124,7 @@ pub struct TestNetwork {
     tx_new_transaction: mpsc::Sender<(TestVnDestination, TransactionRecord)>,
     network_status: watch::Sender<NetworkStatus>,
     offline_destinations: Arc<RwLock<Vec<TestVnDestination>>>,
+    faults: Arc<Mutex<FaultScheduler>>,
     num_sent_messages: Arc<AtomicUsize>,
     num_filtered_messages: Arc<AtomicUsize>,
     _on_message: watch::Receiver<Option<HotstuffMessage>>,
@@ -141,6 +153,24 @@ impl TestNetwork {
             .any(|d| d.is_for(address, ShardGroup::all_shards(TEST_NUM_PRESHARDS), num_committees))
     }
 
+    /// Partitions the network into the given groups. Messages between validators in different groups are dropped
+    /// until the partition is healed.
+    pub fn partition<I: IntoIterator<Item = Vec<TestAddress>>>(&self, groups: I) -> &Self {
+        self.faults.lock().unwrap().partition(groups);
+        self
+    }
+
+    pub fn heal(&self) -> &Self {
+        self.faults.lock().unwrap().heal();
+        self
+    }
+
+    /// Delays every message sent from `from` to `to` by a random duration in the given range.
+    pub fn set_link_delay(&self, from: TestAddress, to: TestAddress, delay: RangeInclusive<Duration>) -> &Self {
+        self.faults.lock().unwrap().set_link_delay(from, to, delay);
+        self
+    }
+
     #[allow(dead_code)]
     pub async fn on_message(&mut self) -> Option<HotstuffMessage> {
         self._on_message.changed().await.unwrap();
@@ -217,6 +247,12 @@ pub struct TestNetworkWorker {
     transaction_store: Arc<RwLock<HashMap<TransactionId, TransactionRecord>>>,
 
     offline_destinations: Arc<RwLock<Vec<TestVnDestination>>>,
+    faults: Arc<Mutex<FaultScheduler>>,
+    /// Messages held back by a link delay, ordered by the time that they are due and then by the order in which they
+    /// were scheduled. Messages are released from the worker loop, so messages that are due at the same time are
+    /// always delivered in the same order.
+    delayed_messages: BTreeMap<(Instant, u64), (TestAddress, TestAddress, HotstuffMessage)>,
+    num_delayed_messages: u64,
     shutdown_signal: ShutdownSignal,
     message_filter: Option<MessageFilter>,
 }
@@ -283,6 +319,7 @@ impl TestNetworkWorker {
                 .iter_mut()
                 .map(|(from, rx)| rx.recv().map(|r| (from.clone(), r)))
                 .collect::<FuturesUnordered<_>>();
+            let next_delayed_message = self.delayed_messages.keys().next().map(|(due, _)| *due);
 
             tokio::select! {
                 biased;
@@ -307,6 +344,9 @@ impl TestNetworkWorker {
                     }
                 }
 
+                _ = sleep_until(next_delayed_message.unwrap_or_else(Instant::now)), if next_delayed_message.is_some() => {
+                    self.release_delayed_messages().await;
+                }
                 Some((from, Some((to, msg)))) = rx_broadcast.next() => self.handle_broadcast(from, to, msg).await,
                 Some((from, Some((to, msg)))) = rx_leader.next() => self.handle_leader(from, to, msg).await,
             }
@@ -333,14 +373,7 @@ impl TestNetworkWorker {
                 continue;
             }
 
-            self.tx_hs_message
-                .get(&to)
-                .unwrap()
-                .send((from.clone(), msg.clone()))
-                .await
-                .unwrap();
-            self.num_sent_messages
-                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            self.deliver(from.clone(), to, msg.clone()).await;
         }
         self.on_message.send(Some(msg.clone())).unwrap();
     }
@@ -362,9 +395,45 @@ impl TestNetworkWorker {
         }
         log::debug!("✉️ Message {} sent from {} to {}", msg, from, to);
         self.on_message.send(Some(msg.clone())).unwrap();
+        self.deliver(from, to, msg).await;
+    }
+
+    async fn deliver(&mut self, from: TestAddress, to: TestAddress, msg: HotstuffMessage) {
+        let delivery = self.faults.lock().unwrap().schedule(&from, &to);
+        match delivery {
+            Delivery::Now => {
+                let tx_hs_message = self.tx_hs_message.get(&to).unwrap();
+                tx_hs_message.send((from, msg)).await.unwrap();
+            },
+            Delivery::Delay(delay) => {
+                log::debug!("🐢 Delaying message {msg} from {from} to {to} by {delay:.2?}");
+                self.delayed_messages
+                    .insert((Instant::now() + delay, self.num_delayed_messages), (from, to, msg));
+                self.num_delayed_messages += 1;
+            },
+            Delivery::Drop => {
+                log::debug!("✂️ Dropping message {msg} from {from} to {to}. The network is partitioned");
+                self.num_filtered_messages
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                return;
+            },
+        }
         self.num_sent_messages
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-        self.tx_hs_message.get(&to).unwrap().send((from, msg)).await.unwrap();
+    }
+
+    /// Delivers all delayed messages that are due, in the order that they are due
+    async fn release_delayed_messages(&mut self) {
+        let now = Instant::now();
+        while let Some(entry) = self.delayed_messages.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            let (from, to, msg) = entry.remove();
+            let tx_hs_message = self.tx_hs_message.get(&to).unwrap();
+            // The validator may have shut down while the message was delayed
+            let _ignore = tx_hs_message.send((from, msg)).await;
+        }
     }
 
     async fn is_offline_destination(&self, from: &TestAddress, to: &TestAddress, shard: ShardGroup) -> bool {
@@ -1,7 +1,10 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use tari_consensus::traits::{hooks::NoopHooks, ConsensusSpec};
+use tari_consensus::{
+    leader_selection::ConsensusLeaderStrategy,
+    traits::{hooks::NoopHooks, ConsensusSpec},
+};
 use tari_state_store_sqlite::SqliteStateStore;
 
 use super::TestBlockTransactionProcessor;
@@ -11,7 +14,6 @@ use crate::support::{
     messaging_impls::{TestInboundMessaging, TestOutboundMessaging},
     signing_service::TestVoteSignatureService,
     sync::AlwaysSyncedSyncManager,
-    RoundRobinLeaderStrategy,
 };
 
 #[derive(Clone)]
@@ -22,7 +24,7 @@ impl ConsensusSpec for TestConsensusSpec {
     type EpochManager = TestEpochManager;
     type Hooks = NoopHooks;
     type InboundMessaging = TestInboundMessaging;
-    type LeaderStrategy = RoundRobinLeaderStrategy;
+    type LeaderStrategy = ConsensusLeaderStrategy;
     type OutboundMessaging = TestOutboundMessaging;
     type SignatureService = TestVoteSignatureService;
     type StateStore = SqliteStateStore<Self::Addr>;
@@ -3,7 +3,7 @@
 
 use std::{collections::HashMap, iter};
 
-use tari_consensus::traits::{BlockTransactionExecutor, BlockTransactionExecutorError};
+use tari_consensus::traits::{BlockExecutionContext, BlockTransactionExecutor, BlockTransactionExecutorError};
 use tari_dan_common_types::{Epoch, LockIntent, SubstateRequirement, VersionedSubstateId};
 use tari_dan_engine::state_store::{memory::MemoryStateStore, new_memory_store, StateWriter};
 use tari_dan_storage::{
@@ -15,6 +15,7 @@ use tari_engine_types::{
     transaction_receipt::TransactionReceiptAddress,
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
+use tari_template_lib::models::BlockInfo;
 use tari_transaction::Transaction;
 
 use crate::support::{create_execution_result_for_transaction, executions_store::TestExecutionSpecStore};
@@ -56,7 +57,7 @@ impl<TStateStore: StateStore> BlockTransactionExecutor<TStateStore> for TestBloc
     fn execute(
         &self,
         transaction: Transaction,
-        current_epoch: Epoch,
+        context: &BlockExecutionContext,
         resolved_inputs: &HashMap<SubstateRequirement, Substate>,
     ) -> Result<ExecutedTransaction, BlockTransactionExecutorError> {
         let id = *transaction.id();
@@ -70,7 +71,16 @@ impl<TStateStore: StateStore> BlockTransactionExecutor<TStateStore> for TestBloc
         let mut virtual_substates = VirtualSubstates::new();
         virtual_substates.insert(
             VirtualSubstateId::CurrentEpoch,
-            VirtualSubstate::CurrentEpoch(current_epoch.as_u64()),
+            VirtualSubstate::CurrentEpoch(context.epoch.as_u64()),
+        );
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentBlock,
+            VirtualSubstate::CurrentBlock(BlockInfo {
+                height: context.height.as_u64(),
+                timestamp: context.timestamp,
+                shard_group_start: context.shard_group.start().as_u32(),
+                shard_group_end: context.shard_group.end().as_u32(),
+            }),
         );
 
         let spec = self
@@ -3,7 +3,9 @@
 
 use tari_common_types::types::{PrivateKey, PublicKey};
 use tari_consensus::{
+    consensus_constants::LeaderSelection,
     hotstuff::{ConsensusCurrentState, ConsensusWorker, ConsensusWorkerContext, HotstuffConfig, HotstuffWorker},
+    leader_selection::ConsensusLeaderStrategy,
     traits::hooks::NoopHooks,
 };
 use tari_crypto::keys::PublicKey as _;
@@ -15,12 +17,12 @@ use tokio::sync::{broadcast, mpsc, watch};
 
 use crate::support::{
     address::TestAddress,
+    adversary::{Adversary, AdversaryBehaviour},
     epoch_manager::TestEpochManager,
     executions_store::TestExecutionSpecStore,
     messaging_impls::{TestInboundMessaging, TestOutboundMessaging},
     signing_service::TestVoteSignatureService,
     sync::AlwaysSyncedSyncManager,
-    RoundRobinLeaderStrategy,
     TestBlockTransactionProcessor,
     TestConsensusSpec,
     Validator,
@@ -35,11 +37,12 @@ pub struct ValidatorBuilder {
     pub shard_address: SubstateAddress,
     pub shard_group: ShardGroup,
     pub sql_url: String,
-    pub leader_strategy: RoundRobinLeaderStrategy,
+    pub leader_strategy: ConsensusLeaderStrategy,
     pub num_committees: u32,
     pub epoch_manager: Option<TestEpochManager>,
     pub transaction_executions: TestExecutionSpecStore,
     pub config: Option<HotstuffConfig>,
+    pub adversary_behaviours: Vec<AdversaryBehaviour>,
 }
 
 impl ValidatorBuilder {
@@ -52,10 +55,11 @@ impl ValidatorBuilder {
             num_committees: 0,
             shard_group: ShardGroup::all_shards(TEST_NUM_PRESHARDS),
             sql_url: ":memory".to_string(),
-            leader_strategy: RoundRobinLeaderStrategy::new(),
+            leader_strategy: ConsensusLeaderStrategy::new(LeaderSelection::RoundRobin),
             epoch_manager: None,
             transaction_executions: TestExecutionSpecStore::new(),
             config: None,
+            adversary_behaviours: Vec::new(),
         }
     }
 
@@ -91,11 +95,19 @@ impl ValidatorBuilder {
         self
     }
 
-    pub fn with_leader_strategy(&mut self, leader_strategy: RoundRobinLeaderStrategy) -> &mut Self {
+    pub fn with_leader_strategy(&mut self, leader_strategy: ConsensusLeaderStrategy) -> &mut Self {
         self.leader_strategy = leader_strategy;
         self
     }
 
+    pub fn with_adversary_behaviours<I: IntoIterator<Item = AdversaryBehaviour>>(
+        &mut self,
+        behaviours: I,
+    ) -> &mut Self {
+        self.adversary_behaviours.extend(behaviours);
+        self
+    }
+
     pub fn with_num_committees(&mut self, num_committees: u32) -> &mut Self {
         self.num_committees = num_committees;
         self
@@ -119,8 +131,16 @@ impl ValidatorBuilder {
             self.shard_address,
         );
 
+        let adversary = Adversary::new(
+            self.address.clone(),
+            self.secret_key.clone(),
+            self.adversary_behaviours.clone(),
+        );
+        if !adversary.is_honest() {
+            log::info!("😈 {} is an adversary: {:?}", self.address, self.adversary_behaviours);
+        }
         let (outbound_messaging, rx_loopback) =
-            TestOutboundMessaging::create(epoch_manager.clone(), tx_leader, tx_broadcast);
+            TestOutboundMessaging::create(epoch_manager.clone(), tx_leader, tx_broadcast, adversary);
         let inbound_messaging = TestInboundMessaging::new(self.address.clone(), rx_hs_message, rx_loopback);
 
         let store = SqliteStateStore::connect(&self.sql_url).unwrap();
@@ -0,0 +1,253 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use indexmap::IndexMap;
+use tari_common::configuration::Network;
+use tari_common_types::types::{FixedHash, PublicKey};
+use tari_consensus::{
+    hotstuff::{
+        substate_store::PendingSubstateStore,
+        transaction_manager::{ConsensusTransactionManager, LocalPreparedTransaction, PreparedTransaction},
+        BlockExecutionStats,
+    },
+    traits::{BlockExecutionContext, WriteableSubstateStore},
+};
+use tari_dan_common_types::{
+    committee::CommitteeInfo,
+    shard::Shard,
+    Epoch,
+    NodeHeight,
+    PeerAddress,
+    ShardGroup,
+    SubstateRequirement,
+};
+use tari_dan_storage::{
+    consensus_models::{
+        Block,
+        BlockId,
+        Command,
+        Decision,
+        Evidence,
+        QcId,
+        QuorumCertificate,
+        SubstateRecord,
+        SubstateRequirementLockIntent,
+        TransactionAtom,
+        TransactionRecord,
+        VersionedSubstateIdLockIntent,
+    },
+    StateStore,
+    StorageError,
+};
+use tari_engine_types::substate::SubstateId;
+use tari_state_store_sqlite::SqliteStateStore;
+use tari_template_lib::models::{ComponentAddress, ObjectKey};
+use tari_transaction::TransactionId;
+
+use crate::support::{
+    build_transaction,
+    helpers::make_test_component,
+    logging::setup_logger,
+    ExecuteSpec,
+    TestBlockTransactionProcessor,
+    TestExecutionSpecStore,
+    TEST_NUM_PRESHARDS,
+};
+
+type TestStore = SqliteStateStore<PeerAddress>;
+
+/// The result of preparing a transaction in a block
+#[derive(Debug, PartialEq, Eq)]
+struct PreparedOutcome {
+    transaction_id: TransactionId,
+    decision: Decision,
+    resolved_inputs: Vec<VersionedSubstateIdLockIntent>,
+    resulting_outputs: Vec<VersionedSubstateIdLockIntent>,
+    /// The encoded finalize result, which includes the substate diff
+    result: Vec<u8>,
+}
+
+#[test]
+fn it_executes_a_block_in_parallel_with_the_same_results_as_sequentially() {
+    setup_logger();
+    let conflicting = new_substate_id(1);
+    // Two transactions write to the same substate, so the later one in the block must see the output of the earlier
+    // one. The other transactions are independent.
+    let transactions = vec![
+        new_transaction(&[1]),
+        new_transaction(&[1, 2]),
+        new_transaction(&[3]),
+        new_transaction(&[4]),
+    ];
+
+    let (sequential, sequential_stats) = execute_block(&transactions, 1);
+    let (parallel, parallel_stats) = execute_block(&transactions, 4);
+
+    assert_eq!(parallel, sequential);
+    assert!(sequential.iter().all(|outcome| outcome.decision.is_commit()));
+    let conflicting_versions = sequential
+        .iter()
+        .flat_map(|outcome| &outcome.resolved_inputs)
+        .filter(|input| *input.substate_id() == conflicting)
+        .map(|input| input.version())
+        .collect::<Vec<_>>();
+    assert_eq!(conflicting_versions, [0, 1]);
+
+    assert_eq!(sequential_stats, BlockExecutionStats {
+        num_sequential: 4,
+        ..Default::default()
+    });
+    // The conflicting transaction is executed sequentially once the earlier transaction's outputs are known
+    assert_eq!(parallel_stats, BlockExecutionStats {
+        num_pre_executed: 3,
+        num_parallel: 3,
+        num_discarded: 0,
+        num_sequential: 1,
+    });
+}
+
+/// Prepares each transaction of a block in block order, in the same way as when voting on a local block, and
+/// returns the outcome of each transaction
+fn execute_block(
+    transactions: &[TransactionRecord],
+    max_concurrency: usize,
+) -> (Vec<PreparedOutcome>, BlockExecutionStats) {
+    let store = create_store();
+    let executions = TestExecutionSpecStore::new();
+    let mut substate_ids = Vec::<SubstateId>::new();
+    for transaction in transactions {
+        let inputs = transaction.transaction().all_inputs_iter().collect::<Vec<_>>();
+        for input in &inputs {
+            if !substate_ids.contains(input.substate_id()) {
+                substate_ids.push(input.substate_id().clone());
+            }
+        }
+        executions.insert(ExecuteSpec {
+            transaction: transaction.transaction().clone(),
+            decision: Decision::Commit,
+            fee: 1,
+            inputs: inputs
+                .into_iter()
+                .map(|input| SubstateRequirementLockIntent::write(input, 0))
+                .collect(),
+            new_outputs: vec![],
+        });
+    }
+    store
+        .with_write_tx(|tx| {
+            for transaction in transactions {
+                transaction.insert(tx)?;
+            }
+            for id in substate_ids {
+                new_substate_record(id).create(tx)?;
+            }
+            Ok::<_, StorageError>(())
+        })
+        .unwrap();
+
+    let manager = ConsensusTransactionManager::<_, TestStore>::new(
+        TestBlockTransactionProcessor::new(executions),
+        max_concurrency,
+    );
+    let shard_group = ShardGroup::all_shards(TEST_NUM_PRESHARDS);
+    let committee_info = CommitteeInfo::new(TEST_NUM_PRESHARDS, 1, 1, shard_group);
+    let block = new_block(transactions, shard_group);
+    let context = BlockExecutionContext::from_block(&block);
+
+    let tx = store.create_read_tx().unwrap();
+    let mut substate_store = PendingSubstateStore::new(&tx, *block.parent(), TEST_NUM_PRESHARDS);
+    manager
+        .pre_execute_block(&mut substate_store, &committee_info, &block)
+        .unwrap();
+
+    let mut outcomes = Vec::with_capacity(transactions.len());
+    for atom in block.commands().iter().filter_map(|cmd| cmd.local_only()) {
+        let prepared = manager
+            .prepare(&mut substate_store, &committee_info, &context, *atom.id(), block.id())
+            .unwrap();
+        let PreparedTransaction::LocalOnly(LocalPreparedTransaction::Accept { execution, .. }) = prepared else {
+            panic!("Expected transaction {} to be prepared as LocalOnly", atom.id());
+        };
+        if execution.decision().is_commit() {
+            if let Some(diff) = execution.result().finalize.accept() {
+                substate_store.put_diff(*atom.id(), diff).unwrap();
+            }
+        }
+        outcomes.push(PreparedOutcome {
+            transaction_id: *execution.id(),
+            decision: execution.decision(),
+            resolved_inputs: execution.resolved_inputs().to_vec(),
+            resulting_outputs: execution.resulting_outputs().to_vec(),
+            result: tari_bor::encode(&execution.result().finalize.result).unwrap(),
+        });
+    }
+
+    let stats = substate_store.pre_executed().stats();
+    (outcomes, stats)
+}
+
+fn new_block(transactions: &[TransactionRecord], shard_group: ShardGroup) -> Block {
+    let commands = transactions
+        .iter()
+        .map(|transaction| {
+            Command::LocalOnly(TransactionAtom {
+                id: *transaction.id(),
+                decision: Decision::Commit,
+                evidence: Evidence::empty(),
+                transaction_fee: 1,
+                leader_fee: None,
+            })
+        })
+        .collect();
+
+    Block::new(
+        Network::LocalNet,
+        BlockId::zero(),
+        QuorumCertificate::genesis(Epoch(1), shard_group),
+        NodeHeight(1),
+        Epoch(1),
+        shard_group,
+        PublicKey::default(),
+        commands,
+        FixedHash::zero(),
+        0,
+        IndexMap::new(),
+        None,
+        0,
+        0,
+        FixedHash::zero(),
+        None,
+    )
+}
+
+/// Builds a transaction with unversioned inputs, so that each input is resolved to the latest version in the block
+fn new_transaction(input_seeds: &[u8]) -> TransactionRecord {
+    let inputs = input_seeds
+        .iter()
+        .map(|seed| SubstateRequirement::unversioned(new_substate_id(*seed)))
+        .collect();
+    build_transaction(Decision::Commit, inputs)
+}
+
+fn create_store() -> TestStore {
+    SqliteStateStore::connect(":memory:").unwrap()
+}
+
+fn new_substate_id(seed: u8) -> SubstateId {
+    ComponentAddress::from_array([seed; ObjectKey::LENGTH]).into()
+}
+
+fn new_substate_record(id: SubstateId) -> SubstateRecord {
+    let value = make_test_component(id.as_component_address().unwrap().entity_id());
+    SubstateRecord::new(
+        id,
+        0,
+        value,
+        Shard::zero(),
+        Epoch(0),
+        NodeHeight(0),
+        BlockId::zero(),
+        TransactionId::default(),
+        QcId::zero(),
+    )
+}
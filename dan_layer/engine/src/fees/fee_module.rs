@@ -34,6 +34,22 @@ impl RuntimeModule for FeeModule {
         Ok(())
     }
 
+    fn on_wasm_executed(
+        &self,
+        track: &StateTracker,
+        points_used: u64,
+        memory_pages_grown: u32,
+    ) -> Result<(), RuntimeModuleError> {
+        const POINTS_PER_COST_UNIT: u64 = 1_000_000;
+        // Round up so that any WASM execution is charged if the cost is non-zero
+        let compute_cost = points_used
+            .saturating_mul(self.fee_table.per_million_wasm_points_cost())
+            .div_ceil(POINTS_PER_COST_UNIT);
+        let memory_cost = u64::from(memory_pages_grown) * self.fee_table.per_wasm_memory_page_cost();
+        track.add_fee_charge(FeeSource::WasmCompute, compute_cost + memory_cost);
+        Ok(())
+    }
+
     fn on_before_finalize(&self, track: &StateTracker) -> Result<(), RuntimeModuleError> {
         let total_storage = track.with_substates_to_persist(|changes| {
             let mut counter = ByteCounter::new();
@@ -7,6 +7,11 @@ pub struct FeeTable {
     pub per_byte_storage_cost: u64,
     pub per_event_cost: u64,
     pub per_log_cost: u64,
+    /// The cost per million metering points used by WASM execution. Each WASM instruction is weighted by the cost
+    /// function of the metering middleware.
+    pub per_million_wasm_points_cost: u64,
+    /// The cost per page (64KiB) that WASM memory grows during a template call
+    pub per_wasm_memory_page_cost: u64,
 }
 
 impl FeeTable {
@@ -16,6 +21,8 @@ impl FeeTable {
             per_byte_storage_cost: 0,
             per_event_cost: 0,
             per_log_cost: 0,
+            per_million_wasm_points_cost: 0,
+            per_wasm_memory_page_cost: 0,
         }
     }
 
@@ -34,4 +41,12 @@ impl FeeTable {
     pub fn per_log_cost(&self) -> u64 {
         self.per_log_cost
     }
+
+    pub fn per_million_wasm_points_cost(&self) -> u64 {
+        self.per_million_wasm_points_cost
+    }
+
+    pub fn per_wasm_memory_page_cost(&self) -> u64 {
+        self.per_wasm_memory_page_cost
+    }
 }
@@ -137,6 +137,14 @@ pub enum RuntimeError {
     ItemNotOnWorkspace { key: String },
     #[error("Attempted to take the last output but there was no previous instruction output")]
     NoLastInstructionOutput,
+    #[error("Workspace item {key} is not a vector and cannot be iterated over")]
+    ItemOnWorkspaceNotIterable { key: String },
+    #[error("Workspace item {key} has {num_items} items which exceeds the maximum of {max_iterations} iterations")]
+    MaxIterationsExceeded {
+        key: String,
+        num_items: usize,
+        max_iterations: u32,
+    },
     #[error(transparent)]
     TransactionCommitError(#[from] TransactionCommitError),
     #[error("Transaction generated too many outputs: {0}")]
@@ -159,6 +167,8 @@ pub enum RuntimeError {
     AccessDeniedOwnerRequired { action: ActionIdent },
     #[error("Invalid method address rule for {template_name}: {details}")]
     InvalidMethodAccessRule { template_name: String, details: String },
+    #[error("Invalid access rule: {details}")]
+    InvalidAccessRule { details: String },
     #[error("Runtime module error: {0}")]
     ModuleError(#[from] RuntimeModuleError),
     #[error("Invalid claiming signature")]
@@ -173,6 +183,12 @@ pub enum RuntimeError {
     },
     #[error("Template {template_address} not found")]
     TemplateNotFound { template_address: TemplateAddress },
+    #[error("Cannot upgrade component from template {from} to {to}: {details}")]
+    IncompatibleTemplateUpgrade {
+        from: TemplateAddress,
+        to: TemplateAddress,
+        details: String,
+    },
     #[error("Insufficient fees paid: required {required_fee}, paid {fees_paid}")]
     InsufficientFeesPaid { required_fee: Amount, fees_paid: Amount },
     #[error("No fee checkpoint")]
@@ -185,6 +201,8 @@ pub enum RuntimeError {
     TransactionReceiptAlreadyExists { address: TransactionReceiptAddress },
     #[error("Transaction Receipt not found")]
     TransactionReceiptNotFound,
+    #[error("Sub-intent {intent_id} has already been used")]
+    SubIntentAlreadyUsed { intent_id: Hash },
     #[error("Component already exists {address}")]
     ComponentAlreadyExists { address: ComponentAddress },
     #[error("Cross-template call function error of function '{function}' on template '{template_address}': {details}")]
@@ -211,6 +229,10 @@ pub enum RuntimeError {
     AuthScopeStackEmpty,
     #[error("Invalid deposit of bucket {bucket_id} has locked value amounting to {locked_amount}")]
     InvalidOpDepositLockedBucket { bucket_id: BucketId, locked_amount: Amount },
+    #[error("Resource {resource_address} is paused")]
+    ResourcePaused { resource_address: ResourceAddress },
+    #[error("Vault {vault_id} is frozen")]
+    VaultFrozen { vault_id: VaultId },
     #[error("Duplicate substate {address}")]
     DuplicateSubstate { address: SubstateId },
     #[error("Substate {address} is orphaned")]
@@ -288,6 +310,34 @@ pub enum AssertError {
     },
     #[error("Assert expected bucket to have at least {expected} tokens but only has {got}")]
     InvalidAmount { expected: Amount, got: Amount },
+    #[error("Assert expected vault {vault_id} to have a balance of at least {min_amount} but it has {balance}")]
+    VaultBalanceTooLow {
+        vault_id: VaultId,
+        min_amount: Amount,
+        balance: Amount,
+    },
+    #[error("Assert expected vault {vault_id} to have a balance of at most {max_amount} but it has {balance}")]
+    VaultBalanceTooHigh {
+        vault_id: VaultId,
+        max_amount: Amount,
+        balance: Amount,
+    },
+    #[error("Assert expected bucket to contain non-fungible {id} but it does not")]
+    NonFungibleNotInBucket { id: NonFungibleId },
+    #[error("Assert expected workspace value '{key}' to equal the given value")]
+    WorkspaceValueMismatch { key: String },
+    #[error(
+        "Assert expected the value at '{path}' in the state of component {component_address} to equal the given value"
+    )]
+    ComponentStateMismatch {
+        component_address: ComponentAddress,
+        path: String,
+    },
+    #[error("Assert expected a value at '{path}' in the state of component {component_address} but none was found")]
+    ComponentStatePathNotFound {
+        component_address: ComponentAddress,
+        path: String,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
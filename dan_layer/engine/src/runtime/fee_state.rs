@@ -8,6 +8,9 @@ use tari_template_lib::models::{Amount, VaultId};
 pub struct FeeState {
     pub fee_payments: Vec<(ResourceContainer, VaultId)>,
     pub fee_charges: FeeBreakdown,
+    /// The index of the top-level instruction that is currently executing, used to attribute fee charges
+    current_instruction: Option<u32>,
+    num_instructions: u32,
 }
 
 impl FeeState {
@@ -15,6 +18,19 @@ impl FeeState {
         Self::default()
     }
 
+    pub fn begin_instruction(&mut self) {
+        self.current_instruction = Some(self.num_instructions);
+        self.num_instructions += 1;
+    }
+
+    pub fn end_instructions(&mut self) {
+        self.current_instruction = None;
+    }
+
+    pub fn current_instruction(&self) -> Option<u32> {
+        self.current_instruction
+    }
+
     pub fn total_charges(&self) -> u64 {
         self.fee_charges.get_total()
     }
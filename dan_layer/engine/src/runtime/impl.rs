@@ -23,18 +23,20 @@
 use std::sync::Arc;
 
 use log::{warn, *};
+use tari_bor::decode_exact;
 use tari_common::configuration::Network;
 use tari_common_types::types::PublicKey;
 use tari_crypto::{range_proof::RangeProofService, ristretto::RistrettoPublicKey, tari_utilities::ByteArray};
 use tari_dan_common_types::{services::template_provider::TemplateProvider, Epoch};
 use tari_engine_types::{
+    assertion::Assertion,
     base_layer_hashing::ownership_proof_hasher64,
     commit_result::{FinalizeResult, RejectReason, TransactionResult},
     component::ComponentHeader,
     confidential::{get_commitment_factory, get_range_proof_service, ConfidentialClaim, ConfidentialOutput},
     entity_id_provider::EntityIdProvider,
-    events::Event,
-    indexed_value::IndexedValue,
+    events::{Event, STANDARD_TOPIC_PREFIX, VAULT_DEPOSIT_TOPIC, VAULT_WITHDRAW_TOPIC},
+    indexed_value::{get_value_by_path, IndexedValue},
     instruction_result::InstructionResult,
     lock::LockFlag,
     logs::LogEntry,
@@ -44,8 +46,13 @@ use tari_engine_types::{
     vault::Vault,
     TemplateAddress,
 };
-use tari_template_abi::{TemplateDef, Type};
-use tari_template_builtin::{ACCOUNT_NFT_TEMPLATE_ADDRESS, ACCOUNT_TEMPLATE_ADDRESS};
+use tari_template_abi::{TemplateDef, Type, MIGRATE_FUNCTION_NAME};
+use tari_template_builtin::{
+    try_get_template_builtin,
+    ACCOUNT_NFT_TEMPLATE_ADDRESS,
+    ACCOUNT_TEMPLATE_ADDRESS,
+    MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
+};
 use tari_template_lib::{
     args,
     args::{
@@ -73,16 +80,28 @@ use tari_template_lib::{
         ProofRef,
         RecallResourceArg,
         ResourceAction,
+        ResourceFreezeVaultArg,
         ResourceGetNonFungibleArg,
         ResourceRef,
         ResourceUpdateNonFungibleDataArg,
         VaultAction,
         VaultCreateProofByFungibleAmountArg,
         VaultCreateProofByNonFungiblesArg,
+        VaultTimeLockArg,
         VaultWithdrawArg,
         WorkspaceAction,
     },
-    auth::{AuthHook, AuthHookCaller, ComponentAccessRules, OwnerRule, ResourceAccessRules, ResourceAuthAction},
+    auth::{
+        AccessRule,
+        AuthHook,
+        AuthHookCaller,
+        ComponentAccessRules,
+        OwnerRule,
+        RequireRule,
+        ResourceAccessRules,
+        ResourceAuthAction,
+        RestrictedAccessRule,
+    },
     constants::{CONFIDENTIAL_TARI_RESOURCE_ADDRESS, XTR},
     crypto::RistrettoPublicKeyBytes,
     models::{
@@ -100,6 +119,7 @@ use tari_template_lib::{
     },
     prelude::ResourceType,
     template::BuiltinTemplate,
+    Hash,
 };
 
 use super::{working_state::WorkingState, Runtime};
@@ -111,6 +131,7 @@ use crate::{
         scope::PushCallFrame,
         tracker::StateTracker,
         utils::to_ristretto_public_key_bytes,
+        AuthorizationScope,
         RuntimeError,
         RuntimeInterface,
         RuntimeModule,
@@ -121,11 +142,6 @@ use crate::{
 
 const LOG_TARGET: &str = "tari::dan::engine::runtime::impl";
 
-// Topics for builtin events emmitted by the engine
-const STANDARD_TOPIC_PREFIX: &str = "std.";
-const VAULT_DEPOSIT_TOPIC: &str = "std.vault.deposit";
-const VAULT_WITHDRAW_TOPIC: &str = "std.vault.withdraw";
-
 #[derive(Clone)]
 pub struct RuntimeInterfaceImpl<TTemplateProvider> {
     tracker: StateTracker,
@@ -360,6 +376,50 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         })
     }
 
+    /// Upgrades the component of the current call frame to the given template. The current state is passed through the
+    /// `migrate` function of the new template, which fails if the new template cannot decode the state.
+    fn apply_template_upgrade(&self, new_template_address: TemplateAddress) -> Result<(), RuntimeError> {
+        let (component_lock, current_template_address, current_state) = self.tracker.write_with(|state| {
+            let component_lock = state
+                .current_call_scope()?
+                .get_current_component_lock()
+                .cloned()
+                .ok_or(RuntimeError::NotInComponentContext {
+                    action: ComponentAction::UpgradeTemplate.into(),
+                })?;
+            let component = state.get_component(&component_lock)?;
+            let template_address = component.template_address;
+            let component_state = component.state().clone();
+            Ok::<_, RuntimeError>((component_lock, template_address, component_state))
+        })?;
+
+        let call_runtime = Runtime::new(Arc::new(self.clone()));
+        let result = TransactionProcessor::migrate_component_state(
+            &*self.template_provider,
+            &call_runtime,
+            &new_template_address,
+            current_state,
+        )
+        .map_err(|e| RuntimeError::IncompatibleTemplateUpgrade {
+            from: current_template_address,
+            to: new_template_address,
+            details: format!("failed to migrate the component state: {e}"),
+        })?;
+        let new_state = result.indexed.into_value();
+
+        let new_template_def = self.get_template_def(&new_template_address)?;
+        self.tracker.write_with(|state| {
+            state.modify_component_with(&component_lock, |component| {
+                component.template_address = new_template_address;
+                component.module_name = new_template_def.template_name().to_string();
+                component.body.set(new_state);
+                true
+            })
+        })?;
+
+        Ok(())
+    }
+
     fn check_resource_auth_hook(&self, hook: &AuthHook) -> Result<(), RuntimeError> {
         let template_address = self
             .tracker
@@ -412,6 +472,15 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
 
         Ok(())
     }
+
+    fn get_vault_balance(&self, vault_id: VaultId) -> Result<Amount, RuntimeError> {
+        self.tracker.write_with(|state| {
+            let vault_lock = state.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Read)?;
+            let balance = state.get_vault(&vault_lock)?.balance();
+            state.unlock_substate(vault_lock)?;
+            Ok(balance)
+        })
+    }
 }
 
 impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInterface
@@ -557,6 +626,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 let template_addr = self.tracker.get_template_address()?;
                 let template_def = self.get_template_def(&template_addr)?;
                 validate_component_access_rule_methods(&access_rules, &template_def)?;
+                validate_component_access_rules(&access_rules)?;
+                validate_owner_rule(&owner_rule)?;
 
                 let owner_key = match owner_rule {
                     OwnerRule::OwnedBySigner => {
@@ -672,6 +743,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                         })?;
 
                 let access_rules: ComponentAccessRules = args.assert_one_arg()?;
+                validate_component_access_rules(&access_rules)?;
 
                 self.tracker.write_with(|state| {
                     let component_lock = state
@@ -708,6 +780,59 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
 
                 Ok(InvokeResult::unit())
             },
+            ComponentAction::SetOwnerRule => {
+                let component_address =
+                    component_ref
+                        .as_component_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "component_ref",
+                            reason: "SetOwnerRule component action requires a component address".to_string(),
+                        })?;
+
+                let owner_rule: OwnerRule = args.assert_one_arg()?;
+                validate_owner_rule(&owner_rule)?;
+                let signer_key = to_ristretto_public_key_bytes(&self.transaction_signer_public_key);
+
+                self.tracker.write_with(|state| {
+                    let component_lock = state
+                        .current_call_scope()?
+                        .get_current_component_lock()
+                        .cloned()
+                        .ok_or(RuntimeError::NotInComponentContext {
+                            action: ComponentAction::SetOwnerRule.into(),
+                        })?;
+                    // As with SetAccessRules, only the current component may be mutated
+                    if *component_lock.address() != component_address {
+                        return Err(RuntimeError::LockError(LockError::SubstateNotLocked {
+                            address: SubstateId::Component(component_address),
+                        }));
+                    }
+                    let component = state.get_component(&component_lock)?;
+                    state
+                        .authorization()
+                        .require_ownership(ComponentAction::SetOwnerRule, component.as_ownership())?;
+
+                    state.modify_component_with(&component_lock, |component| {
+                        let owner_key = match &owner_rule {
+                            OwnerRule::OwnedBySigner => Some(signer_key),
+                            OwnerRule::ByPublicKey(key) => Some(*key),
+                            // As when the component is created, an access rule does not name a single owner key.
+                            // Keeping the previous owner key would report e.g. the lost key of a recovered account as
+                            // the owner.
+                            OwnerRule::ByAccessRule(_) | OwnerRule::None => None,
+                        };
+                        if owner_rule == component.owner_rule && owner_key == component.owner_key {
+                            return false;
+                        }
+                        component.set_owner(owner_key, owner_rule);
+                        true
+                    })?;
+
+                    Ok::<_, RuntimeError>(())
+                })?;
+
+                Ok(InvokeResult::unit())
+            },
             ComponentAction::GetTemplateAddress => {
                 let component_address =
                     component_ref
@@ -719,19 +844,128 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
 
                 args.assert_no_args("Component::GetTemplateAddress")?;
 
-                // The template can never change so we'll just fetch the component
-                self.tracker.read_with(|state| {
-                    let substate = state.store().get_unmodified_substate(&component_address.into())?;
-                    let component = substate
-                        .substate_value()
-                        .component()
-                        .ok_or(RuntimeError::ComponentNotFound {
-                            address: component_address,
+                // The template may have been upgraded in this transaction, so we load the current component state
+                self.tracker.write_with(|state| {
+                    let component = state.load_component(&component_address)?;
+                    Ok(InvokeResult::encode(&component.template_address)?)
+                })
+            },
+            ComponentAction::GetOwnerRule => {
+                let component_address =
+                    component_ref
+                        .as_component_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "component_ref",
+                            reason: "GetOwnerRule component action requires a component address".to_string(),
                         })?;
 
-                    Ok(InvokeResult::encode(&component.template_address)?)
+                args.assert_no_args("Component::GetOwnerRule")?;
+
+                self.tracker.write_with(|state| {
+                    let component = state.load_component(&component_address)?;
+                    Ok(InvokeResult::encode(&component.owner_rule)?)
                 })
             },
+            ComponentAction::GetAccessRules => {
+                let component_address =
+                    component_ref
+                        .as_component_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "component_ref",
+                            reason: "GetAccessRules component action requires a component address".to_string(),
+                        })?;
+
+                args.assert_no_args("Component::GetAccessRules")?;
+
+                self.tracker.write_with(|state| {
+                    let component = state.load_component(&component_address)?;
+                    Ok(InvokeResult::encode(&component.access_rules)?)
+                })
+            },
+            ComponentAction::UpgradeTemplate => {
+                let component_address =
+                    component_ref
+                        .as_component_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "component_ref",
+                            reason: "UpgradeTemplate component action requires a component address".to_string(),
+                        })?;
+
+                let new_template_address: TemplateAddress = args.assert_one_arg()?;
+
+                let current_template_address = self.tracker.write_with(|state| {
+                    let component_lock = state
+                        .current_call_scope()?
+                        .get_current_component_lock()
+                        .cloned()
+                        .ok_or(RuntimeError::NotInComponentContext {
+                            action: ComponentAction::UpgradeTemplate.into(),
+                        })?;
+                    // As with SetAccessRules, only the current component may be upgraded. Otherwise, any template
+                    // called by the owner could upgrade the owner's components using the signer proofs.
+                    if *component_lock.address() != component_address {
+                        return Err(RuntimeError::LockError(LockError::SubstateNotLocked {
+                            address: SubstateId::Component(component_address),
+                        }));
+                    }
+                    component_lock.check_access(LockFlag::Write)?;
+                    let component = state.get_component(&component_lock)?;
+                    state
+                        .authorization()
+                        .require_ownership(ComponentAction::UpgradeTemplate, component.as_ownership())?;
+                    Ok::<_, RuntimeError>(component.template_address)
+                })?;
+
+                if try_get_template_builtin(&current_template_address).is_some() {
+                    return Err(RuntimeError::IncompatibleTemplateUpgrade {
+                        from: current_template_address,
+                        to: new_template_address,
+                        details: "components of builtin templates cannot be upgraded".to_string(),
+                    });
+                }
+
+                if current_template_address == new_template_address {
+                    return Err(RuntimeError::IncompatibleTemplateUpgrade {
+                        from: current_template_address,
+                        to: new_template_address,
+                        details: "the component already uses this template".to_string(),
+                    });
+                }
+
+                let current_template_def = self.get_template_def(&current_template_address)?;
+                let new_template_def = self.get_template_def(&new_template_address)?;
+                new_template_def
+                    .check_upgrade_compatibility(&current_template_def)
+                    .map_err(|details| RuntimeError::IncompatibleTemplateUpgrade {
+                        from: current_template_address,
+                        to: new_template_address,
+                        details,
+                    })?;
+
+                if let Some(migrate_fn) = new_template_def.get_function(MIGRATE_FUNCTION_NAME) {
+                    if migrate_fn.is_method() || migrate_fn.arguments.len() != 1 {
+                        return Err(RuntimeError::IncompatibleTemplateUpgrade {
+                            from: current_template_address,
+                            to: new_template_address,
+                            details: format!(
+                                "the {MIGRATE_FUNCTION_NAME} function must take the previous component state as its \
+                                 only argument"
+                            ),
+                        });
+                    }
+                }
+
+                // The component method writes its state back when it returns, so the upgrade is applied once the
+                // current call frame is popped
+                self.tracker.write_with(|state| {
+                    state
+                        .current_call_scope_mut()?
+                        .set_pending_template_upgrade(new_template_address);
+                    Ok::<_, RuntimeError>(())
+                })?;
+
+                Ok(InvokeResult::unit())
+            },
         }
     }
 
@@ -754,6 +988,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         match action {
             ResourceAction::Create => {
                 let arg: CreateResourceArg = args.assert_one_arg()?;
+                validate_resource_access_rules(&arg.access_rules)?;
+                validate_owner_rule(&arg.owner_rule)?;
 
                 if arg
                     .mint_arg
@@ -951,6 +1187,114 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                     ))?)
                 })
             },
+            ResourceAction::Freeze | ResourceAction::Unfreeze => {
+                let is_freeze = matches!(action, ResourceAction::Freeze);
+                let resource_address =
+                    resource_ref
+                        .as_resource_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "resource_ref",
+                            reason: format!("{:?} resource action requires a resource address", action),
+                        })?;
+                let arg: ResourceFreezeVaultArg = args.assert_one_arg()?;
+
+                let (maybe_auth_hook, auth_caller) = self.tracker.write_with(|state_mut| {
+                    let resource_lock =
+                        state_mut.lock_substate(&SubstateId::Resource(resource_address), LockFlag::Read)?;
+
+                    let resource = state_mut.get_resource(&resource_lock)?;
+
+                    state_mut.authorization().check_resource_access_rules(
+                        ResourceAuthAction::Freeze,
+                        resource.as_ownership(),
+                        resource.access_rules(),
+                    )?;
+
+                    let auth_hook = resource.auth_hook().cloned();
+                    let auth_caller = state_mut.get_auth_caller()?;
+
+                    state_mut.unlock_substate(resource_lock)?;
+                    Ok::<_, RuntimeError>((auth_hook, auth_caller))
+                })?;
+
+                if let Some(auth_hook) = maybe_auth_hook {
+                    self.invoke_resource_access_hook(auth_hook, auth_caller, ResourceAuthAction::Freeze)?;
+                }
+
+                self.tracker.write_with(|state| {
+                    let vault_lock = state.lock_substate(&arg.vault_id.into(), LockFlag::Write)?;
+                    let vault_mut = state.get_vault_mut(&vault_lock)?;
+                    if *vault_mut.resource_address() != resource_address {
+                        return Err(RuntimeError::InvalidArgument {
+                            argument: "vault_id",
+                            reason: format!("Vault {} does not contain resource {}", arg.vault_id, resource_address),
+                        });
+                    }
+                    vault_mut.set_frozen(is_freeze);
+                    state.unlock_substate(vault_lock)?;
+
+                    Ok(InvokeResult::unit())
+                })
+            },
+            ResourceAction::Pause | ResourceAction::Unpause => {
+                let is_pause = matches!(action, ResourceAction::Pause);
+                let resource_address =
+                    resource_ref
+                        .as_resource_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "resource_ref",
+                            reason: format!("{:?} resource action requires a resource address", action),
+                        })?;
+                args.assert_no_args(if is_pause {
+                    "ResourceAction::Pause"
+                } else {
+                    "ResourceAction::Unpause"
+                })?;
+
+                let (resource_lock, maybe_auth_hook, auth_caller) = self.tracker.write_with(|state_mut| {
+                    let resource_lock =
+                        state_mut.lock_substate(&SubstateId::Resource(resource_address), LockFlag::Write)?;
+
+                    let resource = state_mut.get_resource(&resource_lock)?;
+
+                    state_mut.authorization().check_resource_access_rules(
+                        ResourceAuthAction::Freeze,
+                        resource.as_ownership(),
+                        resource.access_rules(),
+                    )?;
+
+                    let auth_caller = state_mut.get_auth_caller()?;
+                    Ok::<_, RuntimeError>((resource_lock, resource.auth_hook().cloned(), auth_caller))
+                })?;
+
+                if let Some(auth_hook) = maybe_auth_hook {
+                    self.invoke_resource_access_hook(auth_hook, auth_caller, ResourceAuthAction::Freeze)?;
+                }
+
+                self.tracker.write_with(|state| {
+                    let resource_mut = state.get_resource_mut(&resource_lock)?;
+                    resource_mut.set_paused(is_pause);
+                    state.unlock_substate(resource_lock)?;
+
+                    Ok(InvokeResult::unit())
+                })
+            },
+            ResourceAction::IsPaused => {
+                let resource_address =
+                    resource_ref
+                        .as_resource_address()
+                        .ok_or_else(|| RuntimeError::InvalidArgument {
+                            argument: "resource_ref",
+                            reason: "IsPaused resource action requires a resource address".to_string(),
+                        })?;
+                args.assert_no_args("ResourceAction::IsPaused")?;
+                self.tracker.write_with(|state| {
+                    let locked = state.lock_substate(&SubstateId::Resource(resource_address), LockFlag::Read)?;
+                    let is_paused = state.get_resource(&locked)?.is_paused();
+                    state.unlock_substate(locked)?;
+                    Ok(InvokeResult::encode(&is_paused)?)
+                })
+            },
             ResourceAction::GetNonFungible => {
                 let resource_address =
                     resource_ref
@@ -1046,6 +1390,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                             reason: "UpdateAccessRules resource action requires a resource address".to_string(),
                         })?;
                 let access_rules: ResourceAccessRules = args.assert_one_arg()?;
+                validate_resource_access_rules(&access_rules)?;
 
                 let (resource_lock, maybe_auth_hook, auth_caller) = self.tracker.write_with(|state_mut| {
                     let resource_lock =
@@ -1173,6 +1518,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                             resource.access_rules(),
                         )?;
 
+                        state_mut.check_vault_not_frozen(&vault_lock, &resource_lock)?;
+
                         let auth_caller = state_mut.get_auth_caller()?;
                         Ok::<_, RuntimeError>((vault_lock, resource_lock, resource.auth_hook().cloned(), auth_caller))
                     })?;
@@ -1234,6 +1581,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                             resource.access_rules(),
                         )?;
 
+                        state_mut.check_vault_not_frozen(&vault_lock, &resource_lock)?;
+
                         let auth_caller = state_mut.get_auth_caller()?;
                         Ok::<_, RuntimeError>((vault_lock, resource_lock, resource.auth_hook().cloned(), auth_caller))
                     })?;
@@ -1267,6 +1616,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                         },
                     };
 
+                    state.check_vault_time_locks(&vault_lock)?;
+
                     // Emit a builtin event for the withdraw
                     self.emit_vault_events(
                         VAULT_WITHDRAW_TOPIC,
@@ -1310,7 +1661,13 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
 
                 self.tracker.write_with(|state| {
                     let vault_lock = state.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Read)?;
-                    let balance = state.get_vault(&vault_lock)?.locked_balance();
+                    let vault = state.get_vault(&vault_lock)?;
+                    let balance = if vault.time_locks().is_empty() {
+                        vault.locked_balance()
+                    } else {
+                        let epoch = state.get_current_epoch()?;
+                        vault.total_locked_balance(epoch.as_u64())
+                    };
                     state.unlock_substate(vault_lock)?;
                     Ok(InvokeResult::encode(&balance)?)
                 })
@@ -1384,6 +1741,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                             resource.access_rules(),
                         )?;
 
+                        state_mut.check_vault_not_frozen(&vault_lock, &resource_lock)?;
+
                         let auth_caller = state_mut.get_auth_caller()?;
                         Ok::<_, RuntimeError>((vault_lock, resource_lock, resource.auth_hook().cloned(), auth_caller))
                     })?;
@@ -1442,6 +1801,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                         resource.as_ownership(),
                         resource.access_rules(),
                     )?;
+                    state.check_vault_not_frozen(&vault_lock, &resource_lock)?;
                     let view_key = resource.view_key().cloned();
 
                     let vault_mut = state.get_vault_mut(&vault_lock)?;
@@ -1461,6 +1821,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                             reason: "Fee payment has zero value".to_string(),
                         });
                     }
+                    state.check_vault_time_locks(&vault_lock)?;
 
                     state.pay_fee(container, vault_id)?;
 
@@ -1603,6 +1964,58 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 })
             },
             VaultAction::CreateProofByConfidentialResource => todo!("CreateProofByConfidentialResource"),
+            VaultAction::TimeLock => {
+                let vault_id = vault_ref.vault_id().ok_or_else(|| RuntimeError::InvalidArgument {
+                    argument: "vault_ref",
+                    reason: "TimeLock vault action requires a vault id".to_string(),
+                })?;
+                let arg: VaultTimeLockArg = args.assert_one_arg()?;
+
+                self.tracker.write_with(|state| {
+                    let epoch = state.get_current_epoch()?;
+                    let vault_lock = state.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Write)?;
+                    let vault_mut = state.get_vault_mut(&vault_lock)?;
+                    vault_mut.add_time_lock(arg.lock, epoch.as_u64())?;
+                    state.unlock_substate(vault_lock)?;
+
+                    Ok(InvokeResult::unit())
+                })
+            },
+            VaultAction::GetTimeLocks => {
+                let vault_id = vault_ref.vault_id().ok_or_else(|| RuntimeError::InvalidArgument {
+                    argument: "vault_ref",
+                    reason: "GetTimeLocks vault action requires a vault id".to_string(),
+                })?;
+                args.assert_no_args("Vault::GetTimeLocks")?;
+
+                self.tracker.write_with(|state| {
+                    let epoch = state.get_current_epoch()?;
+                    let vault_lock = state.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Read)?;
+                    let time_locks = state
+                        .get_vault(&vault_lock)?
+                        .time_locks()
+                        .iter()
+                        .filter(|lock| !lock.is_released_at(epoch.as_u64()))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    state.unlock_substate(vault_lock)?;
+                    Ok(InvokeResult::encode(&time_locks)?)
+                })
+            },
+            VaultAction::IsFrozen => {
+                let vault_id = vault_ref.vault_id().ok_or_else(|| RuntimeError::InvalidArgument {
+                    argument: "vault_ref",
+                    reason: "IsFrozen vault action requires a vault id".to_string(),
+                })?;
+                args.assert_no_args("Vault::IsFrozen")?;
+
+                self.tracker.write_with(|state| {
+                    let vault_lock = state.lock_substate(&SubstateId::Vault(vault_id), LockFlag::Read)?;
+                    let is_frozen = state.get_vault(&vault_lock)?.is_frozen();
+                    state.unlock_substate(vault_lock)?;
+                    Ok(InvokeResult::encode(&is_frozen)?)
+                })
+            },
             VaultAction::GetNonFungibles => {
                 let vault_id = vault_ref.vault_id().ok_or_else(|| RuntimeError::InvalidArgument {
                     argument: "vault_ref",
@@ -1684,6 +2097,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 let amount = args.assert_one_arg()?;
 
                 self.tracker.write_with(|state| {
+                    let resource_address = *state.get_bucket(bucket_id)?.resource_address();
+                    state.check_resource_not_paused(&resource_address)?;
                     let bucket = state.get_bucket_mut(bucket_id)?;
                     let resource = bucket.take(amount)?;
                     let bucket_id = state.id_provider()?.new_bucket_id();
@@ -1699,8 +2114,9 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 let proof = args.assert_one_arg()?;
 
                 self.tracker.write_with(|state| {
-                    let bucket = state.get_bucket(bucket_id)?;
-                    let resource_lock = state.lock_substate(&(*bucket.resource_address()).into(), LockFlag::Read)?;
+                    let resource_address = *state.get_bucket(bucket_id)?.resource_address();
+                    state.check_resource_not_paused(&resource_address)?;
+                    let resource_lock = state.lock_substate(&resource_address.into(), LockFlag::Read)?;
                     let resource = state.get_resource(&resource_lock)?;
                     let view_key = resource.view_key().cloned();
                     let bucket_mut = state.get_bucket_mut(bucket_id)?;
@@ -1719,6 +2135,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 let other_bucket_id = args.assert_one_arg()?;
 
                 self.tracker.write_with(|state| {
+                    let resource_address = *state.get_bucket(bucket_id)?.resource_address();
+                    state.check_resource_not_paused(&resource_address)?;
                     let other_bucket = state.take_bucket(other_bucket_id)?;
                     let bucket = state.get_bucket_mut(bucket_id)?;
                     bucket.join(other_bucket)?;
@@ -1732,8 +2150,9 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 })?;
                 let proof = args.assert_one_arg()?;
                 self.tracker.write_with(|state| {
-                    let bucket = state.get_bucket(bucket_id)?;
-                    let resource_lock = state.lock_substate(&(*bucket.resource_address()).into(), LockFlag::Read)?;
+                    let resource_address = *state.get_bucket(bucket_id)?.resource_address();
+                    state.check_resource_not_paused(&resource_address)?;
+                    let resource_lock = state.lock_substate(&resource_address.into(), LockFlag::Read)?;
                     let resource = state.get_resource(&resource_lock)?;
                     let view_key = resource.view_key().cloned();
                     let bucket = state.get_bucket_mut(bucket_id)?;
@@ -1764,6 +2183,12 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                         resource.access_rules(),
                     )?;
 
+                    if resource.is_paused() {
+                        return Err(RuntimeError::ResourcePaused {
+                            resource_address: *state_mut.get_bucket(bucket_id)?.resource_address(),
+                        });
+                    }
+
                     let auth_caller = state_mut.get_auth_caller()?;
                     Ok::<_, RuntimeError>((resource_lock, resource.auth_hook().cloned(), auth_caller))
                 })?;
@@ -2127,6 +2552,14 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
                 let epoch = self.tracker.get_current_epoch()?;
                 Ok(InvokeResult::encode(&epoch)?)
             },
+            ConsensusAction::GetCurrentTime => {
+                let block_info = self.tracker.get_current_block()?;
+                Ok(InvokeResult::encode(&block_info.timestamp)?)
+            },
+            ConsensusAction::GetCurrentBlock => {
+                let block_info = self.tracker.get_current_block()?;
+                Ok(InvokeResult::encode(&block_info)?)
+            },
         }
     }
 
@@ -2259,6 +2692,106 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         Ok(())
     }
 
+    fn evaluate_assertion(&self, assertion: Assertion) -> Result<(), RuntimeError> {
+        self.invoke_modules_on_runtime_call("evaluate_assertion")?;
+
+        debug!(target: LOG_TARGET, "Evaluate assertion: {}", assertion);
+
+        match assertion {
+            Assertion::VaultBalanceAtLeast { vault_id, min_amount } => {
+                let balance = self.get_vault_balance(vault_id)?;
+                if balance < min_amount {
+                    return Err(AssertError::VaultBalanceTooLow {
+                        vault_id,
+                        min_amount,
+                        balance,
+                    }
+                    .into());
+                }
+            },
+            Assertion::VaultBalanceAtMost { vault_id, max_amount } => {
+                let balance = self.get_vault_balance(vault_id)?;
+                if balance > max_amount {
+                    return Err(AssertError::VaultBalanceTooHigh {
+                        vault_id,
+                        max_amount,
+                        balance,
+                    }
+                    .into());
+                }
+            },
+            Assertion::BucketContainsNonFungible { key, non_fungible_id } => {
+                let value = self.tracker.get_from_workspace(&key)?;
+                let bucket_id = value
+                    .bucket_ids()
+                    .first()
+                    .ok_or(RuntimeError::AssertError(AssertError::InvalidBucket))?;
+
+                self.tracker.read_with(|state| {
+                    let bucket = state.get_bucket(*bucket_id)?;
+                    if !bucket.non_fungible_ids().contains(&non_fungible_id) {
+                        return Err(AssertError::NonFungibleNotInBucket { id: non_fungible_id }.into());
+                    }
+                    Ok::<_, RuntimeError>(())
+                })?;
+            },
+            Assertion::WorkspaceValueEquals { key, value } => {
+                let expected: tari_bor::Value = decode_exact(&value)?;
+                let actual = self.tracker.get_from_workspace(&key)?;
+                if *actual.value() != expected {
+                    return Err(AssertError::WorkspaceValueMismatch {
+                        key: String::from_utf8_lossy(&key).to_string(),
+                    }
+                    .into());
+                }
+            },
+            Assertion::ComponentStateEquals {
+                component_address,
+                path,
+                value,
+            } => {
+                let expected: tari_bor::Value = decode_exact(&value)?;
+                self.tracker.write_with(|state| {
+                    let lock = state.lock_substate(&SubstateId::Component(component_address), LockFlag::Read)?;
+                    let component = state.get_component(&lock)?;
+                    let result = match get_value_by_path(component.state(), &path) {
+                        Some(actual) if *actual == expected => Ok(()),
+                        Some(_) => Err(AssertError::ComponentStateMismatch {
+                            component_address,
+                            path,
+                        }),
+                        None => Err(AssertError::ComponentStatePathNotFound {
+                            component_address,
+                            path,
+                        }),
+                    };
+                    state.unlock_substate(lock)?;
+                    result.map_err(RuntimeError::from)
+                })?;
+            },
+        }
+
+        Ok(())
+    }
+
+    fn begin_loop_iteration(&self) -> Result<(), RuntimeError> {
+        // Each iteration is charged as a runtime call so that the cost of a loop grows with the number of iterations
+        self.invoke_modules_on_runtime_call("begin_loop_iteration")
+    }
+
+    fn begin_instruction(&self) -> Result<(), RuntimeError> {
+        // Subsequent fee charges are attributed to this instruction in the fee breakdown
+        self.tracker.begin_instruction();
+        Ok(())
+    }
+
+    fn record_wasm_execution(&self, points_used: u64, memory_pages_grown: u32) -> Result<(), RuntimeError> {
+        for module in &self.modules {
+            module.on_wasm_executed(&self.tracker, points_used, memory_pages_grown)?;
+        }
+        Ok(())
+    }
+
     fn set_fee_checkpoint(&self) -> Result<(), RuntimeError> {
         if self.tracker.total_fee_payments() < self.tracker.total_fee_charges() {
             return Err(RuntimeError::InsufficientFeesPaid {
@@ -2275,6 +2808,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
     }
 
     fn finalize(&self) -> Result<FinalizeResult, RuntimeError> {
+        // Finalization costs are not attributed to the last instruction
+        self.tracker.end_instructions();
         self.invoke_modules_on_runtime_call("finalize")?;
 
         // If the fee module is present, this will add substate storage fees
@@ -2310,6 +2845,15 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
         })
     }
 
+    fn replace_base_auth_scope(&self, auth_scope: AuthorizationScope) -> Result<AuthorizationScope, RuntimeError> {
+        self.tracker
+            .write_with(|state| state.replace_base_auth_scope(auth_scope))
+    }
+
+    fn use_sub_intent(&self, intent_id: Hash) -> Result<(), RuntimeError> {
+        self.tracker.write_with(|state| state.use_sub_intent(intent_id))
+    }
+
     fn check_component_access_rules(&self, method: &str, locked: &LockedSubstate) -> Result<(), RuntimeError> {
         self.tracker
             .read_with(|state| state.authorization().check_component_access_rules(method, locked))
@@ -2326,6 +2870,12 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
     }
 
     fn pop_call_frame(&self) -> Result<(), RuntimeError> {
+        let pending_upgrade = self.tracker.write_with(|state| {
+            Ok::<_, RuntimeError>(state.current_call_scope_mut()?.take_pending_template_upgrade())
+        })?;
+        if let Some(template_address) = pending_upgrade {
+            self.apply_template_upgrade(template_address)?;
+        }
         self.tracker.pop_call_frame()?;
         Ok(())
     }
@@ -2337,6 +2887,7 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate>> RuntimeInte
             BuiltinTemplateAction::GetTemplateAddress { bultin } => match bultin {
                 BuiltinTemplate::Account => ACCOUNT_TEMPLATE_ADDRESS,
                 BuiltinTemplate::AccountNft => ACCOUNT_NFT_TEMPLATE_ADDRESS,
+                BuiltinTemplate::MultisigAccount => MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
             },
         };
 
@@ -2358,3 +2909,64 @@ fn validate_component_access_rule_methods(
     }
     Ok(())
 }
+
+fn validate_component_access_rules(access_rules: &ComponentAccessRules) -> Result<(), RuntimeError> {
+    access_rules
+        .method_access_rules_iter()
+        .map(|(_, rule)| rule)
+        .chain([access_rules.default_access_rule()])
+        .try_for_each(validate_access_rule)
+}
+
+fn validate_resource_access_rules(access_rules: &ResourceAccessRules) -> Result<(), RuntimeError> {
+    [
+        ResourceAuthAction::Mint,
+        ResourceAuthAction::Burn,
+        ResourceAuthAction::Recall,
+        ResourceAuthAction::Freeze,
+        ResourceAuthAction::Withdraw,
+        ResourceAuthAction::Deposit,
+        ResourceAuthAction::UpdateNonFungibleData,
+    ]
+    .iter()
+    .try_for_each(|action| validate_access_rule(access_rules.get_access_rule(action)))
+}
+
+fn validate_owner_rule(owner_rule: &OwnerRule) -> Result<(), RuntimeError> {
+    match owner_rule {
+        OwnerRule::ByAccessRule(rule) => validate_access_rule(rule),
+        OwnerRule::OwnedBySigner | OwnerRule::None | OwnerRule::ByPublicKey(_) => Ok(()),
+    }
+}
+
+fn validate_access_rule(rule: &AccessRule) -> Result<(), RuntimeError> {
+    match rule {
+        AccessRule::Restricted(rule) => validate_restricted_access_rule(rule),
+        AccessRule::AllowAll | AccessRule::DenyAll => Ok(()),
+    }
+}
+
+fn validate_restricted_access_rule(rule: &RestrictedAccessRule) -> Result<(), RuntimeError> {
+    match rule {
+        RestrictedAccessRule::Require(rule) => validate_require_rule(rule),
+        RestrictedAccessRule::AnyOf(rules) | RestrictedAccessRule::AllOf(rules) => {
+            rules.iter().try_for_each(validate_restricted_access_rule)
+        },
+    }
+}
+
+fn validate_require_rule(rule: &RequireRule) -> Result<(), RuntimeError> {
+    match rule {
+        // A zero threshold is met without any proofs, which would silently allow anyone access
+        RequireRule::MOfN { threshold: 0, .. } | RequireRule::Weighted { threshold: 0, .. } => {
+            Err(RuntimeError::InvalidAccessRule {
+                details: "The threshold of a threshold rule must be greater than zero".to_string(),
+            })
+        },
+        RequireRule::Require(_) |
+        RequireRule::AnyOf(_) |
+        RequireRule::AllOf(_) |
+        RequireRule::MOfN { .. } |
+        RequireRule::Weighted { .. } => Ok(()),
+    }
+}
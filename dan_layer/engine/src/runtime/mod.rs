@@ -47,7 +47,7 @@ pub use locking::{LockError, LockState};
 mod address_allocation;
 mod state_store;
 mod tracker_auth;
-mod utils;
+pub(crate) mod utils;
 mod working_state;
 mod workspace;
 
@@ -57,6 +57,7 @@ use tari_bor::decode_exact;
 use tari_common_types::types::PublicKey;
 use tari_dan_common_types::Epoch;
 use tari_engine_types::{
+    assertion::Assertion,
     commit_result::FinalizeResult,
     component::ComponentHeader,
     confidential::ConfidentialClaim,
@@ -88,6 +89,7 @@ use tari_template_lib::{
     },
     invoke_args,
     models::{ComponentAddress, EntityId, Metadata, NonFungibleAddress, VaultRef},
+    Hash,
 };
 pub use tracker::StateTracker;
 
@@ -159,10 +161,20 @@ pub trait RuntimeInterface: Send + Sync {
 
     fn claim_validator_fees(&self, epoch: Epoch, validator_public_key: PublicKey) -> Result<(), RuntimeError>;
 
+    fn evaluate_assertion(&self, assertion: Assertion) -> Result<(), RuntimeError>;
+
+    fn begin_loop_iteration(&self) -> Result<(), RuntimeError>;
+
+    fn begin_instruction(&self) -> Result<(), RuntimeError>;
+
+    fn record_wasm_execution(&self, points_used: u64, memory_pages_grown: u32) -> Result<(), RuntimeError>;
+
     fn set_fee_checkpoint(&self) -> Result<(), RuntimeError>;
     fn reset_to_fee_checkpoint(&self) -> Result<(), RuntimeError>;
     fn finalize(&self) -> Result<FinalizeResult, RuntimeError>;
     fn validate_finalized(&self) -> Result<(), RuntimeError>;
+    fn replace_base_auth_scope(&self, auth_scope: AuthorizationScope) -> Result<AuthorizationScope, RuntimeError>;
+    fn use_sub_intent(&self, intent_id: Hash) -> Result<(), RuntimeError>;
 
     fn caller_context_invoke(
         &self,
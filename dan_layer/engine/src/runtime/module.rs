@@ -12,6 +12,17 @@ pub trait RuntimeModule: Send + Sync {
         Ok(())
     }
 
+    /// Called after a template function has been executed in the WASM VM with the number of metering points used and
+    /// the number of pages that the memory grew by
+    fn on_wasm_executed(
+        &self,
+        _track: &StateTracker,
+        _points_used: u64,
+        _memory_pages_grown: u32,
+    ) -> Result<(), RuntimeModuleError> {
+        Ok(())
+    }
+
     fn on_before_finalize(&self, _track: &StateTracker) -> Result<(), RuntimeModuleError> {
         Ok(())
     }
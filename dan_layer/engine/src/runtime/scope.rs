@@ -27,6 +27,7 @@ pub struct CallScope {
     proof_scope: IndexSet<ProofId>,
     bucket_scope: IndexSet<BucketId>,
     auth_scope: AuthorizationScope,
+    pending_template_upgrade: Option<TemplateAddress>,
 }
 
 impl CallScope {
@@ -41,6 +42,7 @@ impl CallScope {
             proof_scope: IndexSet::new(),
             bucket_scope: IndexSet::new(),
             auth_scope: AuthorizationScope::new(vec![]),
+            pending_template_upgrade: None,
         }
     }
 
@@ -125,6 +127,15 @@ impl CallScope {
         self.component_lock.as_ref()
     }
 
+    /// Sets the template that the current component is upgraded to once the current call returns
+    pub fn set_pending_template_upgrade(&mut self, template_address: TemplateAddress) {
+        self.pending_template_upgrade = Some(template_address);
+    }
+
+    pub fn take_pending_template_upgrade(&mut self) -> Option<TemplateAddress> {
+        self.pending_template_upgrade.take()
+    }
+
     pub fn owned_nodes(&self) -> &IndexSet<SubstateId> {
         &self.owned
     }
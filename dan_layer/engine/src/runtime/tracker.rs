@@ -44,7 +44,15 @@ use tari_engine_types::{
 use tari_template_lib::{
     auth::{ComponentAccessRules, OwnerRule},
     crypto::RistrettoPublicKeyBytes,
-    models::{AddressAllocation, Amount, BucketId, ComponentAddress, Metadata, UnclaimedConfidentialOutputAddress},
+    models::{
+        AddressAllocation,
+        Amount,
+        BlockInfo,
+        BucketId,
+        ComponentAddress,
+        Metadata,
+        UnclaimedConfidentialOutputAddress,
+    },
     Hash,
 };
 
@@ -89,6 +97,10 @@ impl StateTracker {
         self.read_with(|state| state.get_current_epoch())
     }
 
+    pub fn get_current_block(&self) -> Result<BlockInfo, RuntimeError> {
+        self.read_with(|state| state.get_current_block())
+    }
+
     pub fn get_pseudorandom_bytes(&self, length: usize) -> Result<Vec<u8>, RuntimeError> {
         self.read_with(|state| {
             let id_provider = state.id_provider()?;
@@ -269,10 +281,27 @@ impl StateTracker {
 
         self.write_with(|state| {
             debug!(target: LOG_TARGET, "Add fee: source: {:?}, amount: {}", source, amount);
-            state.fee_state_mut().fee_charges.insert(source, amount);
+            // There is no call frame before initialization and after finalization
+            let current_component = state.current_component().ok().flatten();
+            let fee_state = state.fee_state_mut();
+            fee_state.fee_charges.insert(source, amount);
+            if let Some(index) = fee_state.current_instruction() {
+                fee_state.fee_charges.insert_for_instruction(index, amount);
+            }
+            if let Some(component_address) = current_component {
+                fee_state.fee_charges.insert_for_component(component_address, amount);
+            }
         })
     }
 
+    pub fn begin_instruction(&self) {
+        self.write_with(|state| state.fee_state_mut().begin_instruction());
+    }
+
+    pub fn end_instructions(&self) {
+        self.write_with(|state| state.fee_state_mut().end_instructions());
+    }
+
     pub fn finalize(
         &self,
         mut substates_to_persist: IndexMap<SubstateId, SubstateValue>,
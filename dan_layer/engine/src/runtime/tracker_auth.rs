@@ -10,6 +10,7 @@ use tari_template_lib::auth::{
     ResourceAuthAction,
     RestrictedAccessRule,
     RuleRequirement,
+    WeightedRequirement,
 };
 
 use crate::runtime::{
@@ -196,6 +197,46 @@ fn check_require_rule(
 
             Ok(true)
         },
+        RequireRule::MOfN {
+            threshold,
+            requirements,
+        } => {
+            let mut num_met = 0u32;
+            for (i, requirement) in requirements.iter().enumerate() {
+                if num_met >= *threshold {
+                    break;
+                }
+                // A requirement that is listed more than once is only counted once
+                if requirements[..i].contains(requirement) {
+                    continue;
+                }
+                if check_requirement(state, scope, requirement)? {
+                    num_met += 1;
+                }
+            }
+
+            Ok(num_met >= *threshold)
+        },
+        RequireRule::Weighted {
+            threshold,
+            requirements,
+        } => {
+            let mut total_weight = 0u64;
+            for (i, WeightedRequirement { requirement, weight }) in requirements.iter().enumerate() {
+                if total_weight >= u64::from(*threshold) {
+                    break;
+                }
+                // A requirement that is listed more than once only contributes the weight of its first entry
+                if requirements[..i].iter().any(|r| r.requirement == *requirement) {
+                    continue;
+                }
+                if check_requirement(state, scope, requirement)? {
+                    total_weight += u64::from(*weight);
+                }
+            }
+
+            Ok(total_weight >= u64::from(*threshold))
+        },
     }
 }
 
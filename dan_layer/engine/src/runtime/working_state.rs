@@ -27,7 +27,7 @@ use tari_engine_types::{
     resource::Resource,
     resource_container::{ResourceContainer, ResourceError},
     substate::{Substate, SubstateDiff, SubstateId, SubstateValue},
-    transaction_receipt::TransactionReceipt,
+    transaction_receipt::{TransactionReceipt, TransactionReceiptAddress},
     vault::Vault,
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
     TemplateAddress,
@@ -38,10 +38,12 @@ use tari_template_lib::{
     models::{
         AddressAllocation,
         Amount,
+        BlockInfo,
         BucketId,
         ComponentAddress,
         NonFungibleAddress,
         ProofId,
+        ResourceAddress,
         UnclaimedConfidentialOutputAddress,
         VaultId,
     },
@@ -59,6 +61,7 @@ use crate::{
         state_store::WorkingStateStore,
         tracker_auth::Authorization,
         ActionIdent,
+        AuthorizationScope,
         RuntimeError,
         TransactionCommitError,
     },
@@ -81,6 +84,7 @@ pub(super) struct WorkingState {
     store: WorkingStateStore,
 
     claimed_confidential_outputs: Vec<UnclaimedConfidentialOutputAddress>,
+    used_sub_intents: Vec<Hash>,
     virtual_substates: VirtualSubstates,
 
     last_instruction_output: Option<IndexedValue>,
@@ -110,6 +114,7 @@ impl WorkingState {
             store: WorkingStateStore::new(state_store),
 
             claimed_confidential_outputs: Vec::new(),
+            used_sub_intents: Vec::new(),
             last_instruction_output: None,
 
             workspace: Workspace::default(),
@@ -250,6 +255,17 @@ impl WorkingState {
         Ok(())
     }
 
+    /// Marks a sub-intent as used by this transaction. A receipt for the sub-intent is included in the substate diff,
+    /// so that any other transaction that includes the sub-intent fails to lock the receipt as an output.
+    pub fn use_sub_intent(&mut self, intent_id: Hash) -> Result<(), RuntimeError> {
+        let receipt_address = SubstateId::TransactionReceipt(TransactionReceiptAddress::from_sub_intent_id(&intent_id));
+        if self.used_sub_intents.contains(&intent_id) || self.substate_exists(&receipt_address)? {
+            return Err(RuntimeError::SubIntentAlreadyUsed { intent_id });
+        }
+        self.used_sub_intents.push(intent_id);
+        Ok(())
+    }
+
     pub fn get_locked_substate(&self, lock: &LockedSubstate) -> Result<&SubstateValue, RuntimeError> {
         let (_, substate) = self.store.get_locked_substate(lock.lock_id())?;
         Ok(substate)
@@ -267,6 +283,57 @@ impl WorkingState {
         Ok(vault)
     }
 
+    /// Checks that a vault still holds all of its time locked tokens. This must be called after withdrawing from a
+    /// vault.
+    pub fn check_vault_time_locks(&self, locked: &LockedSubstate) -> Result<(), RuntimeError> {
+        let vault = self.get_vault(locked)?;
+        if vault.time_locks().is_empty() {
+            return Ok(());
+        }
+        let epoch = self.get_current_epoch()?;
+        vault.check_time_locks(epoch.as_u64())?;
+        Ok(())
+    }
+
+    /// Checks that tokens can be moved in or out of a vault i.e. the vault is not frozen and the resource is not paused
+    pub fn check_vault_not_frozen(
+        &self,
+        vault_lock: &LockedSubstate,
+        resource_lock: &LockedSubstate,
+    ) -> Result<(), RuntimeError> {
+        let resource = self.get_resource(resource_lock)?;
+        let vault = self.get_vault(vault_lock)?;
+        if resource.is_paused() {
+            return Err(RuntimeError::ResourcePaused {
+                resource_address: *vault.resource_address(),
+            });
+        }
+        if vault.is_frozen() {
+            let vault_id = vault_lock
+                .address()
+                .as_vault_id()
+                .ok_or_else(|| RuntimeError::InvariantError {
+                    function: "check_vault_not_frozen",
+                    details: format!("Expected a vault lock but got {}", vault_lock.address()),
+                })?;
+            return Err(RuntimeError::VaultFrozen { vault_id });
+        }
+        Ok(())
+    }
+
+    /// Checks that the resource is not paused
+    pub fn check_resource_not_paused(&mut self, resource_address: &ResourceAddress) -> Result<(), RuntimeError> {
+        let resource_lock = self.lock_substate(&SubstateId::Resource(*resource_address), LockFlag::Read)?;
+        let is_paused = self.get_resource(&resource_lock)?.is_paused();
+        self.unlock_substate(resource_lock)?;
+        if is_paused {
+            return Err(RuntimeError::ResourcePaused {
+                resource_address: *resource_address,
+            });
+        }
+        Ok(())
+    }
+
     pub fn get_vault_mut(&mut self, locked: &LockedSubstate) -> Result<&mut Vault, RuntimeError> {
         let (addr, substate) = self.store.get_locked_substate_mut(locked.lock_id())?;
 
@@ -309,6 +376,20 @@ impl WorkingState {
         Ok(Epoch(*epoch))
     }
 
+    pub fn get_current_block(&self) -> Result<BlockInfo, RuntimeError> {
+        let address = VirtualSubstateId::CurrentBlock;
+        let current_block =
+            self.virtual_substates
+                .get(&address)
+                .ok_or_else(|| RuntimeError::VirtualSubstateNotFound {
+                    address: address.clone(),
+                })?;
+        let VirtualSubstate::CurrentBlock(block_info) = current_block else {
+            return Err(RuntimeError::VirtualSubstateNotFound { address });
+        };
+        Ok(*block_info)
+    }
+
     pub(super) fn validate_finalized(&self) -> Result<(), RuntimeError> {
         if !self.buckets.is_empty() {
             return Err(TransactionCommitError::DanglingBuckets {
@@ -1013,6 +1094,24 @@ impl WorkingState {
         &self.initial_call_scope
     }
 
+    /// Replaces the authorization scope of the base call scope and returns the previous scope. The base authorization
+    /// scope applies to all top-level instructions, so this may only be called between instructions.
+    pub fn replace_base_auth_scope(
+        &mut self,
+        auth_scope: AuthorizationScope,
+    ) -> Result<AuthorizationScope, RuntimeError> {
+        if self.call_frame_depth() > 0 {
+            return Err(RuntimeError::InvariantError {
+                function: "replace_base_auth_scope",
+                details: format!(
+                    "The base authorization scope cannot be replaced at call depth {}",
+                    self.call_frame_depth()
+                ),
+            });
+        }
+        Ok(mem::replace(self.initial_call_scope.auth_scope_mut(), auth_scope))
+    }
+
     pub fn take_state(&mut self) -> Self {
         let new_state = WorkingState::new(
             self.store.state_store().clone(),
@@ -1146,6 +1245,22 @@ impl WorkingState {
             substate_diff.down(SubstateId::UnclaimedConfidentialOutput(*claimed), 0);
         }
 
+        // A sub-intent receipt only records the transaction that used the sub-intent
+        for intent_id in &self.used_sub_intents {
+            substate_diff.up(
+                SubstateId::TransactionReceipt(TransactionReceiptAddress::from_sub_intent_id(intent_id)),
+                Substate::new(
+                    0,
+                    SubstateValue::TransactionReceipt(TransactionReceipt {
+                        transaction_hash: transaction_receipt.transaction_hash,
+                        events: vec![],
+                        logs: vec![],
+                        fee_receipt: FeeReceipt::default(),
+                    }),
+                ),
+            );
+        }
+
         substate_diff.up(
             SubstateId::TransactionReceipt(transaction_receipt.transaction_hash.into()),
             Substate::new(0, SubstateValue::TransactionReceipt(transaction_receipt)),
@@ -21,7 +21,7 @@
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use tari_engine_types::indexed_value::IndexedValueError;
-use tari_template_lib::models::TemplateAddress;
+use tari_template_lib::models::{ComponentAddress, TemplateAddress};
 
 use crate::{runtime::RuntimeError, wasm::WasmExecutionError};
 
@@ -43,6 +43,12 @@ pub enum TransactionError {
     ValueVisitorError(#[from] IndexedValueError),
     #[error("Function {name} not found")]
     FunctionNotFound { name: String },
+    #[error("Component {component_address} is not a fee sponsor")]
+    NotAFeeSponsor { component_address: ComponentAddress },
+    #[error("Fee sponsor {component_address} must be specified in the fee instructions")]
+    SponsorFeeOutsideFeeInstructions { component_address: ComponentAddress },
+    #[error("Instructions are nested {depth} levels deep which exceeds the maximum of {max_depth}")]
+    InstructionNestingTooDeep { depth: usize, max_depth: usize },
     #[error("Invariant error: {details}")]
     InvariantError { details: String },
 }
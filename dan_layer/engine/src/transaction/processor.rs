@@ -28,16 +28,17 @@ use tari_common::configuration::Network;
 use tari_common_types::types::PublicKey;
 use tari_dan_common_types::{services::template_provider::TemplateProvider, Epoch};
 use tari_engine_types::{
+    assertion::Assertion,
     commit_result::{ExecuteResult, FinalizeResult, RejectReason, TransactionResult},
     component::new_component_address_from_public_key,
     entity_id_provider::EntityIdProvider,
-    indexed_value::IndexedWellKnownTypes,
-    instruction::Instruction,
+    indexed_value::{IndexedValue, IndexedWellKnownTypes},
+    instruction::{Instruction, MAX_INSTRUCTION_NESTING_DEPTH},
     instruction_result::InstructionResult,
     lock::LockFlag,
     virtual_substate::VirtualSubstates,
 };
-use tari_template_abi::FunctionDef;
+use tari_template_abi::{ArgDef, FunctionDef, Type, FEE_SPONSOR_FUNCTION_NAME, MIGRATE_FUNCTION_NAME};
 use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
 use tari_template_lib::{
     arg,
@@ -49,15 +50,17 @@ use tari_template_lib::{
     models::{Bucket, ComponentAddress, NonFungibleAddress},
     prelude::{AccessRules, TemplateAddress},
 };
-use tari_transaction::Transaction;
+use tari_transaction::{SubIntent, Transaction};
 use tari_utilities::ByteArray;
 
 use crate::{
     runtime::{
         scope::{CallScope, PushCallFrame},
+        utils::to_ristretto_public_key_bytes,
         AuthParams,
         AuthorizationScope,
         Runtime,
+        RuntimeError,
         RuntimeInterfaceImpl,
         RuntimeModule,
         StateTracker,
@@ -147,8 +150,25 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
         let runtime = Runtime::new(Arc::new(runtime_interface));
         let transaction_hash = transaction.hash();
 
+        let sub_intents = transaction.sub_intents().to_vec();
         let (fee_instructions, instructions) = transaction.into_instructions();
 
+        let non_fee_instructions = sub_intents
+            .iter()
+            .flat_map(|sub_intent| sub_intent.instructions())
+            .chain(&instructions);
+        let check_result = Self::check_nesting_depth(fee_instructions.iter().chain(non_fee_instructions.clone()))
+            .and_then(|_| Self::check_no_fee_sponsorship(non_fee_instructions));
+        if let Err(err) = check_result {
+            return Ok(ExecuteResult {
+                finalize: FinalizeResult::new_rejected(
+                    transaction_hash,
+                    RejectReason::ExecutionFailure(err.to_string()),
+                ),
+                execution_time: timer.elapsed(),
+            });
+        }
+
         let fee_exec_results = Self::process_instructions(&template_provider, &runtime, fee_instructions);
 
         let fee_exec_result = match fee_exec_results {
@@ -177,7 +197,8 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
             },
         };
 
-        let instruction_result = Self::process_instructions(&*template_provider, &runtime, instructions);
+        let instruction_result =
+            Self::process_sub_intents_and_instructions(&*template_provider, &runtime, sub_intents, instructions);
 
         match instruction_result {
             Ok(execution_results) => {
@@ -216,6 +237,51 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
         }
     }
 
+    fn process_sub_intents_and_instructions(
+        template_provider: &TTemplateProvider,
+        runtime: &Runtime,
+        sub_intents: Vec<SubIntent>,
+        instructions: Vec<Instruction>,
+    ) -> Result<Vec<InstructionResult>, TransactionError> {
+        let mut results = Vec::new();
+        for sub_intent in sub_intents {
+            results.extend(Self::process_sub_intent(template_provider, runtime, sub_intent)?);
+        }
+        results.extend(Self::process_instructions(template_provider, runtime, instructions)?);
+        Ok(results)
+    }
+
+    /// Executes the instructions of a sub-intent. Only the signers of the sub-intent are authorized for the duration of
+    /// these instructions. The workspace is shared with the rest of the transaction, so that a sub-intent can take and
+    /// place buckets for other sub-intents and the transaction instructions.
+    fn process_sub_intent(
+        template_provider: &TTemplateProvider,
+        runtime: &Runtime,
+        sub_intent: SubIntent,
+    ) -> Result<Vec<InstructionResult>, TransactionError> {
+        runtime.interface().use_sub_intent(*sub_intent.intent_id())?;
+        let signer_proofs = sub_intent
+            .signatures()
+            .iter()
+            .map(|sig| to_ristretto_public_key_bytes(sig.public_key()).to_non_fungible_address())
+            .collect();
+        let transaction_auth_scope = runtime
+            .interface()
+            .replace_base_auth_scope(AuthorizationScope::new(signer_proofs))?;
+
+        let result = sub_intent
+            .into_instructions()
+            .into_iter()
+            .map(|instruction| {
+                runtime.interface().begin_instruction()?;
+                Self::process_instruction(template_provider, runtime, instruction)
+            })
+            .collect();
+
+        runtime.interface().replace_base_auth_scope(transaction_auth_scope)?;
+        result
+    }
+
     fn process_instructions(
         template_provider: &TTemplateProvider,
         runtime: &Runtime,
@@ -223,7 +289,10 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
     ) -> Result<Vec<InstructionResult>, TransactionError> {
         let result: Result<_, _> = instructions
             .into_iter()
-            .map(|instruction| Self::process_instruction(template_provider, runtime, instruction))
+            .map(|instruction| {
+                runtime.interface().begin_instruction()?;
+                Self::process_instruction(template_provider, runtime, instruction)
+            })
             .collect();
 
         // check that the finalized state is valid
@@ -303,9 +372,100 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
                 )?;
                 Ok(InstructionResult::empty())
             },
+            Instruction::Assert { assertion } => {
+                runtime.interface().evaluate_assertion(assertion)?;
+                Ok(InstructionResult::empty())
+            },
+            Instruction::If {
+                condition,
+                then_instructions,
+                else_instructions,
+            } => {
+                let instructions = if Self::evaluate_condition(runtime, condition)? {
+                    then_instructions
+                } else {
+                    else_instructions
+                };
+                Self::process_nested_instructions(template_provider, runtime, instructions)?;
+                Ok(InstructionResult::empty())
+            },
+            Instruction::ForEach {
+                key,
+                item_key,
+                max_iterations,
+                instructions,
+            } => {
+                Self::for_each(template_provider, runtime, key, item_key, max_iterations, instructions)?;
+                Ok(InstructionResult::empty())
+            },
+            Instruction::SponsorFee { component_address } => {
+                Self::sponsor_fee(template_provider, runtime, &component_address)
+            },
+        }
+    }
+
+    fn process_nested_instructions(
+        template_provider: &TTemplateProvider,
+        runtime: &Runtime,
+        instructions: Vec<Instruction>,
+    ) -> Result<(), TransactionError> {
+        for instruction in instructions {
+            Self::process_instruction(template_provider, runtime, instruction)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if the condition holds. A failed assertion is not an error, but any other error is returned.
+    fn evaluate_condition(runtime: &Runtime, condition: Assertion) -> Result<bool, TransactionError> {
+        match runtime.interface().evaluate_assertion(condition) {
+            Ok(()) => Ok(true),
+            Err(RuntimeError::AssertError(err)) => {
+                debug!(target: LOG_TARGET, "Condition does not hold: {}", err);
+                Ok(false)
+            },
+            Err(err) => Err(err.into()),
         }
     }
 
+    fn for_each(
+        template_provider: &TTemplateProvider,
+        runtime: &Runtime,
+        key: Vec<u8>,
+        item_key: Vec<u8>,
+        max_iterations: u32,
+        instructions: Vec<Instruction>,
+    ) -> Result<(), TransactionError> {
+        let value = runtime
+            .interface()
+            .workspace_invoke(WorkspaceAction::Get, invoke_args![key].into())?
+            .into_value()?;
+        let tari_bor::Value::Array(items) = value else {
+            return Err(RuntimeError::ItemOnWorkspaceNotIterable {
+                key: String::from_utf8_lossy(&key).to_string(),
+            }
+            .into());
+        };
+        if items.len() > max_iterations as usize {
+            return Err(RuntimeError::MaxIterationsExceeded {
+                key: String::from_utf8_lossy(&key).to_string(),
+                num_items: items.len(),
+                max_iterations,
+            }
+            .into());
+        }
+
+        for item in items {
+            runtime.interface().begin_loop_iteration()?;
+            runtime
+                .interface()
+                .set_last_instruction_output(IndexedValue::from_value(item)?)?;
+            Self::put_output_on_workspace_with_name(runtime, item_key.clone())?;
+            Self::process_nested_instructions(template_provider, runtime, instructions.clone())?;
+        }
+
+        Ok(())
+    }
+
     pub fn put_output_on_workspace_with_name(runtime: &Runtime, key: Vec<u8>) -> Result<(), TransactionError> {
         runtime
             .interface()
@@ -443,6 +603,63 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
         Ok(result)
     }
 
+    /// Calls the `migrate` function of the template with the previous state of a component that is being upgraded and
+    /// returns the new state. Templates that do not define a `migrate` function get a default one from the template
+    /// macro that decodes the previous state as the new component state, so this fails if the state is not compatible.
+    pub fn migrate_component_state(
+        template_provider: &TTemplateProvider,
+        runtime: &Runtime,
+        template_address: &TemplateAddress,
+        previous_state: tari_bor::Value,
+    ) -> Result<InstructionResult, TransactionError> {
+        let template = template_provider
+            .get_template_module(template_address)
+            .map_err(|e| TransactionError::FailedToLoadTemplate {
+                address: *template_address,
+                details: e.to_string(),
+            })?
+            .ok_or(TransactionError::TemplateNotFound {
+                address: *template_address,
+            })?;
+
+        let function_def = template
+            .template_def()
+            .get_function(MIGRATE_FUNCTION_NAME)
+            .cloned()
+            .unwrap_or_else(|| FunctionDef {
+                name: MIGRATE_FUNCTION_NAME.to_string(),
+                arguments: vec![ArgDef {
+                    name: "previous".to_string(),
+                    arg_type: Type::Other {
+                        name: "Self".to_string(),
+                    },
+                }],
+                output: Type::Other {
+                    name: "Self".to_string(),
+                },
+                is_mut: false,
+            });
+
+        let arg_scope = IndexedWellKnownTypes::from_value(&previous_state)?;
+
+        runtime.interface().push_call_frame(PushCallFrame::Static {
+            template_address: *template_address,
+            module_name: template.template_name().to_string(),
+            arg_scope,
+            entity_id: runtime.interface().next_entity_id()?,
+        })?;
+
+        let result = Self::invoke_template(template, template_provider, runtime.clone(), function_def, vec![
+            previous_state,
+        ])?;
+
+        runtime.interface().validate_return_value(&result.indexed)?;
+
+        runtime.interface().pop_call_frame()?;
+
+        Ok(result)
+    }
+
     pub fn call_method(
         template_provider: &TTemplateProvider,
         runtime: &Runtime,
@@ -511,6 +728,76 @@ impl<TTemplateProvider: TemplateProvider<Template = LoadedTemplate> + 'static> T
         Ok(result)
     }
 
+    /// Checks the nesting depth before any instruction is executed, which bounds the recursion when processing nested
+    /// instructions
+    fn check_nesting_depth<'a, I: IntoIterator<Item = &'a Instruction>>(
+        instructions: I,
+    ) -> Result<(), TransactionError> {
+        let depth = instructions
+            .into_iter()
+            .map(Instruction::nesting_depth)
+            .max()
+            .unwrap_or(0);
+        if depth > MAX_INSTRUCTION_NESTING_DEPTH {
+            return Err(TransactionError::InstructionNestingTooDeep {
+                depth,
+                max_depth: MAX_INSTRUCTION_NESTING_DEPTH,
+            });
+        }
+        Ok(())
+    }
+
+    /// Calls the fee sponsor method on the component. The method is called like any other method so that the
+    /// component's access rules apply, and it is up to the sponsor to decide how much to pay for the signer.
+    /// Fee sponsorship is only allowed in the fee instructions
+    fn check_no_fee_sponsorship<'a, I: IntoIterator<Item = &'a Instruction>>(
+        instructions: I,
+    ) -> Result<(), TransactionError> {
+        let sponsor = instructions
+            .into_iter()
+            .flat_map(Instruction::iter_nested)
+            .find_map(|instruction| match instruction {
+                Instruction::SponsorFee { component_address } => Some(*component_address),
+                _ => None,
+            });
+        match sponsor {
+            Some(component_address) => Err(TransactionError::SponsorFeeOutsideFeeInstructions { component_address }),
+            None => Ok(()),
+        }
+    }
+
+    fn sponsor_fee(
+        template_provider: &TTemplateProvider,
+        runtime: &Runtime,
+        component_address: &ComponentAddress,
+    ) -> Result<InstructionResult, TransactionError> {
+        let component = runtime.interface().load_component(component_address)?;
+        let template_address = component.template_address;
+        let template = template_provider
+            .get_template_module(&template_address)
+            .map_err(|e| TransactionError::FailedToLoadTemplate {
+                address: template_address,
+                details: e.to_string(),
+            })?
+            .ok_or(TransactionError::TemplateNotFound {
+                address: template_address,
+            })?;
+
+        if !template.template_def().is_fee_sponsor() {
+            return Err(TransactionError::NotAFeeSponsor {
+                component_address: *component_address,
+            });
+        }
+
+        Self::call_method(
+            template_provider,
+            runtime,
+            component_address,
+            FEE_SPONSOR_FUNCTION_NAME,
+            vec![],
+        )
+    }
+
     fn invoke_template(
         module: LoadedTemplate,
         template_provider: &TTemplateProvider,
@@ -178,6 +178,11 @@ impl<T: Send + 'static> WasmEnv<T> {
 }
 
 impl<T: Clone + Sync + Send> WasmEnv<T> {
+    /// Returns the current size of the memory in pages
+    pub(super) fn memory_pages<S: AsStoreRef>(&self, store: &S) -> Result<u32, WasmExecutionError> {
+        Ok(self.get_memory()?.view(store).size().0)
+    }
+
     pub fn set_memory(&mut self, memory: Memory) -> &mut Self {
         self.memory = Some(memory);
         self
@@ -20,13 +20,24 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use wasmer::{wasmparser::Operator, ModuleMiddleware};
-use wasmer_middlewares::Metering;
+use wasmer::{wasmparser::Operator, AsStoreMut, Instance, ModuleMiddleware};
+use wasmer_middlewares::{
+    metering::{get_remaining_points, MeteringPoints},
+    Metering,
+};
 
 pub fn middleware(limit: u64) -> impl ModuleMiddleware {
     Metering::new(limit, cost_function)
 }
 
+/// Returns the metering points remaining for the instance, or zero if they have been exhausted
+pub fn remaining_points<S: AsStoreMut>(store: &mut S, instance: &Instance) -> u64 {
+    match get_remaining_points(store, instance) {
+        MeteringPoints::Remaining(points) => points,
+        MeteringPoints::Exhausted => 0,
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 fn cost_function(op: &Operator) -> u64 {
     match op {
@@ -53,6 +53,7 @@ use crate::{
     wasm::{
         environment::{AllocPtr, WasmEnv},
         error::WasmExecutionError,
+        metering,
         module::MainFunction,
         LoadedWasmTemplate,
     },
@@ -273,8 +274,24 @@ impl Invokable<Store> for WasmProcess {
         let main_name = format!("{}_main", self.module.template_name());
         let func: MainFunction = self.instance.exports.get_typed_function(store, &main_name)?;
 
+        let points_before = metering::remaining_points(store, &self.instance);
+        let memory_pages_before = self.env.memory_pages(store)?;
+
         let call_info_ptr = self.alloc_and_write(store, &call_info)?;
         let res = func.call(store, call_info_ptr.as_wasm_ptr(), call_info_ptr.len());
+
+        // Charge for the execution even if it failed, since the work has been done. Errors from recording are only
+        // returned once the execution succeeded, so that they do not hide the execution error.
+        let points_used = points_before.saturating_sub(metering::remaining_points(store, &self.instance));
+        let record_result = self.env.memory_pages(store).and_then(|pages| {
+            let memory_pages_grown = pages.saturating_sub(memory_pages_before);
+            self.env
+                .state()
+                .interface()
+                .record_wasm_execution(points_used, memory_pages_grown)
+                .map_err(WasmExecutionError::from)
+        });
+
         // No need to free since the exported function should free the memory by dropping it at the end - however, if it
         // does not the memory will be freed once the VM is destructed
         // self.env.as_ref(store).free(store, call_info_ptr)?;
@@ -295,6 +312,7 @@ impl Invokable<Store> for WasmProcess {
                 return Err(err.into());
             },
         };
+        record_result?;
 
         // Read response from memory
         let raw = self.env.read_memory_with_embedded_len(store, ptr.offset())?;
@@ -222,6 +222,179 @@ mod component_access_rules {
             action: ComponentAction::SetAccessRules.into(),
         });
     }
+
+    fn create_component_with_set_value_rule(test: &mut TemplateTest, rule: AccessRule) -> ComponentAddress {
+        let access_rules_template = test.get_template_address("AccessRulesTest");
+        let (owner_proof, _, owner_key) = test.create_owner_proof();
+
+        let result = test.execute_expect_success(
+            Transaction::builder()
+                .call_function(access_rules_template, "with_configured_rules", args![
+                    // Owner
+                    OwnerRule::OwnedBySigner,
+                    // Component
+                    ComponentAccessRules::new().add_method_rule("set_value", rule),
+                    // Resource
+                    ResourceAccessRules::new(),
+                    // Badge recall rule
+                    AccessRule::DenyAll,
+                ])
+                .sign(&owner_key)
+                .build(),
+            vec![owner_proof],
+        );
+
+        result.finalize.execution_results[0]
+            .decode::<ComponentAddress>()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_requires_a_threshold_of_signers() {
+        let mut test = TemplateTest::new(["tests/templates/access_rules"]);
+
+        let (signer1_proof, _, signer1_key) = test.create_owner_proof();
+        let (signer2_proof, _, _) = test.create_owner_proof();
+        let (signer3_proof, _, _) = test.create_owner_proof();
+
+        let component_address = create_component_with_set_value_rule(
+            &mut test,
+            rule!(m_of_n(
+                2,
+                non_fungible(signer1_proof.clone()),
+                non_fungible(signer2_proof.clone()),
+                non_fungible(signer3_proof.clone())
+            )),
+        );
+
+        let reason = test.execute_expect_failure(
+            Transaction::builder()
+                .call_method(component_address, "set_value", args![1])
+                .sign(&signer1_key)
+                .build(),
+            vec![signer1_proof.clone()],
+        );
+        assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+            component_address,
+            method: "set_value".to_string(),
+        });
+
+        test.execute_expect_success(
+            Transaction::builder()
+                .call_method(component_address, "set_value", args![1])
+                .sign(&signer1_key)
+                .build(),
+            vec![signer1_proof, signer3_proof],
+        );
+    }
+
+    #[test]
+    fn it_requires_a_threshold_of_weighted_signers() {
+        let mut test = TemplateTest::new(["tests/templates/access_rules"]);
+
+        let (admin_proof, _, admin_key) = test.create_owner_proof();
+        let (member1_proof, _, _) = test.create_owner_proof();
+        let (member2_proof, _, _) = test.create_owner_proof();
+
+        let rule = RequireRule::weighted(3, [
+            (admin_proof.clone(), 2),
+            (member1_proof.clone(), 1),
+            (member2_proof.clone(), 1),
+        ]);
+        let component_address = create_component_with_set_value_rule(
+            &mut test,
+            AccessRule::Restricted(RestrictedAccessRule::Require(rule)),
+        );
+
+        // Two members only have a weight of 2
+        let reason = test.execute_expect_failure(
+            Transaction::builder()
+                .call_method(component_address, "set_value", args![1])
+                .sign(&admin_key)
+                .build(),
+            vec![member1_proof.clone(), member2_proof],
+        );
+        assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+            component_address,
+            method: "set_value".to_string(),
+        });
+
+        test.execute_expect_success(
+            Transaction::builder()
+                .call_method(component_address, "set_value", args![1])
+                .sign(&admin_key)
+                .build(),
+            vec![admin_proof, member1_proof],
+        );
+    }
+
+    #[test]
+    fn it_counts_a_duplicated_requirement_once() {
+        let mut test = TemplateTest::new(["tests/templates/access_rules"]);
+
+        let (signer1_proof, _, signer1_key) = test.create_owner_proof();
+        let (signer2_proof, _, _) = test.create_owner_proof();
+
+        let rule = RequireRule::MOfN {
+            threshold: 2,
+            requirements: vec![
+                signer1_proof.clone().into(),
+                signer1_proof.clone().into(),
+                signer2_proof.clone().into(),
+            ],
+        };
+        let component_address = create_component_with_set_value_rule(
+            &mut test,
+            AccessRule::Restricted(RestrictedAccessRule::Require(rule)),
+        );
+
+        let reason = test.execute_expect_failure(
+            Transaction::builder()
+                .call_method(component_address, "set_value", args![1])
+                .sign(&signer1_key)
+                .build(),
+            vec![signer1_proof.clone()],
+        );
+        assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+            component_address,
+            method: "set_value".to_string(),
+        });
+
+        test.execute_expect_success(
+            Transaction::builder()
+                .call_method(component_address, "set_value", args![1])
+                .sign(&signer1_key)
+                .build(),
+            vec![signer1_proof, signer2_proof],
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_zero_threshold() {
+        let mut test = TemplateTest::new(["tests/templates/access_rules"]);
+        let access_rules_template = test.get_template_address("AccessRulesTest");
+        let (owner_proof, _, owner_key) = test.create_owner_proof();
+
+        let rule = AccessRule::Restricted(RestrictedAccessRule::Require(RequireRule::m_of_n(0, [
+            owner_proof.clone()
+        ])));
+        let reason = test.execute_expect_failure(
+            Transaction::builder()
+                .call_function(access_rules_template, "with_configured_rules", args![
+                    OwnerRule::OwnedBySigner,
+                    ComponentAccessRules::new().add_method_rule("set_value", rule),
+                    ResourceAccessRules::new(),
+                    AccessRule::DenyAll,
+                ])
+                .sign(&owner_key)
+                .build(),
+            vec![owner_proof],
+        );
+        assert!(
+            reason.to_string().contains("threshold must be greater than zero"),
+            "{reason}"
+        );
+    }
 }
 
 mod resource_access_rules {
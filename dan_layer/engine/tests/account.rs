@@ -1,13 +1,21 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use tari_crypto::{keys::PublicKey, ristretto::RistrettoPublicKey, tari_utilities::ByteArray};
+use tari_crypto::{
+    keys::PublicKey,
+    ristretto::{RistrettoPublicKey, RistrettoSecretKey},
+    tari_utilities::ByteArray,
+};
 use tari_dan_engine::runtime::{ActionIdent, RuntimeError};
-use tari_engine_types::instruction::Instruction;
+use tari_engine_types::{
+    instruction::Instruction,
+    virtual_substate::{VirtualSubstate, VirtualSubstateId},
+};
 use tari_template_lib::{
     args,
-    auth::AccessRule,
+    auth::{AccessRule, RequireRule, RestrictedAccessRule, RuleRequirement},
     constants::XTR,
+    crypto::RistrettoPublicKeyBytes,
     models::{Amount, ComponentAddress, ResourceAddress},
     prelude::AccessRules,
     rule,
@@ -303,3 +311,220 @@ fn custom_access_rules() {
         vec![user2_account_proof],
     );
 }
+
+#[test]
+fn it_enforces_account_spending_limits() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let (account, account_proof, account_sk) = test.create_funded_account();
+    let (recipient, _, _) = test.create_empty_account();
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "set_spending_limit", args![XTR, Some(Amount(100))])
+            .sign(&account_sk)
+            .build(),
+        vec![account_proof.clone()],
+    );
+
+    let withdraw = |amount: Amount| {
+        Transaction::builder()
+            .call_method(account, "withdraw", args![XTR, amount])
+            .put_last_instruction_output_on_workspace("b")
+            .call_method(recipient, "deposit", args![Workspace("b")])
+            .sign(&account_sk)
+            .build()
+    };
+
+    test.execute_expect_success(withdraw(Amount(60)), vec![account_proof.clone()]);
+    let reason = test.execute_expect_failure(withdraw(Amount(50)), vec![account_proof.clone()]);
+    assert!(
+        reason.to_string().contains("exceeds the account spending limit"),
+        "{reason}"
+    );
+
+    // The amount spent is reset in the next epoch
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(1));
+    test.execute_expect_success(withdraw(Amount(50)), vec![account_proof]);
+
+    let balance: Amount = test.call_method(recipient, "balance", args![XTR], vec![]);
+    assert_eq!(balance, Amount(110));
+}
+
+#[test]
+fn it_does_not_count_fees_towards_account_spending_limits() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let (account, account_proof, account_sk) = test.create_funded_account();
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "set_spending_limit", args![XTR, Some(Amount(0))])
+            .sign(&account_sk)
+            .build(),
+        vec![account_proof.clone()],
+    );
+
+    // A zero limit must not prevent the owner from paying fees, otherwise the limit could never be lifted
+    test.enable_fees();
+    test.execute_expect_success(
+        Transaction::builder()
+            .fee_transaction_pay_from_component(account, Amount(1000))
+            .call_method(account, "set_spending_limit", args![XTR, None::<Amount>])
+            .sign(&account_sk)
+            .build(),
+        vec![account_proof.clone()],
+    );
+    test.disable_fees();
+
+    // The limit was lifted so withdrawals are allowed again
+    let (recipient, _, _) = test.create_empty_account();
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "withdraw", args![XTR, Amount(10)])
+            .put_last_instruction_output_on_workspace("b")
+            .call_method(recipient, "deposit", args![Workspace("b")])
+            .sign(&account_sk)
+            .build(),
+        vec![account_proof],
+    );
+}
+
+#[test]
+fn it_restricts_session_keys_to_their_limits_and_allowlist() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let (account, account_proof, account_sk) = test.create_funded_account();
+    let (recipient, _, _) = test.create_empty_account();
+    let (other_account, _, _) = test.create_empty_account();
+    let (_, session_public_key, session_sk) = test.create_owner_proof();
+    let session_key = RistrettoPublicKeyBytes::from_bytes(session_public_key.as_bytes()).unwrap();
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "add_session_key", args![
+                session_key,
+                vec![(XTR, Amount(100))],
+                false,
+                5u64
+            ])
+            .call_method(account, "set_recipient_allowlist", args![Some(vec![recipient])])
+            .sign(&account_sk)
+            .build(),
+        vec![account_proof.clone()],
+    );
+
+    let transfer = |amount: Amount, destination: ComponentAddress, sk: &RistrettoSecretKey| {
+        Transaction::builder()
+            .call_method(account, "session_transfer", args![XTR, amount, destination])
+            .sign(sk)
+            .build()
+    };
+
+    test.execute_expect_success(transfer(Amount(40), recipient, &session_sk), vec![]);
+
+    let reason = test.execute_expect_failure(transfer(Amount(10), other_account, &session_sk), vec![]);
+    assert!(reason.to_string().contains("not in the allowlist"), "{reason}");
+    let reason = test.execute_expect_failure(transfer(Amount(70), recipient, &session_sk), vec![]);
+    assert!(
+        reason.to_string().contains("exceeds the session key spending limit"),
+        "{reason}"
+    );
+    // Only registered session keys may use the session methods
+    let (_, _, other_sk) = test.create_owner_proof();
+    let reason = test.execute_expect_failure(transfer(Amount(10), recipient, &other_sk), vec![]);
+    assert!(reason.to_string().contains("is not a session key"), "{reason}");
+
+    // Session keys cannot call the owner methods
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(account, "withdraw", args![XTR, Amount(10)])
+            .put_last_instruction_output_on_workspace("b")
+            .call_method(recipient, "deposit", args![Workspace("b")])
+            .sign(&session_sk)
+            .build(),
+        vec![],
+    );
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: account,
+        method: "withdraw".to_string(),
+    });
+
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(6));
+    let reason = test.execute_expect_failure(transfer(Amount(10), recipient, &session_sk), vec![]);
+    assert!(reason.to_string().contains("expired"), "{reason}");
+
+    let balance: Amount = test.call_method(recipient, "balance", args![XTR], vec![]);
+    assert_eq!(balance, Amount(40));
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "revoke_session_key", args![session_key])
+            .sign(&account_sk)
+            .build(),
+        vec![account_proof],
+    );
+    let reason = test.execute_expect_failure(transfer(Amount(10), recipient, &session_sk), vec![]);
+    assert!(reason.to_string().contains("is not a session key"), "{reason}");
+}
+
+#[test]
+fn it_allows_session_keys_on_accounts_with_custom_access_rules() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let (owner_proof, public_key, secret_key) = test.create_owner_proof();
+    let (recipient, _, _) = test.create_empty_account();
+    let (_, session_public_key, session_sk) = test.create_owner_proof();
+    let session_key = RistrettoPublicKeyBytes::from_bytes(session_public_key.as_bytes()).unwrap();
+
+    // The session methods are restricted to the owner, as for accounts created before session keys were added
+    let access_rules = AccessRules::new()
+        .add_method_rule("balance", rule!(allow_all))
+        .add_method_rule("deposit", rule!(allow_all))
+        .default(rule!(non_fungible(owner_proof.clone())));
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(test_faucet_component(), "take_free_coins", args![])
+            .put_last_instruction_output_on_workspace("bucket")
+            .create_account_with_custom_rules(public_key, None, Some(access_rules), Some("bucket"))
+            .sign(&secret_key)
+            .build(),
+        vec![owner_proof.clone()],
+    );
+    let account: ComponentAddress = result.finalize.execution_results[2].decode().unwrap();
+
+    // Adding a session key allows anyone to call the session methods, which check the signer against the session keys
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "add_session_key", args![
+                session_key,
+                vec![(XTR, Amount(100))],
+                false,
+                5u64
+            ])
+            .sign(&secret_key)
+            .build(),
+        vec![owner_proof],
+    );
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "session_transfer", args![XTR, Amount(40), recipient])
+            .sign(&session_sk)
+            .build(),
+        vec![],
+    );
+    let balance: Amount = test.call_method(recipient, "balance", args![XTR], vec![]);
+    assert_eq!(balance, Amount(40));
+
+    // The other owner methods are still restricted to the owner
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(account, "withdraw", args![XTR, Amount(10)])
+            .put_last_instruction_output_on_workspace("b")
+            .call_method(recipient, "deposit", args![Workspace("b")])
+            .sign(&session_sk)
+            .build(),
+        vec![],
+    );
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: account,
+        method: "withdraw".to_string(),
+    });
+}
@@ -0,0 +1,248 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_crypto::{
+    keys::PublicKey,
+    ristretto::{RistrettoPublicKey, RistrettoSecretKey},
+    tari_utilities::ByteArray,
+};
+use tari_dan_engine::runtime::ActionIdent;
+use tari_engine_types::virtual_substate::{VirtualSubstate, VirtualSubstateId};
+use tari_template_lib::{
+    args,
+    auth::{AccessRule, RequireRule, RestrictedAccessRule, RuleRequirement},
+    constants::XTR,
+    crypto::RistrettoPublicKeyBytes,
+    models::{Amount, ComponentAddress, NonFungibleAddress},
+    prelude::AccessRules,
+    rule,
+};
+use tari_template_test_tooling::{support::assert_error::assert_access_denied_for_action, TemplateTest};
+use tari_transaction::Transaction;
+
+struct Key {
+    proof: NonFungibleAddress,
+    public_key: RistrettoPublicKey,
+    secret_key: RistrettoSecretKey,
+}
+
+fn create_key(test: &mut TemplateTest) -> Key {
+    let (proof, public_key, secret_key) = test.create_owner_proof();
+    Key {
+        proof,
+        public_key,
+        secret_key,
+    }
+}
+
+fn to_public_key_bytes(public_key: &RistrettoPublicKey) -> RistrettoPublicKeyBytes {
+    RistrettoPublicKeyBytes::from_bytes(public_key.as_bytes()).unwrap()
+}
+
+struct Setup {
+    account: ComponentAddress,
+    owner: Key,
+    guardians: Vec<Key>,
+    new_owner: Key,
+}
+
+fn setup(test: &mut TemplateTest) -> Setup {
+    let (account, proof, secret_key) = test.create_funded_account();
+    let owner = Key {
+        proof,
+        public_key: RistrettoPublicKey::from_secret_key(&secret_key),
+        secret_key,
+    };
+    let guardians = (0..3).map(|_| create_key(test)).collect::<Vec<_>>();
+    let new_owner = create_key(test);
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "set_guardians", args![
+                guardians.iter().map(|g| g.proof.clone()).collect::<Vec<_>>(),
+                2u32,
+                10u64
+            ])
+            .sign(&owner.secret_key)
+            .build(),
+        vec![owner.proof.clone()],
+    );
+
+    Setup {
+        account,
+        owner,
+        guardians,
+        new_owner,
+    }
+}
+
+fn call(account: ComponentAddress, method: &str, key: &Key) -> Transaction {
+    Transaction::builder()
+        .call_method(account, method, args![])
+        .sign(&key.secret_key)
+        .build()
+}
+
+fn initiate_recovery(account: ComponentAddress, guardian: &Key, new_owner: &Key) -> Transaction {
+    let new_owner_key = to_public_key_bytes(&new_owner.public_key);
+    Transaction::builder()
+        .call_method(account, "initiate_recovery", args![new_owner_key])
+        .sign(&guardian.secret_key)
+        .build()
+}
+
+fn withdraw(account: ComponentAddress, key: &Key) -> Transaction {
+    Transaction::builder()
+        .call_method(account, "withdraw", args![XTR, Amount(100)])
+        .put_last_instruction_output_on_workspace("bucket")
+        .call_method(account, "deposit", args![Workspace("bucket")])
+        .sign(&key.secret_key)
+        .build()
+}
+
+#[test]
+fn it_rotates_the_owner_key_after_guardian_approval_and_delay() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let Setup {
+        account,
+        owner,
+        guardians,
+        new_owner,
+    } = setup(&mut test);
+
+    test.execute_expect_success(initiate_recovery(account, &guardians[0], &new_owner), vec![]);
+    let reason = test.execute_expect_failure(call(account, "complete_recovery", &new_owner), vec![]);
+    assert!(
+        reason.to_string().contains("1 of 2 required guardian approvals"),
+        "{reason}"
+    );
+
+    test.execute_expect_success(call(account, "approve_recovery", &guardians[2]), vec![]);
+    let reason = test.execute_expect_failure(call(account, "complete_recovery", &new_owner), vec![]);
+    assert!(
+        reason.to_string().contains("cannot be completed until epoch 10"),
+        "{reason}"
+    );
+
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(10));
+    test.execute_expect_success(call(account, "complete_recovery", &new_owner), vec![]);
+
+    let reason = test.execute_expect_failure(withdraw(account, &owner), vec![owner.proof.clone()]);
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: account,
+        method: "withdraw".to_string(),
+    });
+    test.execute_expect_success(withdraw(account, &new_owner), vec![new_owner.proof.clone()]);
+
+    // The account is owned by an access rule, so the lost key is no longer reported as the owner key
+    let component = test.read_only_state_store().get_component(account).unwrap();
+    assert_eq!(component.owner_key, None);
+}
+
+#[test]
+fn it_allows_guardians_on_accounts_with_custom_access_rules() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner = create_key(&mut test);
+    let guardian = create_key(&mut test);
+    let new_owner = create_key(&mut test);
+
+    // The recovery methods are restricted to the owner, as for accounts created before social recovery was added
+    let access_rules = AccessRules::new()
+        .add_method_rule("balance", rule!(allow_all))
+        .add_method_rule("deposit", rule!(allow_all))
+        .default(rule!(non_fungible(owner.proof.clone())));
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .create_account_with_custom_rules::<&str>(owner.public_key.clone(), None, Some(access_rules), None)
+            .sign(&owner.secret_key)
+            .build(),
+        vec![owner.proof.clone()],
+    );
+    let account: ComponentAddress = result.finalize.execution_results[0].decode().unwrap();
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "set_guardians", args![
+                vec![guardian.proof.clone()],
+                1u32,
+                0u64
+            ])
+            .sign(&owner.secret_key)
+            .build(),
+        vec![owner.proof.clone()],
+    );
+
+    // Setting guardians allows anyone to call the recovery methods, which check the guardians against the account state
+    test.execute_expect_success(initiate_recovery(account, &guardian, &new_owner), vec![]);
+    test.execute_expect_success(call(account, "complete_recovery", &new_owner), vec![]);
+    let set_allowlist = |key: &Key| {
+        Transaction::builder()
+            .call_method(account, "set_recipient_allowlist", args![None::<Vec<ComponentAddress>>])
+            .sign(&key.secret_key)
+            .build()
+    };
+    test.execute_expect_failure(set_allowlist(&owner), vec![owner.proof.clone()]);
+    test.execute_expect_success(set_allowlist(&new_owner), vec![new_owner.proof.clone()]);
+}
+
+#[test]
+fn it_allows_the_owner_to_cancel_a_recovery() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let Setup {
+        account,
+        owner,
+        guardians,
+        new_owner,
+    } = setup(&mut test);
+
+    // Only guardians can start a recovery
+    let reason = test.execute_expect_failure(initiate_recovery(account, &new_owner, &new_owner), vec![]);
+    assert!(reason.to_string().contains("is not a guardian"), "{reason}");
+
+    test.execute_expect_success(initiate_recovery(account, &guardians[0], &new_owner), vec![]);
+    test.execute_expect_success(call(account, "approve_recovery", &guardians[1]), vec![]);
+
+    // Guardians cannot cancel the recovery
+    let guardian_proof = guardians[0].proof.clone();
+    test.execute_expect_failure(call(account, "cancel_recovery", &guardians[0]), vec![guardian_proof]);
+    test.execute_expect_success(call(account, "cancel_recovery", &owner), vec![owner.proof.clone()]);
+
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(10));
+    let reason = test.execute_expect_failure(call(account, "complete_recovery", &new_owner), vec![]);
+    assert!(reason.to_string().contains("No recovery is pending"), "{reason}");
+    test.execute_expect_success(withdraw(account, &owner), vec![owner.proof.clone()]);
+}
+
+#[test]
+fn it_allows_an_unapproved_recovery_to_be_replaced_once_expired() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let Setup {
+        account,
+        guardians,
+        new_owner,
+        ..
+    } = setup(&mut test);
+
+    // A single guardian starts a recovery to a key that the other guardians do not approve
+    let rogue_key = create_key(&mut test);
+    test.execute_expect_success(initiate_recovery(account, &guardians[0], &rogue_key), vec![]);
+    let reason = test.execute_expect_failure(initiate_recovery(account, &guardians[1], &new_owner), vec![]);
+    assert!(reason.to_string().contains("can be replaced from epoch 10"), "{reason}");
+
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(10));
+    let reason = test.execute_expect_failure(call(account, "approve_recovery", &guardians[2]), vec![]);
+    assert!(reason.to_string().contains("expired at epoch 10"), "{reason}");
+
+    test.execute_expect_success(initiate_recovery(account, &guardians[1], &new_owner), vec![]);
+    test.execute_expect_success(call(account, "approve_recovery", &guardians[2]), vec![]);
+
+    // Once the threshold is reached, only the owner can cancel the recovery
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(20));
+    let reason = test.execute_expect_failure(initiate_recovery(account, &guardians[0], &rogue_key), vec![]);
+    assert!(
+        reason.to_string().contains("reached the guardian threshold"),
+        "{reason}"
+    );
+    test.execute_expect_success(call(account, "complete_recovery", &new_owner), vec![]);
+    test.execute_expect_success(withdraw(account, &new_owner), vec![new_owner.proof.clone()]);
+}
@@ -5,9 +5,10 @@ use std::vec;
 
 use tari_crypto::ristretto::RistrettoSecretKey;
 use tari_dan_engine::runtime::{AssertError, RuntimeError};
+use tari_engine_types::indexed_value::IndexedWellKnownTypes;
 use tari_template_lib::{
     args,
-    models::{Amount, ComponentAddress, NonFungibleAddress, ResourceAddress},
+    models::{Amount, ComponentAddress, NonFungibleAddress, NonFungibleId, ResourceAddress, VaultId},
     prelude::XTR,
 };
 use tari_template_test_tooling::{support::assert_error::assert_reject_reason, TemplateTest};
@@ -174,3 +175,180 @@ fn it_fails_with_invalid_workspace_key() {
         key: "invalid_key".to_string(),
     });
 }
+
+fn get_faucet_vault(test: &AssertTest) -> VaultId {
+    let component = test
+        .template_test
+        .read_only_state_store()
+        .get_component(test.faucet_component)
+        .unwrap();
+    IndexedWellKnownTypes::from_value(component.state())
+        .unwrap()
+        .vault_ids()[0]
+}
+
+#[test]
+fn it_asserts_vault_balances() {
+    let mut test: AssertTest = setup();
+    let faucet_vault = get_faucet_vault(&test);
+    let balance = Amount(1_000_000_000_000) - FAUCET_WITHDRAWAL_AMOUNT;
+
+    test.template_test.execute_expect_success(
+        Transaction::builder()
+            .call_method(test.faucet_component, "take_free_coins", args![])
+            .put_last_instruction_output_on_workspace("faucet_bucket")
+            .call_method(test.account, "deposit", args![Workspace("faucet_bucket")])
+            .assert_vault_balance_at_least(faucet_vault, balance)
+            .assert_vault_balance_at_most(faucet_vault, balance)
+            .sign(&test.account_key)
+            .build(),
+        vec![test.account_proof.clone()],
+    );
+
+    let reason = test.template_test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(test.faucet_component, "take_free_coins", args![])
+            .put_last_instruction_output_on_workspace("faucet_bucket")
+            .call_method(test.account, "deposit", args![Workspace("faucet_bucket")])
+            .assert_vault_balance_at_least(faucet_vault, balance)
+            .sign(&test.account_key)
+            .build(),
+        vec![test.account_proof.clone()],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::VaultBalanceTooLow {
+            vault_id: faucet_vault,
+            min_amount: balance,
+            balance: balance - FAUCET_WITHDRAWAL_AMOUNT,
+        }),
+    );
+
+    let max_amount = balance - 1;
+    let reason = test.template_test.execute_expect_failure(
+        Transaction::builder()
+            .assert_vault_balance_at_most(faucet_vault, max_amount)
+            .sign(&test.account_key)
+            .build(),
+        vec![test.account_proof.clone()],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::VaultBalanceTooHigh {
+            vault_id: faucet_vault,
+            max_amount,
+            balance,
+        }),
+    );
+}
+
+#[test]
+fn it_asserts_workspace_value_equals() {
+    let mut test: AssertTest = setup();
+
+    test.template_test.execute_expect_success(
+        Transaction::builder()
+            .call_method(test.faucet_component, "total_supply", args![])
+            .put_last_instruction_output_on_workspace("supply")
+            .assert_workspace_value_equals("supply", &Amount(1_000_000_000_000))
+            .sign(&test.account_key)
+            .build(),
+        vec![test.account_proof.clone()],
+    );
+
+    let reason = test.template_test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(test.faucet_component, "total_supply", args![])
+            .put_last_instruction_output_on_workspace("supply")
+            .assert_workspace_value_equals("supply", &Amount(1))
+            .sign(&test.account_key)
+            .build(),
+        vec![test.account_proof.clone()],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::WorkspaceValueMismatch {
+            key: "supply".to_string(),
+        }),
+    );
+}
+
+#[test]
+fn it_asserts_bucket_contains_non_fungible() {
+    let mut test = TemplateTest::new(vec!["tests/templates/nft/basic_nft"]);
+    let (account, account_proof, account_key) = test.create_funded_account();
+    let nft_component: ComponentAddress = test.call_function("SparkleNft", "new", args![], vec![]);
+    let id = NonFungibleId::from_u32(123);
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(nft_component, "mint_specific", args![id])
+            .put_last_instruction_output_on_workspace("nft")
+            .assert_bucket_contains_non_fungible("nft", id)
+            .call_method(account, "deposit", args![Workspace("nft")])
+            .sign(&account_key)
+            .build(),
+        vec![account_proof.clone()],
+    );
+
+    let other_id = NonFungibleId::from_u32(124);
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(nft_component, "mint_specific", args![NonFungibleId::from_u32(125)])
+            .put_last_instruction_output_on_workspace("nft")
+            .assert_bucket_contains_non_fungible("nft", other_id.clone())
+            .call_method(account, "deposit", args![Workspace("nft")])
+            .sign(&account_key)
+            .build(),
+        vec![account_proof],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::NonFungibleNotInBucket { id: other_id }),
+    );
+}
+
+#[test]
+fn it_asserts_component_state_equals() {
+    let mut test = TemplateTest::new(vec!["tests/templates/state"]);
+    let component: ComponentAddress = test.call_function("State", "new", args![], vec![]);
+    test.call_method::<()>(component, "set", args![42u32], vec![]);
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .assert_component_state_equals(component, "$.value", &42u32)
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .assert_component_state_equals(component, "$.value", &41u32)
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::ComponentStateMismatch {
+            component_address: component,
+            path: "$.value".to_string(),
+        }),
+    );
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .assert_component_state_equals(component, "$.missing", &42u32)
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::ComponentStatePathNotFound {
+            component_address: component,
+            path: "$.missing".to_string(),
+        }),
+    );
+}
@@ -0,0 +1,169 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_engine::{runtime::RuntimeError, transaction::TransactionError};
+use tari_engine_types::{assertion::Assertion, instruction::MAX_INSTRUCTION_NESTING_DEPTH};
+use tari_template_lib::{
+    args,
+    args::LogLevel,
+    models::{Amount, ComponentAddress},
+};
+use tari_template_test_tooling::{support::assert_error::assert_reject_reason, TemplateTest};
+use tari_transaction::{Instruction, Transaction};
+
+fn setup(test: &mut TemplateTest) -> ComponentAddress {
+    test.call_function("ControlFlow", "new", args![Amount(1000)], vec![])
+}
+
+fn get_counter(test: &mut TemplateTest, component: ComponentAddress) -> u32 {
+    test.call_method(component, "counter", args![], vec![])
+}
+
+#[test]
+fn it_deposits_every_bucket_in_a_vector() {
+    let mut test = TemplateTest::new(["tests/templates/control_flow"]);
+    let (account, account_proof, account_key) = test.create_empty_account();
+    let component = setup(&mut test);
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(component, "take_many", args![3u32, Amount(100)])
+            .put_last_instruction_output_on_workspace("buckets")
+            .for_each("buckets", "bucket", 10, |builder| {
+                builder
+                    .call_method(account, "deposit", args![Workspace("bucket")])
+                    .call_method(component, "increment", args![])
+            })
+            .sign(&account_key)
+            .build(),
+        vec![account_proof],
+    );
+
+    assert_eq!(get_counter(&mut test, component), 3);
+}
+
+#[test]
+fn it_fails_if_the_vector_exceeds_the_max_iterations() {
+    let mut test = TemplateTest::new(["tests/templates/control_flow"]);
+    let (account, account_proof, account_key) = test.create_empty_account();
+    let component = setup(&mut test);
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "take_many", args![3u32, Amount(100)])
+            .put_last_instruction_output_on_workspace("buckets")
+            .for_each("buckets", "bucket", 2, |builder| {
+                builder.call_method(account, "deposit", args![Workspace("bucket")])
+            })
+            .sign(&account_key)
+            .build(),
+        vec![account_proof],
+    );
+
+    assert_reject_reason(reason, RuntimeError::MaxIterationsExceeded {
+        key: "buckets".to_string(),
+        num_items: 3,
+        max_iterations: 2,
+    });
+}
+
+#[test]
+fn it_executes_the_branch_that_matches_the_condition() {
+    let mut test = TemplateTest::new(["tests/templates/control_flow"]);
+    let component = setup(&mut test);
+
+    let secret_key = test.get_test_secret_key().clone();
+    let increment_if_zero = || {
+        Transaction::builder()
+            .call_method(component, "counter", args![])
+            .put_last_instruction_output_on_workspace("counter")
+            .if_else(
+                Assertion::workspace_value_equals("counter", &0u32).unwrap(),
+                |then| then.call_method(component, "increment", args![]),
+                |otherwise| {
+                    otherwise.add_instruction(Instruction::EmitLog {
+                        level: LogLevel::Info,
+                        message: "Counter is not zero".to_string(),
+                    })
+                },
+            )
+            .sign(&secret_key)
+            .build()
+    };
+
+    test.execute_expect_success(increment_if_zero(), vec![]);
+    assert_eq!(get_counter(&mut test, component), 1);
+
+    // The counter is no longer zero, so the else branch is executed
+    let result = test.execute_expect_success(increment_if_zero(), vec![]);
+    assert_eq!(get_counter(&mut test, component), 1);
+    assert_eq!(result.finalize.logs[0].message, "Counter is not zero");
+}
+
+#[test]
+fn it_executes_control_flow_from_a_manifest() {
+    let mut test = TemplateTest::new(["tests/templates/control_flow"]);
+    let (account, account_proof, _) = test.create_empty_account();
+    let component = setup(&mut test);
+
+    test.execute_and_commit_manifest(
+        r#"
+            let component = var!["component"];
+            let account = var!["account"];
+
+            let buckets = component.take_many(3u32, Amount(100));
+            for bucket in buckets.take(5) {
+                account.deposit(bucket);
+                component.increment();
+            }
+
+            let counter = component.counter();
+            if counter == 3u32 {
+                component.increment();
+            } else {
+                warn!("Unexpected counter value");
+            }
+        "#,
+        [("component", component.into()), ("account", account.into())],
+        vec![account_proof],
+    )
+    .unwrap()
+    .expect_success();
+
+    assert_eq!(get_counter(&mut test, component), 4);
+}
+
+#[test]
+fn it_rejects_instructions_nested_deeper_than_the_maximum() {
+    let mut test = TemplateTest::new(["tests/templates/control_flow"]);
+    let component = setup(&mut test);
+
+    let mut instruction = Instruction::CallMethod {
+        component_address: component,
+        method: "increment".to_string(),
+        args: args![],
+    };
+    for _ in 0..=MAX_INSTRUCTION_NESTING_DEPTH {
+        instruction = Instruction::If {
+            condition: Assertion::workspace_value_equals("counter", &0u32).unwrap(),
+            then_instructions: vec![instruction],
+            else_instructions: vec![],
+        };
+    }
+    assert_eq!(instruction.nesting_depth(), MAX_INSTRUCTION_NESTING_DEPTH + 1);
+
+    let secret_key = test.get_test_secret_key().clone();
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .add_instruction(instruction)
+            .sign(&secret_key)
+            .build(),
+        vec![],
+    );
+
+    assert_reject_reason(reason, TransactionError::InstructionNestingTooDeep {
+        depth: MAX_INSTRUCTION_NESTING_DEPTH + 1,
+        max_depth: MAX_INSTRUCTION_NESTING_DEPTH,
+    });
+    assert_eq!(get_counter(&mut test, component), 0);
+}
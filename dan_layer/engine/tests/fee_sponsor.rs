@@ -0,0 +1,119 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_crypto::{keys::PublicKey, ristretto::RistrettoPublicKey, tari_utilities::ByteArray};
+use tari_dan_engine::transaction::TransactionError;
+use tari_template_lib::{
+    args,
+    crypto::RistrettoPublicKeyBytes,
+    models::{Amount, ComponentAddress},
+};
+use tari_template_test_tooling::{support::assert_error::assert_reject_reason, test_faucet_component, TemplateTest};
+use tari_transaction::{Instruction, Transaction};
+
+fn setup(test: &mut TemplateTest) -> ComponentAddress {
+    let template = test.get_template_address("FeeSponsor");
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(test_faucet_component(), "take_free_coins", args![])
+            .put_last_instruction_output_on_workspace("funds")
+            .call_function(template, "new", args![Workspace("funds"), Amount(1000)])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    result.finalize.execution_results[2].decode().unwrap()
+}
+
+#[test]
+fn it_pays_fees_on_behalf_of_sponsored_signers() {
+    let mut test = TemplateTest::new(["tests/templates/fee_sponsor", "tests/templates/state"]);
+    let sponsor = setup(&mut test);
+    let (_, user_proof, user_key) = test.create_empty_account();
+    let user_public_key = RistrettoPublicKey::from_secret_key(&user_key);
+    let user_public_key = RistrettoPublicKeyBytes::from_bytes(user_public_key.as_bytes()).unwrap();
+
+    // Budget for a single transaction
+    test.call_method::<()>(sponsor, "set_budget", args![user_public_key, Amount(1000)], vec![]);
+
+    let state_template = test.get_template_address("State");
+    test.enable_fees();
+    let sponsored_transaction = || {
+        Transaction::builder()
+            .with_fee_sponsor(sponsor)
+            .call_function(state_template, "new", args![])
+            .sign(&user_key)
+            .build()
+    };
+
+    let result = test.execute_expect_success(sponsored_transaction(), vec![user_proof.clone()]);
+    let fee_receipt = result.finalize.fee_receipt;
+    assert!(fee_receipt.is_paid_in_full());
+
+    // The budget is exhausted
+    let reason = test.execute_expect_failure(sponsored_transaction(), vec![user_proof]);
+    assert!(reason.to_string().contains("Sponsorship budget exceeded"), "{reason}");
+    test.disable_fees();
+
+    // The unused fee is refunded to the sponsor
+    let balance: Amount = test.call_method(sponsor, "balance", args![], vec![]);
+    assert_eq!(balance, Amount(1000) - fee_receipt.total_fees_charged());
+}
+
+#[test]
+fn it_rejects_signers_without_a_budget() {
+    let mut test = TemplateTest::new(["tests/templates/fee_sponsor", "tests/templates/state"]);
+    let sponsor = setup(&mut test);
+    let (_, user_proof, user_key) = test.create_empty_account();
+
+    test.enable_fees();
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .with_fee_sponsor(sponsor)
+            .call_function(test.get_template_address("State"), "new", args![])
+            .sign(&user_key)
+            .build(),
+        vec![user_proof],
+    );
+    assert!(reason.to_string().contains("Signer is not sponsored"), "{reason}");
+}
+
+#[test]
+fn it_fails_if_the_component_is_not_a_fee_sponsor() {
+    let mut test = TemplateTest::new(["tests/templates/fee_sponsor", "tests/templates/state"]);
+    let (account, account_proof, account_key) = test.create_empty_account();
+
+    test.enable_fees();
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .with_fee_sponsor(account)
+            .call_function(test.get_template_address("State"), "new", args![])
+            .sign(&account_key)
+            .build(),
+        vec![account_proof],
+    );
+    assert_reject_reason(reason, TransactionError::NotAFeeSponsor {
+        component_address: account,
+    });
+}
+
+#[test]
+fn it_rejects_fee_sponsorship_outside_of_the_fee_instructions() {
+    let mut test = TemplateTest::new(["tests/templates/fee_sponsor", "tests/templates/state"]);
+    let sponsor = setup(&mut test);
+    let (_, user_proof, user_key) = test.create_empty_account();
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .add_instruction(Instruction::SponsorFee {
+                component_address: sponsor,
+            })
+            .call_function(test.get_template_address("State"), "new", args![])
+            .sign(&user_key)
+            .build(),
+        vec![user_proof],
+    );
+    assert_reject_reason(reason, TransactionError::SponsorFeeOutsideFeeInstructions {
+        component_address: sponsor,
+    });
+}
@@ -3,7 +3,7 @@
 
 use std::iter;
 
-use tari_engine_types::{commit_result::RejectReason, instruction::Instruction};
+use tari_engine_types::{commit_result::RejectReason, fees::FeeSource, instruction::Instruction};
 use tari_template_lib::{
     args,
     constants::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
@@ -403,3 +403,69 @@ fn dangling_bucket_pay_fees() {
     assert!(!payment.total_fees_paid().is_zero());
     assert_eq!(orig_balance - new_balance, payment.total_fees_paid());
 }
+
+#[test]
+fn it_breaks_down_fees_by_instruction_and_component() {
+    let mut test = TemplateTest::new(["tests/templates/state"]);
+
+    let (account, owner_token, private_key) = test.create_funded_account();
+    let state: ComponentAddress = test.call_function("State", "new", args![], vec![]);
+
+    test.enable_fees();
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .fee_transaction_pay_from_component(account, Amount(1000))
+            .call_method(state, "set", args![123u32])
+            .sign(&private_key)
+            .build(),
+        vec![owner_token],
+    );
+
+    test.disable_fees();
+
+    let breakdown = result.finalize.fee_receipt.cost_breakdown;
+    // Instruction 0 is the fee payment and instruction 1 is the call to the state component
+    assert!(breakdown.get_by_instruction(0) > 0);
+    assert!(breakdown.get_by_instruction(1) > 0);
+    assert_eq!(breakdown.iter_by_instruction().count(), 2);
+    assert!(breakdown.get_by_component(&account) > 0);
+    assert!(breakdown.get_by_component(&state) > 0);
+
+    // Storage costs are charged on finalization and are not attributed to an instruction
+    let total_by_instruction = breakdown.iter_by_instruction().map(|(_, fee)| *fee).sum::<u64>();
+    assert!(total_by_instruction < breakdown.get_total());
+}
+
+#[test]
+fn it_charges_for_wasm_compute_and_memory() {
+    let mut test = TemplateTest::new(["tests/templates/compute"]);
+    let template = test.get_template_address("Compute");
+
+    let (account, owner_token, private_key) = test.create_funded_account();
+
+    test.enable_fees();
+
+    let mut execute = |function: &str, arg: u32| {
+        let result = test.execute_expect_success(
+            Transaction::builder()
+                .fee_transaction_pay_from_component(account, Amount(1000))
+                .call_function(template, function, args![arg])
+                .sign(&private_key)
+                .build(),
+            vec![owner_token.clone()],
+        );
+        result.finalize.fee_receipt.cost_breakdown.get(FeeSource::WasmCompute)
+    };
+
+    let light = execute("hash_rounds", 10);
+    let heavy = execute("hash_rounds", 1_000_000);
+    assert!(light > 0);
+    assert!(heavy > light, "heavy={heavy}, light={light}");
+
+    let no_growth = execute("allocate_pages", 0);
+    let growth = execute("allocate_pages", 8);
+    assert!(growth >= no_growth + 8, "growth={growth}, no_growth={no_growth}");
+
+    test.disable_fees();
+}
@@ -0,0 +1,133 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::BTreeMap;
+
+use tari_dan_engine::runtime::RuntimeError;
+use tari_template_lib::{
+    args,
+    auth::ResourceAuthAction,
+    models::{Amount, ComponentAddress, ResourceAddress, VaultId},
+};
+use tari_template_test_tooling::{
+    support::assert_error::{assert_access_denied_for_action, assert_reject_reason},
+    TemplateTest,
+};
+use tari_transaction::Transaction;
+
+fn setup(test: &mut TemplateTest, function: &str) -> (ComponentAddress, ResourceAddress) {
+    let template = test.get_template_address("Freezable");
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_function(template, function, args![Amount(1000)])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    let component: ComponentAddress = result.finalize.execution_results[0].decode().unwrap();
+    let resource_address = test.call_method(component, "resource_address", args![], vec![]);
+    (component, resource_address)
+}
+
+fn withdraw_to_account(test: &TemplateTest, component: ComponentAddress, account: ComponentAddress) -> Transaction {
+    Transaction::builder()
+        .call_method(component, "withdraw", args![Amount(100)])
+        .put_last_instruction_output_on_workspace("bucket")
+        .call_method(account, "deposit", args![Workspace("bucket")])
+        .sign(test.get_test_secret_key())
+        .build()
+}
+
+#[test]
+fn it_prevents_moving_tokens_out_of_or_into_a_frozen_vault() {
+    let mut test = TemplateTest::new(["tests/templates/freeze"]);
+    let (account, owner_proof, owner_key) = test.create_empty_account();
+    let (component, resource_address) = setup(&mut test, "new");
+
+    test.execute_expect_success(withdraw_to_account(&test, component, account), vec![]);
+    let vaults: BTreeMap<ResourceAddress, VaultId> = test.extract_component_value(account, "$.vaults");
+    let account_vault = vaults[&resource_address];
+
+    test.call_method::<()>(component, "freeze_vault", args![account_vault], vec![]);
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(account, "withdraw", args![resource_address, Amount(10)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(&owner_key)
+            .build(),
+        vec![owner_proof.clone()],
+    );
+    assert_reject_reason(reason, RuntimeError::VaultFrozen {
+        vault_id: account_vault,
+    });
+
+    let reason = test.execute_expect_failure(withdraw_to_account(&test, component, account), vec![]);
+    assert_reject_reason(reason, RuntimeError::VaultFrozen {
+        vault_id: account_vault,
+    });
+
+    // Other vaults of the resource are not affected
+    let is_frozen: bool = test.call_method(component, "is_frozen", args![], vec![]);
+    assert!(!is_frozen);
+
+    test.call_method::<()>(component, "unfreeze_vault", args![account_vault], vec![]);
+    test.execute_expect_success(withdraw_to_account(&test, component, account), vec![]);
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "withdraw", args![resource_address, Amount(10)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(&owner_key)
+            .build(),
+        vec![owner_proof],
+    );
+}
+
+#[test]
+fn it_prevents_moving_tokens_of_a_paused_resource() {
+    let mut test = TemplateTest::new(["tests/templates/freeze"]);
+    let (account, _, _) = test.create_empty_account();
+    let (component, resource_address) = setup(&mut test, "new");
+
+    test.call_method::<()>(component, "pause", args![], vec![]);
+    let is_paused: bool = test.call_method(component, "is_paused", args![], vec![]);
+    assert!(is_paused);
+
+    let reason = test.execute_expect_failure(withdraw_to_account(&test, component, account), vec![]);
+    assert_reject_reason(reason, RuntimeError::ResourcePaused { resource_address });
+
+    test.call_method::<()>(component, "unpause", args![], vec![]);
+    test.execute_expect_success(withdraw_to_account(&test, component, account), vec![]);
+
+    // Buckets of a paused resource cannot be split
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "pause_and_split", args![Amount(10)])
+            .put_last_instruction_output_on_workspace("buckets")
+            .call_method(account, "deposit", args![Workspace("buckets.0")])
+            .call_method(account, "deposit", args![Workspace("buckets.1")])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert_reject_reason(reason, RuntimeError::ResourcePaused { resource_address });
+}
+
+#[test]
+fn it_denies_freezing_by_default() {
+    let mut test = TemplateTest::new(["tests/templates/freeze"]);
+    let (component, _) = setup(&mut test, "new_not_freezable");
+    let vault_id: VaultId = test.call_method(component, "vault_id", args![], vec![]);
+    let (user_proof, _, user_key) = test.create_owner_proof();
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "freeze_vault", args![vault_id])
+            .sign(&user_key)
+            .build(),
+        vec![user_proof],
+    );
+    assert_access_denied_for_action(reason, ResourceAuthAction::Freeze);
+}
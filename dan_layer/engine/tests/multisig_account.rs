@@ -0,0 +1,299 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_common_types::types::PublicKey;
+use tari_crypto::ristretto::RistrettoSecretKey;
+use tari_dan_engine::runtime::ActionIdent;
+use tari_engine_types::{
+    commit_result::RejectReason,
+    virtual_substate::{VirtualSubstate, VirtualSubstateId},
+};
+use tari_template_lib::{
+    args,
+    auth::{AccessRule, OwnerRule, RequireRule, RestrictedAccessRule, RuleRequirement},
+    constants::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
+    models::{Amount, ComponentAddress, NonFungibleAddress},
+    prelude::AccessRules,
+    rule,
+};
+use tari_template_test_tooling::{support::assert_error::assert_access_denied_for_action, TemplateTest};
+use tari_transaction::Transaction;
+
+struct Owner {
+    proof: NonFungibleAddress,
+    public_key: PublicKey,
+    secret_key: RistrettoSecretKey,
+}
+
+fn create_owner(test: &mut TemplateTest) -> Owner {
+    let (proof, public_key, secret_key) = test.create_owner_proof();
+    Owner {
+        proof,
+        public_key,
+        secret_key,
+    }
+}
+
+fn setup(test: &mut TemplateTest, owners: &[&Owner], threshold: u32) -> ComponentAddress {
+    let (account, account_proof, account_key) = test.create_funded_account();
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .create_multisig_account(owners.iter().map(|o| o.public_key.clone()), threshold)
+            .sign(&account_key)
+            .build(),
+        vec![],
+    );
+    let multisig: ComponentAddress = result.finalize.execution_results[0].decode().unwrap();
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(account, "withdraw", args![
+                CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
+                Amount(500)
+            ])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(multisig, "deposit", args![Workspace("bucket")])
+            .sign(&account_key)
+            .build(),
+        vec![account_proof],
+    );
+
+    multisig
+}
+
+fn propose_withdrawal(
+    test: &mut TemplateTest,
+    multisig: ComponentAddress,
+    owner: &Owner,
+    amount: Amount,
+    destination: ComponentAddress,
+    expires_at_epoch: u64,
+) -> u64 {
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(multisig, "propose_withdrawal", args![
+                CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
+                amount,
+                destination,
+                expires_at_epoch
+            ])
+            .sign(&owner.secret_key)
+            .build(),
+        vec![owner.proof.clone()],
+    );
+    result.finalize.execution_results[0].decode().unwrap()
+}
+
+fn approve_transaction(multisig: ComponentAddress, owner: &Owner, proposal_id: u64) -> Transaction {
+    Transaction::builder()
+        .call_method(multisig, "approve", args![proposal_id])
+        .sign(&owner.secret_key)
+        .build()
+}
+
+fn approve(test: &mut TemplateTest, multisig: ComponentAddress, owner: &Owner, proposal_id: u64) {
+    let proofs = vec![owner.proof.clone()];
+    test.execute_expect_success(approve_transaction(multisig, owner, proposal_id), proofs);
+}
+
+fn approve_expect_failure(
+    test: &mut TemplateTest,
+    multisig: ComponentAddress,
+    owner: &Owner,
+    proposal_id: u64,
+) -> RejectReason {
+    let proofs = vec![owner.proof.clone()];
+    test.execute_expect_failure(approve_transaction(multisig, owner, proposal_id), proofs)
+}
+
+fn get_balance(test: &mut TemplateTest, component: ComponentAddress) -> Amount {
+    test.call_method(component, "balance", args![CONFIDENTIAL_TARI_RESOURCE_ADDRESS], vec![])
+}
+
+#[test]
+fn it_executes_a_withdrawal_once_the_threshold_is_reached() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner1 = create_owner(&mut test);
+    let owner2 = create_owner(&mut test);
+    let owner3 = create_owner(&mut test);
+    let multisig = setup(&mut test, &[&owner1, &owner2, &owner3], 2);
+    let (recipient, _, _) = test.create_empty_account();
+
+    let proposal_id = propose_withdrawal(&mut test, multisig, &owner1, Amount(200), recipient, 10);
+    // The proposer's approval is not enough to execute the withdrawal
+    assert_eq!(get_balance(&mut test, multisig), Amount(500));
+
+    let reason = approve_expect_failure(&mut test, multisig, &owner1, proposal_id);
+    assert!(reason.to_string().contains("already been approved"), "{reason}");
+
+    approve(&mut test, multisig, &owner3, proposal_id);
+    assert_eq!(get_balance(&mut test, multisig), Amount(300));
+    assert_eq!(get_balance(&mut test, recipient), Amount(200));
+
+    // The proposal was removed once executed
+    let reason = approve_expect_failure(&mut test, multisig, &owner2, proposal_id);
+    assert!(reason.to_string().contains("not found"), "{reason}");
+}
+
+#[test]
+fn it_denies_proposals_from_non_owners() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner1 = create_owner(&mut test);
+    let owner2 = create_owner(&mut test);
+    let non_owner = create_owner(&mut test);
+    let multisig = setup(&mut test, &[&owner1, &owner2], 2);
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(multisig, "propose_withdrawal", args![
+                CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
+                Amount(100),
+                multisig,
+                10u64
+            ])
+            .sign(&non_owner.secret_key)
+            .build(),
+        vec![non_owner.proof.clone()],
+    );
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: multisig,
+        method: "propose_withdrawal".to_string(),
+    });
+
+    let proposal_id = propose_withdrawal(&mut test, multisig, &owner1, Amount(100), multisig, 10);
+    let reason = approve_expect_failure(&mut test, multisig, &non_owner, proposal_id);
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: multisig,
+        method: "approve".to_string(),
+    });
+}
+
+#[test]
+fn it_rejects_approvals_after_the_proposal_expires() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner1 = create_owner(&mut test);
+    let owner2 = create_owner(&mut test);
+    let multisig = setup(&mut test, &[&owner1, &owner2], 2);
+    let (recipient, _, _) = test.create_empty_account();
+
+    let proposal_id = propose_withdrawal(&mut test, multisig, &owner1, Amount(100), recipient, 1);
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(2));
+
+    let reason = approve_expect_failure(&mut test, multisig, &owner2, proposal_id);
+    assert!(reason.to_string().contains("expired"), "{reason}");
+    assert_eq!(get_balance(&mut test, multisig), Amount(500));
+}
+
+#[test]
+fn it_prunes_expired_proposals() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner1 = create_owner(&mut test);
+    let owner2 = create_owner(&mut test);
+    let multisig = setup(&mut test, &[&owner1, &owner2], 2);
+    let (recipient, _, _) = test.create_empty_account();
+
+    let expired_id = propose_withdrawal(&mut test, multisig, &owner1, Amount(100), recipient, 1);
+    let pending_id = propose_withdrawal(&mut test, multisig, &owner1, Amount(100), recipient, 10);
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(2));
+
+    // Making another proposal removes the expired proposal
+    let new_id = propose_withdrawal(&mut test, multisig, &owner2, Amount(100), recipient, 10);
+    let proposals: Vec<tari_bor::Value> = test.call_method(multisig, "get_proposals", args![], vec![]);
+    assert_eq!(proposals.len(), 2);
+    let expired: Option<tari_bor::Value> = test.call_method(multisig, "get_proposal", args![expired_id], vec![]);
+    assert!(expired.is_none());
+
+    let reason = approve_expect_failure(&mut test, multisig, &owner2, expired_id);
+    assert!(reason.to_string().contains("not found"), "{reason}");
+    approve(&mut test, multisig, &owner2, pending_id);
+    approve(&mut test, multisig, &owner1, new_id);
+    assert_eq!(get_balance(&mut test, recipient), Amount(200));
+}
+
+/// Creates an account that is owned by the multisig account and can only be withdrawn from by the multisig account
+fn create_multisig_owned_account(test: &mut TemplateTest, multisig: ComponentAddress) -> ComponentAddress {
+    let (_, public_key, secret_key) = test.create_owner_proof();
+    let access_rules = AccessRules::new()
+        .add_method_rule("balance", rule!(allow_all))
+        .add_method_rule("deposit", rule!(allow_all))
+        .default(rule!(component(multisig)));
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .create_account_with_custom_rules::<&str>(
+                public_key,
+                Some(OwnerRule::ByAccessRule(rule!(component(multisig)))),
+                Some(access_rules),
+                None,
+            )
+            .sign(&secret_key)
+            .build(),
+        vec![],
+    );
+    result.finalize.execution_results[0].decode().unwrap()
+}
+
+#[test]
+fn it_returns_the_value_of_an_approved_call() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner1 = create_owner(&mut test);
+    let owner2 = create_owner(&mut test);
+    let multisig = setup(&mut test, &[&owner1, &owner2], 2);
+    let owned_account = create_multisig_owned_account(&mut test, multisig);
+    let withdrawal_id = propose_withdrawal(&mut test, multisig, &owner1, Amount(200), owned_account, 10);
+    approve(&mut test, multisig, &owner2, withdrawal_id);
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_method(multisig, "propose_call", args![
+                owned_account,
+                "balance",
+                vec![tari_bor::encode(&CONFIDENTIAL_TARI_RESOURCE_ADDRESS).unwrap()],
+                10u64
+            ])
+            .sign(&owner1.secret_key)
+            .build(),
+        vec![owner1.proof.clone()],
+    );
+    let (proposal_id, value) = result.finalize.execution_results[0]
+        .decode::<(u64, Option<Amount>)>()
+        .unwrap();
+    assert!(value.is_none());
+
+    let result = test.execute_expect_success(approve_transaction(multisig, &owner2, proposal_id), vec![owner2
+        .proof
+        .clone()]);
+    let balance = result.finalize.execution_results[0].decode::<Option<Amount>>().unwrap();
+    assert_eq!(balance, Some(Amount(200)));
+}
+
+#[test]
+fn it_rejects_calls_to_components_not_owned_by_the_multisig() {
+    let mut test = TemplateTest::new::<_, &str>([]);
+    let owner1 = create_owner(&mut test);
+    let owner2 = create_owner(&mut test);
+    let multisig = setup(&mut test, &[&owner1, &owner2], 2);
+    // An account owned by owner2. If the call were allowed, it would be executed with the proofs of owner2 when they
+    // approve it.
+    let (owner2_account, _, _) = test.create_empty_account();
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(multisig, "propose_call", args![
+                owner2_account,
+                "withdraw",
+                vec![
+                    tari_bor::encode(&CONFIDENTIAL_TARI_RESOURCE_ADDRESS).unwrap(),
+                    tari_bor::encode(&Amount(100)).unwrap()
+                ],
+                10u64
+            ])
+            .sign(&owner1.secret_key)
+            .build(),
+        vec![owner1.proof.clone()],
+    );
+    assert!(
+        reason.to_string().contains("is not owned by this multisig account"),
+        "{reason}"
+    );
+}
@@ -0,0 +1,224 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_dan_engine::runtime::{ActionIdent, AssertError, RuntimeError};
+use tari_engine_types::{substate::SubstateId, transaction_receipt::TransactionReceiptAddress};
+use tari_template_lib::{
+    args,
+    constants::XTR,
+    models::{Amount, ComponentAddress},
+};
+use tari_template_test_tooling::{
+    support::assert_error::{assert_access_denied_for_action, assert_reject_reason},
+    TemplateTest,
+};
+use tari_transaction::Transaction;
+
+fn get_balance(test: &mut TemplateTest, account: ComponentAddress) -> Amount {
+    test.call_method(account, "balance", args![XTR], vec![])
+}
+
+#[test]
+fn it_executes_a_swap_composed_of_sub_intents() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, _, alice_key) = test.create_funded_account();
+    let (bob_account, bob_proof, bob_key) = test.create_funded_account();
+    let alice_balance = get_balance(&mut test, alice_account);
+    let bob_balance = get_balance(&mut test, bob_account);
+
+    // Bob offers 50 tokens
+    let bob_intent = Transaction::builder()
+        .call_method(bob_account, "withdraw", args![XTR, Amount(50)])
+        .put_last_instruction_output_on_workspace("bob_payment")
+        .build_sub_intent()
+        .sign(&bob_key);
+
+    // Alice only pays if she receives at least 50 tokens
+    let alice_intent = Transaction::builder()
+        .assert_bucket_contains("bob_payment", XTR, Amount(50))
+        .call_method(alice_account, "deposit", args![Workspace("bob_payment")])
+        .call_method(alice_account, "withdraw", args![XTR, Amount(100)])
+        .put_last_instruction_output_on_workspace("alice_payment")
+        .build_sub_intent()
+        .sign(&alice_key);
+
+    // Bob composes the transaction and claims Alice's payment
+    test.execute_expect_success(
+        Transaction::builder()
+            .add_sub_intent(bob_intent)
+            .add_sub_intent(alice_intent)
+            .call_method(bob_account, "deposit", args![Workspace("alice_payment")])
+            .sign(&bob_key)
+            .build(),
+        vec![bob_proof],
+    );
+
+    assert_eq!(get_balance(&mut test, alice_account), alice_balance - Amount(50));
+    assert_eq!(get_balance(&mut test, bob_account), bob_balance + Amount(50));
+}
+
+#[test]
+fn it_fails_if_a_sub_intent_assertion_fails() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, _, alice_key) = test.create_funded_account();
+    let (bob_account, bob_proof, bob_key) = test.create_funded_account();
+
+    let bob_intent = Transaction::builder()
+        .call_method(bob_account, "withdraw", args![XTR, Amount(49)])
+        .put_last_instruction_output_on_workspace("bob_payment")
+        .build_sub_intent()
+        .sign(&bob_key);
+
+    let alice_intent = Transaction::builder()
+        .assert_bucket_contains("bob_payment", XTR, Amount(50))
+        .call_method(alice_account, "deposit", args![Workspace("bob_payment")])
+        .call_method(alice_account, "withdraw", args![XTR, Amount(100)])
+        .put_last_instruction_output_on_workspace("alice_payment")
+        .build_sub_intent()
+        .sign(&alice_key);
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .add_sub_intent(bob_intent)
+            .add_sub_intent(alice_intent)
+            .call_method(bob_account, "deposit", args![Workspace("alice_payment")])
+            .sign(&bob_key)
+            .build(),
+        vec![bob_proof],
+    );
+    assert_reject_reason(
+        reason,
+        RuntimeError::AssertError(AssertError::InvalidAmount {
+            expected: Amount(50),
+            got: Amount(49),
+        }),
+    );
+}
+
+#[test]
+fn it_restricts_the_auth_scope_of_a_sub_intent_to_its_signers() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, alice_proof, alice_key) = test.create_funded_account();
+    let (bob_account, _, bob_key) = test.create_funded_account();
+
+    // Bob attempts to withdraw from Alice's account in his sub-intent
+    let bob_intent = Transaction::builder()
+        .call_method(alice_account, "withdraw", args![XTR, Amount(100)])
+        .put_last_instruction_output_on_workspace("stolen_coins")
+        .build_sub_intent()
+        .sign(&bob_key);
+
+    // Alice's signature on the transaction does not authorize Bob's sub-intent
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .add_sub_intent(bob_intent)
+            .call_method(bob_account, "deposit", args![Workspace("stolen_coins")])
+            .sign(&alice_key)
+            .build(),
+        vec![alice_proof],
+    );
+
+    assert_access_denied_for_action(reason, ActionIdent::ComponentCallMethod {
+        component_address: alice_account,
+        method: "withdraw".to_string(),
+    });
+}
+
+#[test]
+fn it_rejects_a_sub_intent_that_has_been_used() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, alice_proof, alice_key) = test.create_funded_account();
+    let (bob_account, _, bob_key) = test.create_funded_account();
+
+    let bob_intent = Transaction::builder()
+        .call_method(bob_account, "withdraw", args![XTR, Amount(50)])
+        .put_last_instruction_output_on_workspace("bob_payment")
+        .build_sub_intent()
+        .sign(&bob_key);
+    let intent_id = *bob_intent.intent_id();
+
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .add_sub_intent(bob_intent.clone())
+            .call_method(alice_account, "deposit", args![Workspace("bob_payment")])
+            .sign(&alice_key)
+            .build(),
+        vec![alice_proof.clone()],
+    );
+    let receipt_address = SubstateId::TransactionReceipt(TransactionReceiptAddress::from_sub_intent_id(&intent_id));
+    assert!(result
+        .finalize
+        .result
+        .accept()
+        .unwrap()
+        .up_iter()
+        .any(|(id, _)| *id == receipt_address));
+
+    // Alice attempts to withdraw from Bob's account a second time
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .add_sub_intent(bob_intent)
+            .call_method(alice_account, "deposit", args![Workspace("bob_payment")])
+            .sign(&alice_key)
+            .build(),
+        vec![alice_proof],
+    );
+    assert_reject_reason(reason, RuntimeError::SubIntentAlreadyUsed { intent_id });
+}
+
+#[test]
+fn it_rejects_a_sub_intent_that_is_included_twice() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, alice_proof, alice_key) = test.create_funded_account();
+    let (bob_account, _, bob_key) = test.create_funded_account();
+
+    let bob_intent = Transaction::builder()
+        .call_method(bob_account, "withdraw", args![XTR, Amount(50)])
+        .put_last_instruction_output_on_workspace("bob_payment")
+        .build_sub_intent()
+        .sign(&bob_key);
+    let intent_id = *bob_intent.intent_id();
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .add_sub_intent(bob_intent.clone())
+            .add_sub_intent(bob_intent)
+            .call_method(alice_account, "deposit", args![Workspace("bob_payment")])
+            .sign(&alice_key)
+            .build(),
+        vec![alice_proof],
+    );
+    assert_reject_reason(reason, RuntimeError::SubIntentAlreadyUsed { intent_id });
+}
+
+#[test]
+fn it_does_not_use_the_sub_intent_of_a_rejected_transaction() {
+    let mut test = TemplateTest::new(Vec::<&str>::new());
+    let (alice_account, alice_proof, alice_key) = test.create_funded_account();
+    let (bob_account, _, bob_key) = test.create_funded_account();
+
+    let bob_intent = Transaction::builder()
+        .call_method(bob_account, "withdraw", args![XTR, Amount(50)])
+        .put_last_instruction_output_on_workspace("bob_payment")
+        .build_sub_intent()
+        .sign(&bob_key);
+
+    // The bucket from the sub-intent is never deposited, so the transaction is rejected
+    let result = test.execute_expect_commit(
+        Transaction::builder()
+            .add_sub_intent(bob_intent.clone())
+            .sign(&alice_key)
+            .build(),
+        vec![alice_proof.clone()],
+    );
+    result.expect_fee_accept_transaction_reject();
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .add_sub_intent(bob_intent)
+            .call_method(alice_account, "deposit", args![Workspace("bob_payment")])
+            .sign(&alice_key)
+            .build(),
+        vec![alice_proof],
+    );
+}
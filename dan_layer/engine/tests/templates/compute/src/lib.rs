@@ -0,0 +1,27 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod compute {
+    use super::*;
+
+    pub struct Compute {}
+
+    impl Compute {
+        pub fn hash_rounds(rounds: u32) -> u64 {
+            let mut acc = 0xcbf2_9ce4_8422_2325u64;
+            for i in 0..rounds {
+                acc = core::hint::black_box(acc ^ u64::from(i)).wrapping_mul(0x0100_0000_01b3);
+            }
+            acc
+        }
+
+        pub fn allocate_pages(num_pages: u32) -> u32 {
+            let data = vec![1u8; num_pages as usize * 64 * 1024];
+            core::hint::black_box(&data);
+            num_pages
+        }
+    }
+}
@@ -30,7 +30,15 @@ mod consensus_template {
 
     impl TestConsensus {
         pub fn current_epoch() -> u64 {
-            Consensus::current_epoch()    
+            Consensus::current_epoch()
+        }
+
+        pub fn current_time() -> u64 {
+            Consensus::current_time()
+        }
+
+        pub fn current_block_height() -> u64 {
+            Consensus::current_block().height
         }
     }
 }
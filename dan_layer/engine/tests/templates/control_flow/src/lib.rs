@@ -0,0 +1,39 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod control_flow_template {
+    use super::*;
+
+    pub struct ControlFlow {
+        vault: Vault,
+        counter: u32,
+    }
+
+    impl ControlFlow {
+        pub fn new(initial_supply: Amount) -> Component<Self> {
+            let bucket = ResourceBuilder::fungible().initial_supply(initial_supply);
+            Component::new(Self {
+                vault: Vault::from_bucket(bucket),
+                counter: 0,
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .create()
+        }
+
+        /// Withdraws `num_buckets` buckets of `amount` tokens each
+        pub fn take_many(&mut self, num_buckets: u32, amount: Amount) -> Vec<Bucket> {
+            (0..num_buckets).map(|_| self.vault.withdraw(amount)).collect()
+        }
+
+        pub fn increment(&mut self) {
+            self.counter += 1;
+        }
+
+        pub fn counter(&self) -> u32 {
+            self.counter
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::HashMap;
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod fee_sponsor_template {
+    use super::*;
+
+    pub struct FeeSponsor {
+        vault: Vault,
+        fee_per_transaction: Amount,
+        budgets: HashMap<RistrettoPublicKeyBytes, Amount>,
+    }
+
+    impl FeeSponsor {
+        pub fn new(funds: Bucket, fee_per_transaction: Amount) -> Component<Self> {
+            Component::new(Self {
+                vault: Vault::from_bucket(funds),
+                fee_per_transaction,
+                budgets: HashMap::new(),
+            })
+            .with_access_rules(AccessRules::new().add_method_rule("sponsor_fee", rule!(allow_all)))
+            .create()
+        }
+
+        pub fn set_budget(&mut self, signer: RistrettoPublicKeyBytes, budget: Amount) {
+            self.budgets.insert(signer, budget);
+        }
+
+        pub fn sponsor_fee(&mut self) {
+            let signer = CallerContext::transaction_signer_public_key();
+            let budget = self.budgets.get_mut(&signer).expect("Signer is not sponsored");
+            *budget = budget
+                .checked_sub_positive(self.fee_per_transaction)
+                .expect("Sponsorship budget exceeded");
+            self.vault.pay_fee(self.fee_per_transaction);
+        }
+
+        pub fn balance(&self) -> Amount {
+            self.vault.balance()
+        }
+    }
+}
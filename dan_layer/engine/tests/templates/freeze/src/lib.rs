@@ -0,0 +1,78 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod freeze_template {
+    use super::*;
+
+    pub struct Freezable {
+        vault: Vault,
+    }
+
+    impl Freezable {
+        pub fn new(initial_supply: Amount) -> Component<Self> {
+            let bucket = ResourceBuilder::fungible()
+                .freezable(rule!(allow_all))
+                .initial_supply(initial_supply);
+            Component::new(Self {
+                vault: Vault::from_bucket(bucket),
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .create()
+        }
+
+        pub fn new_not_freezable(initial_supply: Amount) -> Component<Self> {
+            let bucket = ResourceBuilder::fungible().initial_supply(initial_supply);
+            Component::new(Self {
+                vault: Vault::from_bucket(bucket),
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .create()
+        }
+
+        pub fn withdraw(&mut self, amount: Amount) -> Bucket {
+            self.vault.withdraw(amount)
+        }
+
+        pub fn pause_and_split(&mut self, amount: Amount) -> (Bucket, Bucket) {
+            let mut bucket = self.vault.withdraw(amount);
+            self.pause();
+            let one = bucket.take(Amount(1));
+            (bucket, one)
+        }
+
+        pub fn freeze_vault(&self, vault_id: VaultId) {
+            ResourceManager::get(self.vault.resource_address()).freeze_vault(vault_id);
+        }
+
+        pub fn unfreeze_vault(&self, vault_id: VaultId) {
+            ResourceManager::get(self.vault.resource_address()).unfreeze_vault(vault_id);
+        }
+
+        pub fn pause(&self) {
+            ResourceManager::get(self.vault.resource_address()).pause();
+        }
+
+        pub fn unpause(&self) {
+            ResourceManager::get(self.vault.resource_address()).unpause();
+        }
+
+        pub fn is_paused(&self) -> bool {
+            ResourceManager::get(self.vault.resource_address()).is_paused()
+        }
+
+        pub fn is_frozen(&self) -> bool {
+            self.vault.is_frozen()
+        }
+
+        pub fn resource_address(&self) -> ResourceAddress {
+            self.vault.resource_address()
+        }
+
+        pub fn vault_id(&self) -> VaultId {
+            self.vault.vault_id()
+        }
+    }
+}
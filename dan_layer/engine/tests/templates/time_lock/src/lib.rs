@@ -0,0 +1,44 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::{models::VaultTimeLock, prelude::*};
+
+#[template]
+mod time_lock_template {
+    use super::*;
+
+    pub struct TimeLock {
+        vault: Vault,
+    }
+
+    impl TimeLock {
+        pub fn new(initial_supply: Amount) -> Component<Self> {
+            let bucket = ResourceBuilder::fungible().initial_supply(initial_supply);
+            Component::new(Self {
+                vault: Vault::from_bucket(bucket),
+            })
+            .with_access_rules(AccessRules::allow_all())
+            .create()
+        }
+
+        pub fn lock_until_epoch(&mut self, amount: Amount, epoch: u64) {
+            self.vault.lock_until_epoch(amount, epoch);
+        }
+
+        pub fn lock_with_linear_vesting(&mut self, amount: Amount, start_epoch: u64, end_epoch: u64) {
+            self.vault.lock_with_linear_vesting(amount, start_epoch, end_epoch);
+        }
+
+        pub fn withdraw(&mut self, amount: Amount) -> Bucket {
+            self.vault.withdraw(amount)
+        }
+
+        pub fn locked_balance(&self) -> Amount {
+            self.vault.locked_balance()
+        }
+
+        pub fn time_locks(&self) -> Vec<VaultTimeLock> {
+            self.vault.time_locks()
+        }
+    }
+}
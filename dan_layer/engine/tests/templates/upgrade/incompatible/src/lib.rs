@@ -0,0 +1,28 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod upgradeable_counter_incompatible {
+    use super::*;
+
+    pub struct UpgradeableCounterIncompatible {
+        value: u64,
+    }
+
+    impl UpgradeableCounterIncompatible {
+        pub fn increase(&mut self) {
+            self.value += 1;
+        }
+
+        // The return type differs from the previous version
+        pub fn value(&self) -> u64 {
+            self.value
+        }
+
+        pub fn upgrade(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+    }
+}
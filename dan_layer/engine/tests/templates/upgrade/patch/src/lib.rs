@@ -0,0 +1,28 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod upgradeable_counter_patch {
+    use super::*;
+
+    /// The component state is unchanged from the previous version, so no migrate function is needed
+    pub struct UpgradeableCounterPatch {
+        value: u32,
+    }
+
+    impl UpgradeableCounterPatch {
+        pub fn increase(&mut self) {
+            self.value += 2;
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn upgrade(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+    }
+}
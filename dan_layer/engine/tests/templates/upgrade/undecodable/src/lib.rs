@@ -0,0 +1,33 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod upgradeable_counter_undecodable {
+    use super::*;
+
+    /// A field was added without a migrate function, so the previous component state cannot be decoded
+    pub struct UpgradeableCounterUndecodable {
+        value: u32,
+        name: String,
+    }
+
+    impl UpgradeableCounterUndecodable {
+        pub fn increase(&mut self) {
+            self.value += 1;
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        pub fn upgrade(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+    }
+}
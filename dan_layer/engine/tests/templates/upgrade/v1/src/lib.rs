@@ -0,0 +1,33 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+#[template]
+mod upgradeable_counter {
+    use super::*;
+
+    pub struct UpgradeableCounter {
+        value: u32,
+    }
+
+    impl UpgradeableCounter {
+        pub fn new(value: u32) -> Component<Self> {
+            Component::new(Self { value })
+                .with_access_rules(AccessRules::new().default(rule!(allow_all)))
+                .create()
+        }
+
+        pub fn increase(&mut self) {
+            self.value += 1;
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn upgrade(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+    }
+}
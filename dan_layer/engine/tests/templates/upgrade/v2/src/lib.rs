@@ -0,0 +1,51 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::prelude::*;
+
+/// The component state of the previous version of the template
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpgradeableCounterV1 {
+    value: u32,
+}
+
+#[template]
+mod upgradeable_counter_v2 {
+    use super::*;
+
+    pub struct UpgradeableCounterV2 {
+        value: u32,
+        num_increases: u32,
+    }
+
+    impl UpgradeableCounterV2 {
+        pub fn migrate(previous: UpgradeableCounterV1) -> Self {
+            Self {
+                value: previous.value,
+                num_increases: 0,
+            }
+        }
+
+        pub fn upgrade(&mut self, template_address: TemplateAddress) {
+            ComponentManager::current().upgrade_template(template_address);
+        }
+
+        /// Attempts to upgrade another component, as a third-party template called by the component owner would
+        pub fn upgrade_other(component_address: ComponentAddress, template_address: TemplateAddress) {
+            ComponentManager::get(component_address).upgrade_template(template_address);
+        }
+
+        pub fn increase(&mut self) {
+            self.value += 1;
+            self.num_increases += 1;
+        }
+
+        pub fn value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn num_increases(&self) -> u32 {
+            self.num_increases
+        }
+    }
+}
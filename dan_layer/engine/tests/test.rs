@@ -36,7 +36,7 @@ use tari_template_builtin::{ACCOUNT_NFT_TEMPLATE_ADDRESS, ACCOUNT_TEMPLATE_ADDRE
 use tari_template_lib::{
     args,
     crypto::RistrettoPublicKeyBytes,
-    models::{Amount, ComponentAddress, NonFungibleAddress},
+    models::{Amount, BlockInfo, ComponentAddress, NonFungibleAddress},
     prelude::{NonFungibleId, ResourceAddress},
 };
 use tari_template_test_tooling::{support::assert_error::assert_reject_reason, SubstateType, TemplateTest};
@@ -409,6 +409,28 @@ mod consensus {
         let result: u64 = template_test.call_function("TestConsensus", "current_epoch", args![], vec![]);
         assert_eq!(result, 1);
     }
+
+    #[test]
+    fn current_time_and_block() {
+        let mut template_test = TemplateTest::new(vec!["tests/templates/consensus"]);
+
+        let result: u64 = template_test.call_function("TestConsensus", "current_time", args![], vec![]);
+        assert_eq!(result, 0);
+
+        template_test.set_virtual_substate(
+            VirtualSubstateId::CurrentBlock,
+            VirtualSubstate::CurrentBlock(BlockInfo {
+                height: 123,
+                timestamp: 1_700_000_000,
+                shard_group_start: 0,
+                shard_group_end: 63,
+            }),
+        );
+        let result: u64 = template_test.call_function("TestConsensus", "current_time", args![], vec![]);
+        assert_eq!(result, 1_700_000_000);
+        let result: u64 = template_test.call_function("TestConsensus", "current_block_height", args![], vec![]);
+        assert_eq!(result, 123);
+    }
 }
 
 mod fungible {
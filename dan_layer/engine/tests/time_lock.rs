@@ -0,0 +1,135 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_engine_types::virtual_substate::{VirtualSubstate, VirtualSubstateId};
+use tari_template_lib::{
+    args,
+    models::{Amount, ComponentAddress, LockSchedule, VaultTimeLock},
+};
+use tari_template_test_tooling::TemplateTest;
+use tari_transaction::Transaction;
+
+fn setup(test: &mut TemplateTest) -> ComponentAddress {
+    let template = test.get_template_address("TimeLock");
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_function(template, "new", args![Amount(1000)])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    result.finalize.execution_results[0].decode().unwrap()
+}
+
+fn set_epoch(test: &mut TemplateTest, epoch: u64) {
+    test.set_virtual_substate(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(epoch));
+}
+
+#[test]
+fn it_prevents_withdrawing_locked_tokens_until_the_unlock_epoch() {
+    let mut test = TemplateTest::new(["tests/templates/time_lock"]);
+    let (account, _, _) = test.create_empty_account();
+    let component = setup(&mut test);
+
+    test.call_method::<()>(component, "lock_until_epoch", args![Amount(600), 10u64], vec![]);
+    let locked: Amount = test.call_method(component, "locked_balance", args![], vec![]);
+    assert_eq!(locked, Amount(600));
+
+    // Unlocked tokens can be withdrawn
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(component, "withdraw", args![Amount(400)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+
+    // Locked tokens cannot be withdrawn
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "withdraw", args![Amount(1)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert!(reason.to_string().contains("Tokens are time locked"), "{reason}");
+
+    set_epoch(&mut test, 10);
+    let locked: Amount = test.call_method(component, "locked_balance", args![], vec![]);
+    assert_eq!(locked, Amount(0));
+    let locks: Vec<VaultTimeLock> = test.call_method(component, "time_locks", args![], vec![]);
+    assert!(locks.is_empty());
+
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(component, "withdraw", args![Amount(600)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+}
+
+#[test]
+fn it_releases_vested_tokens_linearly() {
+    let mut test = TemplateTest::new(["tests/templates/time_lock"]);
+    let (account, _, _) = test.create_empty_account();
+    let component = setup(&mut test);
+
+    test.call_method::<()>(
+        component,
+        "lock_with_linear_vesting",
+        args![Amount(1000), 2u64, 6u64],
+        vec![],
+    );
+    let locks: Vec<VaultTimeLock> = test.call_method(component, "time_locks", args![], vec![]);
+    assert_eq!(locks.len(), 1);
+    assert_eq!(locks[0].schedule, LockSchedule::LinearVesting {
+        start_epoch: 2,
+        end_epoch: 6
+    });
+
+    // A quarter of the tokens are released at epoch 3
+    set_epoch(&mut test, 3);
+    let locked: Amount = test.call_method(component, "locked_balance", args![], vec![]);
+    assert_eq!(locked, Amount(750));
+
+    test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "withdraw", args![Amount(251)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(component, "withdraw", args![Amount(250)])
+            .put_last_instruction_output_on_workspace("bucket")
+            .call_method(account, "deposit", args![Workspace("bucket")])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+}
+
+#[test]
+fn it_rejects_locks_that_exceed_the_vault_balance() {
+    let mut test = TemplateTest::new(["tests/templates/time_lock"]);
+    let component = setup(&mut test);
+
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "lock_until_epoch", args![Amount(1001), 10u64])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert!(reason.to_string().contains("Tokens are time locked"), "{reason}");
+}
@@ -0,0 +1,164 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_lib::{args, models::ComponentAddress, prelude::TemplateAddress};
+use tari_template_test_tooling::TemplateTest;
+use tari_transaction::Transaction;
+
+const TEMPLATES: [&str; 5] = [
+    "tests/templates/upgrade/v1",
+    "tests/templates/upgrade/v2",
+    "tests/templates/upgrade/incompatible",
+    "tests/templates/upgrade/patch",
+    "tests/templates/upgrade/undecodable",
+];
+
+fn setup(test: &mut TemplateTest) -> ComponentAddress {
+    let template = test.get_template_address("UpgradeableCounter");
+    let result = test.execute_expect_success(
+        Transaction::builder()
+            .call_function(template, "new", args![5u32])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    result.finalize.execution_results[0].decode().unwrap()
+}
+
+fn upgrade(test: &mut TemplateTest, component: ComponentAddress, template: TemplateAddress) {
+    test.execute_expect_success(
+        Transaction::builder()
+            .call_method(component, "upgrade", args![template])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+}
+
+#[test]
+fn it_upgrades_the_template_and_migrates_the_component_state() {
+    let mut test = TemplateTest::new(TEMPLATES);
+    let component = setup(&mut test);
+    test.call_method::<()>(component, "increase", args![], vec![]);
+
+    let v2_template = test.get_template_address("UpgradeableCounterV2");
+    upgrade(&mut test, component, v2_template);
+
+    let component_header = test.read_only_state_store().get_component(component).unwrap();
+    assert_eq!(component_header.template_address, v2_template);
+    assert_eq!(component_header.module_name, "UpgradeableCounterV2");
+
+    // The value was carried over by the migrate function
+    let value: u32 = test.call_method(component, "value", args![], vec![]);
+    assert_eq!(value, 6);
+    // Methods that only exist in the new template can be called
+    let num_increases: u32 = test.call_method(component, "num_increases", args![], vec![]);
+    assert_eq!(num_increases, 0);
+
+    test.call_method::<()>(component, "increase", args![], vec![]);
+    let value: u32 = test.call_method(component, "value", args![], vec![]);
+    assert_eq!(value, 7);
+    let num_increases: u32 = test.call_method(component, "num_increases", args![], vec![]);
+    assert_eq!(num_increases, 1);
+}
+
+#[test]
+fn it_upgrades_without_a_migrate_function_if_the_state_decodes() {
+    let mut test = TemplateTest::new(TEMPLATES);
+    let component = setup(&mut test);
+
+    let patch_template = test.get_template_address("UpgradeableCounterPatch");
+    upgrade(&mut test, component, patch_template);
+
+    let component_header = test.read_only_state_store().get_component(component).unwrap();
+    assert_eq!(component_header.template_address, patch_template);
+
+    test.call_method::<()>(component, "increase", args![], vec![]);
+    let value: u32 = test.call_method(component, "value", args![], vec![]);
+    assert_eq!(value, 7);
+}
+
+#[test]
+fn it_rejects_upgrading_without_a_migrate_function_if_the_state_does_not_decode() {
+    let mut test = TemplateTest::new(TEMPLATES);
+    let component = setup(&mut test);
+
+    let undecodable_template = test.get_template_address("UpgradeableCounterUndecodable");
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "upgrade", args![undecodable_template])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert!(
+        reason.to_string().contains("failed to migrate the component state"),
+        "{reason}"
+    );
+
+    let template = test.get_template_address("UpgradeableCounter");
+    let component_header = test.read_only_state_store().get_component(component).unwrap();
+    assert_eq!(component_header.template_address, template);
+}
+
+#[test]
+fn it_rejects_upgrading_to_an_incompatible_template() {
+    let mut test = TemplateTest::new(TEMPLATES);
+    let component = setup(&mut test);
+
+    let incompatible_template = test.get_template_address("UpgradeableCounterIncompatible");
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "upgrade", args![incompatible_template])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert!(
+        reason
+            .to_string()
+            .contains("the signature of method 'value' has changed"),
+        "{reason}"
+    );
+
+    let value: u32 = test.call_method(component, "value", args![], vec![]);
+    assert_eq!(value, 5);
+}
+
+#[test]
+fn it_only_allows_the_owner_to_upgrade_the_template() {
+    let mut test = TemplateTest::new(TEMPLATES);
+    let component = setup(&mut test);
+    let (user_proof, _, user_key) = test.create_owner_proof();
+
+    let v2_template = test.get_template_address("UpgradeableCounterV2");
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_method(component, "upgrade", args![v2_template])
+            .sign(&user_key)
+            .build(),
+        vec![user_proof],
+    );
+    assert!(reason.to_string().contains("You must be the owner"), "{reason}");
+}
+
+#[test]
+fn it_does_not_allow_another_template_to_upgrade_the_component() {
+    let mut test = TemplateTest::new(TEMPLATES);
+    let component = setup(&mut test);
+
+    // Even though the owner signs the transaction, the upgrade is not made from within the component
+    let v2_template = test.get_template_address("UpgradeableCounterV2");
+    let reason = test.execute_expect_failure(
+        Transaction::builder()
+            .call_function(v2_template, "upgrade_other", args![component, v2_template])
+            .sign(test.get_test_secret_key())
+            .build(),
+        vec![],
+    );
+    assert!(reason.to_string().contains("within a component context"), "{reason}");
+
+    let template = test.get_template_address("UpgradeableCounter");
+    let component_header = test.read_only_state_store().get_component(component).unwrap();
+    assert_eq!(component_header.template_address, template);
+}
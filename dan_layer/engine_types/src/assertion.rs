@@ -0,0 +1,116 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+use tari_bor::BorError;
+use tari_template_lib::models::{Amount, ComponentAddress, NonFungibleId, VaultId};
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+use crate::serde_with;
+
+/// A condition that is checked by the engine when the `Instruction::Assert` instruction is executed. If the condition
+/// does not hold, the transaction fails.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub enum Assertion {
+    /// The vault holds at least `min_amount` tokens
+    VaultBalanceAtLeast {
+        #[serde(with = "serde_with::string")]
+        vault_id: VaultId,
+        min_amount: Amount,
+    },
+    /// The vault holds at most `max_amount` tokens
+    VaultBalanceAtMost {
+        #[serde(with = "serde_with::string")]
+        vault_id: VaultId,
+        max_amount: Amount,
+    },
+    /// The bucket in the workspace contains the non-fungible token
+    BucketContainsNonFungible {
+        key: Vec<u8>,
+        non_fungible_id: NonFungibleId,
+    },
+    /// The workspace value is equal to the CBOR-encoded value
+    WorkspaceValueEquals { key: Vec<u8>, value: Vec<u8> },
+    /// The value at the path (e.g. `$.counter` or `$.pools.0`) in the component's state is equal to the CBOR-encoded
+    /// value
+    ComponentStateEquals {
+        #[serde(with = "serde_with::string")]
+        component_address: ComponentAddress,
+        path: String,
+        value: Vec<u8>,
+    },
+}
+
+impl Assertion {
+    pub fn workspace_value_equals<K: AsRef<[u8]>, T: Serialize + ?Sized>(key: K, value: &T) -> Result<Self, BorError> {
+        Ok(Self::WorkspaceValueEquals {
+            key: key.as_ref().to_vec(),
+            value: tari_bor::encode(value)?,
+        })
+    }
+
+    pub fn component_state_equals<T: Serialize + ?Sized>(
+        component_address: ComponentAddress,
+        path: impl Into<String>,
+        value: &T,
+    ) -> Result<Self, BorError> {
+        Ok(Self::ComponentStateEquals {
+            component_address,
+            path: path.into(),
+            value: tari_bor::encode(value)?,
+        })
+    }
+}
+
+impl Display for Assertion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VaultBalanceAtLeast { vault_id, min_amount } => {
+                write!(
+                    f,
+                    "VaultBalanceAtLeast {{ vault_id: {}, min_amount: {} }}",
+                    vault_id, min_amount
+                )
+            },
+            Self::VaultBalanceAtMost { vault_id, max_amount } => {
+                write!(
+                    f,
+                    "VaultBalanceAtMost {{ vault_id: {}, max_amount: {} }}",
+                    vault_id, max_amount
+                )
+            },
+            Self::BucketContainsNonFungible { key, non_fungible_id } => {
+                write!(
+                    f,
+                    "BucketContainsNonFungible {{ key: {:?}, non_fungible_id: {} }}",
+                    key, non_fungible_id
+                )
+            },
+            Self::WorkspaceValueEquals { key, value } => {
+                write!(
+                    f,
+                    "WorkspaceValueEquals {{ key: {:?}, value: {} bytes }}",
+                    key,
+                    value.len()
+                )
+            },
+            Self::ComponentStateEquals {
+                component_address,
+                path,
+                value,
+            } => {
+                write!(
+                    f,
+                    "ComponentStateEquals {{ component_address: {}, path: {}, value: {} bytes }}",
+                    component_address,
+                    path,
+                    value.len()
+                )
+            },
+        }
+    }
+}
@@ -100,6 +100,12 @@ impl ComponentHeader {
         self
     }
 
+    pub fn set_owner(&mut self, owner_key: Option<RistrettoPublicKeyBytes>, owner_rule: OwnerRule) -> &mut Self {
+        self.owner_key = owner_key;
+        self.owner_rule = owner_rule;
+        self
+    }
+
     pub fn contains_substate(&self, address: &SubstateId) -> Result<bool, IndexedValueError> {
         let found = IndexedWellKnownTypes::value_contains_substate(self.state(), address)?;
         Ok(found)
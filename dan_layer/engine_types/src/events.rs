@@ -32,6 +32,11 @@ use ts_rs::TS;
 
 use crate::{serde_with, substate::SubstateId};
 
+// Topics for builtin events emmitted by the engine
+pub const STANDARD_TOPIC_PREFIX: &str = "std.";
+pub const VAULT_DEPOSIT_TOPIC: &str = "std.vault.deposit";
+pub const VAULT_WITHDRAW_TOPIC: &str = "std.vault.withdraw";
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
 pub struct Event {
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use indexmap::{map::Entry, IndexMap};
 use serde::{Deserialize, Serialize};
-use tari_template_lib::models::{Amount, VaultId};
+use tari_template_lib::models::{Amount, ComponentAddress, VaultId};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
@@ -72,12 +72,23 @@ pub enum FeeSource {
     Storage,
     Events,
     Logs,
+    WasmCompute,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
 pub struct FeeBreakdown {
+    /// Fees charged by cost category
     breakdown: IndexMap<FeeSource, u64>,
+    /// Fees charged while executing each top-level instruction. Instructions are numbered in the order that they are
+    /// executed i.e. the fee instructions, then the sub-intent instructions and then the transaction instructions.
+    /// Costs that are not incurred by a particular instruction (e.g. storage) are not included.
+    #[serde(default)]
+    by_instruction: IndexMap<u32, u64>,
+    /// Fees charged while executing a method on each component
+    #[serde(default)]
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, bigint>"))]
+    by_component: IndexMap<ComponentAddress, u64>,
 }
 
 impl FeeBreakdown {
@@ -93,11 +104,43 @@ impl FeeBreakdown {
         }
     }
 
+    /// Attributes a charge to the top-level instruction at the given index.
+    pub fn insert_for_instruction(&mut self, instruction_index: u32, amount: u64) {
+        *self.by_instruction.entry(instruction_index).or_default() += amount;
+    }
+
+    /// Attributes a charge to a component whose method was being executed.
+    pub fn insert_for_component(&mut self, component_address: ComponentAddress, amount: u64) {
+        *self.by_component.entry(component_address).or_default() += amount;
+    }
+
     /// Returns an iterator over the fee breakdown in a canonical order.
     pub fn iter(&self) -> impl Iterator<Item = (&FeeSource, &u64)> {
         self.breakdown.iter()
     }
 
+    /// Returns an iterator over the fees charged for each top-level instruction in execution order.
+    pub fn iter_by_instruction(&self) -> impl Iterator<Item = (&u32, &u64)> {
+        self.by_instruction.iter()
+    }
+
+    /// Returns an iterator over the fees charged for each component in the order that they were first invoked.
+    pub fn iter_by_component(&self) -> impl Iterator<Item = (&ComponentAddress, &u64)> {
+        self.by_component.iter()
+    }
+
+    pub fn get_by_instruction(&self, instruction_index: u32) -> u64 {
+        self.by_instruction.get(&instruction_index).copied().unwrap_or(0)
+    }
+
+    pub fn get_by_component(&self, component_address: &ComponentAddress) -> u64 {
+        self.by_component.get(component_address).copied().unwrap_or(0)
+    }
+
+    pub fn get(&self, source: FeeSource) -> u64 {
+        self.breakdown.get(&source).copied().unwrap_or(0)
+    }
+
     pub fn get_total(&self) -> u64 {
         self.breakdown.values().sum()
     }
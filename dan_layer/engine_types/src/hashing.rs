@@ -183,6 +183,8 @@ pub enum EngineHashDomainLabel {
     QuorumCertificate,
     SubstateValue,
     ViewKey,
+    SubIntentSignature,
+    SubIntentReceipt,
 }
 
 impl EngineHashDomainLabel {
@@ -206,6 +208,8 @@ impl EngineHashDomainLabel {
             Self::QuorumCertificate => "QuorumCertificate",
             Self::SubstateValue => "SubstateValue",
             Self::ViewKey => "ViewKey",
+            Self::SubIntentSignature => "SubIntentSignature",
+            Self::SubIntentReceipt => "SubIntentReceipt",
         }
     }
 }
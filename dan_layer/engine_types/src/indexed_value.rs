@@ -464,7 +464,8 @@ where for<'a> T: serde::Deserialize<'a> {
         .map_err(Into::into)
 }
 
-fn get_value_by_path<'a>(value: &'a tari_bor::Value, path: &str) -> Option<&'a tari_bor::Value> {
+/// Returns the value at the given path (e.g. `$.field.0`) or None if the path does not exist in the value
+pub fn get_value_by_path<'a>(value: &'a tari_bor::Value, path: &str) -> Option<&'a tari_bor::Value> {
     let mut value = value;
     for part in path.split('.') {
         if part == "$" {
@@ -478,7 +479,7 @@ fn get_value_by_path<'a>(value: &'a tari_bor::Value, path: &str) -> Option<&'a t
                     .1;
             },
             tari_bor::Value::Array(list) => {
-                let index: usize = part.parse().expect("invalid index");
+                let index: usize = part.parse().ok()?;
                 value = list.get(index)?;
             },
             _ => return None,
@@ -1,7 +1,10 @@
 //  Copyright 2022 The Tari Project
 //  SPDX-License-Identifier: BSD-3-Clause
 
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    iter,
+};
 
 use serde::{Deserialize, Serialize};
 use tari_common_types::types::PublicKey;
@@ -15,7 +18,10 @@ use tari_template_lib::{
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-use crate::{confidential::ConfidentialClaim, serde_with};
+use crate::{assertion::Assertion, confidential::ConfidentialClaim, serde_with};
+
+/// The maximum depth that control flow instructions may be nested within each other
+pub const MAX_INSTRUCTION_NESTING_DEPTH: usize = 8;
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
@@ -68,6 +74,67 @@ pub enum Instruction {
         resource_address: ResourceAddress,
         min_amount: Amount,
     },
+    Assert {
+        assertion: Assertion,
+    },
+    /// Executes `then_instructions` if the condition holds, otherwise executes `else_instructions`
+    If {
+        condition: Assertion,
+        then_instructions: Vec<Instruction>,
+        else_instructions: Vec<Instruction>,
+    },
+    /// Executes `instructions` once for every item in the vector on the workspace at `key`. Each item is put on the
+    /// workspace at `item_key` before the instructions are executed. Fails if the vector has more than
+    /// `max_iterations` items.
+    ForEach {
+        key: Vec<u8>,
+        item_key: Vec<u8>,
+        max_iterations: u32,
+        instructions: Vec<Instruction>,
+    },
+    /// Calls the fee sponsor method of the component, which pays the transaction fees on behalf of the signer
+    SponsorFee {
+        #[serde(with = "serde_with::string")]
+        component_address: ComponentAddress,
+    },
+}
+
+impl Instruction {
+    /// Returns an iterator over this instruction followed by all instructions nested within it
+    pub fn iter_nested(&self) -> impl Iterator<Item = &Instruction> + '_ {
+        let mut stack = vec![self];
+        iter::from_fn(move || {
+            let instruction = stack.pop()?;
+            let (first, second) = instruction.nested_instructions();
+            stack.extend(first.iter().chain(second).rev());
+            Some(instruction)
+        })
+    }
+
+    /// Returns how deep control flow instructions are nested within this instruction. An instruction that contains
+    /// no nested instructions has a depth of 0.
+    pub fn nesting_depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 0)];
+        while let Some((instruction, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            let (first, second) = instruction.nested_instructions();
+            stack.extend(first.iter().chain(second).map(|i| (i, depth + 1)));
+        }
+        max_depth
+    }
+
+    fn nested_instructions(&self) -> (&[Instruction], &[Instruction]) {
+        match self {
+            Self::If {
+                then_instructions,
+                else_instructions,
+                ..
+            } => (then_instructions, else_instructions),
+            Self::ForEach { instructions, .. } => (instructions, &[]),
+            _ => (&[], &[]),
+        }
+    }
 }
 
 impl Display for Instruction {
@@ -149,6 +216,40 @@ impl Display for Instruction {
                     key, resource_address, min_amount
                 )
             },
+            Self::Assert { assertion } => {
+                write!(f, "Assert {{ {} }}", assertion)
+            },
+            Self::If {
+                condition,
+                then_instructions,
+                else_instructions,
+            } => {
+                write!(
+                    f,
+                    "If {{ condition: {}, then_instructions: {}, else_instructions: {} }}",
+                    condition,
+                    then_instructions.len(),
+                    else_instructions.len()
+                )
+            },
+            Self::ForEach {
+                key,
+                item_key,
+                max_iterations,
+                instructions,
+            } => {
+                write!(
+                    f,
+                    "ForEach {{ key: {:?}, item_key: {:?}, max_iterations: {}, instructions: {} }}",
+                    key,
+                    item_key,
+                    max_iterations,
+                    instructions.len()
+                )
+            },
+            Self::SponsorFee { component_address } => {
+                write!(f, "SponsorFee {{ component_address: {} }}", component_address)
+            },
         }
     }
 }
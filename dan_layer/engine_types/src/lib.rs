@@ -1,6 +1,7 @@
 //  Copyright 2022 The Tari Project
 //  SPDX-License-Identifier: BSD-3-Clause
 
+pub mod assertion;
 pub mod base_layer_hashing;
 pub mod bucket;
 pub mod commit_result;
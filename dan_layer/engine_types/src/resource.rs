@@ -46,6 +46,8 @@ pub struct Resource {
     #[cfg_attr(feature = "ts", ts(type = "string | null"))]
     view_key: Option<PublicKey>,
     auth_hook: Option<AuthHook>,
+    #[serde(default)]
+    is_paused: bool,
 }
 
 impl Resource {
@@ -67,6 +69,7 @@ impl Resource {
             total_supply: 0.into(),
             view_key,
             auth_hook,
+            is_paused: false,
         }
     }
 
@@ -105,6 +108,15 @@ impl Resource {
         self.access_rules = access_rules;
     }
 
+    /// Returns true if the resource is paused. Tokens of a paused resource cannot be moved.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    pub fn set_paused(&mut self, is_paused: bool) {
+        self.is_paused = is_paused;
+    }
+
     pub fn increase_total_supply(&mut self, amount: Amount) -> bool {
         assert!(
             amount.is_positive(),
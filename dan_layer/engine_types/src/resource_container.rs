@@ -185,6 +185,18 @@ impl ResourceContainer {
         }
     }
 
+    /// Returns true if the container holds the non-fungible token, including tokens that are locked by a proof
+    pub fn contains_non_fungible(&self, id: &NonFungibleId) -> bool {
+        match self {
+            ResourceContainer::NonFungible {
+                token_ids,
+                locked_token_ids,
+                ..
+            } => token_ids.contains(id) || locked_token_ids.contains(id),
+            _ => false,
+        }
+    }
+
     pub fn into_non_fungible_ids(self) -> Option<BTreeSet<NonFungibleId>> {
         match self {
             ResourceContainer::NonFungible { token_ids, .. } => Some(token_ids),
@@ -814,4 +826,8 @@ pub enum ResourceError {
     InvalidConfidentialProof { details: String },
     #[error("Invalid confidential mint, no change should be specified")]
     InvalidConfidentialMintWithChange,
+    #[error("Invalid time lock: {details}")]
+    InvalidTimeLock { details: String },
+    #[error("Tokens are time locked: {details}")]
+    TimeLocked { details: String },
 }
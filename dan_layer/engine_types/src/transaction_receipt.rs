@@ -16,7 +16,12 @@ use tari_template_lib::{
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-use crate::{events::Event, fees::FeeReceipt, logs::LogEntry};
+use crate::{
+    events::Event,
+    fees::FeeReceipt,
+    hashing::{hasher32, EngineHashDomainLabel},
+    logs::LogEntry,
+};
 
 const TAG: u64 = BinaryTag::TransactionReceipt.as_u64();
 
@@ -30,6 +35,15 @@ impl TransactionReceiptAddress {
         Self(BorTag::new(key))
     }
 
+    /// Returns the address of the receipt that marks a sub-intent as used. The address is hashed from the intent id so
+    /// that a sub-intent cannot claim the receipt address of a transaction.
+    pub fn from_sub_intent_id(intent_id: &Hash) -> Self {
+        let hash = hasher32(EngineHashDomainLabel::SubIntentReceipt)
+            .chain(intent_id)
+            .result();
+        Self::from_hash(hash)
+    }
+
     pub fn as_object_key(&self) -> &ObjectKey {
         self.0.inner()
     }
@@ -26,7 +26,16 @@ use serde::{Deserialize, Serialize};
 use tari_common_types::types::{Commitment, PrivateKey, PublicKey};
 use tari_template_lib::{
     crypto::PedersonCommitmentBytes,
-    models::{Amount, ConfidentialWithdrawProof, NonFungibleId, ResourceAddress, VaultId},
+    models::{
+        Amount,
+        ConfidentialWithdrawProof,
+        LockSchedule,
+        NonFungibleId,
+        ResourceAddress,
+        TimeLockedAsset,
+        VaultId,
+        VaultTimeLock,
+    },
     prelude::ResourceType,
 };
 #[cfg(feature = "ts")]
@@ -43,15 +52,29 @@ use crate::{
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
 pub struct Vault {
     resource_container: ResourceContainer,
+    #[serde(default)]
+    time_locks: Vec<VaultTimeLock>,
+    #[serde(default)]
+    is_frozen: bool,
 }
 
 impl Vault {
     pub fn new(resource: ResourceContainer) -> Self {
         Self {
             resource_container: resource,
+            time_locks: Vec::new(),
+            is_frozen: false,
         }
     }
 
+    pub fn is_frozen(&self) -> bool {
+        self.is_frozen
+    }
+
+    pub fn set_frozen(&mut self, is_frozen: bool) {
+        self.is_frozen = is_frozen;
+    }
+
     pub fn deposit(&mut self, bucket: Bucket) -> Result<(), ResourceError> {
         self.resource_container.deposit(bucket.into_resource())?;
         Ok(())
@@ -77,6 +100,8 @@ impl Vault {
     }
 
     pub fn recall_all(&mut self) -> Result<ResourceContainer, ResourceError> {
+        // Recalling is a privileged resource operation that empties the vault, so any time locks no longer apply
+        self.time_locks.clear();
         self.resource_container.recall_all()
     }
 
@@ -97,6 +122,146 @@ impl Vault {
         self.resource_container.locked_amount()
     }
 
+    pub fn time_locks(&self) -> &[VaultTimeLock] {
+        &self.time_locks
+    }
+
+    /// Returns the amount (or number of non-fungible tokens) that is time locked at the given epoch
+    pub fn time_locked_balance(&self, epoch: u64) -> Amount {
+        self.time_locks.iter().map(|lock| lock.locked_amount_at(epoch)).sum()
+    }
+
+    /// Returns the total amount that may not be withdrawn at the given epoch, because it is either locked by a proof
+    /// or by a time lock.
+    pub fn total_locked_balance(&self, epoch: u64) -> Amount {
+        let held = self.balance() + self.locked_balance();
+        let locked = self.locked_balance() + self.time_locked_balance(epoch);
+        locked.min(held)
+    }
+
+    /// Adds a time lock to tokens that are held in this vault. Locks that have been fully released at the given epoch
+    /// are removed first, so that released non-fungibles may be locked again.
+    pub fn add_time_lock(&mut self, lock: VaultTimeLock, epoch: u64) -> Result<(), ResourceError> {
+        self.prune_released_time_locks(epoch);
+        match (&lock.asset, self.resource_type()) {
+            (_, ResourceType::Confidential) => {
+                return Err(ResourceError::OperationNotAllowed(
+                    "Time locks are not supported for confidential resources".to_string(),
+                ));
+            },
+            (TimeLockedAsset::Fungible { amount }, ResourceType::Fungible) => {
+                if amount.is_zero() || amount.is_negative() {
+                    return Err(ResourceError::InvalidTimeLock {
+                        details: format!("Locked amount must be positive but was {}", amount),
+                    });
+                }
+            },
+            (TimeLockedAsset::NonFungible { ids }, ResourceType::NonFungible) => {
+                if ids.is_empty() {
+                    return Err(ResourceError::InvalidTimeLock {
+                        details: "No non-fungible ids specified".to_string(),
+                    });
+                }
+                if let Some(id) = ids.iter().find(|id| self.is_non_fungible_time_locked(id)) {
+                    return Err(ResourceError::InvalidTimeLock {
+                        details: format!("Non-fungible {} is already time locked", id),
+                    });
+                }
+            },
+            (TimeLockedAsset::Fungible { .. }, resource_type) => {
+                return Err(ResourceError::ResourceTypeMismatch {
+                    operate: "time lock",
+                    expected: ResourceType::Fungible,
+                    given: resource_type,
+                });
+            },
+            (TimeLockedAsset::NonFungible { .. }, resource_type) => {
+                return Err(ResourceError::ResourceTypeMismatch {
+                    operate: "time lock",
+                    expected: ResourceType::NonFungible,
+                    given: resource_type,
+                });
+            },
+        }
+
+        if let LockSchedule::LinearVesting { start_epoch, end_epoch } = lock.schedule {
+            if end_epoch <= start_epoch {
+                return Err(ResourceError::InvalidTimeLock {
+                    details: format!(
+                        "Vesting end epoch {} must be after the start epoch {}",
+                        end_epoch, start_epoch
+                    ),
+                });
+            }
+        }
+        if lock.is_released_at(epoch) {
+            return Err(ResourceError::InvalidTimeLock {
+                details: format!(
+                    "Lock is already released at the current epoch {} (release epoch {})",
+                    epoch,
+                    lock.schedule.end_epoch()
+                ),
+            });
+        }
+
+        self.time_locks.push(lock);
+        self.check_time_locks(epoch)
+    }
+
+    /// Checks that the vault still holds all tokens that are time locked at the given epoch. This must be called after
+    /// any withdrawal from the vault.
+    pub fn check_time_locks(&self, epoch: u64) -> Result<(), ResourceError> {
+        let mut fungible_locked = Amount::zero();
+        for lock in &self.time_locks {
+            match &lock.asset {
+                TimeLockedAsset::Fungible { .. } => {
+                    fungible_locked += lock.locked_amount_at(epoch);
+                },
+                TimeLockedAsset::NonFungible { ids } => {
+                    let locked = lock.locked_amount_at(epoch);
+                    let num_held = ids
+                        .iter()
+                        .filter(|id| self.resource_container.contains_non_fungible(id))
+                        .count();
+                    if Amount::try_from(num_held).unwrap_or(Amount::MAX) < locked {
+                        return Err(ResourceError::TimeLocked {
+                            details: format!(
+                                "{} of the time locked non-fungibles must remain in the vault until epoch {}, but \
+                                 only {} would remain",
+                                locked,
+                                lock.schedule.end_epoch(),
+                                num_held
+                            ),
+                        });
+                    }
+                },
+            }
+        }
+
+        let held = self.balance() + self.locked_balance();
+        if held < fungible_locked {
+            return Err(ResourceError::TimeLocked {
+                details: format!(
+                    "{} tokens are time locked at epoch {} but the vault would only hold {}",
+                    fungible_locked, epoch, held
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Removes time locks that have been fully released at the given epoch
+    pub fn prune_released_time_locks(&mut self, epoch: u64) {
+        self.time_locks.retain(|lock| !lock.is_released_at(epoch));
+    }
+
+    fn is_non_fungible_time_locked(&self, id: &NonFungibleId) -> bool {
+        self.time_locks
+            .iter()
+            .any(|lock| matches!(&lock.asset, TimeLockedAsset::NonFungible { ids } if ids.contains(id)))
+    }
+
     pub fn get_commitment_count(&self) -> u32 {
         self.resource_container.get_commitment_count()
     }
@@ -177,3 +342,43 @@ impl Vault {
         self.resource_container.unlock(proof.into_resource_container())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tari_template_lib::models::ObjectKey;
+
+    use super::*;
+
+    fn non_fungible_vault(ids: &[u32]) -> Vault {
+        let container = ResourceContainer::non_fungible(
+            ResourceAddress::new(ObjectKey::default()),
+            ids.iter().copied().map(NonFungibleId::from_u32).collect(),
+        );
+        Vault::new(container)
+    }
+
+    fn nft_lock(id: u32, until_epoch: u64) -> VaultTimeLock {
+        VaultTimeLock::new(
+            TimeLockedAsset::NonFungible {
+                ids: BTreeSet::from([NonFungibleId::from_u32(id)]),
+            },
+            LockSchedule::UntilEpoch(until_epoch),
+        )
+    }
+
+    #[test]
+    fn it_rejects_locking_a_non_fungible_that_is_already_locked() {
+        let mut vault = non_fungible_vault(&[1, 2]);
+        vault.add_time_lock(nft_lock(1, 10), 1).unwrap();
+        vault.add_time_lock(nft_lock(1, 20), 2).unwrap_err();
+        vault.add_time_lock(nft_lock(2, 20), 2).unwrap();
+    }
+
+    #[test]
+    fn it_allows_relocking_a_non_fungible_after_its_lock_is_released() {
+        let mut vault = non_fungible_vault(&[1]);
+        vault.add_time_lock(nft_lock(1, 10), 1).unwrap();
+        vault.add_time_lock(nft_lock(1, 20), 10).unwrap();
+        assert_eq!(vault.time_locks.len(), 1);
+    }
+}
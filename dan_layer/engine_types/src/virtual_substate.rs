@@ -9,12 +9,14 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 use tari_common_types::types::PublicKey;
+use tari_template_lib::models::BlockInfo;
 
 use crate::fee_claim::FeeClaim;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum VirtualSubstateId {
     CurrentEpoch,
+    CurrentBlock,
     UnclaimedValidatorFee { epoch: u64, address: PublicKey },
 }
 
@@ -22,6 +24,7 @@ impl Display for VirtualSubstateId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             VirtualSubstateId::CurrentEpoch => write!(f, "Virtual(CurrentEpoch)"),
+            VirtualSubstateId::CurrentBlock => write!(f, "Virtual(CurrentBlock)"),
             VirtualSubstateId::UnclaimedValidatorFee { epoch, address } => {
                 write!(
                     f,
@@ -36,6 +39,7 @@ impl Display for VirtualSubstateId {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum VirtualSubstate {
     CurrentEpoch(u64),
+    CurrentBlock(BlockInfo),
     UnclaimedValidatorFee(FeeClaim),
 }
 
@@ -123,7 +123,7 @@ impl<TAddr: NodeAddressable + DerivableFromPublicKey>
         let epoch_header = self.base_node_client.get_header_by_hash(block_hash).await?;
 
         // persist the epoch data including the validator node set
-        self.insert_current_epoch(epoch, epoch_header)?;
+        self.insert_current_epoch(epoch, epoch_header, block_hash)?;
         self.update_base_layer_consensus_constants(base_layer_constants)?;
         self.assign_validators_for_epoch(epoch)?;
 
@@ -275,11 +275,17 @@ impl<TAddr: NodeAddressable + DerivableFromPublicKey>
         Ok(())
     }
 
-    fn insert_current_epoch(&mut self, epoch: Epoch, header: BlockHeader) -> Result<(), EpochManagerError> {
+    fn insert_current_epoch(
+        &mut self,
+        epoch: Epoch,
+        header: BlockHeader,
+        block_hash: FixedHash,
+    ) -> Result<(), EpochManagerError> {
         let epoch_height = epoch.0;
         let db_epoch = DbEpoch {
             epoch: epoch_height,
             validator_node_mr: header.validator_node_mr.to_vec(),
+            block_hash: Some(block_hash.to_vec()),
         };
 
         let mut tx = self.global_db.create_transaction()?;
@@ -515,9 +521,23 @@ impl<TAddr: NodeAddressable + DerivableFromPublicKey>
         substate_address: SubstateAddress,
     ) -> Result<Committee<TAddr>, EpochManagerError> {
         let result = self.get_committee_vns_from_shard_key(epoch, substate_address)?;
-        Ok(Committee::new(
-            result.into_iter().map(|v| (v.address, v.public_key)).collect(),
-        ))
+        let committee = Committee::new(result.into_iter().map(|v| (v.address, v.public_key)).collect());
+        Ok(committee.with_seed(self.get_epoch_seed(epoch)?))
+    }
+
+    /// Returns the hash of the base layer block at which the epoch started. This is not known before the epoch starts
+    /// and is the same for all validators, so it is used to seed leader selection. Epochs recorded before block hashes
+    /// were stored have a zero seed.
+    fn get_epoch_seed(&self, epoch: Epoch) -> Result<FixedHash, EpochManagerError> {
+        let mut tx = self.global_db.create_transaction()?;
+        let seed = self
+            .global_db
+            .epochs(&mut tx)
+            .get_epoch_data(epoch.as_u64())?
+            .and_then(|e| e.block_hash)
+            .and_then(|hash| FixedHash::try_from(hash.as_slice()).ok())
+            .unwrap_or_default();
+        Ok(seed)
     }
 
     pub fn get_number_of_committees(&self, epoch: Epoch) -> Result<u32, EpochManagerError> {
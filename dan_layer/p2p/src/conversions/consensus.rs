@@ -24,8 +24,9 @@ use std::convert::{TryFrom, TryInto};
 
 use anyhow::anyhow;
 use tari_bor::{decode_exact, encode};
-use tari_common_types::types::PublicKey;
+use tari_common_types::types::{PrivateKey, PublicKey};
 use tari_consensus::messages::{
+    EquivocationMessage,
     ForeignProposalMessage,
     FullBlock,
     HotstuffMessage,
@@ -50,9 +51,14 @@ use tari_dan_common_types::{
 };
 use tari_dan_storage::consensus_models::{
     AbortReason,
+    AggregatedValidatorSignature,
+    BlockHeader,
     BlockId,
     Command,
     Decision,
+    EquivocationEvidence,
+    EquivocationKind,
+    EquivocationStatement,
     Evidence,
     ForeignProposal,
     ForeignProposalAtom,
@@ -100,6 +106,9 @@ impl From<&HotstuffMessage> for proto::consensus::HotStuffMessage {
             HotstuffMessage::SyncResponse(msg) => {
                 proto::consensus::hot_stuff_message::Message::SyncResponse(msg.into())
             },
+            HotstuffMessage::Equivocation(msg) => {
+                proto::consensus::hot_stuff_message::Message::Equivocation(msg.into())
+            },
         };
         Self { message: Some(message) }
     }
@@ -129,6 +138,9 @@ impl TryFrom<proto::consensus::HotStuffMessage> for HotstuffMessage {
             proto::consensus::hot_stuff_message::Message::SyncResponse(msg) => {
                 HotstuffMessage::SyncResponse(msg.try_into()?)
             },
+            proto::consensus::hot_stuff_message::Message::Equivocation(msg) => {
+                HotstuffMessage::Equivocation(msg.try_into()?)
+            },
         })
     }
 }
@@ -390,6 +402,29 @@ impl TryFrom<proto::consensus::VoteMessage> for VoteMessage {
     }
 }
 
+// -------------------------------- EquivocationMessage -------------------------------- //
+
+impl From<&EquivocationMessage> for proto::consensus::EquivocationMessage {
+    fn from(msg: &EquivocationMessage) -> Self {
+        Self {
+            evidence: Some((&msg.evidence).into()),
+        }
+    }
+}
+
+impl TryFrom<proto::consensus::EquivocationMessage> for EquivocationMessage {
+    type Error = anyhow::Error;
+
+    fn try_from(value: proto::consensus::EquivocationMessage) -> Result<Self, Self::Error> {
+        Ok(EquivocationMessage {
+            evidence: value
+                .evidence
+                .ok_or_else(|| anyhow!("Evidence is missing"))?
+                .try_into()?,
+        })
+    }
+}
+
 //---------------------------------- MissingTransactionsRequest --------------------------------------------//
 impl From<&MissingTransactionsRequest> for proto::consensus::MissingTransactionsRequest {
     fn from(msg: &MissingTransactionsRequest) -> Self {
@@ -573,6 +608,7 @@ impl From<&Command> for proto::consensus::Command {
             },
             Command::SuspendNode(atom) => proto::consensus::command::Command::SuspendNode(atom.into()),
             Command::ResumeNode(atom) => proto::consensus::command::Command::ResumeNode(atom.into()),
+            Command::Equivocation(evidence) => proto::consensus::command::Command::Equivocation(evidence.into()),
             Command::EndEpoch => proto::consensus::command::Command::EndEpoch(true),
         };
 
@@ -602,6 +638,7 @@ impl TryFrom<proto::consensus::Command> for Command {
             },
             proto::consensus::command::Command::SuspendNode(atom) => Command::SuspendNode(atom.try_into()?),
             proto::consensus::command::Command::ResumeNode(atom) => Command::ResumeNode(atom.try_into()?),
+            proto::consensus::command::Command::Equivocation(evidence) => Command::Equivocation(evidence.try_into()?),
             proto::consensus::command::Command::EndEpoch(_) => Command::EndEpoch,
         })
     }
@@ -659,6 +696,145 @@ impl TryFrom<proto::consensus::SuspendNodeAtom> for SuspendNodeAtom {
         })
     }
 }
+// -------------------------------- EquivocationEvidence -------------------------------- //
+
+impl From<&EquivocationEvidence> for proto::consensus::EquivocationEvidence {
+    fn from(value: &EquivocationEvidence) -> Self {
+        let kind = match value.kind {
+            EquivocationKind::DoubleVote => proto::consensus::EquivocationKind::DoubleVote,
+            EquivocationKind::DoubleProposal => proto::consensus::EquivocationKind::DoubleProposal,
+        };
+        Self {
+            kind: kind.into(),
+            epoch: value.epoch.as_u64(),
+            shard_group: value.shard_group.encode_as_u32(),
+            height: value.height.as_u64(),
+            validator_public_key: value.validator_public_key.as_bytes().to_vec(),
+            first: Some((&value.first).into()),
+            second: Some((&value.second).into()),
+        }
+    }
+}
+
+impl TryFrom<proto::consensus::EquivocationEvidence> for EquivocationEvidence {
+    type Error = anyhow::Error;
+
+    fn try_from(value: proto::consensus::EquivocationEvidence) -> Result<Self, Self::Error> {
+        let kind = match value.kind() {
+            proto::consensus::EquivocationKind::DoubleVote => EquivocationKind::DoubleVote,
+            proto::consensus::EquivocationKind::DoubleProposal => EquivocationKind::DoubleProposal,
+            proto::consensus::EquivocationKind::Unknown => return Err(anyhow!("Equivocation kind not provided")),
+        };
+        // Proposal statements do not carry a decision
+        let convert_statement = |statement: Option<proto::consensus::EquivocationStatement>| {
+            let mut statement =
+                EquivocationStatement::try_from(statement.ok_or_else(|| anyhow!("Statement is missing"))?)?;
+            if kind == EquivocationKind::DoubleProposal {
+                statement.decision = None;
+            }
+            Ok::<_, anyhow::Error>(statement)
+        };
+        Ok(Self {
+            kind,
+            epoch: Epoch(value.epoch),
+            shard_group: ShardGroup::decode_from_u32(value.shard_group)
+                .ok_or_else(|| anyhow!("Evidence shard_group ({}) is not a valid", value.shard_group))?,
+            height: NodeHeight(value.height),
+            validator_public_key: PublicKey::from_canonical_bytes(&value.validator_public_key)
+                .map_err(|e| anyhow!("EquivocationEvidence failed to decode public key: {e}"))?,
+            first: convert_statement(value.first)?,
+            second: convert_statement(value.second)?,
+        })
+    }
+}
+
+impl From<&EquivocationStatement> for proto::consensus::EquivocationStatement {
+    fn from(value: &EquivocationStatement) -> Self {
+        Self {
+            block_id: value.block_id.as_bytes().to_vec(),
+            decision: value.decision.map(|d| i32::from(d.as_u8())).unwrap_or_default(),
+            signature: Some((&value.signature).into()),
+            header: Some((&value.header).into()),
+        }
+    }
+}
+
+impl TryFrom<proto::consensus::EquivocationStatement> for EquivocationStatement {
+    type Error = anyhow::Error;
+
+    fn try_from(value: proto::consensus::EquivocationStatement) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_id: BlockId::try_from(value.block_id)?,
+            header: value
+                .header
+                .ok_or_else(|| anyhow!("Block header is missing"))?
+                .try_into()?,
+            decision: Some(
+                QuorumDecision::from_u8(u8::try_from(value.decision)?)
+                    .ok_or_else(|| anyhow!("Invalid decision byte {}", value.decision))?,
+            ),
+            signature: value
+                .signature
+                .ok_or_else(|| anyhow!("Signature is missing"))?
+                .try_into()?,
+        })
+    }
+}
+
+// -------------------------------- BlockHeader -------------------------------- //
+
+impl From<&BlockHeader> for proto::consensus::BlockHeader {
+    fn from(value: &BlockHeader) -> Self {
+        Self {
+            parent_id: value.parent.as_bytes().to_vec(),
+            network: value.network.as_byte().into(),
+            justify_id: value.justify_id.as_bytes().to_vec(),
+            height: value.height.as_u64(),
+            epoch: value.epoch.as_u64(),
+            shard_group: value.shard_group.encode_as_u32(),
+            proposed_by: ByteArray::as_bytes(&value.proposed_by).to_vec(),
+            merkle_root: value.merkle_root.as_slice().to_vec(),
+            commands_hash: value.commands_hash.as_slice().to_vec(),
+            total_leader_fee: value.total_leader_fee,
+            foreign_indexes: encode(&value.foreign_indexes).unwrap(),
+            timestamp: value.timestamp,
+            base_layer_block_height: value.base_layer_block_height,
+            base_layer_block_hash: value.base_layer_block_hash.as_slice().to_vec(),
+            is_dummy: value.is_dummy,
+            extra_data: value.extra_data.as_ref().map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<proto::consensus::BlockHeader> for BlockHeader {
+    type Error = anyhow::Error;
+
+    fn try_from(value: proto::consensus::BlockHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            network: u8::try_from(value.network)
+                .map_err(|_| anyhow!("BlockHeader conversion: Invalid network byte {}", value.network))?
+                .try_into()?,
+            parent: value.parent_id.try_into()?,
+            justify_id: value.justify_id.try_into()?,
+            height: NodeHeight(value.height),
+            epoch: Epoch(value.epoch),
+            shard_group: ShardGroup::decode_from_u32(value.shard_group)
+                .ok_or_else(|| anyhow!("BlockHeader shard_group ({}) is not a valid", value.shard_group))?,
+            proposed_by: PublicKey::from_canonical_bytes(&value.proposed_by)
+                .map_err(|_| anyhow!("BlockHeader conversion: Invalid proposed_by"))?,
+            total_leader_fee: value.total_leader_fee,
+            merkle_root: value.merkle_root.try_into()?,
+            is_dummy: value.is_dummy,
+            commands_hash: value.commands_hash.try_into()?,
+            foreign_indexes: decode_exact(&value.foreign_indexes)?,
+            timestamp: value.timestamp,
+            base_layer_block_height: value.base_layer_block_height,
+            base_layer_block_hash: value.base_layer_block_hash.try_into()?,
+            extra_data: value.extra_data.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
 // -------------------------------- ResumeNodeAtom -------------------------------- //
 
 impl From<&ResumeNodeAtom> for proto::consensus::ResumeNodeAtom {
@@ -847,7 +1023,12 @@ impl From<&QuorumCertificate> for proto::consensus::QuorumCertificate {
             block_height: source.block_height().as_u64(),
             epoch: source.epoch().as_u64(),
             shard_group: source.shard_group().encode_as_u32(),
-            signatures: source.signatures().iter().map(Into::into).collect(),
+            signatures: source.legacy_signatures().iter().map(Into::into).collect(),
+            signature: if source.is_legacy() {
+                None
+            } else {
+                Some(source.signature().into())
+            },
             leaf_hashes: source.leaf_hashes().iter().map(|h| h.to_vec()).collect(),
             decision: i32::from(source.decision().as_u8()),
         }
@@ -860,23 +1041,72 @@ impl TryFrom<proto::consensus::QuorumCertificate> for QuorumCertificate {
     fn try_from(value: proto::consensus::QuorumCertificate) -> Result<Self, Self::Error> {
         let shard_group = ShardGroup::decode_from_u32(value.shard_group)
             .ok_or_else(|| anyhow!("QC shard_group ({}) is not a valid", value.shard_group))?;
+        let block_id = value.block_id.try_into()?;
+        let leaf_hashes = value
+            .leaf_hashes
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let decision = QuorumDecision::from_u8(u8::try_from(value.decision)?)
+            .ok_or_else(|| anyhow!("Invalid Decision byte {}", value.decision))?;
+
+        if !value.signatures.is_empty() {
+            if value.signature.is_some() {
+                return Err(anyhow!("QC cannot contain both individual and aggregated signatures"));
+            }
+            return Ok(Self::new_legacy(
+                block_id,
+                NodeHeight(value.block_height),
+                Epoch(value.epoch),
+                shard_group,
+                value
+                    .signatures
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+                leaf_hashes,
+                decision,
+            ));
+        }
+
         Ok(Self::new(
-            value.block_id.try_into()?,
+            block_id,
             NodeHeight(value.block_height),
             Epoch(value.epoch),
             shard_group,
+            value.signature.map(TryInto::try_into).transpose()?.unwrap_or_default(),
+            leaf_hashes,
+            decision,
+        ))
+    }
+}
+
+// -------------------------------- AggregatedValidatorSignature -------------------------------- //
+
+impl From<&AggregatedValidatorSignature> for proto::consensus::AggregatedValidatorSignature {
+    fn from(value: &AggregatedValidatorSignature) -> Self {
+        Self {
+            signers: value.signer_bitmap().as_bytes().to_vec(),
+            public_nonces: value.public_nonces().iter().map(|n| n.as_bytes().to_vec()).collect(),
+            signature: value.signature().as_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<proto::consensus::AggregatedValidatorSignature> for AggregatedValidatorSignature {
+    type Error = anyhow::Error;
+
+    fn try_from(value: proto::consensus::AggregatedValidatorSignature) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            value.signers.into(),
             value
-                .signatures
-                .into_iter()
-                .map(TryInto::try_into)
-                .collect::<Result<_, _>>()?,
-            value
-                .leaf_hashes
-                .into_iter()
-                .map(TryInto::try_into)
-                .collect::<Result<_, _>>()?,
-            QuorumDecision::from_u8(u8::try_from(value.decision)?)
-                .ok_or_else(|| anyhow!("Invalid Decision byte {}", value.decision))?,
+                .public_nonces
+                .iter()
+                .map(|n| PublicKey::from_canonical_bytes(n))
+                .collect::<Result<_, _>>()
+                .map_err(|e| anyhow!("AggregatedValidatorSignature failed to decode public nonce: {e}"))?,
+            PrivateKey::from_canonical_bytes(&value.signature)
+                .map_err(|e| anyhow!("AggregatedValidatorSignature failed to decode signature: {e}"))?,
         ))
     }
 }
@@ -27,7 +27,11 @@ use tari_bor::{decode_exact, encode};
 use tari_common_types::types::{Commitment, PrivateKey, PublicKey};
 use tari_crypto::{ristretto::RistrettoComSig, tari_utilities::ByteArray};
 use tari_dan_common_types::{Epoch, SubstateRequirement, VersionedSubstateId};
-use tari_engine_types::{confidential::ConfidentialClaim, instruction::Instruction, substate::SubstateId};
+use tari_engine_types::{
+    confidential::ConfidentialClaim,
+    instruction::{Instruction, MAX_INSTRUCTION_NESTING_DEPTH},
+    substate::SubstateId,
+};
 use tari_template_lib::{
     args::Arg,
     auth::OwnerRule,
@@ -42,8 +46,9 @@ use tari_template_lib::{
         ViewableBalanceProof,
     },
     prelude::AccessRules,
+    Hash,
 };
-use tari_transaction::{Transaction, UnsignedTransaction};
+use tari_transaction::{SubIntent, Transaction, UnsignedTransaction};
 
 use crate::{
     proto::{
@@ -142,12 +147,18 @@ impl TryFrom<proto::transaction::UnsignedTransaction> for UnsignedTransaction {
             .collect::<Result<_, _>>()?;
         let min_epoch = request.min_epoch.map(|epoch| Epoch(epoch.epoch));
         let max_epoch = request.max_epoch.map(|epoch| Epoch(epoch.epoch));
+        let sub_intents = request
+            .sub_intents
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             fee_instructions,
             instructions,
             inputs,
             min_epoch,
             max_epoch,
+            sub_intents,
         })
     }
 }
@@ -163,6 +174,7 @@ impl From<&UnsignedTransaction> for proto::transaction::UnsignedTransaction {
             .map(|epoch| proto::common::Epoch { epoch: epoch.0 });
         let fee_instructions = transaction.fee_instructions().iter().cloned().map(Into::into).collect();
         let instructions = transaction.instructions().iter().cloned().map(Into::into).collect();
+        let sub_intents = transaction.sub_intents().iter().map(Into::into).collect();
 
         proto::transaction::UnsignedTransaction {
             fee_instructions,
@@ -170,6 +182,52 @@ impl From<&UnsignedTransaction> for proto::transaction::UnsignedTransaction {
             inputs,
             min_epoch,
             max_epoch,
+            sub_intents,
+        }
+    }
+}
+
+//---------------------------------- SubIntent --------------------------------------------//
+
+impl TryFrom<proto::transaction::SubIntent> for SubIntent {
+    type Error = anyhow::Error;
+
+    fn try_from(request: proto::transaction::SubIntent) -> Result<Self, Self::Error> {
+        let instructions = request
+            .instructions
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let signatures = request
+            .signatures
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+        let intent_id = Hash::try_from(request.intent_id)?;
+        let min_epoch = request.min_epoch.map(|epoch| Epoch(epoch.epoch));
+        let max_epoch = request.max_epoch.map(|epoch| Epoch(epoch.epoch));
+        Ok(SubIntent::new(
+            intent_id,
+            instructions,
+            min_epoch,
+            max_epoch,
+            signatures,
+        ))
+    }
+}
+
+impl From<&SubIntent> for proto::transaction::SubIntent {
+    fn from(sub_intent: &SubIntent) -> Self {
+        proto::transaction::SubIntent {
+            instructions: sub_intent.instructions().iter().cloned().map(Into::into).collect(),
+            signatures: sub_intent.signatures().iter().map(Into::into).collect(),
+            intent_id: sub_intent.intent_id().to_vec(),
+            min_epoch: sub_intent
+                .min_epoch()
+                .map(|epoch| proto::common::Epoch { epoch: epoch.0 }),
+            max_epoch: sub_intent
+                .max_epoch()
+                .map(|epoch| proto::common::Epoch { epoch: epoch.0 }),
         }
     }
 }
@@ -180,83 +238,118 @@ impl TryFrom<proto::transaction::Instruction> for Instruction {
     type Error = anyhow::Error;
 
     fn try_from(request: proto::transaction::Instruction) -> Result<Self, Self::Error> {
-        let args = request
-            .args
-            .into_iter()
-            .map(|a| a.try_into())
-            .collect::<Result<_, _>>()?;
-        let instruction_type =
-            InstructionType::try_from(request.instruction_type).map_err(|e| anyhow!("invalid instruction_type {e}"))?;
-        let instruction = match instruction_type {
-            InstructionType::CreateAccount => Instruction::CreateAccount {
-                public_key_address: PublicKey::from_canonical_bytes(&request.create_account_public_key)
-                    .map_err(|e| anyhow!("create_account_public_key: {}", e))?,
-                owner_rule: request.create_account_owner_rule.map(TryInto::try_into).transpose()?,
-                access_rules: request.create_account_access_rules.map(TryInto::try_into).transpose()?,
-                workspace_bucket: Some(request.create_account_workspace_bucket).filter(|s| !s.is_empty()),
-            },
-            InstructionType::Function => {
-                let function = request.function;
-                Instruction::CallFunction {
-                    template_address: request.template_address.try_into()?,
-                    function,
-                    args,
-                }
-            },
-            InstructionType::Method => {
-                let method = request.method;
-                let component_address = ObjectKey::try_from(request.component_address)?.into();
-                Instruction::CallMethod {
-                    component_address,
-                    method,
-                    args,
-                }
-            },
-            InstructionType::PutOutputInWorkspace => {
-                Instruction::PutLastInstructionOutputOnWorkspace { key: request.key }
-            },
-            InstructionType::EmitLog => Instruction::EmitLog {
-                level: request.log_level.parse()?,
-                message: request.log_message,
-            },
-            InstructionType::ClaimBurn => Instruction::ClaimBurn {
-                claim: Box::new(ConfidentialClaim {
-                    public_key: PublicKey::from_canonical_bytes(&request.claim_burn_public_key)
-                        .map_err(|e| anyhow!("claim_burn_public_key: {}", e))?,
-                    output_address: request
-                        .claim_burn_commitment_address
-                        .as_slice()
-                        .try_into()
-                        .map_err(|e| anyhow!("claim_burn_commitment_address: {}", e))?,
-                    range_proof: request.claim_burn_range_proof,
-                    proof_of_knowledge: request
-                        .claim_burn_proof_of_knowledge
-                        .ok_or_else(|| anyhow!("claim_burn_proof_of_knowledge not provided"))?
-                        .try_into()
-                        .map_err(|e| anyhow!("claim_burn_proof_of_knowledge: {}", e))?,
-                    withdraw_proof: request.claim_burn_withdraw_proof.map(TryInto::try_into).transpose()?,
-                }),
-            },
-            InstructionType::ClaimValidatorFees => Instruction::ClaimValidatorFees {
-                epoch: request.claim_validator_fees_epoch,
-                validator_public_key: PublicKey::from_canonical_bytes(
-                    &request.claim_validator_fees_validator_public_key,
-                )
-                .map_err(|e| anyhow!("claim_validator_fees_validator_public_key: {}", e))?,
-            },
-            InstructionType::DropAllProofsInWorkspace => Instruction::DropAllProofsInWorkspace,
-            InstructionType::AssertBucketContains => {
-                let resource_address = ObjectKey::try_from(request.resource_address)?.into();
-                Instruction::AssertBucketContains {
-                    key: request.key,
-                    resource_address,
-                    min_amount: Amount::new(request.min_amount),
-                }
-            },
-        };
+        try_convert_instruction(request, 0)
+    }
+}
 
-        Ok(instruction)
+fn try_convert_nested_instructions(
+    instructions: Vec<proto::transaction::Instruction>,
+    depth: usize,
+) -> Result<Vec<Instruction>, anyhow::Error> {
+    if depth >= MAX_INSTRUCTION_NESTING_DEPTH {
+        return Err(anyhow!(
+            "instructions are nested more than the maximum depth of {MAX_INSTRUCTION_NESTING_DEPTH}"
+        ));
     }
+    instructions
+        .into_iter()
+        .map(|i| try_convert_instruction(i, depth + 1))
+        .collect()
+}
+
+fn try_convert_instruction(
+    request: proto::transaction::Instruction,
+    depth: usize,
+) -> Result<Instruction, anyhow::Error> {
+    let args = request
+        .args
+        .into_iter()
+        .map(|a| a.try_into())
+        .collect::<Result<_, _>>()?;
+    let instruction_type =
+        InstructionType::try_from(request.instruction_type).map_err(|e| anyhow!("invalid instruction_type {e}"))?;
+    let instruction = match instruction_type {
+        InstructionType::CreateAccount => Instruction::CreateAccount {
+            public_key_address: PublicKey::from_canonical_bytes(&request.create_account_public_key)
+                .map_err(|e| anyhow!("create_account_public_key: {}", e))?,
+            owner_rule: request.create_account_owner_rule.map(TryInto::try_into).transpose()?,
+            access_rules: request.create_account_access_rules.map(TryInto::try_into).transpose()?,
+            workspace_bucket: Some(request.create_account_workspace_bucket).filter(|s| !s.is_empty()),
+        },
+        InstructionType::Function => {
+            let function = request.function;
+            Instruction::CallFunction {
+                template_address: request.template_address.try_into()?,
+                function,
+                args,
+            }
+        },
+        InstructionType::Method => {
+            let method = request.method;
+            let component_address = ObjectKey::try_from(request.component_address)?.into();
+            Instruction::CallMethod {
+                component_address,
+                method,
+                args,
+            }
+        },
+        InstructionType::PutOutputInWorkspace => Instruction::PutLastInstructionOutputOnWorkspace { key: request.key },
+        InstructionType::EmitLog => Instruction::EmitLog {
+            level: request.log_level.parse()?,
+            message: request.log_message,
+        },
+        InstructionType::ClaimBurn => Instruction::ClaimBurn {
+            claim: Box::new(ConfidentialClaim {
+                public_key: PublicKey::from_canonical_bytes(&request.claim_burn_public_key)
+                    .map_err(|e| anyhow!("claim_burn_public_key: {}", e))?,
+                output_address: request
+                    .claim_burn_commitment_address
+                    .as_slice()
+                    .try_into()
+                    .map_err(|e| anyhow!("claim_burn_commitment_address: {}", e))?,
+                range_proof: request.claim_burn_range_proof,
+                proof_of_knowledge: request
+                    .claim_burn_proof_of_knowledge
+                    .ok_or_else(|| anyhow!("claim_burn_proof_of_knowledge not provided"))?
+                    .try_into()
+                    .map_err(|e| anyhow!("claim_burn_proof_of_knowledge: {}", e))?,
+                withdraw_proof: request.claim_burn_withdraw_proof.map(TryInto::try_into).transpose()?,
+            }),
+        },
+        InstructionType::ClaimValidatorFees => Instruction::ClaimValidatorFees {
+            epoch: request.claim_validator_fees_epoch,
+            validator_public_key: PublicKey::from_canonical_bytes(&request.claim_validator_fees_validator_public_key)
+                .map_err(|e| anyhow!("claim_validator_fees_validator_public_key: {}", e))?,
+        },
+        InstructionType::DropAllProofsInWorkspace => Instruction::DropAllProofsInWorkspace,
+        InstructionType::AssertBucketContains => {
+            let resource_address = ObjectKey::try_from(request.resource_address)?.into();
+            Instruction::AssertBucketContains {
+                key: request.key,
+                resource_address,
+                min_amount: Amount::new(request.min_amount),
+            }
+        },
+        InstructionType::Assert => Instruction::Assert {
+            assertion: decode_exact(&request.encoded_assertion)?,
+        },
+        InstructionType::If => Instruction::If {
+            condition: decode_exact(&request.encoded_assertion)?,
+            then_instructions: try_convert_nested_instructions(request.then_instructions, depth)?,
+            else_instructions: try_convert_nested_instructions(request.else_instructions, depth)?,
+        },
+        InstructionType::ForEach => Instruction::ForEach {
+            key: request.key,
+            item_key: request.item_key,
+            max_iterations: request.max_iterations,
+            instructions: try_convert_nested_instructions(request.instructions, depth)?,
+        },
+        InstructionType::SponsorFee => Instruction::SponsorFee {
+            component_address: ObjectKey::try_from(request.component_address)?.into(),
+        },
+    };
+
+    Ok(instruction)
 }
 
 impl From<Instruction> for proto::transaction::Instruction {
@@ -334,6 +427,36 @@ impl From<Instruction> for proto::transaction::Instruction {
                 result.resource_address = resource_address.as_bytes().to_vec();
                 result.min_amount = min_amount.0
             },
+            Instruction::Assert { assertion } => {
+                result.instruction_type = InstructionType::Assert as i32;
+                result.encoded_assertion = encode(&assertion).unwrap();
+            },
+            Instruction::If {
+                condition,
+                then_instructions,
+                else_instructions,
+            } => {
+                result.instruction_type = InstructionType::If as i32;
+                result.encoded_assertion = encode(&condition).unwrap();
+                result.then_instructions = then_instructions.into_iter().map(Into::into).collect();
+                result.else_instructions = else_instructions.into_iter().map(Into::into).collect();
+            },
+            Instruction::ForEach {
+                key,
+                item_key,
+                max_iterations,
+                instructions,
+            } => {
+                result.instruction_type = InstructionType::ForEach as i32;
+                result.key = key;
+                result.item_key = item_key;
+                result.max_iterations = max_iterations;
+                result.instructions = instructions.into_iter().map(Into::into).collect();
+            },
+            Instruction::SponsorFee { component_address } => {
+                result.instruction_type = InstructionType::SponsorFee as i32;
+                result.component_address = component_address.as_bytes().to_vec();
+            },
         }
         result
     }
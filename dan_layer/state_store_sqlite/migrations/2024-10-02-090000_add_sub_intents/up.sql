@@ -0,0 +1,2 @@
+ALTER TABLE transactions
+    ADD COLUMN sub_intents text NOT NULL DEFAULT '[]';
@@ -0,0 +1,15 @@
+CREATE TABLE equivocation_evidence
+(
+    id                       integer   not NULL primary key AUTOINCREMENT,
+    evidence_id              text      not NULL,
+    epoch                    bigint    not NULL,
+    validator_public_key     text      not NULL,
+    evidence                 text      not NULL,
+    proposed_in_block        text      NULL REFERENCES blocks (block_id),
+    proposed_in_block_height bigint    NULL,
+    committed_in_block       text      NULL,
+    created_at               timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (evidence_id)
+);
+
+CREATE INDEX equivocation_evidence_idx_epoch_committed_in_block on equivocation_evidence (epoch, committed_in_block);
@@ -49,6 +49,7 @@ use tari_dan_storage::{
         BurntUtxo,
         Command,
         EpochCheckpoint,
+        EquivocationEvidence,
         ForeignProposal,
         ForeignProposalAtom,
         ForeignProposalStatus,
@@ -2442,6 +2443,115 @@ impl<'tx, TAddr: NodeAddressable + Serialize + DeserializeOwned + 'tx> StateStor
         Ok(count as u64)
     }
 
+    fn equivocation_evidence_exists(&self, evidence_id: &FixedHash) -> Result<bool, StorageError> {
+        use crate::schema::equivocation_evidence;
+
+        let count = equivocation_evidence::table
+            .count()
+            .filter(equivocation_evidence::evidence_id.eq(serialize_hex(evidence_id)))
+            .get_result::<i64>(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_exists",
+                source: e,
+            })?;
+
+        Ok(count > 0)
+    }
+
+    fn equivocation_evidence_get_all_unproposed(
+        &self,
+        epoch: Epoch,
+        leaf_block: &BlockId,
+        limit: usize,
+    ) -> Result<Vec<EquivocationEvidence>, StorageError> {
+        use crate::schema::equivocation_evidence;
+        if !self.blocks_exists(leaf_block)? {
+            return Err(StorageError::NotFound {
+                item: "Block",
+                key: leaf_block.to_string(),
+            });
+        }
+
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let locked_block = self.get_current_locked_block()?;
+        let exclude_block_ids = self.get_block_ids_with_commands_between(&locked_block.block_id, leaf_block)?;
+
+        let evidence = equivocation_evidence::table
+            .filter(equivocation_evidence::epoch.eq(epoch.as_u64() as i64))
+            .filter(equivocation_evidence::committed_in_block.is_null())
+            .filter(
+                equivocation_evidence::proposed_in_block
+                    .is_null()
+                    .or(equivocation_evidence::proposed_in_block
+                        .ne_all(exclude_block_ids)
+                        .and(equivocation_evidence::proposed_in_block_height.gt(locked_block.height.as_u64() as i64))),
+            )
+            .limit(limit as i64)
+            .get_results::<sql_models::EquivocationEvidence>(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_get_all_unproposed",
+                source: e,
+            })?;
+
+        evidence.into_iter().map(TryInto::try_into).collect()
+    }
+
+    fn equivocation_evidence_is_proposed_in_chain(
+        &self,
+        evidence_id: &FixedHash,
+        leaf_block: &BlockId,
+    ) -> Result<bool, StorageError> {
+        use crate::schema::equivocation_evidence;
+
+        let maybe_evidence = equivocation_evidence::table
+            .select((
+                equivocation_evidence::proposed_in_block,
+                equivocation_evidence::committed_in_block,
+            ))
+            .filter(equivocation_evidence::evidence_id.eq(serialize_hex(evidence_id)))
+            .first::<(Option<String>, Option<String>)>(self.connection())
+            .optional()
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_is_proposed_in_chain",
+                source: e,
+            })?;
+
+        let Some((proposed_in_block, committed_in_block)) = maybe_evidence else {
+            return Ok(false);
+        };
+
+        if committed_in_block.is_some() {
+            return Ok(true);
+        }
+
+        let Some(proposed_in_block) = proposed_in_block else {
+            return Ok(false);
+        };
+
+        let locked_block = self.get_current_locked_block()?;
+        let block_ids = self.get_block_ids_with_commands_between(&locked_block.block_id, leaf_block)?;
+        Ok(block_ids.contains(&proposed_in_block))
+    }
+
+    fn equivocation_evidence_get_all_committed(&self, epoch: Epoch) -> Result<Vec<EquivocationEvidence>, StorageError> {
+        use crate::schema::equivocation_evidence;
+
+        let evidence = equivocation_evidence::table
+            .filter(equivocation_evidence::epoch.eq(epoch.as_u64() as i64))
+            .filter(equivocation_evidence::committed_in_block.is_not_null())
+            .order_by(equivocation_evidence::id.asc())
+            .get_results::<sql_models::EquivocationEvidence>(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_get_all_committed",
+                source: e,
+            })?;
+
+        evidence.into_iter().map(TryInto::try_into).collect()
+    }
+
     fn foreign_parked_blocks_exists(&self, block_id: &BlockId) -> Result<bool, StorageError> {
         use crate::schema::foreign_parked_blocks;
 
@@ -108,6 +108,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    equivocation_evidence (id) {
+        id -> Integer,
+        evidence_id -> Text,
+        epoch -> BigInt,
+        validator_public_key -> Text,
+        evidence -> Text,
+        proposed_in_block -> Nullable<Text>,
+        proposed_in_block_height -> Nullable<BigInt>,
+        committed_in_block -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     foreign_missing_transactions (id) {
         id -> Integer,
@@ -517,6 +531,7 @@ diesel::table! {
         min_epoch -> Nullable<BigInt>,
         max_epoch -> Nullable<BigInt>,
         created_at -> Timestamp,
+        sub_intents -> Text,
     }
 }
 
@@ -553,6 +568,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     diagnostic_deleted_blocks,
     diagnostics_no_votes,
     epoch_checkpoints,
+    equivocation_evidence,
     foreign_missing_transactions,
     foreign_parked_blocks,
     foreign_proposals,
@@ -0,0 +1,29 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use diesel::Queryable;
+use tari_dan_storage::{consensus_models, StorageError};
+use time::PrimitiveDateTime;
+
+use crate::serialization::deserialize_json;
+
+#[derive(Debug, Clone, Queryable)]
+pub struct EquivocationEvidence {
+    pub id: i32,
+    pub evidence_id: String,
+    pub epoch: i64,
+    pub validator_public_key: String,
+    pub evidence: String,
+    pub proposed_in_block: Option<String>,
+    pub proposed_in_block_height: Option<i64>,
+    pub committed_in_block: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+impl TryFrom<EquivocationEvidence> for consensus_models::EquivocationEvidence {
+    type Error = StorageError;
+
+    fn try_from(value: EquivocationEvidence) -> Result<Self, Self::Error> {
+        deserialize_json(&value.evidence)
+    }
+}
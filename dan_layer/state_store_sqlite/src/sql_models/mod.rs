@@ -6,6 +6,7 @@ mod block_diff;
 mod bookkeeping;
 mod burnt_utxo;
 mod epoch_checkpoint;
+mod equivocation_evidence;
 mod foreign_parked_block;
 mod foreign_proposal;
 mod foreign_substate_pledge;
@@ -25,6 +26,7 @@ pub use block_diff::*;
 pub use bookkeeping::*;
 pub use burnt_utxo::*;
 pub use epoch_checkpoint::*;
+pub use equivocation_evidence::*;
 pub use foreign_parked_block::*;
 pub use foreign_proposal::*;
 pub use foreign_substate_pledge::*;
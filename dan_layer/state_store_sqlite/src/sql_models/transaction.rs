@@ -31,6 +31,7 @@ pub struct Transaction {
     pub min_epoch: Option<i64>,
     pub max_epoch: Option<i64>,
     pub created_at: PrimitiveDateTime,
+    pub sub_intents: String,
 }
 
 impl TryFrom<Transaction> for tari_transaction::Transaction {
@@ -39,6 +40,7 @@ impl TryFrom<Transaction> for tari_transaction::Transaction {
     fn try_from(value: Transaction) -> Result<Self, Self::Error> {
         let fee_instructions = deserialize_json(&value.fee_instructions)?;
         let instructions = deserialize_json(&value.instructions)?;
+        let sub_intents = deserialize_json(&value.sub_intents)?;
         let signatures = deserialize_json(&value.signatures)?;
 
         let inputs = deserialize_json(&value.inputs)?;
@@ -54,6 +56,7 @@ impl TryFrom<Transaction> for tari_transaction::Transaction {
                 inputs,
                 min_epoch,
                 max_epoch,
+                sub_intents,
             },
             signatures,
         )
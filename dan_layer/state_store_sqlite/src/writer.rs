@@ -18,7 +18,7 @@ use diesel::{
 };
 use indexmap::IndexMap;
 use log::*;
-use tari_common_types::types::PublicKey;
+use tari_common_types::types::{FixedHash, PublicKey};
 use tari_dan_common_types::{
     optional::Optional,
     shard::Shard,
@@ -38,6 +38,7 @@ use tari_dan_storage::{
         BurntUtxo,
         Decision,
         EpochCheckpoint,
+        EquivocationEvidence,
         ForeignParkedProposal,
         ForeignProposal,
         ForeignProposalStatus,
@@ -804,6 +805,7 @@ impl<'tx, TAddr: NodeAddressable + 'tx> StateStoreWriteTransaction for SqliteSta
             transactions::transaction_id.eq(serialize_hex(transaction.id())),
             transactions::fee_instructions.eq(serialize_json(transaction.fee_instructions())?),
             transactions::instructions.eq(serialize_json(transaction.instructions())?),
+            transactions::sub_intents.eq(serialize_json(transaction.sub_intents())?),
             transactions::signatures.eq(serialize_json(transaction.signatures())?),
             transactions::inputs.eq(serialize_json(transaction.inputs())?),
             transactions::filled_inputs.eq(serialize_json(transaction.filled_inputs())?),
@@ -900,14 +902,16 @@ impl<'tx, TAddr: NodeAddressable + 'tx> StateStoreWriteTransaction for SqliteSta
     ) -> Result<(), StorageError> {
         use crate::schema::transactions;
 
+        let txs = txs.into_iter().collect::<Vec<_>>();
         let insert = txs
-            .into_iter()
+            .iter()
             .map(|rec| {
                 let transaction = rec.transaction();
                 Ok((
                     transactions::transaction_id.eq(serialize_hex(transaction.id())),
                     transactions::fee_instructions.eq(serialize_json(transaction.fee_instructions())?),
                     transactions::instructions.eq(serialize_json(transaction.instructions())?),
+                    transactions::sub_intents.eq(serialize_json(transaction.sub_intents())?),
                     transactions::signatures.eq(serialize_json(transaction.signatures())?),
                     transactions::inputs.eq(serialize_json(transaction.inputs())?),
                     transactions::resolved_inputs.eq(rec.resolved_inputs().map(serialize_json).transpose()?),
@@ -2085,6 +2089,105 @@ impl<'tx, TAddr: NodeAddressable + 'tx> StateStoreWriteTransaction for SqliteSta
         Ok(())
     }
 
+    fn equivocation_evidence_insert(&mut self, evidence: &EquivocationEvidence) -> Result<(), StorageError> {
+        use crate::schema::equivocation_evidence;
+
+        let values = (
+            equivocation_evidence::evidence_id.eq(serialize_hex(evidence.id())),
+            equivocation_evidence::epoch.eq(evidence.epoch.as_u64() as i64),
+            equivocation_evidence::validator_public_key.eq(evidence.validator_public_key.to_hex()),
+            equivocation_evidence::evidence.eq(serialize_json(evidence)?),
+        );
+
+        diesel::insert_into(equivocation_evidence::table)
+            .values(values)
+            .execute(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_insert",
+                source: e,
+            })?;
+
+        Ok(())
+    }
+
+    fn equivocation_evidence_set_proposed_block(
+        &mut self,
+        evidence_id: &FixedHash,
+        proposed_in_block: &BlockId,
+    ) -> Result<(), StorageError> {
+        use crate::schema::{blocks, equivocation_evidence};
+
+        let proposed_in_block_hex = serialize_hex(proposed_in_block);
+        let num_affected = diesel::update(equivocation_evidence::table)
+            .filter(equivocation_evidence::evidence_id.eq(serialize_hex(evidence_id)))
+            .set((
+                equivocation_evidence::proposed_in_block.eq(&proposed_in_block_hex),
+                equivocation_evidence::proposed_in_block_height.eq(blocks::table
+                    .select(blocks::height)
+                    .filter(blocks::block_id.eq(&proposed_in_block_hex))
+                    .single_value()),
+            ))
+            .execute(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_set_proposed_block",
+                source: e,
+            })?;
+
+        if num_affected == 0 {
+            return Err(StorageError::NotFound {
+                item: "equivocation_evidence",
+                key: evidence_id.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn equivocation_evidence_clear_proposed_block(&mut self, proposed_in_block: &BlockId) -> Result<(), StorageError> {
+        use crate::schema::equivocation_evidence;
+
+        let proposed_in_block_hex = serialize_hex(proposed_in_block);
+        diesel::update(equivocation_evidence::table)
+            .filter(equivocation_evidence::proposed_in_block.eq(&proposed_in_block_hex))
+            .set((
+                equivocation_evidence::proposed_in_block.eq(None::<String>),
+                equivocation_evidence::proposed_in_block_height.eq(None::<i64>),
+            ))
+            .execute(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_clear_proposed_block",
+                source: e,
+            })?;
+
+        Ok(())
+    }
+
+    fn equivocation_evidence_set_committed_block(
+        &mut self,
+        evidence_id: &FixedHash,
+        committed_in_block: &BlockId,
+    ) -> Result<(), StorageError> {
+        use crate::schema::equivocation_evidence;
+
+        let num_affected = diesel::update(equivocation_evidence::table)
+            .filter(equivocation_evidence::evidence_id.eq(serialize_hex(evidence_id)))
+            .set(equivocation_evidence::committed_in_block.eq(serialize_hex(committed_in_block)))
+            .execute(self.connection())
+            .map_err(|e| SqliteStorageError::DieselError {
+                operation: "equivocation_evidence_set_committed_block",
+                source: e,
+            })?;
+
+        if num_affected == 0 {
+            return Err(StorageError::NotFound {
+                item: "equivocation_evidence",
+                key: evidence_id.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn lock_conflicts_insert_all<'a, I: IntoIterator<Item = (&'a TransactionId, &'a Vec<LockConflict>)>>(
         &mut self,
         block_id: &BlockId,
@@ -2117,59 +2220,6 @@ impl<'tx, TAddr: NodeAddressable + 'tx> StateStoreWriteTransaction for SqliteSta
         Ok(())
     }
 
-    fn validator_epoch_stats_add_participation_share(&mut self, qc_id: &QcId) -> Result<(), StorageError> {
-        use crate::schema::{quorum_certificates, validator_epoch_stats};
-
-        let qc_id = serialize_hex(qc_id);
-        let qc_json = quorum_certificates::table
-            .select(quorum_certificates::json)
-            .filter(quorum_certificates::qc_id.eq(&qc_id))
-            .filter(quorum_certificates::is_shares_processed.eq(false))
-            .first::<String>(self.connection())
-            .optional()
-            .map_err(|e| SqliteStorageError::DieselError {
-                operation: "validator_epoch_stats_add_participation_share",
-                source: e,
-            })?;
-        let Some(qc_json) = qc_json else {
-            return Ok(());
-        };
-
-        let qc = deserialize_json::<QuorumCertificate>(&qc_json)?;
-        let epoch = qc.epoch().as_u64() as i64;
-
-        for sig in qc.signatures() {
-            let values = (
-                validator_epoch_stats::epoch.eq(epoch),
-                validator_epoch_stats::public_key.eq(serialize_hex(sig.public_key().as_bytes())),
-                validator_epoch_stats::participation_shares.eq(1),
-            );
-
-            diesel::insert_into(validator_epoch_stats::table)
-                .values(values)
-                .on_conflict((validator_epoch_stats::epoch, validator_epoch_stats::public_key))
-                .do_update()
-                .set(validator_epoch_stats::participation_shares.eq(validator_epoch_stats::participation_shares + 1))
-                .execute(self.connection())
-                .map_err(|e| SqliteStorageError::DieselError {
-                    operation: "validator_epoch_stats_add_participation_share",
-                    source: e,
-                })?;
-        }
-
-        // Mark QC shares as processed
-        diesel::update(quorum_certificates::table)
-            .filter(quorum_certificates::qc_id.eq(qc_id))
-            .set(quorum_certificates::is_shares_processed.eq(true))
-            .execute(self.connection())
-            .map_err(|e| SqliteStorageError::DieselError {
-                operation: "validator_epoch_stats_add_participation_share",
-                source: e,
-            })?;
-
-        Ok(())
-    }
-
     fn validator_epoch_stats_updates<'a, I: IntoIterator<Item = ValidatorStatsUpdate<'a>>>(
         &mut self,
         epoch: Epoch,
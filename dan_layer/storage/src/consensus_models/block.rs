@@ -37,7 +37,9 @@ use ts_rs::TS;
 
 use super::{
     BlockDiff,
+    BlockHeader,
     BlockPledge,
+    EquivocationEvidence,
     ForeignProposal,
     ForeignProposalAtom,
     ForeignSendCounters,
@@ -337,39 +339,29 @@ impl Block {
     }
 
     pub fn calculate_hash(&self) -> FixedHash {
-        // Hash is created from the hash of the "body" and
-        // then hashed with the parent, so that you can
-        // create a merkle proof of a chain of blocks
-        // ```pre
-        // root
-        // |\
-        // |  block1
-        // |\
-        // |  block2
-        // |
-        // blockbody
-        // ```
-
-        let inner_hash = hashing::block_hasher()
-            .chain(&self.network)
-            // This allows us to exclude the justify and still validate the block
-            .chain(self.justify.id())
-            .chain(&self.height)
-            .chain(&self.total_leader_fee)
-            .chain(&self.epoch)
-            .chain(&self.shard_group)
-            .chain(&self.proposed_by)
-            .chain(&self.merkle_root)
-            .chain(&self.is_dummy)
-            .chain(&self.commands)
-            .chain(&self.foreign_indexes)
-            .chain(&self.timestamp)
-            .chain(&self.base_layer_block_height)
-            .chain(&self.base_layer_block_hash)
-            .chain(&self.extra_data)
-            .result();
-
-        hashing::block_hasher().chain(&self.parent).chain(&inner_hash).result()
+        self.header().calculate_hash()
+    }
+
+    /// Returns the header of the block, which commits to the commands by their hash
+    pub fn header(&self) -> BlockHeader {
+        BlockHeader {
+            network: self.network,
+            parent: self.parent,
+            justify_id: *self.justify.id(),
+            height: self.height,
+            epoch: self.epoch,
+            shard_group: self.shard_group,
+            proposed_by: self.proposed_by.clone(),
+            total_leader_fee: self.total_leader_fee,
+            merkle_root: self.merkle_root,
+            is_dummy: self.is_dummy,
+            commands_hash: hashing::block_commands_hasher().chain(&self.commands).result(),
+            foreign_indexes: self.foreign_indexes.clone(),
+            timestamp: self.timestamp,
+            base_layer_block_height: self.base_layer_block_height,
+            base_layer_block_hash: self.base_layer_block_hash,
+            extra_data: self.extra_data.clone(),
+        }
     }
 }
 
@@ -417,6 +409,10 @@ impl Block {
         self.commands.iter().filter_map(|c| c.resume_node())
     }
 
+    pub fn all_equivocations(&self) -> impl Iterator<Item = &EquivocationEvidence> + '_ {
+        self.commands.iter().filter_map(|c| c.equivocation())
+    }
+
     pub fn all_confidential_output_mints(&self) -> impl Iterator<Item = &MintConfidentialOutputAtom> + '_ {
         self.commands.iter().filter_map(|c| c.mint_confidential_output())
     }
@@ -1275,6 +1271,7 @@ where
     tx.transaction_executions_remove_any_by_block_id(block_id)?;
     tx.foreign_proposals_clear_proposed_in(block_id)?;
     tx.burnt_utxos_clear_proposed_block(block_id)?;
+    tx.equivocation_evidence_clear_proposed_block(block_id)?;
 
     Block::delete_record(tx, block_id)?;
 
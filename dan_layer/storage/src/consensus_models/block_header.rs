@@ -0,0 +1,83 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tari_common_types::types::{FixedHash, PublicKey};
+use tari_dan_common_types::{hashing, shard::Shard, Epoch, ExtraData, NodeHeight, ShardGroup};
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+use crate::consensus_models::{BlockId, QcId};
+
+/// The fields of a block that are committed to by the block id. The commands are only included as a hash, so a header
+/// can be used to prove the epoch, shard group, height and proposer of a block without the block body.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct BlockHeader {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub network: Network,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub parent: BlockId,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub justify_id: QcId,
+    pub height: NodeHeight,
+    pub epoch: Epoch,
+    pub shard_group: ShardGroup,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub proposed_by: PublicKey,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub total_leader_fee: u64,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub merkle_root: FixedHash,
+    pub is_dummy: bool,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub commands_hash: FixedHash,
+    pub foreign_indexes: IndexMap<Shard, u64>,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "ts", ts(type = "number"))]
+    pub base_layer_block_height: u64,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub base_layer_block_hash: FixedHash,
+    pub extra_data: Option<ExtraData>,
+}
+
+impl BlockHeader {
+    pub fn calculate_hash(&self) -> FixedHash {
+        // Hash is created from the hash of the "body" and
+        // then hashed with the parent, so that you can
+        // create a merkle proof of a chain of blocks
+        // ```pre
+        // root
+        // |\
+        // |  block1
+        // |\
+        // |  block2
+        // |
+        // blockbody
+        // ```
+
+        let inner_hash = hashing::block_hasher()
+            .chain(&self.network)
+            // This allows us to exclude the justify and still validate the block
+            .chain(&self.justify_id)
+            .chain(&self.height)
+            .chain(&self.total_leader_fee)
+            .chain(&self.epoch)
+            .chain(&self.shard_group)
+            .chain(&self.proposed_by)
+            .chain(&self.merkle_root)
+            .chain(&self.is_dummy)
+            .chain(&self.commands_hash)
+            .chain(&self.foreign_indexes)
+            .chain(&self.timestamp)
+            .chain(&self.base_layer_block_height)
+            .chain(&self.base_layer_block_hash)
+            .chain(&self.extra_data)
+            .result();
+
+        hashing::block_hasher().chain(&self.parent).chain(&inner_hash).result()
+    }
+}
@@ -8,13 +8,14 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 use tari_common_types::types::PublicKey;
-use tari_dan_common_types::ShardGroup;
+use tari_dan_common_types::{NodeHeight, ShardGroup};
 use tari_engine_types::substate::SubstateId;
 use tari_transaction::TransactionId;
 
 use super::{
     AbortReason,
     BlockId,
+    EquivocationEvidence,
     ExecutedTransaction,
     ForeignProposalAtom,
     LeaderFee,
@@ -114,12 +115,16 @@ pub enum Command {
     MintConfidentialOutput(MintConfidentialOutputAtom),
     SuspendNode(SuspendNodeAtom),
     ResumeNode(ResumeNodeAtom),
+    /// Record evidence that a validator signed two conflicting blocks at the same height, so that it can be reported
+    /// to the base layer for slashing.
+    Equivocation(EquivocationEvidence),
     EndEpoch,
 }
 
 /// Defines the order in which commands should be processed in a block. "Smallest" comes first and "largest" comes last.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 enum CommandOrdering<'a> {
+    Equivocation(&'a PublicKey, NodeHeight, &'a BlockId, &'a BlockId),
     ResumeNode,
     SuspendNode,
     /// Foreign proposals should come first in the block so that they are processed before commands
@@ -144,6 +149,7 @@ impl Command {
             Command::MintConfidentialOutput(_) |
             Command::SuspendNode(_) |
             Command::ResumeNode(_) |
+            Command::Equivocation(_) |
             Command::EndEpoch => None,
         }
     }
@@ -165,6 +171,12 @@ impl Command {
             Command::MintConfidentialOutput(mint) => CommandOrdering::MintConfidentialOutput(&mint.substate_id),
             Command::SuspendNode(_) => CommandOrdering::SuspendNode,
             Command::ResumeNode(_) => CommandOrdering::ResumeNode,
+            Command::Equivocation(evidence) => CommandOrdering::Equivocation(
+                &evidence.validator_public_key,
+                evidence.height,
+                &evidence.first.block_id,
+                &evidence.second.block_id,
+            ),
             Command::EndEpoch => CommandOrdering::EndEpoch,
         }
     }
@@ -218,6 +230,13 @@ impl Command {
         }
     }
 
+    pub fn equivocation(&self) -> Option<&EquivocationEvidence> {
+        match self {
+            Command::Equivocation(evidence) => Some(evidence),
+            _ => None,
+        }
+    }
+
     pub fn mint_confidential_output(&self) -> Option<&MintConfidentialOutputAtom> {
         match self {
             Command::MintConfidentialOutput(mint) => Some(mint),
@@ -297,6 +316,7 @@ impl Display for Command {
             Command::MintConfidentialOutput(mint) => write!(f, "MintConfidentialOutput({})", mint.substate_id),
             Command::SuspendNode(atom) => write!(f, "SuspendNode({atom})"),
             Command::ResumeNode(atom) => write!(f, "ResumeNode({atom})"),
+            Command::Equivocation(evidence) => write!(f, "Equivocation({evidence})"),
             Command::EndEpoch => write!(f, "EndEpoch"),
         }
     }
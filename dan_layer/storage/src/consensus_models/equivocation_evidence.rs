@@ -0,0 +1,395 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    fmt::{Display, Formatter},
+    ops::Deref,
+};
+
+use serde::{Deserialize, Serialize};
+use tari_common_types::types::{FixedHash, PublicKey};
+use tari_dan_common_types::{
+    hashing::{equivocation_evidence_hasher, vote_signature_hasher},
+    Epoch,
+    NodeHeight,
+    ShardGroup,
+};
+
+use crate::{
+    consensus_models::{Block, BlockHeader, BlockId, QuorumDecision, ValidatorSchnorrSignature, Vote},
+    StateStoreReadTransaction,
+    StateStoreWriteTransaction,
+    StorageError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub enum EquivocationKind {
+    /// The validator voted for two different blocks at the same height
+    DoubleVote,
+    /// The validator proposed two different blocks at the same height
+    DoubleProposal,
+}
+
+impl Display for EquivocationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EquivocationKind::DoubleVote => write!(f, "DoubleVote"),
+            EquivocationKind::DoubleProposal => write!(f, "DoubleProposal"),
+        }
+    }
+}
+
+/// A block id signed by the equivocating validator, either as a vote or as a proposal. The header of the block is
+/// included so that the statement can be verified without having the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct EquivocationStatement {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub block_id: BlockId,
+    pub header: BlockHeader,
+    /// The vote decision. This is None for proposals.
+    pub decision: Option<QuorumDecision>,
+    #[cfg_attr(feature = "ts", ts(type = "{public_nonce : string, signature: string}"))]
+    pub signature: ValidatorSchnorrSignature,
+}
+
+impl EquivocationStatement {
+    fn for_block(block: &Block, decision: Option<QuorumDecision>, signature: ValidatorSchnorrSignature) -> Self {
+        Self {
+            block_id: *block.id(),
+            header: block.header(),
+            decision,
+            signature,
+        }
+    }
+
+    fn verify(&self, kind: EquivocationKind, public_key: &PublicKey) -> bool {
+        // The header must be the preimage of the block id, otherwise it proves nothing about the block
+        if self.header.calculate_hash() != *self.block_id.hash() {
+            return false;
+        }
+
+        match (kind, self.decision) {
+            (EquivocationKind::DoubleVote, Some(decision)) => {
+                // Must match the message signed by the VoteSignatureService
+                let message = vote_signature_hasher().chain(&self.block_id).chain(&decision).result();
+                self.signature.verify(public_key, message)
+            },
+            (EquivocationKind::DoubleProposal, None) => {
+                self.header.proposed_by == *public_key && self.signature.verify(public_key, self.block_id)
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Signed evidence that a validator has signed two conflicting blocks at the same height. This evidence is included in
+/// a block so that it can be reported to the base layer for slashing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct EquivocationEvidence {
+    pub kind: EquivocationKind,
+    pub epoch: Epoch,
+    pub shard_group: ShardGroup,
+    pub height: NodeHeight,
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    pub validator_public_key: PublicKey,
+    pub first: EquivocationStatement,
+    pub second: EquivocationStatement,
+}
+
+impl EquivocationEvidence {
+    fn new(
+        kind: EquivocationKind,
+        epoch: Epoch,
+        shard_group: ShardGroup,
+        height: NodeHeight,
+        validator_public_key: PublicKey,
+        a: EquivocationStatement,
+        b: EquivocationStatement,
+    ) -> Self {
+        // Canonical ordering so that the same equivocation detected by different validators has the same id
+        let (first, second) = if a.block_id <= b.block_id { (a, b) } else { (b, a) };
+        Self {
+            kind,
+            epoch,
+            shard_group,
+            height,
+            validator_public_key,
+            first,
+            second,
+        }
+    }
+
+    /// Creates evidence from two votes by the same validator for different blocks at the same height. Each vote is
+    /// given with the block that it votes for.
+    pub fn double_vote(a: &Vote, a_block: &Block, b: &Vote, b_block: &Block) -> Self {
+        Self::new(
+            EquivocationKind::DoubleVote,
+            a_block.epoch(),
+            a_block.shard_group(),
+            a_block.height(),
+            a.signature.public_key.clone(),
+            EquivocationStatement::for_block(a_block, Some(a.decision), a.signature.signature.clone()),
+            EquivocationStatement::for_block(b_block, Some(b.decision), b.signature.signature.clone()),
+        )
+    }
+
+    /// Creates evidence from two signed blocks proposed by the same validator at the same height. Returns None if
+    /// either block is not signed.
+    pub fn double_proposal(a: &Block, b: &Block) -> Option<Self> {
+        Some(Self::new(
+            EquivocationKind::DoubleProposal,
+            a.epoch(),
+            a.shard_group(),
+            a.height(),
+            a.proposed_by().clone(),
+            EquivocationStatement::for_block(a, None, a.signature()?.clone()),
+            EquivocationStatement::for_block(b, None, b.signature()?.clone()),
+        ))
+    }
+
+    /// The id commits to the equivocating validator and the conflicting blocks, but not the signatures.
+    pub fn id(&self) -> FixedHash {
+        equivocation_evidence_hasher()
+            .chain(&self.kind)
+            .chain(&self.epoch)
+            .chain(&self.shard_group)
+            .chain(&self.height)
+            .chain(&self.validator_public_key)
+            .chain(&self.first.block_id)
+            .chain(&self.second.block_id)
+            .result()
+    }
+
+    /// Returns true if the evidence refers to two different blocks in the epoch and shard group and at the height of
+    /// the evidence, and both are validly signed by the validator. The block headers prove where the blocks are, so
+    /// the evidence can be verified by validators that do not have either block.
+    pub fn is_valid(&self) -> bool {
+        self.first.block_id != self.second.block_id &&
+            self.is_statement_in_scope(&self.first) &&
+            self.is_statement_in_scope(&self.second) &&
+            self.first.verify(self.kind, &self.validator_public_key) &&
+            self.second.verify(self.kind, &self.validator_public_key)
+    }
+
+    fn is_statement_in_scope(&self, statement: &EquivocationStatement) -> bool {
+        statement.header.epoch == self.epoch &&
+            statement.header.shard_group == self.shard_group &&
+            statement.header.height == self.height
+    }
+}
+
+impl EquivocationEvidence {
+    pub fn exists<TTx: StateStoreReadTransaction>(&self, tx: &TTx) -> Result<bool, StorageError> {
+        tx.equivocation_evidence_exists(&self.id())
+    }
+
+    /// Inserts the evidence if it does not exist. Returns true if the evidence was inserted, otherwise false.
+    pub fn save<TTx>(&self, tx: &mut TTx) -> Result<bool, StorageError>
+    where
+        TTx: StateStoreWriteTransaction + Deref,
+        TTx::Target: StateStoreReadTransaction,
+    {
+        if self.exists(&**tx)? {
+            return Ok(false);
+        }
+        tx.equivocation_evidence_insert(self)?;
+        Ok(true)
+    }
+
+    pub fn get_all_unproposed<TTx: StateStoreReadTransaction>(
+        tx: &TTx,
+        epoch: Epoch,
+        block_id: &BlockId,
+        limit: usize,
+    ) -> Result<Vec<Self>, StorageError> {
+        tx.equivocation_evidence_get_all_unproposed(epoch, block_id, limit)
+    }
+
+    /// Returns true if there is evidence that has not been proposed in the chain ending at the given leaf block
+    pub fn has_unproposed<TTx: StateStoreReadTransaction>(
+        tx: &TTx,
+        epoch: Epoch,
+        leaf_block: &BlockId,
+    ) -> Result<bool, StorageError> {
+        let unproposed = tx.equivocation_evidence_get_all_unproposed(epoch, leaf_block, 1)?;
+        Ok(!unproposed.is_empty())
+    }
+
+    /// Returns true if the evidence has been committed or proposed in a block between the locked block and the given
+    /// block.
+    pub fn is_proposed_in_chain<TTx: StateStoreReadTransaction>(
+        &self,
+        tx: &TTx,
+        block_id: &BlockId,
+    ) -> Result<bool, StorageError> {
+        tx.equivocation_evidence_is_proposed_in_chain(&self.id(), block_id)
+    }
+
+    /// Returns all evidence committed in the given epoch. This evidence is ready to be reported to the base layer.
+    pub fn get_all_committed<TTx: StateStoreReadTransaction>(
+        tx: &TTx,
+        epoch: Epoch,
+    ) -> Result<Vec<Self>, StorageError> {
+        tx.equivocation_evidence_get_all_committed(epoch)
+    }
+
+    pub fn set_proposed_in_block<TTx: StateStoreWriteTransaction>(
+        &self,
+        tx: &mut TTx,
+        proposed_in_block: &BlockId,
+    ) -> Result<(), StorageError> {
+        tx.equivocation_evidence_set_proposed_block(&self.id(), proposed_in_block)
+    }
+
+    pub fn set_committed_in_block<TTx: StateStoreWriteTransaction>(
+        &self,
+        tx: &mut TTx,
+        committed_in_block: &BlockId,
+    ) -> Result<(), StorageError> {
+        tx.equivocation_evidence_set_committed_block(&self.id(), committed_in_block)
+    }
+}
+
+impl Display for EquivocationEvidence {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({}, {}, {}, {} vs {})",
+            self.kind, self.validator_public_key, self.epoch, self.height, self.first.block_id, self.second.block_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+    use rand::rngs::OsRng;
+    use tari_common::configuration::Network;
+    use tari_common_types::types::PrivateKey;
+    use tari_crypto::keys::{PublicKey as _, SecretKey};
+
+    use super::*;
+    use crate::consensus_models::{QuorumCertificate, ValidatorSignature};
+
+    fn shard_group() -> ShardGroup {
+        ShardGroup::new(0, 63)
+    }
+
+    fn create_block(proposer: &PrivateKey, height: NodeHeight, timestamp: u64) -> Block {
+        let mut block = Block::new(
+            Network::LocalNet,
+            BlockId::zero(),
+            QuorumCertificate::genesis(Epoch(1), shard_group()),
+            height,
+            Epoch(1),
+            shard_group(),
+            PublicKey::from_secret_key(proposer),
+            Default::default(),
+            FixedHash::zero(),
+            0,
+            IndexMap::new(),
+            None,
+            timestamp,
+            0,
+            FixedHash::zero(),
+            None,
+        );
+        block.set_signature(ValidatorSignature::sign(proposer, block.id()).signature);
+        block
+    }
+
+    fn create_vote(secret_key: &PrivateKey, block: &Block, decision: QuorumDecision) -> Vote {
+        let message = vote_signature_hasher().chain(block.id()).chain(&decision).result();
+        Vote {
+            epoch: Epoch(1),
+            block_id: *block.id(),
+            decision,
+            sender_leaf_hash: FixedHash::zero(),
+            signature: ValidatorSignature::sign(secret_key, message),
+        }
+    }
+
+    #[test]
+    fn it_validates_double_vote_evidence() {
+        let leader = PrivateKey::random(&mut OsRng);
+        let block_a = create_block(&leader, NodeHeight(10), 1);
+        let block_b = create_block(&leader, NodeHeight(10), 2);
+
+        let secret_key = PrivateKey::random(&mut OsRng);
+        let a = create_vote(&secret_key, &block_a, QuorumDecision::Accept);
+        let b = create_vote(&secret_key, &block_b, QuorumDecision::Accept);
+
+        let evidence = EquivocationEvidence::double_vote(&b, &block_b, &a, &block_a);
+        assert!(evidence.is_valid());
+        assert_eq!(evidence.height, NodeHeight(10));
+        assert_eq!(evidence.validator_public_key, PublicKey::from_secret_key(&secret_key));
+        // The id does not depend on the order that the votes were received
+        let swapped = EquivocationEvidence::double_vote(&a, &block_a, &b, &block_b);
+        assert_eq!(evidence.id(), swapped.id());
+        assert_eq!(evidence.first, swapped.first);
+
+        // The same block twice is not an equivocation
+        let same = EquivocationEvidence::double_vote(&a, &block_a, &a, &block_a);
+        assert!(!same.is_valid());
+
+        // Votes signed by another validator are not valid evidence
+        let other = create_vote(&PrivateKey::random(&mut OsRng), &block_b, b.decision);
+        let forged = EquivocationEvidence::double_vote(&a, &block_a, &other, &block_b);
+        assert!(!forged.is_valid());
+
+        // Votes for blocks at different heights are not an equivocation
+        let block_c = create_block(&leader, NodeHeight(11), 3);
+        let c = create_vote(&secret_key, &block_c, QuorumDecision::Accept);
+        let different_heights = EquivocationEvidence::double_vote(&a, &block_a, &c, &block_c);
+        assert!(!different_heights.is_valid());
+
+        // A header that does not hash to the block id is rejected
+        let mut wrong_header = evidence.clone();
+        wrong_header.second.header.timestamp += 1;
+        assert!(!wrong_header.is_valid());
+
+        // A vote signature cannot be used as proposal evidence
+        let mut wrong_kind = evidence;
+        wrong_kind.kind = EquivocationKind::DoubleProposal;
+        assert!(!wrong_kind.is_valid());
+    }
+
+    #[test]
+    fn it_validates_double_proposal_evidence() {
+        let leader = PrivateKey::random(&mut OsRng);
+        let block_a = create_block(&leader, NodeHeight(10), 1);
+        let block_b = create_block(&leader, NodeHeight(10), 2);
+
+        let evidence = EquivocationEvidence::double_proposal(&block_a, &block_b).unwrap();
+        assert!(evidence.is_valid());
+        assert_eq!(evidence.validator_public_key, PublicKey::from_secret_key(&leader));
+
+        // Blocks proposed by different leaders are not an equivocation, even if the signatures are valid
+        let other_leader = PrivateKey::random(&mut OsRng);
+        let block_c = create_block(&other_leader, NodeHeight(10), 3);
+        let mut forged = EquivocationEvidence::double_proposal(&block_a, &block_c).unwrap();
+        assert!(!forged.is_valid());
+        forged.validator_public_key = PublicKey::from_secret_key(&other_leader);
+        assert!(!forged.is_valid());
+
+        // The evidence cannot claim a different height than the blocks
+        let mut wrong_height = evidence;
+        wrong_height.height = NodeHeight(11);
+        assert!(!wrong_height.is_valid());
+    }
+}
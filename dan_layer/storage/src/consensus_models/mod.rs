@@ -1,12 +1,15 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
+mod aggregated_signature;
 mod block;
 mod block_diff;
+mod block_header;
 mod block_pledges;
 mod burnt_utxo;
 mod command;
 mod epoch_checkpoint;
+mod equivocation_evidence;
 mod evidence;
 mod executed_transaction;
 mod foreign_parked_proposal;
@@ -41,12 +44,15 @@ mod validator_stats;
 mod vote;
 mod vote_signature;
 
+pub use aggregated_signature::*;
 pub use block::*;
 pub use block_diff::*;
+pub use block_header::*;
 pub use block_pledges::*;
 pub use burnt_utxo::*;
 pub use command::*;
 pub use epoch_checkpoint::*;
+pub use equivocation_evidence::*;
 pub use evidence::*;
 pub use executed_transaction::*;
 pub use foreign_parked_proposal::*;
@@ -62,6 +62,12 @@ pub enum NoVoteReason {
     CannotSuspendNodeBelowQuorumThreshold,
     #[error("Leader proposed to resume a node but the node should not be resumed")]
     ShouldNodeResumeNode,
+    #[error("Block timestamp {timestamp} is not within the allowed range [{min}, {max}]")]
+    InvalidBlockTimestamp { timestamp: u64, min: u64, max: u64 },
+    #[error("Leader proposed invalid equivocation evidence")]
+    InvalidEquivocationEvidence,
+    #[error("Leader proposed equivocation evidence that has already been proposed")]
+    EquivocationAlreadyProposed,
 }
 
 impl NoVoteReason {
@@ -94,6 +100,9 @@ impl NoVoteReason {
             Self::NodeNotSuspended => "NodeNotSuspended",
             Self::ShouldNodeResumeNode => "ShouldNodeResumeNode",
             Self::CannotSuspendNodeBelowQuorumThreshold => "CannotSuspendNodeBelowQuorumThreshold",
+            Self::InvalidBlockTimestamp { .. } => "InvalidBlockTimestamp",
+            Self::InvalidEquivocationEvidence => "InvalidEquivocationEvidence",
+            Self::EquivocationAlreadyProposed => "EquivocationAlreadyProposed",
         }
     }
 }
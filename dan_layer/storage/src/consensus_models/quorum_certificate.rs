@@ -7,6 +7,7 @@ use log::*;
 use serde::{Deserialize, Serialize};
 use tari_common_types::types::{FixedHash, FixedHashSizeError};
 use tari_dan_common_types::{
+    committee::Committee,
     hashing::quorum_certificate_hasher,
     optional::Optional,
     serde_with,
@@ -17,6 +18,7 @@ use tari_dan_common_types::{
 
 use crate::{
     consensus_models::{
+        AggregatedValidatorSignature,
         Block,
         BlockId,
         HighQc,
@@ -47,7 +49,13 @@ pub struct QuorumCertificate {
     block_height: NodeHeight,
     epoch: Epoch,
     shard_group: ShardGroup,
-    signatures: Vec<ValidatorSignature>,
+    #[serde(default)]
+    signature: AggregatedValidatorSignature,
+    /// Individual signatures of a QC that was created before signatures were aggregated. These are only read from
+    /// storage or from peers that have not upgraded, new QCs always use the aggregated signature.
+    #[serde(default, rename = "signatures", skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "ts", ts(skip))]
+    legacy_signatures: Vec<ValidatorSignature>,
     #[serde(with = "serde_with::hex::vec")]
     #[cfg_attr(feature = "ts", ts(type = "Array<string>"))]
     leaf_hashes: Vec<FixedHash>,
@@ -61,7 +69,7 @@ impl QuorumCertificate {
         block_height: NodeHeight,
         epoch: Epoch,
         shard_group: ShardGroup,
-        signatures: Vec<ValidatorSignature>,
+        signature: AggregatedValidatorSignature,
         mut leaf_hashes: Vec<FixedHash>,
         decision: QuorumDecision,
     ) -> Self {
@@ -72,7 +80,8 @@ impl QuorumCertificate {
             block_height,
             epoch,
             shard_group,
-            signatures,
+            signature,
+            legacy_signatures: vec![],
             leaf_hashes,
             decision,
             is_shares_processed: false,
@@ -87,23 +96,49 @@ impl QuorumCertificate {
             NodeHeight::zero(),
             epoch,
             shard_group,
-            vec![],
+            AggregatedValidatorSignature::default(),
             vec![],
             QuorumDecision::Accept,
         )
     }
 
+    /// Creates a QC with individual signatures as produced before signatures were aggregated.
+    pub fn new_legacy(
+        block: BlockId,
+        block_height: NodeHeight,
+        epoch: Epoch,
+        shard_group: ShardGroup,
+        signatures: Vec<ValidatorSignature>,
+        leaf_hashes: Vec<FixedHash>,
+        decision: QuorumDecision,
+    ) -> Self {
+        let mut qc = Self::new(
+            block,
+            block_height,
+            epoch,
+            shard_group,
+            AggregatedValidatorSignature::default(),
+            leaf_hashes,
+            decision,
+        );
+        qc.legacy_signatures = signatures;
+        qc.qc_id = qc.calculate_id();
+        qc
+    }
+
     pub fn calculate_id(&self) -> QcId {
-        quorum_certificate_hasher()
+        let hasher = quorum_certificate_hasher()
             .chain(&self.epoch)
             .chain(&self.shard_group)
             .chain(&self.block_id)
-            .chain(&self.block_height)
-            .chain(&self.signatures)
-            .chain(&self.leaf_hashes)
-            .chain(&self.decision)
-            .result()
-            .into()
+            .chain(&self.block_height);
+        // Legacy QCs keep the id they were created with
+        let hasher = if self.is_legacy() {
+            hasher.chain(&self.legacy_signatures)
+        } else {
+            hasher.chain(&self.signature)
+        };
+        hasher.chain(&self.leaf_hashes).chain(&self.decision).result().into()
     }
 }
 
@@ -128,8 +163,25 @@ impl QuorumCertificate {
         &self.leaf_hashes
     }
 
-    pub fn signatures(&self) -> &[ValidatorSignature] {
-        &self.signatures
+    pub fn signature(&self) -> &AggregatedValidatorSignature {
+        &self.signature
+    }
+
+    /// Returns true if this QC carries individual signatures rather than an aggregated signature.
+    pub fn is_legacy(&self) -> bool {
+        !self.legacy_signatures.is_empty()
+    }
+
+    pub fn legacy_signatures(&self) -> &[ValidatorSignature] {
+        &self.legacy_signatures
+    }
+
+    pub fn num_signers(&self) -> usize {
+        if self.is_legacy() {
+            self.legacy_signatures.len()
+        } else {
+            self.signature.num_signers()
+        }
     }
 
     pub fn block_height(&self) -> NodeHeight {
@@ -242,17 +294,30 @@ impl QuorumCertificate {
         Ok(high_qc)
     }
 
-    pub fn update_participation_shares<TTx: StateStoreWriteTransaction>(
+    /// Increments the participation shares of each signer. The committee must be the committee for this QC's epoch
+    /// and shard group, because the signer bitmap is resolved against it.
+    pub fn update_participation_shares<TTx: StateStoreWriteTransaction, TAddr>(
         &self,
         tx: &mut TTx,
+        committee: &Committee<TAddr>,
     ) -> Result<(), StorageError> {
         if self.is_shares_processed {
             return Ok(());
         }
 
+        let signers = if self.is_legacy() {
+            self.legacy_signatures.iter().map(|s| s.public_key()).collect()
+        } else {
+            self.signature
+                .signers(committee)
+                .ok_or_else(|| StorageError::DataInconsistency {
+                    details: format!("QC {} signers are not members of the given committee", self.qc_id),
+                })?
+        };
+
         tx.validator_epoch_stats_updates(
             self.epoch,
-            self.signatures.iter().map(|s| s.public_key()).map(|pk| {
+            signers.into_iter().map(|pk| {
                 ValidatorStatsUpdate::new(pk)
                     .increment_participation_share()
                     .decrement_missed_proposal()
@@ -280,12 +345,12 @@ impl Display for QuorumCertificate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Qc(block: {} {}, qc_id: {}, epoch: {}, {} signatures)",
+            "Qc(block: {} {}, qc_id: {}, epoch: {}, {} signers)",
             self.block_height,
             self.block_id,
             self.qc_id,
             self.epoch,
-            self.signatures.len()
+            self.num_signers()
         )
     }
 }
@@ -342,3 +407,35 @@ impl Display for QcId {
         Display::fmt(&self.0, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tari_common_types::types::PrivateKey;
+    use tari_crypto::keys::SecretKey;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_a_legacy_qc_with_the_same_id() {
+        let secret_key = PrivateKey::random(&mut rand::rngs::OsRng);
+        let qc = QuorumCertificate::new_legacy(
+            BlockId::zero(),
+            NodeHeight(1),
+            Epoch(1),
+            ShardGroup::new(0, 0),
+            vec![ValidatorSignature::sign(&secret_key, b"vote")],
+            vec![],
+            QuorumDecision::Accept,
+        );
+
+        // QCs stored before aggregation have a "signatures" field and no "signature" field
+        let mut json = serde_json::to_value(&qc).unwrap();
+        json.as_object_mut().unwrap().remove("signature");
+        let decoded: QuorumCertificate = serde_json::from_value(json).unwrap();
+
+        assert!(decoded.is_legacy());
+        assert_eq!(decoded.num_signers(), 1);
+        assert_eq!(decoded.id(), qc.id());
+        assert_eq!(decoded.calculate_id(), *qc.id());
+    }
+}
@@ -41,6 +41,14 @@ impl Vote {
             .is_some())
     }
 
+    pub fn get_by_block_and_sender<TTx: StateStoreReadTransaction>(
+        tx: &TTx,
+        block_id: &BlockId,
+        sender_leaf_hash: &FixedHash,
+    ) -> Result<Self, StorageError> {
+        tx.votes_get_by_block_and_sender(block_id, sender_leaf_hash)
+    }
+
     pub fn save<TTx>(&self, tx: &mut TTx) -> Result<bool, StorageError>
     where
         TTx: StateStoreWriteTransaction + Deref,
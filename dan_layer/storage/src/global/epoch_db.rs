@@ -49,4 +49,6 @@ impl<'a, 'tx, TGlobalDbAdapter: GlobalDbAdapter> EpochDb<'a, 'tx, TGlobalDbAdapt
 pub struct DbEpoch {
     pub epoch: u64,
     pub validator_node_mr: Vec<u8>,
+    /// The hash of the base layer block at which the epoch started
+    pub block_hash: Option<Vec<u8>>,
 }
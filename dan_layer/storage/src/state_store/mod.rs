@@ -37,6 +37,7 @@ use crate::{
         BurntUtxo,
         Decision,
         EpochCheckpoint,
+        EquivocationEvidence,
         ForeignParkedProposal,
         ForeignProposal,
         ForeignProposalAtom,
@@ -349,6 +350,21 @@ pub trait StateStoreReadTransaction: Sized {
 
     fn burnt_utxos_count(&self) -> Result<u64, StorageError>;
 
+    // -------------------------------- Equivocation evidence -------------------------------- //
+    fn equivocation_evidence_exists(&self, evidence_id: &FixedHash) -> Result<bool, StorageError>;
+    fn equivocation_evidence_get_all_unproposed(
+        &self,
+        epoch: Epoch,
+        leaf_block: &BlockId,
+        limit: usize,
+    ) -> Result<Vec<EquivocationEvidence>, StorageError>;
+    fn equivocation_evidence_is_proposed_in_chain(
+        &self,
+        evidence_id: &FixedHash,
+        leaf_block: &BlockId,
+    ) -> Result<bool, StorageError>;
+    fn equivocation_evidence_get_all_committed(&self, epoch: Epoch) -> Result<Vec<EquivocationEvidence>, StorageError>;
+
     // -------------------------------- Foreign parked block -------------------------------- //
     fn foreign_parked_blocks_exists(&self, block_id: &BlockId) -> Result<bool, StorageError>;
 
@@ -590,6 +606,20 @@ pub trait StateStoreWriteTransaction {
     fn burnt_utxos_clear_proposed_block(&mut self, proposed_in_block: &BlockId) -> Result<(), StorageError>;
     fn burnt_utxos_delete(&mut self, substate_id: &SubstateId) -> Result<(), StorageError>;
 
+    // -------------------------------- Equivocation evidence -------------------------------- //
+    fn equivocation_evidence_insert(&mut self, evidence: &EquivocationEvidence) -> Result<(), StorageError>;
+    fn equivocation_evidence_set_proposed_block(
+        &mut self,
+        evidence_id: &FixedHash,
+        proposed_in_block: &BlockId,
+    ) -> Result<(), StorageError>;
+    fn equivocation_evidence_clear_proposed_block(&mut self, proposed_in_block: &BlockId) -> Result<(), StorageError>;
+    fn equivocation_evidence_set_committed_block(
+        &mut self,
+        evidence_id: &FixedHash,
+        committed_in_block: &BlockId,
+    ) -> Result<(), StorageError>;
+
     // -------------------------------- Lock conflicts -------------------------------- //
     fn lock_conflicts_insert_all<'a, I: IntoIterator<Item = (&'a TransactionId, &'a Vec<LockConflict>)>>(
         &mut self,
@@ -598,7 +628,6 @@ pub trait StateStoreWriteTransaction {
     ) -> Result<(), StorageError>;
 
     // -------------------------------- ParticipationShares -------------------------------- //
-    fn validator_epoch_stats_add_participation_share(&mut self, qc_id: &QcId) -> Result<(), StorageError>;
     fn validator_epoch_stats_updates<'a, I: IntoIterator<Item = ValidatorStatsUpdate<'a>>>(
         &mut self,
         epoch: Epoch,
@@ -0,0 +1,2 @@
+ALTER TABLE epochs
+    DROP COLUMN block_hash;
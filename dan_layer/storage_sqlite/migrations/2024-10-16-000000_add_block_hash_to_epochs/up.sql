@@ -0,0 +1,3 @@
+-- The hash of the base layer block at which the epoch started. Epochs recorded before this column was added have no hash.
+ALTER TABLE epochs
+    ADD COLUMN block_hash BLOB NULL;
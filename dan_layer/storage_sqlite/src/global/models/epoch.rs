@@ -28,6 +28,7 @@ use crate::global::schema::*;
 pub struct Epoch {
     pub epoch: i64,
     pub validator_node_mr: Vec<u8>,
+    pub block_hash: Option<Vec<u8>>,
 }
 
 impl From<Epoch> for DbEpoch {
@@ -35,6 +36,7 @@ impl From<Epoch> for DbEpoch {
         Self {
             epoch: e.epoch as u64,
             validator_node_mr: e.validator_node_mr,
+            block_hash: e.block_hash,
         }
     }
 }
@@ -44,6 +46,7 @@ impl From<Epoch> for DbEpoch {
 pub struct NewEpoch {
     pub epoch: i64,
     pub validator_node_mr: Vec<u8>,
+    pub block_hash: Option<Vec<u8>>,
 }
 
 impl From<DbEpoch> for NewEpoch {
@@ -51,6 +54,7 @@ impl From<DbEpoch> for NewEpoch {
         Self {
             epoch: e.epoch as i64,
             validator_node_mr: e.validator_node_mr,
+            block_hash: e.block_hash,
         }
     }
 }
@@ -28,6 +28,7 @@ diesel::table! {
     epochs (epoch) {
         epoch -> BigInt,
         validator_node_mr -> Binary,
+        block_hash -> Nullable<Binary>,
     }
 }
 
@@ -43,3 +43,10 @@ pub use types::*;
 
 /// The name of the global export that defines the template definition
 pub const ABI_TEMPLATE_DEF_GLOBAL_NAME: &str = "_ABI_TEMPLATE_DEF";
+/// The name of the optional template function that is called by the engine to migrate the state of a component when
+/// its template is upgraded. It must take the previous component state as its only argument and return `Self`.
+pub const MIGRATE_FUNCTION_NAME: &str = "migrate";
+/// The name of the optional component method that marks a component as a fee sponsor. The engine calls it to pay the
+/// fees of a transaction that names the component as its sponsor. It must take `&mut self` as its only argument and
+/// is expected to pay the fee from one of the component's vaults (`Vault::pay_fee`).
+pub const FEE_SPONSOR_FUNCTION_NAME: &str = "sponsor_fee";
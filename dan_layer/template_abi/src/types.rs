@@ -24,7 +24,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-use crate::rust::{boxed::Box, string::String, vec::Vec};
+use crate::{
+    rust::{boxed::Box, format, string::String, vec::Vec},
+    FEE_SPONSOR_FUNCTION_NAME,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
@@ -56,6 +59,29 @@ impl TemplateDef {
             TemplateDef::V1(def) => &def.functions,
         }
     }
+
+    /// Returns true if the template declares the fee sponsor method (see [FEE_SPONSOR_FUNCTION_NAME])
+    pub fn is_fee_sponsor(&self) -> bool {
+        self.get_function(FEE_SPONSOR_FUNCTION_NAME)
+            .is_some_and(|f| f.is_method() && f.is_mut)
+    }
+
+    /// Checks that components of the `previous` template can be upgraded to this template. Every method of the
+    /// previous template must exist in this template with the same argument and return types. New functions and
+    /// methods may be added. Functions that are not methods are not checked as they are never called on an existing
+    /// component.
+    pub fn check_upgrade_compatibility(&self, previous: &TemplateDef) -> Result<(), String> {
+        for prev_method in previous.functions().iter().filter(|f| f.is_method()) {
+            let Some(method) = self.get_function(&prev_method.name) else {
+                return Err(format!("method '{}' was removed", prev_method.name));
+            };
+            if !method.has_same_signature(prev_method) {
+                return Err(format!("the signature of method '{}' has changed", prev_method.name));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,6 +107,27 @@ pub struct FunctionDef {
     pub is_mut: bool,
 }
 
+impl FunctionDef {
+    /// Returns true if the function is a method i.e. it takes a `&self` or `&mut self` receiver
+    pub fn is_method(&self) -> bool {
+        self.arguments
+            .first()
+            .and_then(|arg| arg.arg_type.other())
+            .map_or(false, |ty| ty == "&self" || ty == "&mut self")
+    }
+
+    /// Returns true if the argument types and the return type of both functions are the same. Argument names and
+    /// mutability are not considered.
+    pub fn has_same_signature(&self, other: &FunctionDef) -> bool {
+        self.output == other.output &&
+            self.arguments.len() == other.arguments.len() &&
+            self.arguments
+                .iter()
+                .zip(&other.arguments)
+                .all(|(a, b)| a.arg_type == b.arg_type)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
 pub struct ArgDef {
@@ -11,7 +11,12 @@ use std::{
     process::Command,
 };
 
-const TEMPLATE_BUILTINS: &[&str] = &["templates/account", "templates/account_nfts", "templates/faucet"];
+const TEMPLATE_BUILTINS: &[&str] = &[
+    "templates/account",
+    "templates/account_nfts",
+    "templates/faucet",
+    "templates/multisig_account",
+];
 
 fn main() -> Result<(), Box<dyn Error>> {
     // Rebuild templates if abi or lib changes
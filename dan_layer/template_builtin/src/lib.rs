@@ -28,6 +28,9 @@ pub const ACCOUNT_NFT_TEMPLATE_ADDRESS: TemplateAddress = TemplateAddress::from_
 pub const FAUCET_TEMPLATE_ADDRESS: TemplateAddress = TemplateAddress::from_array([
     1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
 ]);
+pub const MULTISIG_ACCOUNT_TEMPLATE_ADDRESS: TemplateAddress = TemplateAddress::from_array([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+]);
 
 pub fn get_template_builtin(address: &TemplateAddress) -> &'static [u8] {
     try_get_template_builtin(address).unwrap_or_else(|| panic!("Unknown builtin template address {address}"))
@@ -51,6 +54,10 @@ pub fn all_builtin_templates() -> impl Iterator<Item = (TemplateAddress, &'stati
             FAUCET_TEMPLATE_ADDRESS,
             include_bytes!("../templates/faucet/faucet.wasm").as_slice(),
         ),
+        (
+            MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
+            include_bytes!("../templates/multisig_account/multisig_account.wasm").as_slice(),
+        ),
     ]
     .into_iter()
 }
@@ -23,6 +23,30 @@
 use tari_template_abi::rust::collections::BTreeMap;
 use tari_template_lib::prelude::*;
 
+/// The number of epochs after which a recovery that has not reached the guardian threshold may be replaced by another
+/// guardian
+const RECOVERY_PROPOSAL_EXPIRY_EPOCHS: u64 = 10;
+
+/// Methods that check the session key that signed the transaction against the account state, so must be callable by
+/// anyone
+const SESSION_KEY_METHODS: [&str; 4] = [
+    "get_session_keys",
+    "session_transfer",
+    "session_transfer_non_fungibles",
+    "session_pay_fee",
+];
+
+/// Methods that check the guardian against the account state, so must be callable by anyone
+const GUARDIAN_METHODS: [&str; 7] = [
+    "get_guardians",
+    "get_pending_recovery",
+    "initiate_recovery",
+    "initiate_recovery_with_proof",
+    "approve_recovery",
+    "approve_recovery_with_proof",
+    "complete_recovery",
+];
+
 #[template]
 mod account_template {
     use super::*;
@@ -30,6 +54,61 @@ mod account_template {
     pub struct Account {
         // TODO: Lazy key value map/store
         vaults: BTreeMap<ResourceAddress, Vault>,
+        // Defaulted so that accounts created before these fields were added can still be decoded
+        #[serde(default)]
+        spending_limits: BTreeMap<ResourceAddress, SpendingLimit>,
+        /// If set, session keys may only transfer to these components
+        #[serde(default)]
+        recipient_allowlist: Option<Vec<ComponentAddress>>,
+        #[serde(default)]
+        session_keys: BTreeMap<RistrettoPublicKeyBytes, SessionKey>,
+        /// The public key token of the current owner. This is `None` for accounts created before social recovery was
+        /// added, until guardians are set.
+        #[serde(default)]
+        owner_token: Option<NonFungibleAddress>,
+        #[serde(default)]
+        guardians: Option<Guardians>,
+        /// Held by the account so that it can rotate its own owner once a recovery completes
+        #[serde(default)]
+        recovery_badge: Option<Vault>,
+        #[serde(default)]
+        pending_recovery: Option<PendingRecovery>,
+    }
+
+    /// Limits the amount of a resource that may be withdrawn from the account in a single epoch
+    #[derive(Clone)]
+    pub struct SpendingLimit {
+        pub limit_per_epoch: Amount,
+        pub epoch: u64,
+        pub spent: Amount,
+    }
+
+    /// A delegated key that may transfer funds and pay fees on behalf of the account, within its own per-epoch limits,
+    /// until it expires
+    #[derive(Clone)]
+    pub struct SessionKey {
+        /// Per-epoch limits for each resource the key may spend. Resources that are not listed cannot be spent.
+        pub spending_limits: BTreeMap<ResourceAddress, SpendingLimit>,
+        pub can_pay_fees: bool,
+        pub expires_at_epoch: u64,
+    }
+
+    /// Guardians that can together rotate the owner key of the account if it is lost
+    #[derive(Clone)]
+    pub struct Guardians {
+        /// Public key tokens or non-fungibles held by the guardians
+        pub guardians: Vec<NonFungibleAddress>,
+        pub threshold: u32,
+        /// The number of epochs that the owner has to cancel a recovery once the guardian threshold is reached
+        pub recovery_delay_epochs: u64,
+    }
+
+    #[derive(Clone)]
+    pub struct PendingRecovery {
+        pub new_owner_key: RistrettoPublicKeyBytes,
+        pub approvals: Vec<NonFungibleAddress>,
+        pub initiated_at_epoch: u64,
+        pub threshold_reached_at_epoch: Option<u64>,
     }
 
     impl Account {
@@ -44,16 +123,7 @@ mod account_template {
                 OwnerRule::ByPublicKey(public_key)
             );
 
-            let access_rules = access_rules.unwrap_or(
-                AccessRules::new()
-                    .add_method_rule("balance", rule!(allow_all))
-                    .add_method_rule("get_balances", rule!(allow_all))
-                    .add_method_rule("deposit", rule!(allow_all))
-                    .add_method_rule("deposit_all", rule!(allow_all))
-                    .add_method_rule("get_non_fungible_ids", rule!(allow_all))
-                    // By defaul, only the owner of the token will be able to withdraw funds from the account
-                    .default(rule!(non_fungible(public_key_token)))
-            );
+            let access_rules = access_rules.unwrap_or_else(|| Self::default_access_rules(public_key_token.clone()));
 
             // add the funds from the (optional) bucket
             let mut vaults = BTreeMap::new();
@@ -61,13 +131,58 @@ mod account_template {
                 vaults.insert(b.resource_address(), Vault::from_bucket(b));
             }
 
-            Component::new(Self { vaults })
+            Component::new(Self {
+                vaults,
+                spending_limits: BTreeMap::new(),
+                recipient_allowlist: None,
+                session_keys: BTreeMap::new(),
+                owner_token: Some(public_key_token),
+                guardians: None,
+                recovery_badge: None,
+                pending_recovery: None,
+            })
                 .with_access_rules(access_rules)
                 .with_public_key_address(public_key)
                 .with_owner_rule(owner_rule)
                 .create()
         }
 
+        fn default_access_rules(owner_token: NonFungibleAddress) -> AccessRules {
+            let rules = AccessRules::new()
+                .add_method_rule("balance", rule!(allow_all))
+                .add_method_rule("get_balances", rule!(allow_all))
+                .add_method_rule("deposit", rule!(allow_all))
+                .add_method_rule("deposit_all", rule!(allow_all))
+                .add_method_rule("get_non_fungible_ids", rule!(allow_all))
+                .add_method_rule("get_spending_limits", rule!(allow_all))
+                // By defaul, only the owner of the token will be able to withdraw funds from the account
+                .default(rule!(non_fungible(owner_token)));
+            // Session keys and guardians are checked against the account state rather than by access rules
+            SESSION_KEY_METHODS
+                .iter()
+                .chain(GUARDIAN_METHODS.iter())
+                .fold(rules, |rules, method| rules.add_method_rule(*method, rule!(allow_all)))
+        }
+
+        /// Allows anyone to call the given methods. Accounts created before session keys or guardians were added, or
+        /// with custom access rules, do not allow this, so the access rules of the account are updated when session
+        /// keys or guardians are first set.
+        fn allow_all_methods(methods: &[&str]) {
+            let component = ComponentManager::current();
+            let access_rules = component.get_access_rules();
+            let missing = methods
+                .iter()
+                .filter(|method| *access_rules.get_method_access_rule(method) != AccessRule::AllowAll)
+                .collect::<Vec<_>>();
+            if missing.is_empty() {
+                return;
+            }
+            let access_rules = missing
+                .into_iter()
+                .fold(access_rules, |rules, method| rules.add_method_rule(*method, rule!(allow_all)));
+            component.set_access_rules(access_rules);
+        }
+
         // #[access_rule(allow_all)]
         pub fn balance(&self, resource: ResourceAddress) -> Amount {
             self.vaults
@@ -87,6 +202,7 @@ mod account_template {
                 ("amount", amount.to_string()),
                 ("resource", resource.to_string()),
             ]);
+            self.spend(resource, amount);
             let v = self.get_vault_mut(resource);
             v.withdraw(amount)
         }
@@ -97,6 +213,7 @@ mod account_template {
                 ("id", nf_id.to_string()),
                 ("resource", resource.to_string()),
             ]);
+            self.spend(resource, Amount(1));
             let v = self.get_vault_mut(resource);
             v.withdraw_non_fungibles([nf_id])
         }
@@ -109,6 +226,7 @@ mod account_template {
                     nf_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(","),
                 ),
             ]);
+            self.spend(resource, Amount(nf_ids.len() as i64));
             let v = self.get_vault_mut(resource);
             v.withdraw_non_fungibles(nf_ids)
         }
@@ -189,7 +307,8 @@ mod account_template {
 
         // Fee methods. These are used to pay fees and satisfy a "duck-typed" interface.
 
-        /// Pay fees from previously revealed confidential resource.
+        /// Pay fees from previously revealed confidential resource. Fees are not counted towards the account spending
+        /// limit so that the owner can always pay for a transaction, including one that changes the limit.
         pub fn pay_fee(&mut self, amount: Amount) {
             emit_event("pay_fee", [("amount", amount.to_string())]);
             self.get_vault_mut(CONFIDENTIAL_TARI_RESOURCE_ADDRESS).pay_fee(amount);
@@ -202,6 +321,412 @@ mod account_template {
                 .pay_fee_confidential(proof);
         }
 
+        // Spending limits and session keys. Confidential withdrawals are not counted towards spending limits because
+        // the amount is not known to the account.
+
+        /// Sets (or removes, if `None`) the per-epoch limit on withdrawals of `resource` from the account
+        pub fn set_spending_limit(&mut self, resource: ResourceAddress, limit_per_epoch: Option<Amount>) {
+            emit_event("set_spending_limit", [
+                ("resource", resource.to_string()),
+                (
+                    "limit_per_epoch",
+                    limit_per_epoch.map(|l| l.to_string()).unwrap_or_else(|| "none".to_string()),
+                ),
+            ]);
+            match limit_per_epoch {
+                Some(limit) => {
+                    assert!(!limit.is_negative(), "Spending limit must not be negative");
+                    self.spending_limits.insert(resource, SpendingLimit::new(limit));
+                },
+                None => {
+                    self.spending_limits.remove(&resource);
+                },
+            }
+        }
+
+        pub fn get_spending_limits(&self) -> Vec<(ResourceAddress, SpendingLimit)> {
+            self.spending_limits.iter().map(|(k, v)| (*k, v.clone())).collect()
+        }
+
+        /// Sets (or removes, if `None`) the components that session keys are allowed to transfer to
+        pub fn set_recipient_allowlist(&mut self, allowlist: Option<Vec<ComponentAddress>>) {
+            emit_event("set_recipient_allowlist", [(
+                "num_recipients",
+                allowlist
+                    .as_ref()
+                    .map(|l| l.len().to_string())
+                    .unwrap_or_else(|| "none".to_string()),
+            )]);
+            self.recipient_allowlist = allowlist;
+        }
+
+        /// Adds or replaces a session key. The key may spend up to the given amount of each resource per epoch and
+        /// may only be used up to and including `expires_at_epoch`.
+        pub fn add_session_key(
+            &mut self,
+            public_key: RistrettoPublicKeyBytes,
+            spending_limits: Vec<(ResourceAddress, Amount)>,
+            can_pay_fees: bool,
+            expires_at_epoch: u64,
+        ) {
+            assert!(
+                Consensus::current_epoch() <= expires_at_epoch,
+                "Session key expiry epoch {} is in the past",
+                expires_at_epoch
+            );
+            emit_event("add_session_key", [
+                ("public_key", public_key.to_string()),
+                ("expires_at_epoch", expires_at_epoch.to_string()),
+            ]);
+            let spending_limits = spending_limits
+                .into_iter()
+                .map(|(resource, limit)| {
+                    assert!(!limit.is_negative(), "Spending limit must not be negative");
+                    (resource, SpendingLimit::new(limit))
+                })
+                .collect();
+            self.session_keys.insert(public_key, SessionKey {
+                spending_limits,
+                can_pay_fees,
+                expires_at_epoch,
+            });
+            Self::allow_all_methods(&SESSION_KEY_METHODS);
+        }
+
+        pub fn revoke_session_key(&mut self, public_key: RistrettoPublicKeyBytes) {
+            emit_event("revoke_session_key", [("public_key", public_key.to_string())]);
+            self.session_keys
+                .remove(&public_key)
+                .unwrap_or_else(|| panic!("Session key {} not found", public_key));
+        }
+
+        pub fn get_session_keys(&self) -> Vec<(RistrettoPublicKeyBytes, SessionKey)> {
+            self.session_keys.iter().map(|(k, v)| (*k, v.clone())).collect()
+        }
+
+        /// Transfers fungible tokens to an allowed recipient using the session key that signed the transaction
+        pub fn session_transfer(&mut self, resource: ResourceAddress, amount: Amount, destination: ComponentAddress) {
+            assert!(amount > Amount::zero(), "Transfer amount must be positive");
+            let session_key = self.session_spend(resource, amount);
+            self.check_recipient_allowed(destination);
+            emit_event("session_transfer", [
+                ("session_key", session_key.to_string()),
+                ("amount", amount.to_string()),
+                ("resource", resource.to_string()),
+                ("destination", destination.to_string()),
+            ]);
+            self.spend(resource, amount);
+            let bucket = self.get_vault_mut(resource).withdraw(amount);
+            ComponentManager::get(destination).invoke("deposit", args![bucket]);
+        }
+
+        /// Transfers non-fungibles to an allowed recipient using the session key that signed the transaction. Each
+        /// non-fungible counts as one unit towards the spending limits.
+        pub fn session_transfer_non_fungibles(
+            &mut self,
+            resource: ResourceAddress,
+            nf_ids: Vec<NonFungibleId>,
+            destination: ComponentAddress,
+        ) {
+            let amount = Amount(nf_ids.len() as i64);
+            let session_key = self.session_spend(resource, amount);
+            self.check_recipient_allowed(destination);
+            emit_event("session_transfer_non_fungibles", [
+                ("session_key", session_key.to_string()),
+                ("resource", resource.to_string()),
+                ("destination", destination.to_string()),
+                (
+                    "ids",
+                    nf_ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(","),
+                ),
+            ]);
+            self.spend(resource, amount);
+            let bucket = self.get_vault_mut(resource).withdraw_non_fungibles(nf_ids);
+            ComponentManager::get(destination).invoke("deposit", args![bucket]);
+        }
+
+        /// Pays transaction fees using the session key that signed the transaction. Fees count towards the key's
+        /// limit for Tari.
+        pub fn session_pay_fee(&mut self, amount: Amount) {
+            let session_key = self.session_spend(CONFIDENTIAL_TARI_RESOURCE_ADDRESS, amount);
+            assert!(
+                self.session_keys[&session_key].can_pay_fees,
+                "Session key {} is not permitted to pay fees",
+                session_key
+            );
+            self.pay_fee(amount);
+        }
+
+        /// Records a withdrawal against the account's spending limit for the resource, if one is set
+        fn spend(&mut self, resource: ResourceAddress, amount: Amount) {
+            if let Some(limit) = self.spending_limits.get_mut(&resource) {
+                let current_epoch = Consensus::current_epoch();
+                assert!(
+                    limit.try_spend(amount, current_epoch),
+                    "Withdrawal of {} {} exceeds the account spending limit of {} per epoch",
+                    amount,
+                    resource,
+                    limit.limit_per_epoch
+                );
+            }
+        }
+
+        /// Checks that the transaction signer is an active session key and records the amount against its limit.
+        /// Returns the session key.
+        fn session_spend(&mut self, resource: ResourceAddress, amount: Amount) -> RistrettoPublicKeyBytes {
+            let signer = CallerContext::transaction_signer_public_key();
+            let current_epoch = Consensus::current_epoch();
+            let session_key = self
+                .session_keys
+                .get_mut(&signer)
+                .unwrap_or_else(|| panic!("Signer {} is not a session key for this account", signer));
+            assert!(
+                current_epoch <= session_key.expires_at_epoch,
+                "Session key {} expired at epoch {}",
+                signer,
+                session_key.expires_at_epoch
+            );
+            let limit = session_key
+                .spending_limits
+                .get_mut(&resource)
+                .unwrap_or_else(|| panic!("Session key {} is not permitted to spend {}", signer, resource));
+            assert!(
+                limit.try_spend(amount, current_epoch),
+                "Withdrawal of {} {} exceeds the session key spending limit of {} per epoch",
+                amount,
+                resource,
+                limit.limit_per_epoch
+            );
+            signer
+        }
+
+        fn check_recipient_allowed(&self, destination: ComponentAddress) {
+            if let Some(allowlist) = &self.recipient_allowlist {
+                assert!(
+                    allowlist.contains(&destination),
+                    "Recipient {} is not in the allowlist",
+                    destination
+                );
+            }
+        }
+
+        // Social recovery. Once the guardian threshold approve a new owner key and the recovery delay has passed without
+        // the owner cancelling, anyone may complete the recovery to rotate the owner key. A recovery that does not reach
+        // the threshold within RECOVERY_PROPOSAL_EXPIRY_EPOCHS may be replaced by any guardian. Registering guardians replaces
+        // any custom owner rule, and completing a recovery replaces any custom access rules, with the defaults for the
+        // new owner key.
+
+        /// Registers (or replaces) the guardians of the account. Any pending recovery is cancelled.
+        pub fn set_guardians(&mut self, guardians: Vec<NonFungibleAddress>, threshold: u32, recovery_delay_epochs: u64) {
+            assert!(threshold > 0, "Threshold must be at least 1");
+            assert!(
+                threshold as usize <= guardians.len(),
+                "Threshold {} exceeds the number of guardians {}",
+                threshold,
+                guardians.len()
+            );
+            // Accounts created before social recovery was added do not record their owner token. Only the owner may
+            // set guardians, so it is taken from the signer.
+            let owner_token = self
+                .owner_token
+                .get_or_insert_with(|| NonFungibleAddress::from_public_key(CallerContext::transaction_signer_public_key()))
+                .clone();
+            for (i, guardian) in guardians.iter().enumerate() {
+                assert!(!guardians[..i].contains(guardian), "Duplicate guardian {}", guardian);
+                assert!(*guardian != owner_token, "The owner cannot be a guardian");
+            }
+            emit_event("set_guardians", [
+                ("num_guardians", guardians.len().to_string()),
+                ("threshold", threshold.to_string()),
+                ("recovery_delay_epochs", recovery_delay_epochs.to_string()),
+            ]);
+
+            Self::allow_all_methods(&GUARDIAN_METHODS);
+
+            if self.recovery_badge.is_none() {
+                // No-one can mint more badges or change the rules of the badge, and only this account can withdraw it or
+                // create proofs from it
+                let badge = ResourceBuilder::non_fungible()
+                    .with_owner_rule(OwnerRule::None)
+                    .withdrawable(rule!(component(CallerContext::current_component_address())))
+                    .initial_supply([NonFungibleId::from_u64(0)]);
+                let badge_resource = badge.resource_address();
+                self.recovery_badge = Some(Vault::from_bucket(badge));
+                ComponentManager::current()
+                    .set_owner_rule(Self::recoverable_owner_rule(owner_token, badge_resource));
+            }
+
+            self.guardians = Some(Guardians {
+                guardians,
+                threshold,
+                recovery_delay_epochs,
+            });
+            self.pending_recovery = None;
+        }
+
+        pub fn get_guardians(&self) -> Option<Guardians> {
+            self.guardians.clone()
+        }
+
+        pub fn get_pending_recovery(&self) -> Option<PendingRecovery> {
+            self.pending_recovery.clone()
+        }
+
+        /// Starts a recovery to `new_owner_key` as the guardian that signed the transaction
+        pub fn initiate_recovery(&mut self, new_owner_key: RistrettoPublicKeyBytes) {
+            let guardian = self.signer_guardian();
+            self.start_recovery(guardian, new_owner_key);
+        }
+
+        /// Starts a recovery to `new_owner_key` as the guardian whose non-fungible is in the proof
+        pub fn initiate_recovery_with_proof(&mut self, new_owner_key: RistrettoPublicKeyBytes, proof: Proof) {
+            let guardian = self.proof_guardian(proof);
+            self.start_recovery(guardian, new_owner_key);
+        }
+
+        /// Approves the pending recovery as the guardian that signed the transaction
+        pub fn approve_recovery(&mut self) {
+            let guardian = self.signer_guardian();
+            self.add_recovery_approval(guardian);
+        }
+
+        /// Approves the pending recovery as the guardian whose non-fungible is in the proof
+        pub fn approve_recovery_with_proof(&mut self, proof: Proof) {
+            let guardian = self.proof_guardian(proof);
+            self.add_recovery_approval(guardian);
+        }
+
+        pub fn cancel_recovery(&mut self) {
+            let pending = self.pending_recovery.take().expect("No recovery is pending");
+            emit_event("cancel_recovery", [("new_owner_key", pending.new_owner_key.to_string())]);
+        }
+
+        /// Rotates the owner key once the guardian threshold has been reached and the recovery delay has passed. Session
+        /// keys are revoked.
+        pub fn complete_recovery(&mut self) {
+            let guardians = self.guardians.as_ref().expect("Account has no guardians");
+            let pending = self.pending_recovery.as_ref().expect("No recovery is pending");
+            let threshold_reached_at_epoch = pending.threshold_reached_at_epoch.unwrap_or_else(|| {
+                panic!(
+                    "Recovery has {} of {} required guardian approvals",
+                    pending.approvals.len(),
+                    guardians.threshold
+                )
+            });
+            let completes_at_epoch = threshold_reached_at_epoch + guardians.recovery_delay_epochs;
+            assert!(
+                Consensus::current_epoch() >= completes_at_epoch,
+                "Recovery cannot be completed until epoch {}",
+                completes_at_epoch
+            );
+
+            let pending = self.pending_recovery.take().unwrap();
+            let new_owner_token = NonFungibleAddress::from_public_key(pending.new_owner_key);
+            let badge = self.recovery_badge.as_ref().unwrap();
+            let badge_resource = badge.resource_address();
+            let proof = badge.create_proof();
+            proof.authorize_with(|| {
+                let component = ComponentManager::current();
+                component.set_owner_rule(Self::recoverable_owner_rule(new_owner_token.clone(), badge_resource));
+                component.set_access_rules(Self::default_access_rules(new_owner_token.clone()));
+            });
+            proof.drop();
+
+            emit_event("complete_recovery", [
+                (
+                    "old_owner",
+                    self.owner_token.as_ref().map(|t| t.to_string()).unwrap_or_default(),
+                ),
+                ("new_owner", new_owner_token.to_string()),
+            ]);
+            self.owner_token = Some(new_owner_token);
+            self.session_keys.clear();
+        }
+
+        fn recoverable_owner_rule(owner_token: NonFungibleAddress, badge_resource: ResourceAddress) -> OwnerRule {
+            OwnerRule::ByAccessRule(rule!(any_of(non_fungible(owner_token), resource(badge_resource))))
+        }
+
+        fn start_recovery(&mut self, guardian: NonFungibleAddress, new_owner_key: RistrettoPublicKeyBytes) {
+            let current_epoch = Consensus::current_epoch();
+            // A single guardian must not be able to block recovery by starting one that the others do not approve, so
+            // a pending recovery can be replaced once it expires without reaching the threshold
+            if let Some(pending) = &self.pending_recovery {
+                assert!(
+                    pending.threshold_reached_at_epoch.is_none(),
+                    "A recovery that has reached the guardian threshold is already pending. The owner must cancel it \
+                     first."
+                );
+                let expires_at_epoch = pending.initiated_at_epoch + RECOVERY_PROPOSAL_EXPIRY_EPOCHS;
+                assert!(
+                    current_epoch >= expires_at_epoch,
+                    "A recovery is already pending. It can be replaced from epoch {}.",
+                    expires_at_epoch
+                );
+            }
+            emit_event("initiate_recovery", [
+                ("guardian", guardian.to_string()),
+                ("new_owner_key", new_owner_key.to_string()),
+            ]);
+            self.pending_recovery = Some(PendingRecovery {
+                new_owner_key,
+                approvals: vec![],
+                initiated_at_epoch: current_epoch,
+                threshold_reached_at_epoch: None,
+            });
+            self.add_recovery_approval(guardian);
+        }
+
+        fn add_recovery_approval(&mut self, guardian: NonFungibleAddress) {
+            let threshold = self.guardians.as_ref().expect("Account has no guardians").threshold;
+            let current_epoch = Consensus::current_epoch();
+            let pending = self.pending_recovery.as_mut().expect("No recovery is pending");
+            assert!(
+                !pending.approvals.contains(&guardian),
+                "Guardian {} has already approved the recovery",
+                guardian
+            );
+            if pending.threshold_reached_at_epoch.is_none() {
+                let expires_at_epoch = pending.initiated_at_epoch + RECOVERY_PROPOSAL_EXPIRY_EPOCHS;
+                assert!(
+                    current_epoch < expires_at_epoch,
+                    "The pending recovery expired at epoch {}",
+                    expires_at_epoch
+                );
+            }
+            emit_event("approve_recovery", [("guardian", guardian.to_string())]);
+            pending.approvals.push(guardian);
+            if pending.threshold_reached_at_epoch.is_none() && pending.approvals.len() >= threshold as usize {
+                pending.threshold_reached_at_epoch = Some(current_epoch);
+            }
+        }
+
+        fn signer_guardian(&self) -> NonFungibleAddress {
+            let guardian = NonFungibleAddress::from_public_key(CallerContext::transaction_signer_public_key());
+            assert!(self.is_guardian(&guardian), "Signer {} is not a guardian", guardian);
+            guardian
+        }
+
+        fn proof_guardian(&self, proof: Proof) -> NonFungibleAddress {
+            let resource = proof.resource_address();
+            let guardian = proof
+                .get_non_fungibles()
+                .into_iter()
+                .map(|id| NonFungibleAddress::new(resource, id))
+                .find(|addr| self.is_guardian(addr))
+                .unwrap_or_else(|| panic!("Proof of {} does not contain a guardian", resource));
+            proof.drop();
+            guardian
+        }
+
+        fn is_guardian(&self, guardian: &NonFungibleAddress) -> bool {
+            self.guardians
+                .as_ref()
+                .expect("Account has no guardians")
+                .guardians
+                .contains(guardian)
+        }
+
         pub fn create_proof_for_resource(&mut self, resource: ResourceAddress) -> Proof {
             emit_event("create_proof_for_resource", [("resource", resource.to_string())]);
             let v = self.get_vault_mut(resource);
@@ -236,4 +761,30 @@ mod account_template {
             bucket.get_non_fungible_ids()
         }
     }
+
+    impl SpendingLimit {
+        fn new(limit_per_epoch: Amount) -> Self {
+            Self {
+                limit_per_epoch,
+                epoch: 0,
+                spent: Amount::zero(),
+            }
+        }
+
+        /// Adds the amount to the amount spent in the current epoch if it is within the limit. The amount spent is
+        /// reset at the start of each epoch.
+        fn try_spend(&mut self, amount: Amount, current_epoch: u64) -> bool {
+            if self.epoch != current_epoch {
+                self.epoch = current_epoch;
+                self.spent = Amount::zero();
+            }
+            match self.spent.checked_add(amount) {
+                Some(spent) if spent <= self.limit_per_epoch => {
+                    self.spent = spent;
+                    true
+                },
+                _ => false,
+            }
+        }
+    }
 }
@@ -0,0 +1,321 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use tari_template_abi::rust::collections::BTreeMap;
+use tari_template_lib::{args::Arg, prelude::*};
+
+/// An account that is controlled by a set of owners. Any owner may propose a withdrawal or a call, and the proposal is
+/// executed once `threshold` owners (including the proposer) have approved it. Owners approve in separate transactions
+/// signed by their own keys. Expired proposals are removed whenever a proposal is made or approved.
+#[template]
+mod multisig_account_template {
+    use super::*;
+
+    pub struct MultisigAccount {
+        owners: Vec<RistrettoPublicKeyBytes>,
+        threshold: u32,
+        vaults: BTreeMap<ResourceAddress, Vault>,
+        proposals: BTreeMap<u64, Proposal>,
+        next_proposal_id: u64,
+    }
+
+    #[derive(Clone)]
+    pub struct Proposal {
+        pub id: u64,
+        pub proposer: RistrettoPublicKeyBytes,
+        pub action: ProposalAction,
+        pub approvals: Vec<RistrettoPublicKeyBytes>,
+        /// The proposal can no longer be approved after this epoch
+        pub expires_at_epoch: u64,
+    }
+
+    #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "self::serde")]
+    pub enum ProposalAction {
+        /// Withdraw fungible tokens and deposit them into the destination component
+        Withdraw {
+            resource: ResourceAddress,
+            amount: Amount,
+            destination: ComponentAddress,
+        },
+        /// Call a method on a component that is owned by the multisig account with CBOR-encoded arguments. The value
+        /// returned by the method is returned by the call that executes the proposal.
+        Call {
+            component: ComponentAddress,
+            method: String,
+            args: Vec<Vec<u8>>,
+        },
+    }
+
+    impl MultisigAccount {
+        pub fn create(owners: Vec<RistrettoPublicKeyBytes>, threshold: u32) -> Component<MultisigAccount> {
+            assert!(!owners.is_empty(), "At least one owner is required");
+            assert!(threshold > 0, "Threshold must be at least 1");
+            assert!(
+                threshold as usize <= owners.len(),
+                "Threshold {} exceeds the number of owners {}",
+                threshold,
+                owners.len()
+            );
+            for (i, owner) in owners.iter().enumerate() {
+                assert!(!owners[..i].contains(owner), "Duplicate owner {}", owner);
+            }
+
+            let owner_tokens = owners
+                .iter()
+                .map(|owner| NonFungibleAddress::from_public_key(*owner))
+                .collect::<Vec<_>>();
+
+            let any_owner = AccessRule::Restricted(RestrictedAccessRule::Require(RequireRule::AnyOf(
+                owner_tokens.iter().cloned().map(Into::into).collect(),
+            )));
+            // A threshold of the owners are able to update the access rules of the account
+            let owner_rule = OwnerRule::ByAccessRule(AccessRule::Restricted(RestrictedAccessRule::Require(
+                RequireRule::m_of_n(threshold, owner_tokens),
+            )));
+
+            let access_rules = AccessRules::new()
+                .add_method_rule("balance", rule!(allow_all))
+                .add_method_rule("get_balances", rule!(allow_all))
+                .add_method_rule("deposit", rule!(allow_all))
+                .add_method_rule("deposit_all", rule!(allow_all))
+                .add_method_rule("owners", rule!(allow_all))
+                .add_method_rule("threshold", rule!(allow_all))
+                .add_method_rule("get_proposal", rule!(allow_all))
+                .add_method_rule("get_proposals", rule!(allow_all))
+                .add_method_rule("propose_withdrawal", any_owner.clone())
+                .add_method_rule("propose_call", any_owner.clone())
+                .add_method_rule("approve", any_owner.clone())
+                .add_method_rule("prune_expired_proposals", any_owner)
+                .default(rule!(deny_all));
+
+            Component::new(Self {
+                owners,
+                threshold,
+                vaults: BTreeMap::new(),
+                proposals: BTreeMap::new(),
+                next_proposal_id: 0,
+            })
+            .with_access_rules(access_rules)
+            .with_owner_rule(owner_rule)
+            .create()
+        }
+
+        pub fn owners(&self) -> Vec<RistrettoPublicKeyBytes> {
+            self.owners.clone()
+        }
+
+        pub fn threshold(&self) -> u32 {
+            self.threshold
+        }
+
+        pub fn balance(&self, resource: ResourceAddress) -> Amount {
+            self.vaults
+                .get(&resource)
+                .map(|v| v.balance())
+                .unwrap_or_else(Amount::zero)
+        }
+
+        pub fn get_balances(&self) -> Vec<(ResourceAddress, Amount)> {
+            self.vaults.iter().map(|(k, v)| (*k, v.balance())).collect()
+        }
+
+        pub fn deposit(&mut self, bucket: Bucket) {
+            emit_event("deposit", [
+                ("amount", bucket.amount().to_string()),
+                ("resource", bucket.resource_address().to_string()),
+            ]);
+            let resource_address = bucket.resource_address();
+            self.vaults
+                .entry(resource_address)
+                .or_insert_with(|| Vault::new_empty(resource_address))
+                .deposit(bucket);
+        }
+
+        pub fn deposit_all(&mut self, buckets: Vec<Bucket>) {
+            for bucket in buckets {
+                self.deposit(bucket);
+            }
+        }
+
+        pub fn get_proposal(&self, proposal_id: u64) -> Option<Proposal> {
+            self.proposals.get(&proposal_id).cloned()
+        }
+
+        pub fn get_proposals(&self) -> Vec<Proposal> {
+            self.proposals.values().cloned().collect()
+        }
+
+        /// Proposes withdrawing `amount` of `resource` into the `destination` component. Returns the proposal id.
+        pub fn propose_withdrawal(
+            &mut self,
+            resource: ResourceAddress,
+            amount: Amount,
+            destination: ComponentAddress,
+            expires_at_epoch: u64,
+        ) -> u64 {
+            assert!(amount.is_positive(), "Withdrawal amount must be positive");
+            let (id, _) = self.propose(
+                ProposalAction::Withdraw {
+                    resource,
+                    amount,
+                    destination,
+                },
+                expires_at_epoch,
+            );
+            id
+        }
+
+        /// Proposes calling `method` on `component` with the CBOR-encoded `args`. Returns the proposal id, and the
+        /// value returned by the method if the proposal was executed immediately because the threshold is 1.
+        ///
+        /// The call is executed in the transaction of the final approver, so the signer of that transaction is able to
+        /// authorize the call. To prevent a single owner from using a proposal to call components that they control,
+        /// the component must be owned by this multisig account i.e. have the owner rule
+        /// `OwnerRule::ByAccessRule(rule!(component(<multisig account address>)))`.
+        pub fn propose_call(
+            &mut self,
+            component: ComponentAddress,
+            method: String,
+            args: Vec<Vec<u8>>,
+            expires_at_epoch: u64,
+        ) -> (u64, Option<tari_bor::Value>) {
+            let this = CallerContext::current_component_address();
+            assert_ne!(component, this, "A multisig account cannot call itself");
+            assert_eq!(
+                ComponentManager::get(component).get_owner_rule(),
+                OwnerRule::ByAccessRule(rule!(component(this))),
+                "Component {} is not owned by this multisig account",
+                component
+            );
+            self.propose(
+                ProposalAction::Call {
+                    component,
+                    method,
+                    args,
+                },
+                expires_at_epoch,
+            )
+        }
+
+        /// Approves a proposal as the transaction signer. The proposal is executed and removed once the threshold of
+        /// approvals is reached. Returns the value returned by a proposed call if the proposal was executed, and None
+        /// otherwise.
+        pub fn approve(&mut self, proposal_id: u64) -> Option<tari_bor::Value> {
+            let signer = self.signer_owner();
+            let current_epoch = Consensus::current_epoch();
+            let proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .unwrap_or_else(|| panic!("Proposal {} not found", proposal_id));
+            assert!(
+                current_epoch <= proposal.expires_at_epoch,
+                "Proposal {} expired at epoch {}",
+                proposal_id,
+                proposal.expires_at_epoch
+            );
+            assert!(
+                !proposal.approvals.contains(&signer),
+                "Proposal {} has already been approved by {}",
+                proposal_id,
+                signer
+            );
+            proposal.approvals.push(signer);
+
+            emit_event("multisig_approve", [
+                ("proposal_id", proposal_id.to_string()),
+                ("approver", signer.to_string()),
+            ]);
+
+            self.remove_expired_proposals(current_epoch);
+            self.execute_if_approved(proposal_id)
+        }
+
+        /// Removes the proposals that have expired and can no longer be approved
+        pub fn prune_expired_proposals(&mut self) {
+            self.remove_expired_proposals(Consensus::current_epoch());
+        }
+
+        fn propose(&mut self, action: ProposalAction, expires_at_epoch: u64) -> (u64, Option<tari_bor::Value>) {
+            let proposer = self.signer_owner();
+            let current_epoch = Consensus::current_epoch();
+            assert!(
+                current_epoch <= expires_at_epoch,
+                "Proposal expiry epoch {} is in the past",
+                expires_at_epoch
+            );
+            self.remove_expired_proposals(current_epoch);
+
+            let id = self.next_proposal_id;
+            self.next_proposal_id += 1;
+            self.proposals.insert(id, Proposal {
+                id,
+                proposer,
+                action,
+                approvals: vec![proposer],
+                expires_at_epoch,
+            });
+
+            emit_event("multisig_propose", [
+                ("proposal_id", id.to_string()),
+                ("proposer", proposer.to_string()),
+            ]);
+
+            let result = self.execute_if_approved(id);
+            (id, result)
+        }
+
+        fn remove_expired_proposals(&mut self, current_epoch: u64) {
+            let expired = self
+                .proposals
+                .values()
+                .filter(|proposal| proposal.expires_at_epoch < current_epoch)
+                .map(|proposal| proposal.id)
+                .collect::<Vec<_>>();
+            for proposal_id in expired {
+                self.proposals.remove(&proposal_id);
+                emit_event("multisig_expire", [("proposal_id", proposal_id.to_string())]);
+            }
+        }
+
+        /// Executes and removes the proposal if it has enough approvals. Returns the value returned by the proposed
+        /// action if it was executed.
+        fn execute_if_approved(&mut self, proposal_id: u64) -> Option<tari_bor::Value> {
+            let num_approvals = self.proposals[&proposal_id].approvals.len();
+            if num_approvals < self.threshold as usize {
+                return None;
+            }
+
+            let proposal = self.proposals.remove(&proposal_id).unwrap();
+            let result = match proposal.action {
+                ProposalAction::Withdraw {
+                    resource,
+                    amount,
+                    destination,
+                } => {
+                    let bucket = self
+                        .vaults
+                        .get_mut(&resource)
+                        .unwrap_or_else(|| panic!("No vault for resource {}", resource))
+                        .withdraw(amount);
+                    ComponentManager::get(destination).invoke("deposit", args![bucket]);
+                    tari_bor::Value::Null
+                },
+                ProposalAction::Call {
+                    component,
+                    method,
+                    args,
+                } => ComponentManager::get(component).call(method, args.into_iter().map(Arg::Literal).collect()),
+            };
+
+            emit_event("multisig_execute", [("proposal_id", proposal_id.to_string())]);
+            Some(result)
+        }
+
+        fn signer_owner(&self) -> RistrettoPublicKeyBytes {
+            let signer = CallerContext::transaction_signer_public_key();
+            assert!(self.owners.contains(&signer), "Signer {} is not an owner", signer);
+            signer
+        }
+    }
+}
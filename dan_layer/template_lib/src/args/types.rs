@@ -47,6 +47,7 @@ use crate::{
         ResourceAddress,
         VaultId,
         VaultRef,
+        VaultTimeLock,
     },
     prelude::{ComponentAccessRules, ConfidentialOutputStatement, TemplateAddress},
     resource::ResourceType,
@@ -126,7 +127,11 @@ pub enum ComponentAction {
     GetState,
     SetState,
     SetAccessRules,
+    SetOwnerRule,
     GetTemplateAddress,
+    UpgradeTemplate,
+    GetOwnerRule,
+    GetAccessRules,
 }
 
 /// Encapsulates all the ways that a component can be referenced
@@ -219,6 +224,11 @@ pub enum ResourceAction {
     Create,
     Mint,
     Recall,
+    Freeze,
+    Unfreeze,
+    Pause,
+    Unpause,
+    IsPaused,
     UpdateNonFungibleData,
     GetTotalSupply,
     GetResourceType,
@@ -303,6 +313,12 @@ pub struct RecallResourceArg {
     pub vault_id: VaultId,
     pub resource: ResourceDiscriminator,
 }
+
+/// A vault freeze or unfreeze operation argument
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResourceFreezeVaultArg {
+    pub vault_id: VaultId,
+}
 // -------------------------------- Vault -------------------------------- //
 
 /// A vault operation argument
@@ -331,6 +347,9 @@ pub enum VaultAction {
     CreateProofByNonFungibles,
     CreateProofByConfidentialResource,
     GetNonFungibles,
+    TimeLock,
+    GetTimeLocks,
+    IsFrozen,
 }
 
 impl VaultAction {
@@ -343,11 +362,19 @@ impl VaultAction {
                 GetResourceAddress |
                 GetNonFungibleIds |
                 GetCommitmentCount |
-                GetNonFungibles
+                GetNonFungibles |
+                GetTimeLocks |
+                IsFrozen
         )
     }
 }
 
+/// A vault time lock operation argument
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VaultTimeLockArg {
+    pub lock: VaultTimeLock,
+}
+
 /// A vault withdraw operation argument
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum VaultWithdrawArg {
@@ -486,6 +513,8 @@ pub struct ConsensusInvokeArg {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ConsensusAction {
     GetCurrentEpoch,
+    GetCurrentTime,
+    GetCurrentBlock,
 }
 
 // -------------------------------- GenerateRandom -------------------------------- //
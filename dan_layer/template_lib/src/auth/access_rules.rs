@@ -103,6 +103,71 @@ pub enum RequireRule {
     Require(RuleRequirement),
     AnyOf(Vec<RuleRequirement>),
     AllOf(Vec<RuleRequirement>),
+    /// At least `threshold` of the requirements must be met e.g. 3-of-5. A requirement that is listed more than once
+    /// is only counted once. The threshold must be greater than zero and a threshold greater than the number of
+    /// distinct requirements can never be met.
+    MOfN {
+        threshold: u32,
+        requirements: Vec<RuleRequirement>,
+    },
+    /// The sum of the weights of the requirements that are met must be at least `threshold`. A requirement that is
+    /// listed more than once only contributes the weight of its first entry. The threshold must be greater than
+    /// zero.
+    Weighted {
+        threshold: u32,
+        requirements: Vec<WeightedRequirement>,
+    },
+}
+
+impl RequireRule {
+    /// Builds a rule that requires at least `threshold` of the distinct `requirements` to be met
+    pub fn m_of_n<I: IntoIterator<Item = T>, T: Into<RuleRequirement>>(threshold: u32, requirements: I) -> Self {
+        let mut deduped = Vec::new();
+        for requirement in requirements.into_iter().map(Into::into) {
+            if !deduped.contains(&requirement) {
+                deduped.push(requirement);
+            }
+        }
+        Self::MOfN {
+            threshold,
+            requirements: deduped,
+        }
+    }
+
+    /// Builds a rule that requires the sum of the weights of the met requirements to be at least `threshold`
+    pub fn weighted<I: IntoIterator<Item = (T, u32)>, T: Into<RuleRequirement>>(
+        threshold: u32,
+        requirements: I,
+    ) -> Self {
+        let mut deduped = Vec::<WeightedRequirement>::new();
+        for (requirement, weight) in requirements {
+            let requirement = WeightedRequirement::new(requirement, weight);
+            if deduped.iter().all(|r| r.requirement != requirement.requirement) {
+                deduped.push(requirement);
+            }
+        }
+        Self::Weighted {
+            threshold,
+            requirements: deduped,
+        }
+    }
+}
+
+/// A [RuleRequirement] that contributes `weight` towards the threshold of a [RequireRule::Weighted] rule
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct WeightedRequirement {
+    pub requirement: RuleRequirement,
+    pub weight: u32,
+}
+
+impl WeightedRequirement {
+    pub fn new<T: Into<RuleRequirement>>(requirement: T, weight: u32) -> Self {
+        Self {
+            requirement: requirement.into(),
+            weight,
+        }
+    }
 }
 
 /// Information needed to specify access rules to methods of a component
@@ -150,6 +215,11 @@ impl ComponentAccessRules {
         self.method_access.get(name).unwrap_or(&self.default)
     }
 
+    /// Return the access rule for methods that do not have a specific rule
+    pub fn default_access_rule(&self) -> &AccessRule {
+        &self.default
+    }
+
     /// Return an iterator over the access rules of all methods
     pub fn method_access_rules_iter(&self) -> impl Iterator<Item = (&String, &AccessRule)> {
         self.method_access.iter()
@@ -168,6 +238,7 @@ pub enum ResourceAuthAction {
     Mint,
     Burn,
     Recall,
+    Freeze,
     Withdraw,
     Deposit,
     UpdateNonFungibleData,
@@ -187,16 +258,22 @@ pub struct ResourceAccessRules {
     mintable: AccessRule,
     burnable: AccessRule,
     recallable: AccessRule,
+    #[serde(default = "deny_all_rule")]
+    freezable: AccessRule,
     withdrawable: AccessRule,
     depositable: AccessRule,
     update_non_fungible_data: AccessRule,
 }
 
+fn deny_all_rule() -> AccessRule {
+    AccessRule::DenyAll
+}
+
 impl ResourceAccessRules {
     /// Builds a new set of access rules for a resource.
     ///
     /// By default:
-    /// * Minting, burning and recalling are disabled for all users
+    /// * Minting, burning, recalling and freezing are disabled for all users
     /// * Withdrawals, deposits and non-fungible data updates are allowed for all users
     pub fn new() -> Self {
         Self {
@@ -204,6 +281,7 @@ impl ResourceAccessRules {
             mintable: AccessRule::DenyAll,
             burnable: AccessRule::DenyAll,
             recallable: AccessRule::DenyAll,
+            freezable: AccessRule::DenyAll,
             // But explicitly disable withdrawing, updating and/or depositing
             withdrawable: AccessRule::AllowAll,
             depositable: AccessRule::AllowAll,
@@ -217,6 +295,7 @@ impl ResourceAccessRules {
             mintable: AccessRule::DenyAll,
             burnable: AccessRule::DenyAll,
             recallable: AccessRule::DenyAll,
+            freezable: AccessRule::DenyAll,
             withdrawable: AccessRule::DenyAll,
             depositable: AccessRule::DenyAll,
             update_non_fungible_data: AccessRule::DenyAll,
@@ -242,6 +321,13 @@ impl ResourceAccessRules {
         self
     }
 
+    /// Sets up who can freeze and unfreeze individual vaults of the resource, as well as pause and unpause the whole
+    /// resource. Frozen vaults and paused resources cannot be withdrawn from or deposited into.
+    pub fn freezable(mut self, rule: AccessRule) -> Self {
+        self.freezable = rule;
+        self
+    }
+
     /// Sets up who can withdraw tokens of the resource from any vault
     pub fn withdrawable(mut self, rule: AccessRule) -> Self {
         self.withdrawable = rule;
@@ -266,6 +352,7 @@ impl ResourceAccessRules {
             ResourceAuthAction::Mint => &self.mintable,
             ResourceAuthAction::Burn => &self.burnable,
             ResourceAuthAction::Recall => &self.recallable,
+            ResourceAuthAction::Freeze => &self.freezable,
             ResourceAuthAction::Withdraw => &self.withdrawable,
             ResourceAuthAction::Deposit => &self.depositable,
             ResourceAuthAction::UpdateNonFungibleData => &self.update_non_fungible_data,
@@ -302,6 +389,9 @@ macro_rules! __restricted_access_rule {
     (all_of($($tail:tt)*)) => {
         RestrictedAccessRule::AllOf($crate::__build_vec!(@ {__restricted_access_rule} $($tail)*))
     };
+    (m_of_n($($tail:tt)*)) => {
+        RestrictedAccessRule::Require($crate::__require_rule!(m_of_n($($tail)*)))
+    };
     ($a:ident($b:expr)) => {
         RestrictedAccessRule::Require($crate::__require_rule!($a($b)))
     };
@@ -315,6 +405,12 @@ macro_rules! __require_rule {
     (all_of($($tail:tt)*)) => {
         RequireRule::AllOf($crate::__build_vec!(@ {__rule_requirement} $($tail)*))
     };
+    (m_of_n($threshold:expr, $($tail:tt)*)) => {
+        RequireRule::MOfN {
+            threshold: $threshold,
+            requirements: $crate::__build_vec!(@ {__rule_requirement} $($tail)*),
+        }
+    };
     ($a:ident($b:expr)) => {
         RequireRule::Require($crate::__rule_requirement!($a($b)))
     };
@@ -435,6 +531,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_builds_threshold_access_rules() {
+        let resource_address = ResourceAddress::new(ObjectKey::default());
+        let component_address = ComponentAddress::new(ObjectKey::default());
+        let template_address = TemplateAddress::default();
+
+        let rule = rule!(m_of_n(
+            2,
+            component(component_address),
+            resource(resource_address),
+            template(template_address)
+        ));
+        assert_eq!(
+            rule,
+            AccessRule::Restricted(RestrictedAccessRule::Require(RequireRule::MOfN {
+                threshold: 2,
+                requirements: vec![
+                    RuleRequirement::ScopedToComponent(component_address),
+                    RuleRequirement::Resource(resource_address),
+                    RuleRequirement::ScopedToTemplate(template_address),
+                ],
+            }))
+        );
+
+        let rule = RequireRule::weighted(3, [
+            (RuleRequirement::from(resource_address), 2),
+            (RuleRequirement::from(component_address), 1),
+        ]);
+        assert_eq!(rule, RequireRule::Weighted {
+            threshold: 3,
+            requirements: vec![
+                WeightedRequirement::new(resource_address, 2),
+                WeightedRequirement::new(component_address, 1),
+            ],
+        });
+    }
+
+    #[test]
+    fn it_dedupes_threshold_requirements() {
+        let resource_address = ResourceAddress::new(ObjectKey::default());
+        let template_address = TemplateAddress::default();
+
+        let rule = RequireRule::m_of_n(2, [resource_address, resource_address]);
+        assert_eq!(rule, RequireRule::MOfN {
+            threshold: 2,
+            requirements: vec![RuleRequirement::Resource(resource_address)],
+        });
+
+        let rule = RequireRule::weighted(2, [
+            (RuleRequirement::from(resource_address), 1),
+            (RuleRequirement::from(template_address), 1),
+            (RuleRequirement::from(resource_address), 1),
+        ]);
+        assert_eq!(rule, RequireRule::Weighted {
+            threshold: 2,
+            requirements: vec![
+                WeightedRequirement::new(resource_address, 1),
+                WeightedRequirement::new(template_address, 1),
+            ],
+        });
+    }
+
     fn access_rule_from_requirement(requirement: RuleRequirement) -> AccessRule {
         AccessRule::Restricted(RestrictedAccessRule::Require(RequireRule::Require(requirement)))
     }
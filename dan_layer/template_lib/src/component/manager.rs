@@ -35,7 +35,7 @@ use crate::{
         ComponentRef,
         InvokeResult,
     },
-    auth::ComponentAccessRules,
+    auth::{ComponentAccessRules, OwnerRule},
     caller_context::CallerContext,
     models::{ComponentAddress, TemplateAddress},
 };
@@ -122,6 +122,17 @@ impl ComponentManager {
         });
     }
 
+    /// Replaces the rule that determines who owns the component. The owner can call any method and update the access
+    /// rules of the component.
+    /// It will panic if the caller is not the current owner
+    pub fn set_owner_rule(&self, owner_rule: OwnerRule) {
+        call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
+            component_ref: ComponentRef::Ref(self.address),
+            action: ComponentAction::SetOwnerRule,
+            args: invoke_args![owner_rule],
+        });
+    }
+
     /// Returns the template address of the component that is being managed
     pub fn get_template_address(&self) -> TemplateAddress {
         let result = call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
@@ -135,6 +146,46 @@ impl ComponentManager {
             .expect("failed to decode component template address from engine")
     }
 
+    /// Returns the owner rule of the component that is being managed
+    pub fn get_owner_rule(&self) -> OwnerRule {
+        let result = call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
+            component_ref: ComponentRef::Ref(self.address),
+            action: ComponentAction::GetOwnerRule,
+            args: invoke_args![],
+        });
+
+        result
+            .decode()
+            .expect("failed to decode component owner rule from engine")
+    }
+
+    /// Returns the access rules of the component that is being managed
+    pub fn get_access_rules(&self) -> ComponentAccessRules {
+        let result = call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
+            component_ref: ComponentRef::Ref(self.address),
+            action: ComponentAction::GetAccessRules,
+            args: invoke_args![],
+        });
+
+        result
+            .decode()
+            .expect("failed to decode component access rules from engine")
+    }
+
+    /// Upgrades the component to a new version of its template. This may only be called from a `&mut self` method of
+    /// the component being upgraded and takes effect once that method returns. If the new template defines a
+    /// `migrate` function, it is called with the final component state and the returned value becomes the new
+    /// component state, otherwise the new template must be able to decode the current state as is.
+    /// It will panic if the caller is not the component owner, if the component uses a builtin template or if the new
+    /// template is not compatible with the current one.
+    pub fn upgrade_template(&self, template_address: TemplateAddress) {
+        call_engine::<_, InvokeResult>(EngineOp::ComponentInvoke, &ComponentInvokeArg {
+            component_ref: ComponentRef::Ref(self.address),
+            action: ComponentAction::UpgradeTemplate,
+            args: invoke_args![template_address],
+        });
+    }
+
     pub fn component_address(&self) -> ComponentAddress {
         self.address
     }
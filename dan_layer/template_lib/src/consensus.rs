@@ -22,10 +22,13 @@
 
 use tari_template_abi::{call_engine, EngineOp};
 
-use crate::args::{ConsensusAction, ConsensusInvokeArg, InvokeResult};
+use crate::{
+    args::{ConsensusAction, ConsensusInvokeArg, InvokeResult},
+    models::BlockInfo,
+};
 
 /// The Consensus module provides access to data about the current state of the
-/// chain. Currently, it only exposes the epoch via `current_epoch`.
+/// chain, such as the current epoch and the consensus-agreed time of the current block.
 pub struct Consensus {}
 
 impl Consensus {
@@ -37,4 +40,24 @@ impl Consensus {
         resp.decode()
             .expect("Consensus GetCurrentEpoch returned invalid resource type")
     }
+
+    /// Returns the timestamp, in seconds since the unix epoch, of the block in which the current transaction is
+    /// executed. This value is agreed upon by the validator committee and never decreases from block to block, so it
+    /// is suitable for timelocks and deadlines with a precision of the block time.
+    pub fn current_time() -> u64 {
+        let resp: InvokeResult = call_engine(EngineOp::ConsensusInvoke, &ConsensusInvokeArg {
+            action: ConsensusAction::GetCurrentTime,
+        });
+        resp.decode()
+            .expect("Consensus GetCurrentTime returned invalid resource type")
+    }
+
+    /// Returns the height, timestamp and shard group of the block in which the current transaction is executed.
+    pub fn current_block() -> BlockInfo {
+        let resp: InvokeResult = call_engine(EngineOp::ConsensusInvoke, &ConsensusInvokeArg {
+            action: ConsensusAction::GetCurrentBlock,
+        });
+        resp.decode()
+            .expect("Consensus GetCurrentBlock returned invalid resource type")
+    }
 }
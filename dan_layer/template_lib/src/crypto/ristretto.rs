@@ -12,7 +12,7 @@ use crate::{crypto::InvalidByteLengthError, models::NonFungibleAddress, Hash};
 
 /// A Ristretto public key byte contents
 #[serde_as]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct RistrettoPublicKeyBytes(#[serde_as(as = "Bytes")] [u8; RistrettoPublicKeyBytes::length()]);
 
@@ -0,0 +1,20 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use serde::{Deserialize, Serialize};
+
+/// Consensus-agreed information about the block in which the current transaction is executed.
+///
+/// The timestamp is set by the block proposer and validated by the rest of the committee, so it is only as accurate as
+/// the clock drift tolerated by consensus. It never decreases from one block to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockInfo {
+    /// The height of the block in the local shard group chain
+    pub height: u64,
+    /// The block timestamp in seconds since the unix epoch
+    pub timestamp: u64,
+    /// The first shard (inclusive) of the shard group that is executing the transaction
+    pub shard_group_start: u32,
+    /// The last shard (inclusive) of the shard group that is executing the transaction
+    pub shard_group_end: u32,
+}
@@ -35,6 +35,9 @@ pub use amount::Amount;
 mod binary_tag;
 pub use binary_tag::BinaryTag;
 
+mod block_info;
+pub use block_info::BlockInfo;
+
 mod bucket;
 pub use bucket::{Bucket, BucketId};
 
@@ -68,5 +71,8 @@ pub use system::SystemAddress;
 mod template;
 pub use template::TemplateAddress;
 
+mod time_lock;
+pub use time_lock::{LockSchedule, TimeLockedAsset, VaultTimeLock};
+
 mod vault;
 pub use vault::{Vault, VaultId, VaultRef};
@@ -0,0 +1,136 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use serde::{Deserialize, Serialize};
+use tari_template_abi::rust::collections::BTreeSet;
+#[cfg(feature = "ts")]
+use ts_rs::TS;
+
+use crate::models::{Amount, NonFungibleId};
+
+/// Tokens held in a vault that may not be withdrawn until they are released by a [`LockSchedule`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub enum LockSchedule {
+    /// All tokens are locked until the given epoch is reached
+    UntilEpoch(#[cfg_attr(feature = "ts", ts(type = "number"))] u64),
+    /// Tokens are locked until `start_epoch` and are then released in equal parts each epoch, until all tokens are
+    /// released at `end_epoch`
+    LinearVesting {
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
+        start_epoch: u64,
+        #[cfg_attr(feature = "ts", ts(type = "number"))]
+        end_epoch: u64,
+    },
+}
+
+impl LockSchedule {
+    /// Returns the epoch at which all tokens are released
+    pub fn end_epoch(&self) -> u64 {
+        match self {
+            LockSchedule::UntilEpoch(epoch) => *epoch,
+            LockSchedule::LinearVesting { end_epoch, .. } => *end_epoch,
+        }
+    }
+}
+
+/// The tokens that are subject to a time lock
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub enum TimeLockedAsset {
+    Fungible { amount: Amount },
+    NonFungible { ids: BTreeSet<NonFungibleId> },
+}
+
+impl TimeLockedAsset {
+    /// Returns the fungible amount or number of non-fungible tokens that are locked
+    pub fn amount(&self) -> Amount {
+        match self {
+            TimeLockedAsset::Fungible { amount } => *amount,
+            TimeLockedAsset::NonFungible { ids } => Amount::try_from(ids.len()).unwrap_or(Amount::MAX),
+        }
+    }
+}
+
+/// A time lock on some of the tokens held in a vault. The engine will reject any withdrawal that would leave the vault
+/// holding fewer tokens than are still locked at the current epoch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
+pub struct VaultTimeLock {
+    pub asset: TimeLockedAsset,
+    pub schedule: LockSchedule,
+}
+
+impl VaultTimeLock {
+    pub fn new(asset: TimeLockedAsset, schedule: LockSchedule) -> Self {
+        Self { asset, schedule }
+    }
+
+    /// Returns the fungible amount (or number of non-fungible tokens) that is still locked at the given epoch.
+    /// Partially vested amounts are rounded in favour of the lock.
+    pub fn locked_amount_at(&self, epoch: u64) -> Amount {
+        let total = self.asset.amount();
+        match self.schedule {
+            LockSchedule::UntilEpoch(unlock_epoch) => {
+                if epoch < unlock_epoch {
+                    total
+                } else {
+                    Amount::zero()
+                }
+            },
+            LockSchedule::LinearVesting { start_epoch, end_epoch } => {
+                if epoch < start_epoch {
+                    return total;
+                }
+                if epoch >= end_epoch {
+                    return Amount::zero();
+                }
+                // start_epoch <= epoch < end_epoch, so the duration is non-zero
+                let remaining = i128::from(end_epoch - epoch);
+                let duration = i128::from(end_epoch - start_epoch);
+                let locked = (i128::from(total.value()) * remaining + duration - 1) / duration;
+                Amount(i64::try_from(locked).expect("locked amount cannot exceed the total amount"))
+            },
+        }
+    }
+
+    /// Returns true if all tokens have been released at the given epoch
+    pub fn is_released_at(&self, epoch: u64) -> bool {
+        epoch >= self.schedule.end_epoch()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn until_epoch_releases_everything_at_the_unlock_epoch() {
+        let lock = VaultTimeLock::new(
+            TimeLockedAsset::Fungible { amount: 100.into() },
+            LockSchedule::UntilEpoch(10),
+        );
+        assert_eq!(lock.locked_amount_at(0), 100);
+        assert_eq!(lock.locked_amount_at(9), 100);
+        assert_eq!(lock.locked_amount_at(10), 0);
+        assert!(lock.is_released_at(10));
+    }
+
+    #[test]
+    fn linear_vesting_releases_in_equal_parts() {
+        let lock = VaultTimeLock::new(
+            TimeLockedAsset::Fungible { amount: 100.into() },
+            LockSchedule::LinearVesting {
+                start_epoch: 10,
+                end_epoch: 13,
+            },
+        );
+        assert_eq!(lock.locked_amount_at(5), 100);
+        assert_eq!(lock.locked_amount_at(10), 100);
+        // Rounded up in favour of the lock
+        assert_eq!(lock.locked_amount_at(11), 67);
+        assert_eq!(lock.locked_amount_at(12), 34);
+        assert_eq!(lock.locked_amount_at(13), 0);
+        assert!(!lock.is_released_at(12));
+    }
+}
@@ -45,9 +45,19 @@ use crate::{
         VaultCreateProofByFungibleAmountArg,
         VaultCreateProofByNonFungiblesArg,
         VaultInvokeArg,
+        VaultTimeLockArg,
         VaultWithdrawArg,
     },
-    models::{Amount, Bucket, ConfidentialWithdrawProof, NonFungibleId, ResourceAddress},
+    models::{
+        Amount,
+        Bucket,
+        ConfidentialWithdrawProof,
+        LockSchedule,
+        NonFungibleId,
+        ResourceAddress,
+        TimeLockedAsset,
+        VaultTimeLock,
+    },
     newtype_struct_serde_impl,
     prelude::ResourceType,
     resource::ResourceManager,
@@ -254,7 +264,8 @@ impl Vault {
         resp.decode().expect("failed to decode Amount")
     }
 
-    /// Returns how many tokens this vault holds
+    /// Returns how many tokens in this vault are locked, either by a proof or by a time lock that has not yet been
+    /// released in the current epoch
     pub fn locked_balance(&self) -> Amount {
         let resp: InvokeResult = call_engine(EngineOp::VaultInvoke, &VaultInvokeArg {
             vault_ref: self.vault_ref(),
@@ -265,6 +276,70 @@ impl Vault {
         resp.decode().expect("failed to decode Amount")
     }
 
+    /// Locks an `amount` of fungible tokens in the vault until the given epoch. The vault must hold enough tokens that
+    /// are not already time locked.
+    pub fn lock_until_epoch<T: Into<Amount>>(&self, amount: T, epoch: u64) {
+        self.time_lock(VaultTimeLock::new(
+            TimeLockedAsset::Fungible { amount: amount.into() },
+            LockSchedule::UntilEpoch(epoch),
+        ));
+    }
+
+    /// Locks the given non-fungible tokens in the vault until the given epoch
+    pub fn lock_non_fungibles_until_epoch<I: IntoIterator<Item = NonFungibleId>>(&self, ids: I, epoch: u64) {
+        self.time_lock(VaultTimeLock::new(
+            TimeLockedAsset::NonFungible {
+                ids: ids.into_iter().collect(),
+            },
+            LockSchedule::UntilEpoch(epoch),
+        ));
+    }
+
+    /// Locks an `amount` of fungible tokens in the vault that are released linearly from `start_epoch` until
+    /// `end_epoch`
+    pub fn lock_with_linear_vesting<T: Into<Amount>>(&self, amount: T, start_epoch: u64, end_epoch: u64) {
+        self.time_lock(VaultTimeLock::new(
+            TimeLockedAsset::Fungible { amount: amount.into() },
+            LockSchedule::LinearVesting { start_epoch, end_epoch },
+        ));
+    }
+
+    /// Adds a time lock to tokens held in the vault. Time locks cannot be removed, locked tokens can only be withdrawn
+    /// once they have been released by the lock schedule.
+    /// It will panic if the vault does not hold the tokens, or if the vault holds confidential tokens
+    pub fn time_lock(&self, lock: VaultTimeLock) {
+        let resp: InvokeResult = call_engine(EngineOp::VaultInvoke, &VaultInvokeArg {
+            vault_ref: self.vault_ref(),
+            action: VaultAction::TimeLock,
+            args: invoke_args![VaultTimeLockArg { lock }],
+        });
+
+        resp.decode::<()>().expect("TimeLock failed");
+    }
+
+    /// Returns all time locks on this vault that have not been fully released
+    pub fn time_locks(&self) -> Vec<VaultTimeLock> {
+        let resp: InvokeResult = call_engine(EngineOp::VaultInvoke, &VaultInvokeArg {
+            vault_ref: self.vault_ref(),
+            action: VaultAction::GetTimeLocks,
+            args: invoke_args![],
+        });
+
+        resp.decode().expect("failed to decode time locks")
+    }
+
+    /// Returns true if the vault has been frozen by the resource. A frozen vault cannot be withdrawn from or deposited
+    /// into until it is unfrozen.
+    pub fn is_frozen(&self) -> bool {
+        let resp: InvokeResult = call_engine(EngineOp::VaultInvoke, &VaultInvokeArg {
+            vault_ref: self.vault_ref(),
+            action: VaultAction::IsFrozen,
+            args: invoke_args![],
+        });
+
+        resp.decode().expect("failed to decode bool")
+    }
+
     /// Returns how many Pederson commitments (related to confidential balances) this vault holds
     pub fn commitment_count(&self) -> u32 {
         let resp: InvokeResult = call_engine(EngineOp::VaultInvoke, &VaultInvokeArg {
@@ -74,6 +74,13 @@ impl ConfidentialResourceBuilder {
         self
     }
 
+    /// Sets up who can freeze individual vaults of the resource and pause the whole resource.
+    /// Tokens in a frozen vault or of a paused resource cannot be moved until they are unfrozen or unpaused
+    pub fn freezable(mut self, rule: AccessRule) -> Self {
+        self.access_rules = self.access_rules.freezable(rule);
+        self
+    }
+
     /// Sets up who can withdraw tokens of the resource from any vault
     pub fn withdrawable(mut self, rule: AccessRule) -> Self {
         self.access_rules = self.access_rules.withdrawable(rule);
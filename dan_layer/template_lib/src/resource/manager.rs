@@ -35,6 +35,7 @@ use crate::{
         RecallResourceArg,
         ResourceAction,
         ResourceDiscriminator,
+        ResourceFreezeVaultArg,
         ResourceGetNonFungibleArg,
         ResourceInvokeArg,
         ResourceRef,
@@ -320,6 +321,51 @@ impl ResourceManager {
         })
     }
 
+    /// Freezes a vault of the resource being managed. Tokens cannot be withdrawn from or deposited into a frozen
+    /// vault until it is unfrozen.
+    /// It will panic if the caller doesn't have permissions (via access rules) for freezing
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - The vault that is going to be frozen
+    pub fn freeze_vault(&self, vault_id: VaultId) {
+        self.freeze_internal(ResourceAction::Freeze, vault_id);
+    }
+
+    /// Unfreezes a previously frozen vault of the resource being managed
+    /// It will panic if the caller doesn't have permissions (via access rules) for freezing
+    ///
+    /// # Arguments
+    ///
+    /// * `vault_id` - The vault that is going to be unfrozen
+    pub fn unfreeze_vault(&self, vault_id: VaultId) {
+        self.freeze_internal(ResourceAction::Unfreeze, vault_id);
+    }
+
+    /// Pauses the resource being managed. While paused, no tokens of the resource can be withdrawn from or deposited
+    /// into any vault, nor can buckets containing the resource be split, joined or burnt.
+    /// It will panic if the caller doesn't have permissions (via access rules) for freezing
+    pub fn pause(&self) {
+        self.pause_internal(ResourceAction::Pause);
+    }
+
+    /// Unpauses the resource being managed
+    /// It will panic if the caller doesn't have permissions (via access rules) for freezing
+    pub fn unpause(&self) {
+        self.pause_internal(ResourceAction::Unpause);
+    }
+
+    /// Returns true if the resource being managed is paused
+    pub fn is_paused(&self) -> bool {
+        let resp: InvokeResult = call_engine(EngineOp::ResourceInvoke, &ResourceInvokeArg {
+            resource_ref: self.expect_resource_address(),
+            action: ResourceAction::IsPaused,
+            args: invoke_args![],
+        });
+
+        resp.decode().expect("[is_paused] Failed to decode bool")
+    }
+
     /// Returns the total supply of tokens for the resource being managed
     pub fn total_supply(&self) -> Amount {
         let resp: InvokeResult = call_engine(EngineOp::ResourceInvoke, &ResourceInvokeArg {
@@ -381,6 +427,26 @@ impl ResourceManager {
         Bucket::from_id(bucket_id)
     }
 
+    fn freeze_internal(&self, action: ResourceAction, vault_id: VaultId) {
+        let resp: InvokeResult = call_engine(EngineOp::ResourceInvoke, &ResourceInvokeArg {
+            resource_ref: self.expect_resource_address(),
+            action,
+            args: invoke_args![ResourceFreezeVaultArg { vault_id }],
+        });
+
+        resp.decode().expect("[freeze_vault] Failed")
+    }
+
+    fn pause_internal(&self, action: ResourceAction) {
+        let resp: InvokeResult = call_engine(EngineOp::ResourceInvoke, &ResourceInvokeArg {
+            resource_ref: self.expect_resource_address(),
+            action,
+            args: invoke_args![],
+        });
+
+        resp.decode().expect("[pause] Failed")
+    }
+
     fn mint_internal(&self, arg: MintResourceArg) -> Bucket {
         let resp: InvokeResult = call_engine(EngineOp::ResourceInvoke, &ResourceInvokeArg {
             resource_ref: self.expect_resource_address(),
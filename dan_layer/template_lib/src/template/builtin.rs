@@ -33,6 +33,7 @@ use crate::{
 pub enum BuiltinTemplate {
     Account,
     AccountNft,
+    MultisigAccount,
 }
 
 impl BuiltinTemplate {
@@ -46,6 +46,7 @@ pub fn generate_abi(ast: &TemplateAst) -> Result<TokenStream> {
             .get_functions()
             .map(|func| {
                 let is_mut = func.is_mut();
+                let is_migration = func.is_migration();
                 Ok::<_, syn::Error>(FunctionDef {
                     name: func.name,
                     arguments: func
@@ -53,11 +54,17 @@ pub fn generate_abi(ast: &TemplateAst) -> Result<TokenStream> {
                         .iter()
                         .map(|ty| convert_to_arg_def(&template_name_as_str, ty))
                         .collect::<Result<_>>()?,
-                    output: func
-                        .output_type
-                        .as_ref()
-                        .map(|ty| convert_to_arg_type(&template_name_as_str, ty))
-                        .unwrap_or(ArgType::Unit),
+                    output: if is_migration {
+                        // The migrate function returns the component state rather than a new component
+                        ArgType::Other {
+                            name: template_name_as_str.clone(),
+                        }
+                    } else {
+                        func.output_type
+                            .as_ref()
+                            .map(|ty| convert_to_arg_type(&template_name_as_str, ty))
+                            .unwrap_or(ArgType::Unit)
+                    },
                     is_mut,
                 })
             })
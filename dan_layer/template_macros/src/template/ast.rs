@@ -40,6 +40,7 @@ use syn::{
     TypeTuple,
     UseTree,
 };
+use tari_template_abi::{FEE_SPONSOR_FUNCTION_NAME, MIGRATE_FUNCTION_NAME};
 
 #[allow(dead_code)]
 pub struct TemplateAst {
@@ -117,6 +118,66 @@ impl Parse for TemplateAst {
 }
 
 impl TemplateAst {
+    /// Checks that the `migrate` function, if declared, takes the previous component state as its only argument and
+    /// returns `Self`
+    pub fn validate_migrate_function(&self) -> Result<()> {
+        let Some(migrate_fn) = self.find_public_function(MIGRATE_FUNCTION_NAME) else {
+            return Ok(());
+        };
+
+        let is_valid_input =
+            migrate_fn.sig.inputs.len() == 1 && matches!(migrate_fn.sig.inputs.first(), Some(FnArg::Typed(_)));
+        let is_valid_output = match &migrate_fn.sig.output {
+            ReturnType::Type(_, ty) => {
+                matches!(ty.as_ref(), syn::Type::Path(path) if path.path.is_ident("Self"))
+            },
+            ReturnType::Default => false,
+        };
+
+        if !is_valid_input || !is_valid_output {
+            return Err(Error::new_spanned(
+                &migrate_fn.sig,
+                "the migrate function must take the previous component state as its only argument and return Self",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the `sponsor_fee` method, if declared, takes `&mut self` as its only argument and returns nothing
+    pub fn validate_fee_sponsor_function(&self) -> Result<()> {
+        let Some(sponsor_fn) = self.find_public_function(FEE_SPONSOR_FUNCTION_NAME) else {
+            return Ok(());
+        };
+
+        let is_valid_input = sponsor_fn.sig.inputs.len() == 1 &&
+            matches!(sponsor_fn.sig.inputs.first(), Some(FnArg::Receiver(r)) if r.mutability.is_some());
+        let is_valid_output = matches!(sponsor_fn.sig.output, ReturnType::Default);
+
+        if !is_valid_input || !is_valid_output {
+            return Err(Error::new_spanned(
+                &sponsor_fn.sig,
+                "the sponsor_fee method must take &mut self as its only argument and return nothing",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn find_public_function(&self, name: &str) -> Option<&ImplItemMethod> {
+        self.module_content
+            .iter()
+            .filter_map(|i| match i {
+                Item::Impl(impl_item) => Some(&impl_item.items),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|item| match item {
+                ImplItem::Method(m) if Self::is_public_function(m) && m.sig.ident == name => Some(m),
+                _ => None,
+            })
+    }
+
     pub fn get_functions(&self) -> impl Iterator<Item = FunctionAst> + '_ {
         self.module_content
             .iter()
@@ -232,6 +293,11 @@ pub struct FunctionAst {
 }
 
 impl FunctionAst {
+    /// Returns true if this is the function that migrates the component state when upgrading from a previous template
+    pub fn is_migration(&self) -> bool {
+        self.name == MIGRATE_FUNCTION_NAME
+    }
+
     /// Returns true if the any argument is a &mut Self receiver
     pub fn is_mut(&self) -> bool {
         self.input_types
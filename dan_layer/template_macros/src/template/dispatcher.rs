@@ -23,6 +23,7 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{parse_quote, token::Brace, Block, Expr, ExprBlock, ExprField, Result, Stmt, TypePath, TypeTuple};
+use tari_template_abi::MIGRATE_FUNCTION_NAME;
 
 use crate::template::ast::{FunctionAst, TemplateAst, TypeAst};
 
@@ -30,6 +31,7 @@ pub fn generate_dispatcher(ast: &TemplateAst) -> Result<TokenStream> {
     let dispatcher_function_name = format_ident!("{}_main", ast.template_name);
     let function_names = get_function_names(ast);
     let function_blocks = get_function_blocks(ast);
+    let default_migrate = get_default_migrate_block(ast);
     let uses = &ast.uses;
 
     let output = quote! {
@@ -60,6 +62,7 @@ pub fn generate_dispatcher(ast: &TemplateAst) -> Result<TokenStream> {
             let result;
             match call_info.func_name.as_str() {
                 #( #function_names => #function_blocks ),*,
+                #default_migrate
                 _ => panic!("invalid function name")
             };
 
@@ -79,6 +82,26 @@ fn get_function_blocks(ast: &TemplateAst) -> impl Iterator<Item = Expr> + '_ {
         .map(|function| get_function_block(&ast.template_name, function))
 }
 
+/// Templates that do not define a `migrate` function get one that decodes the previous component state as the new
+/// component state. The engine calls it when a component is upgraded to this template, so that the upgrade fails if the
+/// state is incompatible. It is not part of the template ABI and so cannot be called by transactions.
+fn get_default_migrate_block(ast: &TemplateAst) -> Option<TokenStream> {
+    if ast.get_functions().any(|f| f.is_migration()) {
+        return None;
+    }
+
+    let template_ident = &ast.template_name;
+    let template_mod_name = format_ident!("{}_template", template_ident);
+    Some(quote! {
+        #MIGRATE_FUNCTION_NAME => {
+            assert_eq!(call_info.args.len(), 1, "migrate expects the previous component state as its only argument");
+            let state = from_value::<#template_mod_name::#template_ident>(&call_info.args[0])
+                .unwrap_or_else(|e| panic!("failed to decode the previous component state: {}", e));
+            result = encode_with_len(&state);
+        },
+    })
+}
+
 fn get_function_block(template_ident: &Ident, ast: FunctionAst) -> Expr {
     let template_mod_name = format_ident!("{}_template", template_ident);
     let mut args: Vec<Expr> = vec![];
@@ -147,8 +170,11 @@ fn get_function_block(template_ident: &Ident, ast: FunctionAst) -> Expr {
         let rtn = #template_mod_name::#template_ident::#function_ident(#(#args),*);
     });
 
-    // replace "Self" if present in the return value
-    stmts.extend(replace_self_in_output(&ast));
+    // replace "Self" if present in the return value. The migrate function returns the new component state, so the
+    // engine must receive it as is.
+    if !ast.is_migration() {
+        stmts.extend(replace_self_in_output(&ast));
+    }
 
     // encode the result value
     stmts.push(parse_quote! {
@@ -33,6 +33,8 @@ use self::{abi::generate_abi, ast::TemplateAst, definition::generate_definition,
 
 pub fn generate_template(input: TokenStream) -> Result<TokenStream> {
     let ast = parse2::<TemplateAst>(input).unwrap();
+    ast.validate_migrate_function()?;
+    ast.validate_fee_sponsor_function()?;
 
     let definition = generate_definition(&ast);
     let abi = generate_abi(&ast)?;
@@ -37,13 +37,17 @@ use tari_engine_types::{
     vault::Vault,
     virtual_substate::{VirtualSubstate, VirtualSubstateId, VirtualSubstates},
 };
-use tari_template_builtin::{ACCOUNT_NFT_TEMPLATE_ADDRESS, ACCOUNT_TEMPLATE_ADDRESS};
+use tari_template_builtin::{
+    ACCOUNT_NFT_TEMPLATE_ADDRESS,
+    ACCOUNT_TEMPLATE_ADDRESS,
+    MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
+};
 use tari_template_lib::{
     args,
     args::Arg,
     auth::OwnerRule,
     crypto::RistrettoPublicKeyBytes,
-    models::{Amount, ComponentAddress, EntityId, NonFungibleAddress, ObjectKey, TemplateAddress},
+    models::{Amount, BlockInfo, ComponentAddress, EntityId, NonFungibleAddress, ObjectKey, TemplateAddress},
     prelude::{ComponentAccessRules, CONFIDENTIAL_TARI_RESOURCE_ADDRESS},
     Hash,
 };
@@ -77,6 +81,7 @@ impl TemplateTest {
         // Add builtin templates
         builder.add_builtin_template(&ACCOUNT_TEMPLATE_ADDRESS);
         builder.add_builtin_template(&ACCOUNT_NFT_TEMPLATE_ADDRESS);
+        builder.add_builtin_template(&MULTISIG_ACCOUNT_TEMPLATE_ADDRESS);
 
         // Add the faucet template for fungible tokens
         builder.add_template(concat!(env!("CARGO_MANIFEST_DIR"), "/templates/faucet"));
@@ -112,6 +117,15 @@ impl TemplateTest {
 
         let mut virtual_substates = VirtualSubstates::new();
         virtual_substates.insert(VirtualSubstateId::CurrentEpoch, VirtualSubstate::CurrentEpoch(0));
+        virtual_substates.insert(
+            VirtualSubstateId::CurrentBlock,
+            VirtualSubstate::CurrentBlock(BlockInfo {
+                height: 0,
+                timestamp: 0,
+                shard_group_start: 0,
+                shard_group_end: 0,
+            }),
+        );
 
         Self {
             package: Arc::new(package),
@@ -128,6 +142,8 @@ impl TemplateTest {
                 per_byte_storage_cost: 1,
                 per_event_cost: 1,
                 per_log_cost: 1,
+                per_million_wasm_points_cost: 1,
+                per_wasm_memory_page_cost: 1,
             },
             key_seed: 1,
         }
@@ -505,6 +521,12 @@ impl TemplateTest {
             for (source, amount) in fee.cost_breakdown.iter() {
                 eprintln!("- {:?} {}", source, amount);
             }
+            for (index, amount) in fee.cost_breakdown.iter_by_instruction() {
+                eprintln!("- Instruction {} {}", index, amount);
+            }
+            for (component, amount) in fee.cost_breakdown.iter_by_component() {
+                eprintln!("- {} {}", component, amount);
+            }
         }
 
         let timer = Instant::now();
@@ -1,18 +1,27 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
+use serde::Serialize;
 use tari_common_types::types::{PrivateKey, PublicKey};
+use tari_crypto::tari_utilities::ByteArray;
 use tari_dan_common_types::{Epoch, SubstateRequirement};
-use tari_engine_types::{confidential::ConfidentialClaim, instruction::Instruction, TemplateAddress};
+use tari_engine_types::{
+    assertion::Assertion,
+    confidential::ConfidentialClaim,
+    instruction::Instruction,
+    TemplateAddress,
+};
+use tari_template_builtin::MULTISIG_ACCOUNT_TEMPLATE_ADDRESS;
 use tari_template_lib::{
     args,
     args::Arg,
     auth::OwnerRule,
-    models::{Amount, ComponentAddress, ConfidentialWithdrawProof, ResourceAddress},
+    crypto::RistrettoPublicKeyBytes,
+    models::{Amount, ComponentAddress, ConfidentialWithdrawProof, NonFungibleId, ResourceAddress, VaultId},
     prelude::AccessRules,
 };
 
-use crate::{unsigned_transaction::UnsignedTransaction, Transaction, TransactionSignature};
+use crate::{unsigned_transaction::UnsignedTransaction, SubIntent, Transaction, TransactionSignature};
 
 #[derive(Debug, Clone, Default)]
 pub struct TransactionBuilder {
@@ -62,6 +71,13 @@ impl TransactionBuilder {
         })
     }
 
+    /// Adds a fee instruction that asks the component to pay the fees for this transaction. The component's template
+    /// must declare the fee sponsor method, which is subject to the component's access rules and decides whether to
+    /// sponsor the signer.
+    pub fn with_fee_sponsor(self, component_address: ComponentAddress) -> Self {
+        self.add_fee_instruction(Instruction::SponsorFee { component_address })
+    }
+
     pub fn create_account(self, owner_public_key: PublicKey) -> Self {
         self.add_instruction(Instruction::CreateAccount {
             public_key_address: owner_public_key,
@@ -95,6 +111,19 @@ impl TransactionBuilder {
         })
     }
 
+    /// Creates a multisig account controlled by `owners`, that requires `threshold` of the owners to approve each
+    /// withdrawal or call proposed by one of the owners.
+    pub fn create_multisig_account<I: IntoIterator<Item = PublicKey>>(self, owners: I, threshold: u32) -> Self {
+        let owners = owners
+            .into_iter()
+            .map(|owner| {
+                RistrettoPublicKeyBytes::from_bytes(owner.as_bytes())
+                    .expect("RistrettoPublicKeyBytes::from_bytes is infallible for a valid public key")
+            })
+            .collect::<Vec<_>>();
+        self.call_function(MULTISIG_ACCOUNT_TEMPLATE_ADDRESS, "create", args![owners, threshold])
+    }
+
     pub fn call_function<T: ToString>(self, template_address: TemplateAddress, function: T, args: Vec<Arg>) -> Self {
         self.add_instruction(Instruction::CallFunction {
             template_address,
@@ -134,6 +163,72 @@ impl TransactionBuilder {
         })
     }
 
+    pub fn assert(self, assertion: Assertion) -> Self {
+        self.add_instruction(Instruction::Assert { assertion })
+    }
+
+    pub fn assert_vault_balance_at_least(self, vault_id: VaultId, min_amount: Amount) -> Self {
+        self.assert(Assertion::VaultBalanceAtLeast { vault_id, min_amount })
+    }
+
+    pub fn assert_vault_balance_at_most(self, vault_id: VaultId, max_amount: Amount) -> Self {
+        self.assert(Assertion::VaultBalanceAtMost { vault_id, max_amount })
+    }
+
+    pub fn assert_bucket_contains_non_fungible<T: AsRef<[u8]>>(self, label: T, non_fungible_id: NonFungibleId) -> Self {
+        self.assert(Assertion::BucketContainsNonFungible {
+            key: label.as_ref().to_vec(),
+            non_fungible_id,
+        })
+    }
+
+    pub fn assert_workspace_value_equals<K: AsRef<[u8]>, T: Serialize + ?Sized>(self, label: K, value: &T) -> Self {
+        self.assert(Assertion::workspace_value_equals(label, value).expect("failed to encode assertion value"))
+    }
+
+    pub fn assert_component_state_equals<T: Serialize + ?Sized>(
+        self,
+        component_address: ComponentAddress,
+        path: &str,
+        value: &T,
+    ) -> Self {
+        self.assert(
+            Assertion::component_state_equals(component_address, path, value)
+                .expect("failed to encode assertion value"),
+        )
+    }
+
+    /// Adds an `If` instruction. The instructions added to the builders passed to `then_fn` and `else_fn` are executed
+    /// if the condition holds or does not hold respectively.
+    pub fn if_else<F1, F2>(self, condition: Assertion, then_fn: F1, else_fn: F2) -> Self
+    where
+        F1: FnOnce(Self) -> Self,
+        F2: FnOnce(Self) -> Self,
+    {
+        self.add_instruction(Instruction::If {
+            condition,
+            then_instructions: then_fn(Self::new()).unsigned_transaction.instructions,
+            else_instructions: else_fn(Self::new()).unsigned_transaction.instructions,
+        })
+    }
+
+    /// Adds a `ForEach` instruction. The instructions added to the builder passed to `body_fn` are executed for every
+    /// item in the vector on the workspace at `label`, with the item available on the workspace at `item_label`.
+    pub fn for_each<K: AsRef<[u8]>, I: AsRef<[u8]>, F: FnOnce(Self) -> Self>(
+        self,
+        label: K,
+        item_label: I,
+        max_iterations: u32,
+        body_fn: F,
+    ) -> Self {
+        self.add_instruction(Instruction::ForEach {
+            key: label.as_ref().to_vec(),
+            item_key: item_label.as_ref().to_vec(),
+            max_iterations,
+            instructions: body_fn(Self::new()).unsigned_transaction.instructions,
+        })
+    }
+
     pub fn claim_burn(self, claim: ConfidentialClaim) -> Self {
         self.add_instruction(Instruction::ClaimBurn { claim: Box::new(claim) })
     }
@@ -183,6 +278,15 @@ impl TransactionBuilder {
         self
     }
 
+    /// Adds a signed sub-intent to the transaction. Sub-intents are executed in the order that they are added, before
+    /// the instructions of the transaction.
+    pub fn add_sub_intent(mut self, sub_intent: SubIntent) -> Self {
+        self.unsigned_transaction.sub_intents.push(sub_intent);
+        // Reset the signatures as they are no longer valid
+        self.signatures = vec![];
+        self
+    }
+
     /// Add an input to use in the transaction
     pub fn add_input<I: Into<SubstateRequirement>>(mut self, input_object: I) -> Self {
         self.unsigned_transaction.inputs.insert(input_object.into());
@@ -216,6 +320,17 @@ impl TransactionBuilder {
         self.unsigned_transaction
     }
 
+    /// Builds an unsigned sub-intent with a random intent id from the instructions and epoch bounds added to this
+    /// builder. Fee instructions and inputs only apply to the transaction that the sub-intent is included in and are
+    /// ignored.
+    pub fn build_sub_intent(self) -> SubIntent {
+        SubIntent::new_unsigned(
+            self.unsigned_transaction.instructions,
+            self.unsigned_transaction.min_epoch,
+            self.unsigned_transaction.max_epoch,
+        )
+    }
+
     pub fn sign(mut self, secret_key: &PrivateKey) -> Self {
         self.signatures
             .push(TransactionSignature::sign(secret_key, &self.unsigned_transaction));
@@ -22,12 +22,14 @@
 
 mod builder;
 mod signature;
+mod sub_intent;
 mod transaction;
 mod transaction_id;
 mod unsigned_transaction;
 
 pub use builder::TransactionBuilder;
 pub use signature::TransactionSignature;
+pub use sub_intent::SubIntent;
 pub use tari_engine_types::instruction::Instruction;
 pub use transaction::*;
 pub use transaction_id::*;
@@ -14,10 +14,11 @@ use tari_engine_types::{
     hashing::{hasher64, EngineHashDomainLabel},
     instruction::Instruction,
 };
+use tari_template_lib::Hash;
 #[cfg(feature = "ts")]
 use ts_rs::TS;
 
-use crate::unsigned_transaction::UnsignedTransaction;
+use crate::{unsigned_transaction::UnsignedTransaction, SubIntent};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[cfg_attr(feature = "ts", derive(TS), ts(export, export_to = "../../bindings/src/types/"))]
@@ -48,6 +49,24 @@ impl TransactionSignature {
         self.signature.verify(&self.public_key, message)
     }
 
+    /// Signs a [`SubIntent`]. The signature commits to the intent id, the instructions and the epoch bounds of the
+    /// sub-intent. Unlike a transaction signature, this signature does not commit to the transaction that the
+    /// sub-intent is included in.
+    pub fn sign_sub_intent(secret_key: &RistrettoSecretKey, sub_intent: &SubIntent) -> Self {
+        let public_key = RistrettoPublicKey::from_secret_key(secret_key);
+        let message = Self::create_sub_intent_message(sub_intent);
+
+        Self {
+            signature: Signature::sign(secret_key, message, &mut OsRng).unwrap(),
+            public_key,
+        }
+    }
+
+    pub fn verify_sub_intent(&self, sub_intent: &SubIntent) -> bool {
+        let message = Self::create_sub_intent_message(sub_intent);
+        self.signature.verify(&self.public_key, message)
+    }
+
     pub fn signature(&self) -> &Signature {
         &self.signature
     }
@@ -62,6 +81,13 @@ impl TransactionSignature {
             .chain(&signature_fields)
             .result()
     }
+
+    fn create_sub_intent_message(sub_intent: &SubIntent) -> [u8; 64] {
+        let signature_fields = SubIntentSignatureFields::from(sub_intent);
+        hasher64(EngineHashDomainLabel::SubIntentSignature)
+            .chain(&signature_fields)
+            .result()
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -71,6 +97,13 @@ struct TransactionSignatureFields<'a> {
     inputs: &'a IndexSet<SubstateRequirement>,
     min_epoch: Option<Epoch>,
     max_epoch: Option<Epoch>,
+    // Omitted when empty so that signatures of transactions without sub-intents are unchanged
+    #[serde(skip_serializing_if = "is_empty_slice")]
+    sub_intents: &'a [SubIntent],
+}
+
+fn is_empty_slice<T>(slice: &&[T]) -> bool {
+    slice.is_empty()
 }
 
 impl<'a> From<&'a UnsignedTransaction> for TransactionSignatureFields<'a> {
@@ -81,6 +114,26 @@ impl<'a> From<&'a UnsignedTransaction> for TransactionSignatureFields<'a> {
             inputs: &transaction.inputs,
             min_epoch: transaction.min_epoch,
             max_epoch: transaction.max_epoch,
+            sub_intents: &transaction.sub_intents,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SubIntentSignatureFields<'a> {
+    intent_id: &'a Hash,
+    instructions: &'a [Instruction],
+    min_epoch: Option<Epoch>,
+    max_epoch: Option<Epoch>,
+}
+
+impl<'a> From<&'a SubIntent> for SubIntentSignatureFields<'a> {
+    fn from(sub_intent: &'a SubIntent) -> Self {
+        Self {
+            intent_id: sub_intent.intent_id(),
+            instructions: sub_intent.instructions(),
+            min_epoch: sub_intent.min_epoch(),
+            max_epoch: sub_intent.max_epoch(),
         }
     }
 }
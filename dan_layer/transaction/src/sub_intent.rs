@@ -0,0 +1,136 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use tari_crypto::ristretto::RistrettoSecretKey;
+use tari_dan_common_types::Epoch;
+use tari_engine_types::instruction::Instruction;
+use tari_template_lib::Hash;
+
+use crate::TransactionSignature;
+
+/// A list of instructions that is signed independently of the transaction that includes it.
+///
+/// Each party to a multi-party transaction (e.g. a swap) signs a sub-intent that only contains their own instructions,
+/// typically including `AssertBucketContains` instructions that guarantee what they receive in return. The signed
+/// sub-intents are then composed into a single transaction. The sub-intents are executed in order, before the
+/// instructions of the transaction, and the authorization scope of each sub-intent is restricted to its own signers.
+///
+/// The signatures commit to the intent id and the epoch bounds. A transaction that is accepted creates a receipt
+/// substate for each of its sub-intents, so a sub-intent cannot be used again once it has been committed. A sub-intent
+/// that is included in a rejected transaction remains unused.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "ts",
+    derive(ts_rs::TS),
+    ts(export, export_to = "../../bindings/src/types/")
+)]
+pub struct SubIntent {
+    #[cfg_attr(feature = "ts", ts(type = "string"))]
+    intent_id: Hash,
+    instructions: Vec<Instruction>,
+    min_epoch: Option<Epoch>,
+    max_epoch: Option<Epoch>,
+    signatures: Vec<TransactionSignature>,
+}
+
+impl SubIntent {
+    pub fn new(
+        intent_id: Hash,
+        instructions: Vec<Instruction>,
+        min_epoch: Option<Epoch>,
+        max_epoch: Option<Epoch>,
+        signatures: Vec<TransactionSignature>,
+    ) -> Self {
+        Self {
+            intent_id,
+            instructions,
+            min_epoch,
+            max_epoch,
+            signatures,
+        }
+    }
+
+    /// Creates an unsigned sub-intent with a random intent id
+    pub fn new_unsigned(instructions: Vec<Instruction>, min_epoch: Option<Epoch>, max_epoch: Option<Epoch>) -> Self {
+        let mut intent_id = [0u8; Hash::LENGTH];
+        OsRng.fill_bytes(&mut intent_id);
+        Self::new(Hash::from_array(intent_id), instructions, min_epoch, max_epoch, vec![])
+    }
+
+    pub fn sign(mut self, secret: &RistrettoSecretKey) -> Self {
+        let sig = TransactionSignature::sign_sub_intent(secret, &self);
+        self.signatures.push(sig);
+        self
+    }
+
+    pub fn intent_id(&self) -> &Hash {
+        &self.intent_id
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn min_epoch(&self) -> Option<Epoch> {
+        self.min_epoch
+    }
+
+    pub fn max_epoch(&self) -> Option<Epoch> {
+        self.max_epoch
+    }
+
+    pub fn signatures(&self) -> &[TransactionSignature] {
+        &self.signatures
+    }
+
+    /// Returns true if the sub-intent is signed by at least one signer and all signatures are valid
+    pub fn verify_all_signatures(&self) -> bool {
+        if self.signatures.is_empty() {
+            return false;
+        }
+
+        self.signatures.iter().all(|sig| sig.verify_sub_intent(self))
+    }
+
+    pub fn into_instructions(self) -> Vec<Instruction> {
+        self.instructions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tari_crypto::keys::SecretKey;
+
+    use super::*;
+
+    fn signed_sub_intent() -> SubIntent {
+        let secret = RistrettoSecretKey::random(&mut OsRng);
+        SubIntent::new_unsigned(vec![], Some(Epoch(1)), Some(Epoch(10))).sign(&secret)
+    }
+
+    #[test]
+    fn it_verifies_a_signed_sub_intent() {
+        assert!(signed_sub_intent().verify_all_signatures());
+        assert!(!SubIntent::new_unsigned(vec![], None, None).verify_all_signatures());
+    }
+
+    #[test]
+    fn it_fails_to_verify_if_the_epoch_bounds_are_changed() {
+        let mut sub_intent = signed_sub_intent();
+        sub_intent.max_epoch = Some(Epoch(100));
+        assert!(!sub_intent.verify_all_signatures());
+
+        let mut sub_intent = signed_sub_intent();
+        sub_intent.min_epoch = None;
+        assert!(!sub_intent.verify_all_signatures());
+    }
+
+    #[test]
+    fn it_fails_to_verify_if_the_intent_id_is_changed() {
+        let mut sub_intent = signed_sub_intent();
+        sub_intent.intent_id = Hash::default();
+        assert!(!sub_intent.verify_all_signatures());
+    }
+}
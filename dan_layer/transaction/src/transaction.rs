@@ -10,13 +10,19 @@ use tari_crypto::ristretto::RistrettoSecretKey;
 use tari_dan_common_types::{committee::CommitteeInfo, Epoch, SubstateRequirement, VersionedSubstateId};
 use tari_engine_types::{
     hashing::{hasher32, EngineHashDomainLabel},
-    indexed_value::{IndexedValue, IndexedValueError},
+    indexed_value::IndexedValueError,
     instruction::Instruction,
     substate::SubstateId,
 };
 use tari_template_lib::{models::ComponentAddress, Hash};
 
-use crate::{builder::TransactionBuilder, transaction_id::TransactionId, TransactionSignature, UnsignedTransaction};
+use crate::{
+    builder::TransactionBuilder,
+    transaction_id::TransactionId,
+    SubIntent,
+    TransactionSignature,
+    UnsignedTransaction,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
@@ -99,18 +105,32 @@ impl Transaction {
         &self.signatures
     }
 
+    pub fn sub_intents(&self) -> &[SubIntent] {
+        self.transaction.sub_intents()
+    }
+
+    /// Verifies the transaction signatures and the signatures of all sub-intents
     pub fn verify_all_signatures(&self) -> bool {
         if self.signatures.is_empty() {
             return false;
         }
 
-        self.signatures().iter().all(|sig| sig.verify(&self.transaction))
+        self.signatures().iter().all(|sig| sig.verify(&self.transaction)) &&
+            self.sub_intents()
+                .iter()
+                .all(|sub_intent| sub_intent.verify_all_signatures())
     }
 
     pub fn inputs(&self) -> &IndexSet<SubstateRequirement> {
         &self.transaction.inputs
     }
 
+    /// Returns an iterator over all instructions of this transaction, see
+    /// [`UnsignedTransaction::all_instructions_iter`]
+    pub fn all_instructions_iter(&self) -> impl Iterator<Item = &Instruction> + '_ {
+        self.transaction.all_instructions_iter()
+    }
+
     /// Returns (fee instructions, instructions)
     pub fn into_instructions(self) -> (Vec<Instruction>, Vec<Instruction>) {
         (self.transaction.fee_instructions, self.transaction.instructions)
@@ -163,20 +183,17 @@ impl Transaction {
     }
 
     pub fn fee_claims(&self) -> impl Iterator<Item = (Epoch, PublicKey)> + '_ {
-        self.instructions()
-            .iter()
-            .chain(self.fee_instructions())
-            .filter_map(|instruction| {
-                if let Instruction::ClaimValidatorFees {
-                    epoch,
-                    validator_public_key,
-                } = instruction
-                {
-                    Some((Epoch(*epoch), validator_public_key.clone()))
-                } else {
-                    None
-                }
-            })
+        self.transaction.all_instructions_iter().filter_map(|instruction| {
+            if let Instruction::ClaimValidatorFees {
+                epoch,
+                validator_public_key,
+            } = instruction
+            {
+                Some((Epoch(*epoch), validator_public_key.clone()))
+            } else {
+                None
+            }
+        })
     }
 
     pub fn min_epoch(&self) -> Option<Epoch> {
@@ -188,49 +205,12 @@ impl Transaction {
     }
 
     pub fn as_referenced_components(&self) -> impl Iterator<Item = &ComponentAddress> + '_ {
-        self.instructions()
-            .iter()
-            .chain(self.fee_instructions())
-            .filter_map(|instruction| {
-                if let Instruction::CallMethod { component_address, .. } = instruction {
-                    Some(component_address)
-                } else {
-                    None
-                }
-            })
+        self.transaction.as_referenced_components()
     }
 
     /// Returns all substates addresses referenced by this transaction
     pub fn to_referenced_substates(&self) -> Result<HashSet<SubstateId>, IndexedValueError> {
-        let all_instructions = self.instructions().iter().chain(self.fee_instructions());
-
-        let mut substates = HashSet::new();
-        for instruction in all_instructions {
-            match instruction {
-                Instruction::CallFunction { args, .. } => {
-                    for arg in args.iter().filter_map(|a| a.as_literal_bytes()) {
-                        let value = IndexedValue::from_raw(arg)?;
-                        substates.extend(value.referenced_substates().filter(|id| !id.is_virtual()));
-                    }
-                },
-                Instruction::CallMethod {
-                    component_address,
-                    args,
-                    ..
-                } => {
-                    substates.insert(SubstateId::Component(*component_address));
-                    for arg in args.iter().filter_map(|a| a.as_literal_bytes()) {
-                        let value = IndexedValue::from_raw(arg)?;
-                        substates.extend(value.referenced_substates().filter(|id| !id.is_virtual()));
-                    }
-                },
-                Instruction::ClaimBurn { claim } => {
-                    substates.insert(SubstateId::UnclaimedConfidentialOutput(claim.output_address));
-                },
-                _ => {},
-            }
-        }
-        Ok(substates)
+        self.transaction.to_referenced_substates()
     }
 
     pub fn has_inputs_without_version(&self) -> bool {
@@ -242,10 +222,12 @@ impl Display for Transaction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Transaction[{}, Inputs: {}, Fee Instructions: {}, Instructions: {}, Signatures: {}, Filled Inputs: {}]",
+            "Transaction[{}, Inputs: {}, Fee Instructions: {}, Sub-intents: {}, Instructions: {}, Signatures: {}, \
+             Filled Inputs: {}]",
             self.id,
             self.transaction.inputs.len(),
             self.transaction.fee_instructions.len(),
+            self.transaction.sub_intents.len(),
             self.transaction.instructions.len(),
             self.signatures.len(),
             self.filled_inputs.len(),
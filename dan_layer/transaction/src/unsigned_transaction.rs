@@ -8,13 +8,14 @@ use serde::{Deserialize, Serialize};
 use tari_crypto::ristretto::RistrettoSecretKey;
 use tari_dan_common_types::{Epoch, SubstateRequirement};
 use tari_engine_types::{
+    assertion::Assertion,
     indexed_value::{IndexedValue, IndexedValueError},
     instruction::Instruction,
     substate::SubstateId,
 };
 use tari_template_lib::models::ComponentAddress;
 
-use crate::{builder::TransactionBuilder, Transaction, TransactionSignature};
+use crate::{builder::TransactionBuilder, SubIntent, Transaction, TransactionSignature};
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[cfg_attr(
@@ -30,6 +31,9 @@ pub struct UnsignedTransaction {
     pub inputs: IndexSet<SubstateRequirement>,
     pub min_epoch: Option<Epoch>,
     pub max_epoch: Option<Epoch>,
+    /// Independently signed instruction lists that are executed, in order, before `instructions`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_intents: Vec<SubIntent>,
 }
 
 impl UnsignedTransaction {
@@ -50,6 +54,7 @@ impl UnsignedTransaction {
             inputs,
             min_epoch,
             max_epoch,
+            sub_intents: vec![],
         }
     }
 
@@ -65,6 +70,20 @@ impl UnsignedTransaction {
         &self.inputs
     }
 
+    pub fn sub_intents(&self) -> &[SubIntent] {
+        &self.sub_intents
+    }
+
+    /// Returns an iterator over the fee instructions, the instructions of all sub-intents and the instructions of this
+    /// transaction, including instructions nested in control flow instructions
+    pub fn all_instructions_iter(&self) -> impl Iterator<Item = &Instruction> + '_ {
+        self.fee_instructions
+            .iter()
+            .chain(self.sub_intents.iter().flat_map(|s| s.instructions()))
+            .chain(&self.instructions)
+            .flat_map(Instruction::iter_nested)
+    }
+
     /// Returns (fee instructions, instructions)
     pub fn into_instructions(self) -> (Vec<Instruction>, Vec<Instruction>) {
         (self.fee_instructions, self.instructions)
@@ -79,24 +98,19 @@ impl UnsignedTransaction {
     }
 
     pub fn as_referenced_components(&self) -> impl Iterator<Item = &ComponentAddress> + '_ {
-        self.instructions()
-            .iter()
-            .chain(self.fee_instructions())
-            .filter_map(|instruction| {
-                if let Instruction::CallMethod { component_address, .. } = instruction {
-                    Some(component_address)
-                } else {
-                    None
-                }
-            })
+        self.all_instructions_iter().filter_map(|instruction| {
+            if let Instruction::CallMethod { component_address, .. } = instruction {
+                Some(component_address)
+            } else {
+                None
+            }
+        })
     }
 
     /// Returns all substates addresses referenced by this transaction
     pub fn to_referenced_substates(&self) -> Result<HashSet<SubstateId>, IndexedValueError> {
-        let all_instructions = self.instructions().iter().chain(self.fee_instructions());
-
         let mut substates = HashSet::new();
-        for instruction in all_instructions {
+        for instruction in self.all_instructions_iter() {
             match instruction {
                 Instruction::CallFunction { args, .. } => {
                     for arg in args.iter().filter_map(|a| a.as_literal_bytes()) {
@@ -118,6 +132,22 @@ impl UnsignedTransaction {
                 Instruction::ClaimBurn { claim } => {
                     substates.insert(SubstateId::UnclaimedConfidentialOutput(claim.output_address));
                 },
+                Instruction::SponsorFee { component_address } => {
+                    substates.insert(SubstateId::Component(*component_address));
+                },
+                Instruction::Assert { assertion } |
+                Instruction::If {
+                    condition: assertion, ..
+                } => match assertion {
+                    Assertion::VaultBalanceAtLeast { vault_id, .. } |
+                    Assertion::VaultBalanceAtMost { vault_id, .. } => {
+                        substates.insert(SubstateId::Vault(*vault_id));
+                    },
+                    Assertion::ComponentStateEquals { component_address, .. } => {
+                        substates.insert(SubstateId::Component(*component_address));
+                    },
+                    _ => {},
+                },
                 _ => {},
             }
         }
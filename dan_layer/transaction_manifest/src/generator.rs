@@ -5,21 +5,24 @@ use std::collections::{HashMap, HashSet};
 
 use proc_macro2::Ident;
 use syn::Lit;
-use tari_engine_types::{instruction::Instruction, substate::SubstateId, TemplateAddress};
+use tari_engine_types::{assertion::Assertion, instruction::Instruction, substate::SubstateId, TemplateAddress};
 use tari_template_lib::{
     arg,
     args::Arg,
-    models::{Amount, NonFungibleId},
+    models::{Amount, NonFungibleId, VaultId},
 };
 
 use crate::{
     ast::ManifestAst,
     error::ManifestError,
-    parser::{InvokeIntent, ManifestIntent, ManifestLiteral, SpecialLiteral},
+    parser::{AssertIntent, ForEachIntent, IfIntent, InvokeIntent, ManifestIntent, ManifestLiteral, SpecialLiteral},
     ManifestInstructions,
     ManifestValue,
 };
 
+/// The maximum number of iterations of a `for` loop that does not specify a bound with `.take(n)`
+const DEFAULT_MAX_LOOP_ITERATIONS: u32 = 100;
+
 pub struct ManifestInstructionGenerator {
     imported_templates: HashMap<Ident, TemplateAddress>,
     global_aliases: HashMap<String, ManifestValue>,
@@ -57,15 +60,8 @@ impl ManifestInstructionGenerator {
             })
             .collect::<Result<_, _>>()?;
 
-        let mut instructions = Vec::with_capacity(ast.parsed.instruction_intents.len());
-        for intent in ast.parsed.instruction_intents {
-            instructions.extend(self.translate_intent(intent)?);
-        }
-
-        let mut fee_instructions = Vec::with_capacity(ast.parsed.fee_instruction_intents.len());
-        for intent in ast.parsed.fee_instruction_intents {
-            fee_instructions.extend(self.translate_intent(intent)?);
-        }
+        let instructions = self.translate_intents(ast.parsed.instruction_intents)?;
+        let fee_instructions = self.translate_intents(ast.parsed.fee_instruction_intents)?;
 
         Ok(ManifestInstructions {
             instructions,
@@ -143,6 +139,197 @@ impl ManifestInstructionGenerator {
                 level: log.level,
                 message: log.message,
             }]),
+            ManifestIntent::Assert(assert) => Ok(vec![self.translate_assert(assert)?]),
+            ManifestIntent::If(IfIntent {
+                variable,
+                value,
+                then_intents,
+                else_intents,
+            }) => {
+                let condition = Assertion::WorkspaceValueEquals {
+                    key: self.get_workspace_key(ManifestLiteral::Variable(variable))?,
+                    value: self.get_literal_value(value)?,
+                };
+                Ok(vec![Instruction::If {
+                    condition,
+                    then_instructions: self.translate_intents(then_intents)?,
+                    else_instructions: self.translate_intents(else_intents)?,
+                }])
+            },
+            ManifestIntent::ForEach(ForEachIntent {
+                item_variable,
+                collection_variable,
+                max_iterations,
+                intents,
+            }) => {
+                let key = self.get_workspace_key(ManifestLiteral::Variable(collection_variable))?;
+                let item_key = item_variable.to_string();
+                self.variables.insert(item_key.clone());
+                Ok(vec![Instruction::ForEach {
+                    key,
+                    item_key: item_key.into_bytes(),
+                    max_iterations: max_iterations.unwrap_or(DEFAULT_MAX_LOOP_ITERATIONS),
+                    instructions: self.translate_intents(intents)?,
+                }])
+            },
+        }
+    }
+
+    fn translate_intents(&mut self, intents: Vec<ManifestIntent>) -> Result<Vec<Instruction>, ManifestError> {
+        let mut instructions = Vec::with_capacity(intents.len());
+        for intent in intents {
+            instructions.extend(self.translate_intent(intent)?);
+        }
+        Ok(instructions)
+    }
+
+    fn translate_assert(&self, intent: AssertIntent) -> Result<Instruction, ManifestError> {
+        let AssertIntent { assertion, arguments } = intent;
+        let name = assertion.to_string();
+        let mut args = arguments.into_iter();
+        let mut next_arg = || {
+            args.next()
+                .ok_or_else(|| ManifestError::UnsupportedExpr(format!("Missing argument for {}!", name)))
+        };
+
+        let instruction =
+            match name.as_str() {
+                // assert_bucket_contains!(bucket, resource, Amount(100));
+                "assert_bucket_contains" => Instruction::AssertBucketContains {
+                    key: self.get_workspace_key(next_arg()?)?,
+                    resource_address: self
+                        .get_substate_id(next_arg()?)?
+                        .as_resource_address()
+                        .ok_or_else(|| ManifestError::InvalidVariableType("Expected a resource address".to_string()))?,
+                    min_amount: get_amount(next_arg()?)?,
+                },
+                // assert_bucket_contains_non_fungible!(bucket, NonFungibleId("id"));
+                "assert_bucket_contains_non_fungible" => Instruction::Assert {
+                    assertion: Assertion::BucketContainsNonFungible {
+                        key: self.get_workspace_key(next_arg()?)?,
+                        non_fungible_id: self.get_non_fungible_id(next_arg()?)?,
+                    },
+                },
+                // assert_vault_balance_at_least!(vault, Amount(100));
+                "assert_vault_balance_at_least" => Instruction::Assert {
+                    assertion: Assertion::VaultBalanceAtLeast {
+                        vault_id: self.get_vault_id(next_arg()?)?,
+                        min_amount: get_amount(next_arg()?)?,
+                    },
+                },
+                // assert_vault_balance_at_most!(vault, Amount(100));
+                "assert_vault_balance_at_most" => Instruction::Assert {
+                    assertion: Assertion::VaultBalanceAtMost {
+                        vault_id: self.get_vault_id(next_arg()?)?,
+                        max_amount: get_amount(next_arg()?)?,
+                    },
+                },
+                // assert_workspace_value_eq!(value, 123u64);
+                "assert_workspace_value_eq" => Instruction::Assert {
+                    assertion: Assertion::WorkspaceValueEquals {
+                        key: self.get_workspace_key(next_arg()?)?,
+                        value: self.get_literal_value(next_arg()?)?,
+                    },
+                },
+                // assert_component_state_eq!(component, "$.counter", 123u64);
+                "assert_component_state_eq" => Instruction::Assert {
+                    assertion: Assertion::ComponentStateEquals {
+                        component_address: self.get_substate_id(next_arg()?)?.as_component_address().ok_or_else(
+                            || ManifestError::InvalidVariableType("Expected a component address".to_string()),
+                        )?,
+                        path: get_string(next_arg()?)?,
+                        value: self.get_literal_value(next_arg()?)?,
+                    },
+                },
+                _ => {
+                    return Err(ManifestError::UnsupportedExpr(format!(
+                        "Unsupported assertion {}!",
+                        assertion
+                    )))
+                },
+            };
+
+        if args.next().is_some() {
+            return Err(ManifestError::UnsupportedExpr(format!(
+                "Too many arguments for {}!",
+                assertion
+            )));
+        }
+
+        Ok(instruction)
+    }
+
+    fn get_workspace_key(&self, arg: ManifestLiteral) -> Result<Vec<u8>, ManifestError> {
+        match arg {
+            ManifestLiteral::Variable(ident) if self.variables.contains(&ident.to_string()) => {
+                Ok(ident.to_string().into_bytes())
+            },
+            ManifestLiteral::Variable(ident) => Err(ManifestError::UndefinedVariable {
+                name: ident.to_string(),
+            }),
+            arg => Err(ManifestError::InvalidVariableType(format!(
+                "Expected a workspace variable but got {:?}",
+                arg
+            ))),
+        }
+    }
+
+    fn get_substate_id(&self, arg: ManifestLiteral) -> Result<SubstateId, ManifestError> {
+        let ManifestLiteral::Variable(ident) = arg else {
+            return Err(ManifestError::InvalidVariableType(format!(
+                "Expected a substate address variable but got {:?}",
+                arg
+            )));
+        };
+        let name = ident.to_string();
+        self.globals
+            .get(&name)
+            .or_else(|| self.global_aliases.get(&name))
+            .ok_or_else(|| ManifestError::UndefinedVariable { name: name.clone() })?
+            .as_address()
+            .cloned()
+            .ok_or_else(|| ManifestError::InvalidVariableType(format!("Expected '{}' to be a substate address", name)))
+    }
+
+    fn get_vault_id(&self, arg: ManifestLiteral) -> Result<VaultId, ManifestError> {
+        self.get_substate_id(arg)?
+            .as_vault_id()
+            .ok_or_else(|| ManifestError::InvalidVariableType("Expected a vault id".to_string()))
+    }
+
+    fn get_non_fungible_id(&self, arg: ManifestLiteral) -> Result<NonFungibleId, ManifestError> {
+        match arg {
+            ManifestLiteral::Special(SpecialLiteral::NonFungibleId(lit)) => lit_to_nonfungible_id(&lit),
+            ManifestLiteral::Variable(ident) => {
+                let name = ident.to_string();
+                match self.globals.get(&name).or_else(|| self.global_aliases.get(&name)) {
+                    Some(ManifestValue::NonFungibleId(id)) => Ok(id.clone()),
+                    Some(_) => Err(ManifestError::InvalidVariableType(format!(
+                        "Expected '{}' to be a non-fungible id",
+                        name
+                    ))),
+                    None => Err(ManifestError::UndefinedVariable { name }),
+                }
+            },
+            arg => Err(ManifestError::InvalidVariableType(format!(
+                "Expected a non-fungible id but got {:?}",
+                arg
+            ))),
+        }
+    }
+
+    /// Returns the CBOR-encoded value of a literal or global argument
+    fn get_literal_value(&self, arg: ManifestLiteral) -> Result<Vec<u8>, ManifestError> {
+        let arg = self
+            .process_args(vec![arg])?
+            .pop()
+            .expect("process_args returns one arg for each input");
+        match arg {
+            Arg::Literal(value) => Ok(value),
+            Arg::Workspace(key) => Err(ManifestError::InvalidVariableType(format!(
+                "Expected a literal value but got workspace variable '{}'",
+                String::from_utf8_lossy(&key)
+            ))),
         }
     }
 
@@ -249,6 +436,26 @@ fn lit_to_arg(lit: &Lit) -> Result<Arg, ManifestError> {
     }
 }
 
+fn get_amount(arg: ManifestLiteral) -> Result<Amount, ManifestError> {
+    match arg {
+        ManifestLiteral::Special(SpecialLiteral::Amount(amount)) => Ok(Amount(amount)),
+        arg => Err(ManifestError::InvalidVariableType(format!(
+            "Expected Amount(..) but got {:?}",
+            arg
+        ))),
+    }
+}
+
+fn get_string(arg: ManifestLiteral) -> Result<String, ManifestError> {
+    match arg {
+        ManifestLiteral::Lit(Lit::Str(s)) => Ok(s.value()),
+        arg => Err(ManifestError::InvalidVariableType(format!(
+            "Expected a string literal but got {:?}",
+            arg
+        ))),
+    }
+}
+
 fn lit_to_nonfungible_id(lit: &Lit) -> Result<NonFungibleId, ManifestError> {
     match lit {
         Lit::Str(s) => Ok(NonFungibleId::try_from_string(s.value()).map_err(|e| {
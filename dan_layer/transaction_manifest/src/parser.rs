@@ -1,15 +1,22 @@
 //   Copyright 2022 The Tari Project
 //   SPDX-License-Identifier: BSD-3-clause
 
+use std::mem;
+
 use proc_macro2::{Ident, TokenStream};
 use syn::{
-    parse::ParseStream,
+    parse::{ParseStream, Parser},
     parse2,
     punctuated::Punctuated,
     token::Comma,
+    BinOp,
     Block,
     Expr,
+    ExprBinary,
+    ExprBlock,
     ExprCall,
+    ExprForLoop,
+    ExprIf,
     ExprLit,
     ExprMacro,
     ExprMethodCall,
@@ -38,6 +45,9 @@ pub enum ManifestIntent {
     InvokeComponent(InvokeIntent),
     AssignInput(AssignInputStmt),
     Log(LogIntent),
+    Assert(AssertIntent),
+    If(IfIntent),
+    ForEach(ForEachIntent),
 }
 
 #[derive(Debug, Clone)]
@@ -67,6 +77,30 @@ pub struct LogIntent {
     pub message: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct AssertIntent {
+    pub assertion: Ident,
+    pub arguments: Vec<ManifestLiteral>,
+}
+
+/// `if variable == value { .. } else { .. }`
+#[derive(Debug, Clone)]
+pub struct IfIntent {
+    pub variable: Ident,
+    pub value: ManifestLiteral,
+    pub then_intents: Vec<ManifestIntent>,
+    pub else_intents: Vec<ManifestIntent>,
+}
+
+/// `for item in collection { .. }` or `for item in collection.take(max_iterations) { .. }`
+#[derive(Debug, Clone)]
+pub struct ForEachIntent {
+    pub item_variable: Ident,
+    pub collection_variable: Ident,
+    pub max_iterations: Option<u32>,
+    pub intents: Vec<ManifestIntent>,
+}
+
 #[derive(Debug, Clone)]
 pub enum ManifestLiteral {
     Lit(Lit),
@@ -175,6 +209,8 @@ impl ManifestParser {
             Stmt::Local(local) => self.handle_local(local),
             // component.function_name(arg1, arg2);
             Stmt::Semi(expr, _) => self.handle_semi_expr(expr),
+            // if x == y { .. } else { .. } / for x in y { .. }
+            Stmt::Expr(expr @ (Expr::If(_) | Expr::ForLoop(_))) => self.handle_semi_expr(expr),
             _ => Err(syn::Error::new_spanned(
                 stmt.clone(),
                 format!("Invalid statement {:?}", stmt),
@@ -311,12 +347,107 @@ impl ManifestParser {
                 let mac = &path.segments[0].ident;
                 macro_call(mac, tokens)
             },
+            Expr::If(expr_if) => self.handle_if(expr_if),
+            Expr::ForLoop(expr_for) => self.handle_for_loop(expr_for),
             _ => Err(syn::Error::new_spanned(
                 expr.clone(),
                 format!("Only function calls are supported in let statements. {:?}", expr),
             )),
         }
     }
+
+    fn handle_if(&self, expr_if: ExprIf) -> Result<ManifestIntent, syn::Error> {
+        let ExprIf {
+            cond,
+            then_branch,
+            else_branch,
+            ..
+        } = expr_if;
+
+        let (left, is_equal, right) = match *cond {
+            Expr::Binary(ExprBinary {
+                left,
+                op: BinOp::Eq(_),
+                right,
+                ..
+            }) => (left, true, right),
+            Expr::Binary(ExprBinary {
+                left,
+                op: BinOp::Ne(_),
+                right,
+                ..
+            }) => (left, false, right),
+            cond => {
+                return Err(syn::Error::new_spanned(
+                    cond,
+                    "Invalid condition, only `variable == value` and `variable != value` are supported",
+                ))
+            },
+        };
+
+        let variable = extract_single_var_name(&left)?;
+        let value = build_arguments(Punctuated::from_iter([*right]))?
+            .pop()
+            .expect("build_arguments returns one argument for each input");
+
+        let mut then_intents = self.parse_block(then_branch)?;
+        let mut else_intents = match else_branch {
+            Some((_, expr)) => match *expr {
+                Expr::Block(ExprBlock { block, .. }) => self.parse_block(block)?,
+                // else if ..
+                Expr::If(expr_if) => vec![self.handle_if(expr_if)?],
+                expr => return Err(syn::Error::new_spanned(expr, "Invalid else branch")),
+            },
+            None => vec![],
+        };
+
+        if !is_equal {
+            mem::swap(&mut then_intents, &mut else_intents);
+        }
+
+        Ok(ManifestIntent::If(IfIntent {
+            variable,
+            value,
+            then_intents,
+            else_intents,
+        }))
+    }
+
+    fn handle_for_loop(&self, expr_for: ExprForLoop) -> Result<ManifestIntent, syn::Error> {
+        let ExprForLoop { pat, expr, body, .. } = expr_for;
+
+        let item_variable = match pat {
+            Pat::Ident(PatIdent { ident, .. }) => ident,
+            pat => return Err(syn::Error::new_spanned(pat, "Invalid loop variable")),
+        };
+
+        let (collection_variable, max_iterations) = match *expr {
+            // for x in collection.take(10)
+            Expr::MethodCall(ExprMethodCall {
+                receiver, method, args, ..
+            }) if method == "take" => {
+                let max_iterations = match args.first() {
+                    Some(Expr::Lit(ExprLit { lit: Lit::Int(lit), .. })) if args.len() == 1 => lit.base10_parse()?,
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &args,
+                            "Invalid argument, take requires a single integer literal",
+                        ))
+                    },
+                };
+                (extract_single_var_name(&receiver)?, Some(max_iterations))
+            },
+            // for x in collection
+            expr => (extract_single_var_name(&expr)?, None),
+        };
+
+        Ok(ManifestIntent::ForEach(ForEachIntent {
+            item_variable,
+            collection_variable,
+            max_iterations,
+            intents: self.parse_block(body)?,
+        }))
+    }
 }
 
 fn assignment_from_macro(var_name: Ident, mac: &Ident, tokens: TokenStream) -> Result<ManifestIntent, syn::Error> {
@@ -348,6 +479,15 @@ fn macro_call(mac: &Ident, tokens: TokenStream) -> Result<ManifestIntent, syn::E
             level: LogLevel::Error,
             message: parse2::<LitStr>(tokens)?.value(),
         })),
+        "assert_bucket_contains" |
+        "assert_bucket_contains_non_fungible" |
+        "assert_vault_balance_at_least" |
+        "assert_vault_balance_at_most" |
+        "assert_workspace_value_eq" |
+        "assert_component_state_eq" => Ok(ManifestIntent::Assert(AssertIntent {
+            assertion: mac.clone(),
+            arguments: build_arguments(Punctuated::<Expr, Comma>::parse_terminated.parse2(tokens)?)?,
+        })),
         _ => Err(syn::Error::new_spanned(mac, "Invalid macro name")),
     }
 }
@@ -0,0 +1,18 @@
+fn main() {
+    let faucet = global!["test_faucet"];
+    let faucet_vault = global!["faucet_vault"];
+    let counter = global!["counter"];
+    let XTR = global!["xtr_resource"];
+
+    let funds = faucet.take_free_coins(Amount(1_000));
+    assert_bucket_contains!(funds, XTR, Amount(1_000));
+    assert_vault_balance_at_least!(faucet_vault, Amount(500));
+    assert_vault_balance_at_most!(faucet_vault, Amount(10_000));
+
+    let nft = faucet.take_nft();
+    assert_bucket_contains_non_fungible!(nft, NonFungibleId(1u32));
+
+    let value = counter.value();
+    assert_workspace_value_eq!(value, 1u32);
+    assert_component_state_eq!(counter, "$.value", 1u32);
+}
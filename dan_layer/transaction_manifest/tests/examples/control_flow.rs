@@ -0,0 +1,16 @@
+fn main() {
+    let faucet = global!["test_faucet"];
+    let account = global!["account"];
+
+    let buckets = faucet.take_many(3u32);
+    for bucket in buckets.take(5) {
+        account.deposit(bucket);
+    }
+
+    let balance = account.balance();
+    if balance == Amount(300) {
+        info!("All buckets deposited");
+    } else {
+        warn!("Unexpected balance");
+    }
+}
@@ -22,10 +22,11 @@
 
 use std::{collections::HashMap, fs};
 
-use tari_engine_types::{instruction::Instruction, substate::SubstateId};
+use tari_engine_types::{assertion::Assertion, instruction::Instruction, substate::SubstateId};
 use tari_template_lib::{
     args,
-    models::{Amount, ComponentAddress, ObjectKey, ResourceAddress, TemplateAddress},
+    args::LogLevel,
+    models::{Amount, ComponentAddress, NonFungibleId, ObjectKey, ResourceAddress, TemplateAddress, VaultId},
 };
 use tari_transaction_manifest::{parse_manifest, ManifestInstructions};
 
@@ -103,3 +104,133 @@ fn manifest_smoke_test() {
     assert_eq!(instructions, expected);
     assert_eq!(fee_instructions, vec![]);
 }
+
+#[test]
+fn it_parses_assertions() {
+    let input = fs::read_to_string("tests/examples/assertions.rs").unwrap();
+    let test_faucet_component = ComponentAddress::new([1u8; ObjectKey::LENGTH].into());
+    let counter_component = ComponentAddress::new([2u8; ObjectKey::LENGTH].into());
+    let xtr_resource = ResourceAddress::from([3u8; ObjectKey::LENGTH]);
+    let faucet_vault = VaultId::new([4u8; ObjectKey::LENGTH].into());
+
+    let globals = HashMap::from([
+        (
+            "test_faucet".to_string(),
+            SubstateId::Component(test_faucet_component).into(),
+        ),
+        ("counter".to_string(), SubstateId::Component(counter_component).into()),
+        ("xtr_resource".to_string(), SubstateId::Resource(xtr_resource).into()),
+        ("faucet_vault".to_string(), SubstateId::Vault(faucet_vault).into()),
+    ]);
+    let ManifestInstructions { instructions, .. } = parse_manifest(&input, globals, Default::default()).unwrap();
+
+    let expected = vec![
+        Instruction::CallMethod {
+            component_address: test_faucet_component,
+            method: "take_free_coins".to_string(),
+            args: args![Amount(1_000)],
+        },
+        Instruction::PutLastInstructionOutputOnWorkspace { key: b"funds".to_vec() },
+        Instruction::AssertBucketContains {
+            key: b"funds".to_vec(),
+            resource_address: xtr_resource,
+            min_amount: Amount(1_000),
+        },
+        Instruction::Assert {
+            assertion: Assertion::VaultBalanceAtLeast {
+                vault_id: faucet_vault,
+                min_amount: Amount(500),
+            },
+        },
+        Instruction::Assert {
+            assertion: Assertion::VaultBalanceAtMost {
+                vault_id: faucet_vault,
+                max_amount: Amount(10_000),
+            },
+        },
+        Instruction::CallMethod {
+            component_address: test_faucet_component,
+            method: "take_nft".to_string(),
+            args: args![],
+        },
+        Instruction::PutLastInstructionOutputOnWorkspace { key: b"nft".to_vec() },
+        Instruction::Assert {
+            assertion: Assertion::BucketContainsNonFungible {
+                key: b"nft".to_vec(),
+                non_fungible_id: NonFungibleId::from_u32(1),
+            },
+        },
+        Instruction::CallMethod {
+            component_address: counter_component,
+            method: "value".to_string(),
+            args: args![],
+        },
+        Instruction::PutLastInstructionOutputOnWorkspace { key: b"value".to_vec() },
+        Instruction::Assert {
+            assertion: Assertion::workspace_value_equals("value", &1u32).unwrap(),
+        },
+        Instruction::Assert {
+            assertion: Assertion::component_state_equals(counter_component, "$.value", &1u32).unwrap(),
+        },
+    ];
+
+    assert_eq!(instructions, expected);
+}
+
+#[test]
+fn it_parses_control_flow() {
+    let input = fs::read_to_string("tests/examples/control_flow.rs").unwrap();
+    let test_faucet_component = ComponentAddress::new([1u8; ObjectKey::LENGTH].into());
+    let account_component = ComponentAddress::new([2u8; ObjectKey::LENGTH].into());
+
+    let globals = HashMap::from([
+        (
+            "test_faucet".to_string(),
+            SubstateId::Component(test_faucet_component).into(),
+        ),
+        ("account".to_string(), SubstateId::Component(account_component).into()),
+    ]);
+    let ManifestInstructions { instructions, .. } = parse_manifest(&input, globals, Default::default()).unwrap();
+
+    let expected = vec![
+        Instruction::CallMethod {
+            component_address: test_faucet_component,
+            method: "take_many".to_string(),
+            args: args![3u32],
+        },
+        Instruction::PutLastInstructionOutputOnWorkspace {
+            key: b"buckets".to_vec(),
+        },
+        Instruction::ForEach {
+            key: b"buckets".to_vec(),
+            item_key: b"bucket".to_vec(),
+            max_iterations: 5,
+            instructions: vec![Instruction::CallMethod {
+                component_address: account_component,
+                method: "deposit".to_string(),
+                args: args![Workspace("bucket")],
+            }],
+        },
+        Instruction::CallMethod {
+            component_address: account_component,
+            method: "balance".to_string(),
+            args: args![],
+        },
+        Instruction::PutLastInstructionOutputOnWorkspace {
+            key: b"balance".to_vec(),
+        },
+        Instruction::If {
+            condition: Assertion::workspace_value_equals("balance", &Amount(300)).unwrap(),
+            then_instructions: vec![Instruction::EmitLog {
+                level: LogLevel::Info,
+                message: "All buckets deposited".to_string(),
+            }],
+            else_instructions: vec![Instruction::EmitLog {
+                level: LogLevel::Warn,
+                message: "Unexpected balance".to_string(),
+            }],
+        },
+    ];
+
+    assert_eq!(instructions, expected);
+}
@@ -80,9 +80,15 @@ impl<'a, TStore: WalletStore> AccountsApi<'a, TStore> {
         vault_address: &SubstateId,
         revealed_balance: Amount,
         confidential_balance: Amount,
+        time_locked_balance: Amount,
     ) -> Result<(), AccountsApiError> {
         let mut tx = self.store.create_write_tx()?;
-        tx.vaults_update(vault_address, revealed_balance, confidential_balance)?;
+        tx.vaults_update(
+            vault_address,
+            revealed_balance,
+            confidential_balance,
+            time_locked_balance,
+        )?;
         tx.commit()?;
         Ok(())
     }
@@ -93,6 +99,7 @@ impl<'a, TStore: WalletStore> AccountsApi<'a, TStore> {
             account: vault.account_address,
             confidential: vault.confidential_balance,
             revealed: vault.revealed_balance,
+            time_locked: vault.time_locked_balance,
         })
     }
 
@@ -175,6 +182,7 @@ impl<'a, TStore: WalletStore> AccountsApi<'a, TStore> {
             revealed_balance: Amount::zero(),
             confidential_balance: Amount::zero(),
             locked_revealed_balance: Amount::zero(),
+            time_locked_balance: Amount::zero(),
             token_symbol,
         })?;
         tx.commit()?;
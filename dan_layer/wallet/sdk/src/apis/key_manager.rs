@@ -78,6 +78,19 @@ impl<'a, TStore: WalletStore> KeyManagerApi<'a, TStore> {
         Ok(key)
     }
 
+    /// Records that the key at the given index is in use, so that `next_key` only derives keys after it
+    pub fn set_key_used(&self, branch: &str, index: u64) -> Result<(), KeyManagerApiError> {
+        let mut tx = self.store.create_write_tx()?;
+        let last_index = tx.key_manager_get_last_index(branch).optional()?;
+        if last_index.map_or(true, |last_index| last_index < index) {
+            tx.key_manager_insert(branch, index)?;
+            tx.commit()?;
+        } else {
+            tx.rollback()?;
+        }
+        Ok(())
+    }
+
     pub fn set_active_key(&self, branch: &str, index: u64) -> Result<(), KeyManagerApiError> {
         let mut tx = self.store.create_write_tx()?;
         tx.key_manager_set_active_index(branch, index)?;
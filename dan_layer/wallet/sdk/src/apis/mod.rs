@@ -9,5 +9,6 @@ pub mod config;
 pub mod jwt;
 pub mod key_manager;
 pub mod non_fungible_tokens;
+pub mod restore;
 pub mod substate;
 pub mod transaction;
@@ -0,0 +1,189 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use log::*;
+use tari_common_types::types::PublicKey;
+use tari_crypto::{keys::PublicKey as PublicKeyTrait, tari_utilities::ByteArray};
+use tari_dan_common_types::optional::{IsNotFoundError, Optional};
+use tari_engine_types::substate::SubstateId;
+use tari_template_builtin::ACCOUNT_TEMPLATE_ADDRESS;
+use tari_template_lib::{auth::OwnerRule, crypto::RistrettoPublicKeyBytes, models::ComponentAddress};
+
+use crate::{
+    apis::{
+        accounts::{AccountsApi, AccountsApiError},
+        key_manager,
+        key_manager::{KeyManagerApi, KeyManagerApiError},
+        substate::{SubstateApiError, SubstatesApi, ValidatorScanResult},
+    },
+    models::Account,
+    network::WalletNetworkInterface,
+    storage::{WalletStorageError, WalletStore, WalletStoreWriter},
+};
+
+const LOG_TARGET: &str = "tari::dan::wallet_sdk::apis::restore";
+
+/// The number of accounts requested from the network interface at a time
+const ACCOUNTS_PAGE_SIZE: u32 = 100;
+
+pub struct RestoreApi<'a, TStore, TNetworkInterface> {
+    store: &'a TStore,
+    network_interface: &'a TNetworkInterface,
+    key_manager_api: KeyManagerApi<'a, TStore>,
+    accounts_api: AccountsApi<'a, TStore>,
+    substate_api: SubstatesApi<'a, TStore, TNetworkInterface>,
+}
+
+impl<'a, TStore, TNetworkInterface> RestoreApi<'a, TStore, TNetworkInterface>
+where
+    TStore: WalletStore,
+    TNetworkInterface: WalletNetworkInterface,
+    TNetworkInterface::Error: IsNotFoundError,
+{
+    pub fn new(
+        store: &'a TStore,
+        network_interface: &'a TNetworkInterface,
+        key_manager_api: KeyManagerApi<'a, TStore>,
+        accounts_api: AccountsApi<'a, TStore>,
+        substate_api: SubstatesApi<'a, TStore, TNetworkInterface>,
+    ) -> Self {
+        Self {
+            store,
+            network_interface,
+            key_manager_api,
+            accounts_api,
+            substate_api,
+        }
+    }
+
+    /// Discovers the accounts owned by keys derived from the wallet seed and adds them to the wallet. Keys are derived
+    /// in order from index 0 until `gap_limit` consecutive keys do not own any accounts. Keys in the transaction branch
+    /// are also used for purposes other than account ownership, so the gap limit should allow for this.
+    ///
+    /// Returns the accounts that were added. The vaults of the accounts are not loaded.
+    pub async fn discover_accounts(&self, gap_limit: u64) -> Result<Vec<Account>, RestoreApiError> {
+        let mut restored = Vec::new();
+        let mut index = 0;
+        let mut num_unused = 0;
+
+        while num_unused < gap_limit {
+            let key = self
+                .key_manager_api
+                .derive_key(key_manager::TRANSACTION_BRANCH, index)?;
+            let owner = PublicKey::from_secret_key(&key.key);
+            let accounts = self.get_all_accounts_by_owner(&owner).await?;
+            if accounts.is_empty() {
+                num_unused += 1;
+            } else {
+                num_unused = 0;
+                info!(
+                    target: LOG_TARGET,
+                    "Found {} account(s) owned by key {} ({})",
+                    accounts.len(),
+                    index,
+                    owner
+                );
+                self.key_manager_api
+                    .set_key_used(key_manager::TRANSACTION_BRANCH, index)?;
+                for account_address in accounts {
+                    if let Some(account) = self.restore_account(account_address, &owner, index).await? {
+                        restored.push(account);
+                    }
+                }
+            }
+            index += 1;
+        }
+
+        Ok(restored)
+    }
+
+    async fn get_all_accounts_by_owner(&self, owner: &PublicKey) -> Result<Vec<ComponentAddress>, RestoreApiError> {
+        let mut accounts = Vec::new();
+        loop {
+            let page = self
+                .network_interface
+                .get_accounts_by_owner(owner, accounts.len() as u32, ACCOUNTS_PAGE_SIZE)
+                .await
+                .map_err(|e| RestoreApiError::NetworkInterfaceError(e.into()))?;
+            let is_last_page = page.len() < ACCOUNTS_PAGE_SIZE as usize;
+            accounts.extend(page);
+            if is_last_page {
+                break;
+            }
+        }
+        Ok(accounts)
+    }
+
+    /// Adds the account to the wallet, owned by the key at the given index. Returns None if the wallet already has the
+    /// account or if the component is not an account that is owned by the key.
+    async fn restore_account(
+        &self,
+        account_address: ComponentAddress,
+        owner: &PublicKey,
+        key_index: u64,
+    ) -> Result<Option<Account>, RestoreApiError> {
+        let account_address = SubstateId::Component(account_address);
+        if self.accounts_api.exists_by_address(&account_address)? {
+            debug!(
+                target: LOG_TARGET,
+                "Account {} is already in the wallet", account_address
+            );
+            return Ok(None);
+        }
+
+        let ValidatorScanResult {
+            address,
+            substate,
+            created_by_tx,
+        } = self.substate_api.scan_for_substate(&account_address, None).await?;
+        let component = substate
+            .component()
+            .ok_or_else(|| RestoreApiError::UnexpectedSubstate {
+                details: format!("Expected account {} to be a component", account_address),
+            })?;
+        let owner = RistrettoPublicKeyBytes::from_bytes(owner.as_bytes())
+            .expect("RistrettoPublicKeyBytes::from_bytes is infallible for a valid public key");
+        if component.template_address != ACCOUNT_TEMPLATE_ADDRESS ||
+            component.owner_rule != OwnerRule::ByPublicKey(owner)
+        {
+            debug!(
+                target: LOG_TARGET,
+                "Component {} is not an account owned by key {}", account_address, key_index
+            );
+            return Ok(None);
+        }
+
+        let module_name = component.module_name.clone();
+        let template_address = component.template_address;
+        self.store.with_write_tx(|tx| {
+            tx.substates_upsert_root(created_by_tx, address, Some(module_name), Some(template_address))
+        })?;
+
+        let is_default = self.accounts_api.get_default().optional()?.is_none();
+        self.accounts_api
+            .add_account(None, &account_address, key_index, is_default)?;
+        info!(
+            target: LOG_TARGET,
+            "Restored account {} owned by key {}", account_address, key_index
+        );
+
+        let account = self.accounts_api.get_account_by_address(&account_address)?;
+        Ok(Some(account))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RestoreApiError {
+    #[error("Store error: {0}")]
+    StoreError(#[from] WalletStorageError),
+    #[error("Key manager error: {0}")]
+    KeyManager(#[from] KeyManagerApiError),
+    #[error("Accounts API error: {0}")]
+    Accounts(#[from] AccountsApiError),
+    #[error("Substate API error: {0}")]
+    Substate(#[from] SubstateApiError),
+    #[error("Network interface error: {0}")]
+    NetworkInterfaceError(anyhow::Error),
+    #[error("Unexpected substate: {details}")]
+    UnexpectedSubstate { details: String },
+}
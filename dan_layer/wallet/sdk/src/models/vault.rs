@@ -16,12 +16,14 @@ pub struct VaultModel {
     pub confidential_balance: Amount,
     pub revealed_balance: Amount,
     pub locked_revealed_balance: Amount,
+    /// The revealed balance that is time locked by the vault and cannot be spent as of the last vault update
+    pub time_locked_balance: Amount,
     pub token_symbol: Option<String>,
 }
 
 impl VaultModel {
     pub fn available_revealed_balance(&self) -> Amount {
-        self.revealed_balance - self.locked_revealed_balance
+        self.revealed_balance - self.locked_revealed_balance - self.time_locked_balance
     }
 }
 
@@ -30,4 +32,5 @@ pub struct VaultBalance {
     pub account: SubstateId,
     pub confidential: Amount,
     pub revealed: Amount,
+    pub time_locked: Amount,
 }
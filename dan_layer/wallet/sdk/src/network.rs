@@ -6,14 +6,15 @@ use std::time::Duration;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tari_dan_common_types::{substate_type::SubstateType, SubstateRequirement};
+use tari_common_types::types::PublicKey;
+use tari_dan_common_types::{substate_type::SubstateType, Epoch, SubstateRequirement};
 use tari_dan_storage::consensus_models::Decision;
 use tari_engine_types::{
     commit_result::ExecuteResult,
     substate::{Substate, SubstateId},
 };
 use tari_template_abi::TemplateDef;
-use tari_template_lib::prelude::TemplateAddress;
+use tari_template_lib::prelude::{ComponentAddress, TemplateAddress};
 use tari_transaction::{Transaction, TransactionId};
 
 #[async_trait]
@@ -53,6 +54,16 @@ pub trait WalletNetworkInterface {
     ) -> Result<TransactionQueryResult, Self::Error>;
 
     async fn fetch_template_definition(&self, template_address: TemplateAddress) -> Result<TemplateDef, Self::Error>;
+
+    async fn get_current_epoch(&self) -> Result<Epoch, Self::Error>;
+
+    /// Returns the account components that are owned by the public key
+    async fn get_accounts_by_owner(
+        &self,
+        owner: &PublicKey,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ComponentAddress>, Self::Error>;
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -5,11 +5,16 @@ use std::{sync::Arc, time::Duration};
 
 use tari_crypto::tari_utilities::SafePassword;
 use tari_dan_common_types::optional::{IsNotFoundError, Optional};
-use tari_key_manager::cipher_seed::CipherSeed;
+use tari_key_manager::{
+    cipher_seed::CipherSeed,
+    error::KeyManagerError,
+    mnemonic::{Mnemonic, MnemonicLanguage},
+    SeedWords,
+};
 
 use crate::{
     apis::{
-        accounts::AccountsApi,
+        accounts::{AccountsApi, AccountsApiError},
         confidential_crypto::ConfidentialCryptoApi,
         confidential_outputs::ConfidentialOutputsApi,
         confidential_transfer::ConfidentialTransferApi,
@@ -17,6 +22,7 @@ use crate::{
         jwt::JwtApi,
         key_manager::KeyManagerApi,
         non_fungible_tokens::NonFungibleTokensApi,
+        restore::RestoreApi,
         substate::SubstatesApi,
         transaction::TransactionApi,
     },
@@ -65,6 +71,35 @@ where
         })
     }
 
+    /// Sets the cipher seed of a new wallet to the seed encoded in the given seed words. This must be called before
+    /// `initialize`. Once the wallet is initialized, the accounts owned by the seed can be discovered using the
+    /// `RestoreApi`. Restoring the seed that the wallet already has is a no-op.
+    pub fn restore_cipher_seed(store: &TStore, seed_words: &SeedWords) -> Result<(), WalletSdkError> {
+        let config_api = ConfigApi::new(store);
+        let maybe_cipher_seed = config_api.get::<CipherSeed>(ConfigKey::CipherSeed).optional()?;
+        if let Some(cipher_seed) = maybe_cipher_seed {
+            let current_words = cipher_seed.to_mnemonic(MnemonicLanguage::English, None)?;
+            if current_words.join(" ").reveal() == seed_words.join(" ").reveal() {
+                return Ok(());
+            }
+        }
+
+        let accounts_api = AccountsApi::new(store);
+        if accounts_api.count()? > 0 {
+            return Err(WalletSdkError::CannotRestoreWalletWithAccounts);
+        }
+
+        let cipher_seed = CipherSeed::from_mnemonic(seed_words, None)?;
+        config_api.set(ConfigKey::CipherSeed, &cipher_seed, true)?;
+        Ok(())
+    }
+
+    /// Returns the seed words for the wallet cipher seed, from which the wallet can be restored
+    pub fn get_seed_words(&self) -> Result<SeedWords, WalletSdkError> {
+        let seed_words = self.cipher_seed.to_mnemonic(MnemonicLanguage::English, None)?;
+        Ok(seed_words)
+    }
+
     pub fn config_api(&self) -> ConfigApi<'_, TStore> {
         ConfigApi::new(&self.store)
     }
@@ -128,6 +163,16 @@ where
         NonFungibleTokensApi::new(&self.store)
     }
 
+    pub fn restore_api(&self) -> RestoreApi<'_, TStore, TNetworkInterface> {
+        RestoreApi::new(
+            &self.store,
+            &self.network_interface,
+            self.key_manager_api(),
+            self.accounts_api(),
+            self.substate_api(),
+        )
+    }
+
     fn get_or_create_cipher_seed(store: &TStore) -> Result<CipherSeed, WalletSdkError> {
         let config_api = ConfigApi::new(store);
         let maybe_cipher_seed = config_api.get(ConfigKey::CipherSeed).optional()?;
@@ -149,4 +194,10 @@ pub enum WalletSdkError {
     WalletStorageError(#[from] WalletStorageError),
     #[error("Config API error: {0}")]
     ConfigApiError(#[from] ConfigApiError),
+    #[error("Accounts API error: {0}")]
+    AccountsApiError(#[from] AccountsApiError),
+    #[error("Key manager error: {0}")]
+    KeyManagerError(#[from] KeyManagerError),
+    #[error("Cannot restore the cipher seed of a wallet that already has accounts")]
+    CannotRestoreWalletWithAccounts,
 }
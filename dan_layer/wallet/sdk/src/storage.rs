@@ -274,6 +274,7 @@ pub trait WalletStoreWriter {
         vault_address: &SubstateId,
         revealed_balance: Amount,
         confidential_balance: Amount,
+        time_locked_balance: Amount,
     ) -> Result<(), WalletStorageError>;
     fn vaults_lock_revealed_funds(
         &mut self,
@@ -4,9 +4,9 @@
 use std::{convert::Infallible, time::Duration};
 
 use async_trait::async_trait;
-use tari_common_types::types::Commitment;
+use tari_common_types::types::{Commitment, PublicKey};
 use tari_crypto::commitment::HomomorphicCommitmentFactory;
-use tari_dan_common_types::{optional::Optional, SubstateRequirement};
+use tari_dan_common_types::{optional::Optional, Epoch, SubstateRequirement};
 use tari_dan_wallet_sdk::{
     models::{ConfidentialOutputModel, ConfidentialProofId, OutputStatus},
     network::{SubstateQueryResult, TransactionQueryResult, WalletNetworkInterface},
@@ -19,7 +19,7 @@ use tari_engine_types::{confidential::get_commitment_factory, substate::Substate
 use tari_template_abi::TemplateDef;
 use tari_template_lib::{
     constants::CONFIDENTIAL_TARI_RESOURCE_ADDRESS,
-    models::{Amount, EncryptedData, TemplateAddress},
+    models::{Amount, ComponentAddress, EncryptedData, TemplateAddress},
     resource::ResourceType,
 };
 use tari_transaction::{Transaction, TransactionId};
@@ -270,6 +270,19 @@ impl WalletNetworkInterface for PanicIndexer {
         panic!("PanicIndexer called")
     }
 
+    async fn get_current_epoch(&self) -> Result<Epoch, Self::Error> {
+        panic!("PanicIndexer called")
+    }
+
+    async fn get_accounts_by_owner(
+        &self,
+        _owner: &PublicKey,
+        _offset: u32,
+        _limit: u32,
+    ) -> Result<Vec<ComponentAddress>, Self::Error> {
+        panic!("PanicIndexer called")
+    }
+
     async fn list_substates(
         &self,
         _filter_by_template: Option<TemplateAddress>,
@@ -0,0 +1,340 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use tari_common_types::types::PublicKey;
+use tari_crypto::{keys::PublicKey as PublicKeyTrait, tari_utilities::ByteArray};
+use tari_dan_common_types::{substate_type::SubstateType, Epoch, SubstateRequirement};
+use tari_dan_wallet_sdk::{
+    apis::key_manager,
+    network::{SubstateListResult, SubstateQueryResult, TransactionQueryResult, WalletNetworkInterface},
+    DanWalletSdk,
+    WalletSdkConfig,
+    WalletSdkError,
+};
+use tari_dan_wallet_storage_sqlite::SqliteWalletStore;
+use tari_engine_types::{
+    component::{ComponentBody, ComponentHeader},
+    substate::{Substate, SubstateId},
+};
+use tari_template_abi::TemplateDef;
+use tari_template_builtin::{ACCOUNT_TEMPLATE_ADDRESS, MULTISIG_ACCOUNT_TEMPLATE_ADDRESS};
+use tari_template_lib::{
+    auth::{AccessRule, ComponentAccessRules, OwnerRule},
+    crypto::RistrettoPublicKeyBytes,
+    models::{ComponentAddress, TemplateAddress},
+};
+use tari_transaction::{Transaction, TransactionId};
+
+#[tokio::test]
+async fn it_discovers_accounts_within_the_gap_limit() {
+    let test = Test::new();
+    let account0 = test.add_network_account(0, 1);
+    let account3 = test.add_network_account(3, 2);
+    // Keys 4, 5 and 6 are unused, so discovery stops before this account
+    let _account7 = test.add_network_account(7, 3);
+
+    let restored = test.sdk().restore_api().discover_accounts(3).await.unwrap();
+
+    let addresses = restored.iter().map(|a| a.address.clone()).collect::<Vec<_>>();
+    assert_eq!(addresses, vec![account0.clone(), account3.clone()]);
+    assert_eq!(restored[0].key_index, 0);
+    assert!(restored[0].is_default);
+    assert_eq!(restored[1].key_index, 3);
+    assert!(!restored[1].is_default);
+    assert_eq!(test.sdk().accounts_api().count().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn it_skips_accounts_that_the_wallet_already_has() {
+    let test = Test::new();
+    let existing = test.add_network_account(0, 1);
+    let new = test.add_network_account(0, 2);
+    test.sdk()
+        .accounts_api()
+        .add_account(Some("existing"), &existing, 0, true)
+        .unwrap();
+
+    let restored = test.sdk().restore_api().discover_accounts(1).await.unwrap();
+
+    assert_eq!(restored.len(), 1);
+    assert_eq!(restored[0].address, new);
+    assert!(!restored[0].is_default);
+    let existing = test.sdk().accounts_api().get_account_by_address(&existing).unwrap();
+    assert_eq!(existing.name.as_deref(), Some("existing"));
+    assert_eq!(test.sdk().accounts_api().count().unwrap(), 2);
+}
+
+#[tokio::test]
+async fn it_restores_nothing_if_the_seed_owns_no_accounts() {
+    let test = Test::new();
+
+    let restored = test.sdk().restore_api().discover_accounts(5).await.unwrap();
+
+    assert!(restored.is_empty());
+    assert_eq!(*test.network.num_owner_queries.read().unwrap(), 5);
+}
+
+#[tokio::test]
+async fn it_only_restores_accounts_that_are_owned_by_the_derived_key() {
+    let test = Test::new();
+    let account = test.add_network_account(0, 1);
+    let owner = test.derive_public_key(0);
+    // Components that the indexer returns for the key but that the wallet cannot act as the owner of alone
+    test.network.add_component(
+        owner.clone(),
+        ComponentAddress::from_array([2; 32]),
+        new_component(
+            MULTISIG_ACCOUNT_TEMPLATE_ADDRESS,
+            OwnerRule::ByPublicKey(public_key_bytes(&owner)),
+        ),
+    );
+    test.network.add_component(
+        owner.clone(),
+        ComponentAddress::from_array([3; 32]),
+        new_component(ACCOUNT_TEMPLATE_ADDRESS, OwnerRule::ByAccessRule(AccessRule::AllowAll)),
+    );
+    test.network.add_component(
+        owner,
+        ComponentAddress::from_array([4; 32]),
+        new_component(
+            ACCOUNT_TEMPLATE_ADDRESS,
+            OwnerRule::ByPublicKey(public_key_bytes(&test.derive_public_key(1))),
+        ),
+    );
+
+    let restored = test.sdk().restore_api().discover_accounts(1).await.unwrap();
+
+    let addresses = restored.iter().map(|a| a.address.clone()).collect::<Vec<_>>();
+    assert_eq!(addresses, vec![account]);
+    assert_eq!(test.sdk().accounts_api().count().unwrap(), 1);
+}
+
+#[test]
+fn it_rejects_a_seed_restore_when_the_wallet_has_accounts() {
+    let test = Test::new();
+    let other = Test::new();
+    let account = test.add_network_account(0, 1);
+    test.sdk().accounts_api().add_account(None, &account, 0, true).unwrap();
+
+    let other_seed_words = other.sdk().get_seed_words().unwrap();
+    let err = DanWalletSdk::<_, MockIndexer>::restore_cipher_seed(test.store(), &other_seed_words).unwrap_err();
+    assert!(matches!(err, WalletSdkError::CannotRestoreWalletWithAccounts));
+
+    // Restoring the seed that the wallet already has is allowed
+    let seed_words = test.sdk().get_seed_words().unwrap();
+    DanWalletSdk::<_, MockIndexer>::restore_cipher_seed(test.store(), &seed_words).unwrap();
+}
+
+#[test]
+fn it_restores_the_seed_of_a_wallet_without_accounts() {
+    let test = Test::new();
+    let other = Test::new();
+
+    let other_seed_words = other.sdk().get_seed_words().unwrap();
+    DanWalletSdk::<_, MockIndexer>::restore_cipher_seed(test.store(), &other_seed_words).unwrap();
+
+    // The restored seed is used when the wallet is next initialized
+    let sdk = DanWalletSdk::initialize(test.store().clone(), MockIndexer::default(), Test::config()).unwrap();
+    let restored_words = sdk.get_seed_words().unwrap();
+    assert_eq!(restored_words.join(" ").reveal(), other_seed_words.join(" ").reveal());
+}
+
+// -------------------------------- Test Harness -------------------------------- //
+
+struct Test {
+    store: SqliteWalletStore,
+    sdk: DanWalletSdk<SqliteWalletStore, MockIndexer>,
+    network: MockIndexer,
+    _temp: tempfile::TempDir,
+}
+
+impl Test {
+    pub fn new() -> Self {
+        let temp = tempfile::tempdir().unwrap();
+        let store = SqliteWalletStore::try_open(temp.path().join("data/wallet.sqlite")).unwrap();
+        store.run_migrations().unwrap();
+
+        let network = MockIndexer::default();
+        let sdk = DanWalletSdk::initialize(store.clone(), network.clone(), Self::config()).unwrap();
+
+        Self {
+            store,
+            sdk,
+            network,
+            _temp: temp,
+        }
+    }
+
+    pub fn config() -> WalletSdkConfig {
+        WalletSdkConfig {
+            password: None,
+            jwt_expiry: Duration::from_secs(60),
+            jwt_secret_key: "secret_key".to_string(),
+        }
+    }
+
+    /// Adds an account component to the network that is owned by the wallet key at the given index
+    pub fn add_network_account(&self, key_index: u64, id: u8) -> SubstateId {
+        let owner = self.derive_public_key(key_index);
+        let address = ComponentAddress::from_array([id; 32]);
+        let component = new_component(
+            ACCOUNT_TEMPLATE_ADDRESS,
+            OwnerRule::ByPublicKey(public_key_bytes(&owner)),
+        );
+        self.network.add_component(owner, address, component);
+        SubstateId::Component(address)
+    }
+
+    pub fn derive_public_key(&self, key_index: u64) -> PublicKey {
+        let key = self
+            .sdk
+            .key_manager_api()
+            .derive_key(key_manager::TRANSACTION_BRANCH, key_index)
+            .unwrap();
+        PublicKey::from_secret_key(&key.key)
+    }
+
+    pub fn sdk(&self) -> &DanWalletSdk<SqliteWalletStore, MockIndexer> {
+        &self.sdk
+    }
+
+    pub fn store(&self) -> &SqliteWalletStore {
+        &self.store
+    }
+}
+
+fn public_key_bytes(public_key: &PublicKey) -> RistrettoPublicKeyBytes {
+    RistrettoPublicKeyBytes::from_bytes(public_key.as_bytes()).unwrap()
+}
+
+fn new_component(template_address: TemplateAddress, owner_rule: OwnerRule) -> ComponentHeader {
+    ComponentHeader {
+        template_address,
+        module_name: "Account".to_string(),
+        owner_key: None,
+        owner_rule,
+        access_rules: ComponentAccessRules::new(),
+        entity_id: Default::default(),
+        body: ComponentBody {
+            state: tari_bor::Value::Null,
+        },
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct MockIndexer {
+    accounts_by_owner: Arc<RwLock<HashMap<PublicKey, Vec<ComponentAddress>>>>,
+    components: Arc<RwLock<HashMap<ComponentAddress, ComponentHeader>>>,
+    num_owner_queries: Arc<RwLock<usize>>,
+}
+
+impl MockIndexer {
+    /// Adds a component that is returned as an account of the owner
+    pub fn add_component(&self, owner: PublicKey, address: ComponentAddress, component: ComponentHeader) {
+        self.accounts_by_owner
+            .write()
+            .unwrap()
+            .entry(owner)
+            .or_default()
+            .push(address);
+        self.components.write().unwrap().insert(address, component);
+    }
+}
+
+#[async_trait]
+impl WalletNetworkInterface for MockIndexer {
+    type Error = Infallible;
+
+    async fn query_substate(
+        &self,
+        address: &SubstateId,
+        _version: Option<u32>,
+        _local_search_only: bool,
+    ) -> Result<SubstateQueryResult, Self::Error> {
+        let component = address
+            .as_component_address()
+            .and_then(|addr| self.components.read().unwrap().get(&addr).cloned())
+            .unwrap_or_else(|| panic!("MockIndexer: unexpected substate query for {}", address));
+
+        Ok(SubstateQueryResult {
+            address: address.clone(),
+            version: 0,
+            substate: Substate::new(0, component),
+            created_by_transaction: TransactionId::new([0; 32]),
+        })
+    }
+
+    #[allow(clippy::diverging_sub_expression)]
+    async fn list_substates(
+        &self,
+        _filter_by_template: Option<TemplateAddress>,
+        _filter_by_type: Option<SubstateType>,
+        _limit: Option<u64>,
+        _offset: Option<u64>,
+    ) -> Result<SubstateListResult, Self::Error> {
+        panic!("MockIndexer: list_substates called")
+    }
+
+    #[allow(clippy::diverging_sub_expression)]
+    async fn submit_transaction(
+        &self,
+        _transaction: Transaction,
+        _required_substates: Vec<SubstateRequirement>,
+    ) -> Result<TransactionId, Self::Error> {
+        panic!("MockIndexer: submit_transaction called")
+    }
+
+    #[allow(clippy::diverging_sub_expression)]
+    async fn submit_dry_run_transaction(
+        &self,
+        _transaction: Transaction,
+        _required_substates: Vec<SubstateRequirement>,
+    ) -> Result<TransactionQueryResult, Self::Error> {
+        panic!("MockIndexer: submit_dry_run_transaction called")
+    }
+
+    #[allow(clippy::diverging_sub_expression)]
+    async fn query_transaction_result(
+        &self,
+        _transaction_id: TransactionId,
+    ) -> Result<TransactionQueryResult, Self::Error> {
+        panic!("MockIndexer: query_transaction_result called")
+    }
+
+    async fn fetch_template_definition(&self, _template_address: TemplateAddress) -> Result<TemplateDef, Self::Error> {
+        panic!("MockIndexer: fetch_template_definition called")
+    }
+
+    async fn get_current_epoch(&self) -> Result<Epoch, Self::Error> {
+        panic!("MockIndexer: get_current_epoch called")
+    }
+
+    async fn get_accounts_by_owner(
+        &self,
+        owner: &PublicKey,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ComponentAddress>, Self::Error> {
+        *self.num_owner_queries.write().unwrap() += 1;
+        let accounts = self.accounts_by_owner.read().unwrap();
+        Ok(accounts
+            .get(owner)
+            .map(|accounts| {
+                accounts
+                    .iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
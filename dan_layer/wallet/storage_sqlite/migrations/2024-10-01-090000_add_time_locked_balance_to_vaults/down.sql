@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE vaults
+    DROP COLUMN time_locked_balance;
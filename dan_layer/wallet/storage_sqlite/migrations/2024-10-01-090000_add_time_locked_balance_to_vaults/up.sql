@@ -0,0 +1,2 @@
+ALTER TABLE vaults
+    ADD COLUMN time_locked_balance BIGINT NOT NULL DEFAULT 0;
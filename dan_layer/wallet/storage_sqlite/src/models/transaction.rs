@@ -35,6 +35,7 @@ pub struct Transaction {
     pub finalized_time_ms: Option<i64>,
     pub required_substates: String,
     pub new_account_info: Option<String>,
+    pub sub_intents: String,
 }
 
 impl Transaction {
@@ -50,6 +51,7 @@ impl Transaction {
                     inputs,
                     min_epoch: self.min_epoch.map(|epoch| Epoch(epoch as u64)),
                     max_epoch: self.max_epoch.map(|epoch| Epoch(epoch as u64)),
+                    sub_intents: deserialize_json(&self.sub_intents)?,
                 },
                 signatures,
             ),
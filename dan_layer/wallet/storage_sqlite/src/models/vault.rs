@@ -28,6 +28,7 @@ pub struct Vault {
     pub token_symbol: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub time_locked_balance: i64,
 }
 
 impl Vault {
@@ -53,6 +54,7 @@ impl Vault {
             token_symbol: self.token_symbol,
             revealed_balance: Amount(self.revealed_balance),
             locked_revealed_balance: Amount(self.locked_revealed_balance),
+            time_locked_balance: Amount(self.time_locked_balance),
             confidential_balance: Amount(self.confidential_balance),
         })
     }
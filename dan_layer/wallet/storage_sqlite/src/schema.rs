@@ -121,6 +121,7 @@ diesel::table! {
         finalized_time_ms -> Nullable<BigInt>,
         required_substates -> Text,
         new_account_info -> Nullable<Text>,
+        sub_intents -> Text,
     }
 }
 
@@ -137,6 +138,7 @@ diesel::table! {
         token_symbol -> Nullable<Text>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        time_locked_balance -> BigInt,
     }
 }
 
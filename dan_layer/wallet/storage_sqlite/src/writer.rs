@@ -277,6 +277,7 @@ impl WalletStoreWriter for WriteTransaction<'_> {
                 transactions::hash.eq(transaction.id().to_string()),
                 transactions::fee_instructions.eq(serialize_json(transaction.fee_instructions())?),
                 transactions::instructions.eq(serialize_json(transaction.instructions())?),
+                transactions::sub_intents.eq(serialize_json(transaction.sub_intents())?),
                 transactions::signatures.eq(serialize_json(transaction.signatures())?),
                 transactions::inputs.eq(serialize_json(transaction.inputs())?),
                 transactions::status.eq(TransactionStatus::New.as_key_str()),
@@ -501,6 +502,7 @@ impl WalletStoreWriter for WriteTransaction<'_> {
             vaults::address.eq(vault.address.to_string()),
             vaults::revealed_balance.eq(vault.revealed_balance.value()),
             vaults::confidential_balance.eq(vault.confidential_balance.value()),
+            vaults::time_locked_balance.eq(vault.time_locked_balance.value()),
             vaults::resource_address.eq(vault.resource_address.to_string()),
             vaults::resource_type.eq(format!("{:?}", vault.resource_type)),
             vaults::token_symbol.eq(vault.token_symbol),
@@ -518,12 +520,14 @@ impl WalletStoreWriter for WriteTransaction<'_> {
         vault_address: &SubstateId,
         revealed_balance: Amount,
         confidential_balance: Amount,
+        time_locked_balance: Amount,
     ) -> Result<(), WalletStorageError> {
         use crate::schema::vaults;
 
         let changeset = (
             vaults::revealed_balance.eq(revealed_balance.value()),
             vaults::confidential_balance.eq(confidential_balance.value()),
+            vaults::time_locked_balance.eq(time_locked_balance.value()),
         );
 
         let num_rows = diesel::update(vaults::table)
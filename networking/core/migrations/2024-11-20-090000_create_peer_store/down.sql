@@ -0,0 +1,2 @@
+drop table if exists banned_peers;
+drop table if exists peers;
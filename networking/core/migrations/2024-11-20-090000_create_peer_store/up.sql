@@ -0,0 +1,20 @@
+-- Signed peer records obtained from peer sync and peer announcements
+create table peers
+(
+    peer_id    text   not NULL primary key,
+    -- The protobuf-encoded signed peer record
+    record     blob   not NULL,
+    -- The time (seconds since the unix epoch) that the peer record was signed
+    updated_at bigint not NULL
+);
+
+create table banned_peers
+(
+    peer_id      text      not NULL primary key,
+    reason       text      not NULL,
+    -- The time (seconds since the unix epoch) that the ban expires
+    banned_until bigint    not NULL,
+    created_at   timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+create index banned_peers_idx_banned_until on banned_peers (banned_until);
@@ -1,7 +1,7 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use libp2p::Multiaddr;
 
@@ -13,6 +13,14 @@ pub struct Config {
     pub announce: bool,
     pub check_connections_interval: Duration,
     pub known_local_public_address: Vec<Multiaddr>,
+    /// The path to the peer store database. If None, the peer store is kept in memory.
+    pub peer_store_path: Option<PathBuf>,
+    /// The default duration that a peer is banned for
+    pub ban_duration: Duration,
+    /// The number of invalid messages a peer may send within `invalid_message_window` before it is banned
+    pub max_invalid_messages: u32,
+    /// The window in which invalid messages from a peer are counted. The count is reset once the window has elapsed.
+    pub invalid_message_window: Duration,
 }
 
 impl Default for Config {
@@ -24,6 +32,10 @@ impl Default for Config {
             announce: false,
             check_connections_interval: Duration::from_secs(2 * 60 * 60),
             known_local_public_address: vec![],
+            peer_store_path: None,
+            ban_duration: Duration::from_secs(60 * 60),
+            max_invalid_messages: 3,
+            invalid_message_window: Duration::from_secs(10 * 60),
         }
     }
 }
@@ -55,6 +55,10 @@ pub enum NetworkingError {
     PeerSyncError(#[from] tari_swarm::peersync::Error),
     #[error("Messaging is disabled")]
     MessagingDisabled,
+    #[error("Cannot ban the local peer")]
+    CannotBanLocalPeer,
+    #[error("Peer store error: {0}")]
+    PeerStoreError(#[from] crate::peer_store::PeerStoreError),
 }
 
 impl From<oneshot::error::RecvError> for NetworkingError {
@@ -1,6 +1,8 @@
 //   Copyright 2023 The Tari Project
 //   SPDX-License-Identifier: BSD-3-Clause
 
+use std::time::Duration;
+
 use libp2p::{identity, PeerId, StreamProtocol};
 
 #[derive(Debug, Clone)]
@@ -10,4 +12,12 @@ pub enum NetworkingEvent {
         public_key: identity::PublicKey,
         supported_protocols: Vec<StreamProtocol>,
     },
+    PeerBanned {
+        peer_id: PeerId,
+        reason: String,
+        ban_duration: Duration,
+    },
+    PeerUnbanned {
+        peer_id: PeerId,
+    },
 }
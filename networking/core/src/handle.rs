@@ -20,7 +20,7 @@
 //   WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //   USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
 use async_trait::async_trait;
 use libp2p::{gossipsub::IdentTopic, swarm::dial_opts::DialOpts, PeerId, StreamProtocol};
@@ -42,6 +42,7 @@ use crate::{
     event::NetworkingEvent,
     message::MessageSpec,
     peer::PeerInfo,
+    peer_store::BannedPeer,
     NetworkingError,
     NetworkingService,
     Waiter,
@@ -100,6 +101,23 @@ pub enum NetworkingRequest<TMsg: MessageSpec> {
         reply_tx: oneshot::Sender<Result<PeerInfo, NetworkingError>>,
     },
     SetWantPeers(HashSet<PeerId>),
+    BanPeer {
+        peer_id: PeerId,
+        reason: String,
+        ban_duration: Option<Duration>,
+        reply_tx: oneshot::Sender<Result<(), NetworkingError>>,
+    },
+    UnbanPeer {
+        peer_id: PeerId,
+        reply_tx: oneshot::Sender<Result<bool, NetworkingError>>,
+    },
+    GetBannedPeers {
+        reply_tx: oneshot::Sender<Result<Vec<BannedPeer>, NetworkingError>>,
+    },
+    ReportInvalidMessage {
+        peer_id: PeerId,
+        reason: String,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -278,6 +296,62 @@ impl<TMsg: MessageSpec> NetworkingHandle<TMsg> {
             .map_err(|_| NetworkingHandleError::ServiceHasShutdown)?;
         rx.await?
     }
+
+    /// Bans the peer, disconnecting it and refusing any further connections until the ban expires. If `ban_duration`
+    /// is None, the configured default ban duration is used.
+    pub async fn ban_peer<T: Into<String>>(
+        &self,
+        peer_id: PeerId,
+        reason: T,
+        ban_duration: Option<Duration>,
+    ) -> Result<(), NetworkingError> {
+        let (tx, rx) = oneshot::channel();
+        self.tx_request
+            .send(NetworkingRequest::BanPeer {
+                peer_id,
+                reason: reason.into(),
+                ban_duration,
+                reply_tx: tx,
+            })
+            .await
+            .map_err(|_| NetworkingHandleError::ServiceHasShutdown)?;
+        rx.await?
+    }
+
+    /// Removes the ban for the peer. Returns true if the peer was banned, otherwise false.
+    pub async fn unban_peer(&self, peer_id: PeerId) -> Result<bool, NetworkingError> {
+        let (tx, rx) = oneshot::channel();
+        self.tx_request
+            .send(NetworkingRequest::UnbanPeer { peer_id, reply_tx: tx })
+            .await
+            .map_err(|_| NetworkingHandleError::ServiceHasShutdown)?;
+        rx.await?
+    }
+
+    pub async fn get_banned_peers(&self) -> Result<Vec<BannedPeer>, NetworkingError> {
+        let (tx, rx) = oneshot::channel();
+        self.tx_request
+            .send(NetworkingRequest::GetBannedPeers { reply_tx: tx })
+            .await
+            .map_err(|_| NetworkingHandleError::ServiceHasShutdown)?;
+        rx.await?
+    }
+
+    /// Reports that the peer sent an invalid message. The peer is banned once it has sent too many invalid messages.
+    pub async fn report_invalid_message<T: Into<String>>(
+        &self,
+        peer_id: PeerId,
+        reason: T,
+    ) -> Result<(), NetworkingError> {
+        self.tx_request
+            .send(NetworkingRequest::ReportInvalidMessage {
+                peer_id,
+                reason: reason.into(),
+            })
+            .await
+            .map_err(|_| NetworkingHandleError::ServiceHasShutdown)?;
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -24,13 +24,16 @@ mod handle;
 mod message;
 mod notify;
 mod peer;
+mod peer_store;
 mod relay_state;
 mod spawn;
 
 pub use config::*;
 pub use connection::*;
+pub use event::NetworkingEvent;
 pub use handle::*;
 pub use message::*;
+pub use peer_store::{BannedPeer, PeerStoreError, SqlitePeerStore};
 pub use spawn::*;
 pub use tari_swarm::{
     config::{Config as SwarmConfig, LimitPerInterval, RelayCircuitLimits, RelayReservationLimits},
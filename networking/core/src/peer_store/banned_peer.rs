@@ -0,0 +1,31 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use libp2p::PeerId;
+
+#[derive(Debug, Clone)]
+pub struct BannedPeer {
+    pub peer_id: PeerId,
+    pub reason: String,
+    /// The time since the unix epoch that the ban expires
+    pub banned_until: Duration,
+}
+
+impl BannedPeer {
+    /// Returns the remaining duration of the ban, or None if the ban has expired
+    pub fn remaining(&self) -> Option<Duration> {
+        self.banned_until.checked_sub(epoch_time_now()).filter(|d| !d.is_zero())
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_none()
+    }
+}
+
+pub(crate) fn epoch_time_now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the unix epoch")
+}
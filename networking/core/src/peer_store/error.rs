@@ -0,0 +1,31 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::io;
+
+use tari_swarm::peersync;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PeerStoreError {
+    #[error("Could not connect to peer store database: {0}")]
+    ConnectionError(#[from] diesel::ConnectionError),
+    #[error("Could not migrate the peer store database: {0}")]
+    MigrationError(Box<dyn std::error::Error + Send + Sync>),
+    #[error("Peer store database error during operation {operation}: {source}")]
+    DieselError {
+        source: diesel::result::Error,
+        operation: &'static str,
+    },
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid peer store path: {0}")]
+    InvalidPath(String),
+    #[error("Invalid peer id '{peer_id}' in peer store")]
+    InvalidPeerId { peer_id: String },
+    #[error("Peer record error: {0}")]
+    PeerRecord(#[from] peersync::Error),
+    #[error("Peer store connection lock was poisoned")]
+    ConnectionLockPoisoned,
+    #[error("Peer store task failed: {0}")]
+    TaskJoinError(#[from] tokio::task::JoinError),
+}
@@ -0,0 +1,13 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+mod banned_peer;
+pub use banned_peer::*;
+
+mod error;
+pub use error::PeerStoreError;
+
+mod schema;
+
+mod sqlite;
+pub use sqlite::SqlitePeerStore;
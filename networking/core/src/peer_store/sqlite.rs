@@ -0,0 +1,380 @@
+//   Copyright 2024 The Tari Project
+//   SPDX-License-Identifier: BSD-3-Clause
+
+use std::{
+    collections::HashSet,
+    fmt,
+    fs,
+    path::Path,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use diesel::{prelude::*, SqliteConnection};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use libp2p::{
+    futures::{stream, stream::BoxStream, StreamExt},
+    Multiaddr,
+    PeerId,
+};
+use tari_swarm::peersync::{store::PeerStore, SignedPeerRecord};
+use tokio::task;
+
+use crate::peer_store::{
+    banned_peer::epoch_time_now,
+    schema::{banned_peers, peers},
+    BannedPeer,
+    PeerStoreError,
+};
+
+/// A [PeerStore] backed by a SQLite database. Peer records and peer bans are persisted so that they survive a restart
+/// of the node.
+#[derive(Clone)]
+pub struct SqlitePeerStore {
+    connection: Arc<Mutex<SqliteConnection>>,
+}
+
+impl SqlitePeerStore {
+    /// Opens the peer store database at the given path, creating it if it does not exist.
+    pub fn connect<P: AsRef<Path>>(path: P) -> Result<Self, PeerStoreError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let url = path
+            .to_str()
+            .ok_or_else(|| PeerStoreError::InvalidPath(path.display().to_string()))?;
+        Self::establish(url)
+    }
+
+    /// Creates a peer store that is not persisted.
+    pub fn in_memory() -> Result<Self, PeerStoreError> {
+        Self::establish(":memory:")
+    }
+
+    fn establish(url: &str) -> Result<Self, PeerStoreError> {
+        let mut connection = SqliteConnection::establish(url)?;
+
+        const MIGRATIONS: EmbeddedMigrations = embed_migrations!("./migrations");
+        connection
+            .run_pending_migrations(MIGRATIONS)
+            .map_err(PeerStoreError::MigrationError)?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Runs the given function with the database connection on the blocking thread pool, so that queries do not block
+    /// the async runtime.
+    async fn with_connection<F, T>(&self, f: F) -> Result<T, PeerStoreError>
+    where
+        F: FnOnce(&mut SqliteConnection) -> Result<T, PeerStoreError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let connection = self.connection.clone();
+        task::spawn_blocking(move || {
+            let mut conn = connection.lock().map_err(|_| PeerStoreError::ConnectionLockPoisoned)?;
+            f(&mut conn)
+        })
+        .await?
+    }
+
+    /// Bans the peer until the given time since the unix epoch. If the peer is already banned, the ban is replaced.
+    pub async fn ban_peer(
+        &self,
+        peer_id: PeerId,
+        reason: String,
+        banned_until: Duration,
+    ) -> Result<(), PeerStoreError> {
+        self.with_connection(move |conn| {
+            diesel::replace_into(banned_peers::table)
+                .values((
+                    banned_peers::peer_id.eq(peer_id.to_string()),
+                    banned_peers::reason.eq(reason),
+                    banned_peers::banned_until.eq(duration_to_i64(banned_until)),
+                ))
+                .execute(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "ban_peer",
+                })?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Removes the ban for the peer. Returns true if the peer was banned, otherwise false.
+    pub async fn unban_peer(&self, peer_id: PeerId) -> Result<bool, PeerStoreError> {
+        self.with_connection(move |conn| {
+            let num_deleted = diesel::delete(banned_peers::table)
+                .filter(banned_peers::peer_id.eq(peer_id.to_string()))
+                .execute(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "unban_peer",
+                })?;
+            Ok(num_deleted > 0)
+        })
+        .await
+    }
+
+    pub async fn is_banned(&self, peer_id: PeerId) -> Result<bool, PeerStoreError> {
+        self.with_connection(move |conn| {
+            let count = banned_peers::table
+                .filter(banned_peers::peer_id.eq(peer_id.to_string()))
+                .filter(banned_peers::banned_until.gt(duration_to_i64(epoch_time_now())))
+                .count()
+                .get_result::<i64>(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "is_banned",
+                })?;
+            Ok(count > 0)
+        })
+        .await
+    }
+
+    /// Returns all peers with a ban that has not expired
+    pub async fn get_banned_peers(&self) -> Result<Vec<BannedPeer>, PeerStoreError> {
+        self.with_connection(|conn| {
+            let rows = banned_peers::table
+                .select((banned_peers::peer_id, banned_peers::reason, banned_peers::banned_until))
+                .filter(banned_peers::banned_until.gt(duration_to_i64(epoch_time_now())))
+                .order_by(banned_peers::banned_until.asc())
+                .get_results::<(String, String, i64)>(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "get_banned_peers",
+                })?;
+
+            rows.into_iter()
+                .map(|(peer_id, reason, banned_until)| {
+                    Ok(BannedPeer {
+                        peer_id: parse_peer_id(peer_id)?,
+                        reason,
+                        banned_until: Duration::from_secs(banned_until.max(0) as u64),
+                    })
+                })
+                .collect()
+        })
+        .await
+    }
+
+    /// Removes all expired bans and returns the peers that are no longer banned.
+    pub async fn remove_expired_bans(&self) -> Result<Vec<PeerId>, PeerStoreError> {
+        self.with_connection(|conn| {
+            let now = duration_to_i64(epoch_time_now());
+            let expired = banned_peers::table
+                .select(banned_peers::peer_id)
+                .filter(banned_peers::banned_until.le(now))
+                .get_results::<String>(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "remove_expired_bans",
+                })?;
+
+            if expired.is_empty() {
+                return Ok(vec![]);
+            }
+
+            diesel::delete(banned_peers::table)
+                .filter(banned_peers::peer_id.eq_any(&expired))
+                .execute(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "remove_expired_bans",
+                })?;
+
+            expired.into_iter().map(parse_peer_id).collect()
+        })
+        .await
+    }
+
+    fn get_peer(conn: &mut SqliteConnection, peer_id: &PeerId) -> Result<Option<SignedPeerRecord>, PeerStoreError> {
+        let record = peers::table
+            .select(peers::record)
+            .filter(peers::peer_id.eq(peer_id.to_string()))
+            .first::<Vec<u8>>(conn)
+            .optional()
+            .map_err(|source| PeerStoreError::DieselError {
+                source,
+                operation: "get_peer",
+            })?;
+
+        record
+            .map(|record| SignedPeerRecord::decode_from_proto(&record))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    fn put_peer(conn: &mut SqliteConnection, peer: &SignedPeerRecord) -> Result<(), PeerStoreError> {
+        let record = peer.encode_to_proto()?;
+        diesel::replace_into(peers::table)
+            .values((
+                peers::peer_id.eq(peer.to_peer_id().to_string()),
+                peers::record.eq(&record[..]),
+                peers::updated_at.eq(duration_to_i64(peer.updated_at)),
+            ))
+            .execute(conn)
+            .map_err(|source| PeerStoreError::DieselError {
+                source,
+                operation: "put_peer",
+            })?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PeerStore for SqlitePeerStore {
+    type Error = PeerStoreError;
+    type Stream = BoxStream<'static, Result<SignedPeerRecord, Self::Error>>;
+
+    async fn get(&self, peer_id: &PeerId) -> Result<Option<SignedPeerRecord>, Self::Error> {
+        let peer_id = *peer_id;
+        self.with_connection(move |conn| Self::get_peer(conn, &peer_id)).await
+    }
+
+    async fn put(&self, peer: SignedPeerRecord) -> Result<(), Self::Error> {
+        self.with_connection(move |conn| Self::put_peer(conn, &peer)).await
+    }
+
+    async fn put_address(&self, peer_id: &PeerId, address: Multiaddr) -> Result<bool, Self::Error> {
+        let peer_id = *peer_id;
+        self.with_connection(move |conn| match Self::get_peer(conn, &peer_id)? {
+            Some(mut peer) => {
+                if !peer.addresses.contains(&address) {
+                    peer.addresses.push(address);
+                    Self::put_peer(conn, &peer)?;
+                }
+                Ok(true)
+            },
+            None => Ok(false),
+        })
+        .await
+    }
+
+    async fn remove(&self, peer_id: &PeerId) -> Result<Option<SignedPeerRecord>, Self::Error> {
+        let peer_id = *peer_id;
+        self.with_connection(move |conn| {
+            let Some(peer) = Self::get_peer(conn, &peer_id)? else {
+                return Ok(None);
+            };
+            diesel::delete(peers::table)
+                .filter(peers::peer_id.eq(peer_id.to_string()))
+                .execute(conn)
+                .map_err(|source| PeerStoreError::DieselError {
+                    source,
+                    operation: "remove",
+                })?;
+            Ok(Some(peer))
+        })
+        .await
+    }
+
+    async fn difference<'a, I>(&self, peers: I) -> Result<HashSet<PeerId>, Self::Error>
+    where I: IntoIterator<Item = &'a PeerId> + Send {
+        let peers = peers.into_iter().copied().collect::<HashSet<_>>();
+        if peers.is_empty() {
+            return Ok(peers);
+        }
+        let peer_ids = peers.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        let known = self
+            .with_connection(move |conn| {
+                peers::table
+                    .select(peers::peer_id)
+                    .filter(peers::peer_id.eq_any(peer_ids))
+                    .get_results::<String>(conn)
+                    .map_err(|source| PeerStoreError::DieselError {
+                        source,
+                        operation: "difference",
+                    })
+            })
+            .await?
+            .into_iter()
+            .collect::<HashSet<_>>();
+
+        Ok(peers.into_iter().filter(|p| !known.contains(&p.to_string())).collect())
+    }
+
+    fn stream(&self) -> Self::Stream {
+        let store = self.clone();
+        stream::once(async move {
+            store
+                .with_connection(|conn| {
+                    peers::table
+                        .select(peers::record)
+                        .get_results::<Vec<u8>>(conn)
+                        .map_err(|source| PeerStoreError::DieselError {
+                            source,
+                            operation: "stream",
+                        })
+                })
+                .await
+        })
+        .flat_map(|records| match records {
+            Ok(records) => stream::iter(
+                records
+                    .into_iter()
+                    .map(|record| SignedPeerRecord::decode_from_proto(&record).map_err(Into::into)),
+            )
+            .boxed(),
+            Err(err) => stream::once(async move { Err(err) }).boxed(),
+        })
+        .boxed()
+    }
+}
+
+// Manually implement the Debug implementation because `SqliteConnection` does not implement the Debug trait
+impl fmt::Debug for SqlitePeerStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SqlitePeerStore")
+    }
+}
+
+fn parse_peer_id(peer_id: String) -> Result<PeerId, PeerStoreError> {
+    PeerId::from_str(&peer_id).map_err(|_| PeerStoreError::InvalidPeerId { peer_id })
+}
+
+fn duration_to_i64(duration: Duration) -> i64 {
+    i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn it_bans_and_unbans_peers() {
+        let store = SqlitePeerStore::in_memory().unwrap();
+        let peer1 = PeerId::random();
+        let peer2 = PeerId::random();
+        let now = epoch_time_now();
+
+        store
+            .ban_peer(peer1, "test".to_string(), now + Duration::from_secs(60))
+            .await
+            .unwrap();
+        store
+            .ban_peer(peer2, "expired".to_string(), now - Duration::from_secs(1))
+            .await
+            .unwrap();
+        assert!(store.is_banned(peer1).await.unwrap());
+        assert!(!store.is_banned(peer2).await.unwrap());
+
+        let banned = store.get_banned_peers().await.unwrap();
+        assert_eq!(banned.len(), 1);
+        assert_eq!(banned[0].peer_id, peer1);
+        assert_eq!(banned[0].reason, "test");
+        assert!(!banned[0].is_expired());
+
+        assert_eq!(store.remove_expired_bans().await.unwrap(), vec![peer2]);
+        assert!(store.remove_expired_bans().await.unwrap().is_empty());
+
+        assert!(store.unban_peer(peer1).await.unwrap());
+        assert!(!store.unban_peer(peer1).await.unwrap());
+        assert!(store.get_banned_peers().await.unwrap().is_empty());
+    }
+}
@@ -12,7 +12,7 @@ use tokio::{
     task::JoinHandle,
 };
 
-use crate::{message::MessageSpec, worker::NetworkingWorker, NetworkingHandle};
+use crate::{message::MessageSpec, peer_store::SqlitePeerStore, worker::NetworkingWorker, NetworkingHandle};
 
 pub const TOPIC_DELIMITER: &str = "-";
 
@@ -52,8 +52,16 @@ where
 
     config.swarm.enable_relay = config.swarm.enable_relay || !config.reachability_mode.is_private();
     config.swarm.enable_messaging = messaging_mode.is_enabled();
-    let swarm =
-        tari_swarm::create_swarm::<ProstCodec<TMsg::Message>>(identity.clone(), HashSet::new(), config.swarm.clone())?;
+    let peer_store = match config.peer_store_path {
+        Some(ref path) => SqlitePeerStore::connect(path)?,
+        None => SqlitePeerStore::in_memory()?,
+    };
+    let swarm = tari_swarm::create_swarm::<ProstCodec<TMsg::Message>, _>(
+        identity.clone(),
+        HashSet::new(),
+        peer_store.clone(),
+        config.swarm.clone(),
+    )?;
     let local_peer_id = *swarm.local_peer_id();
     let (tx, rx) = mpsc::channel(1);
     let (tx_events, _) = broadcast::channel(100);
@@ -64,6 +72,7 @@ where
             tx_events.clone(),
             messaging_mode,
             swarm,
+            peer_store,
             config,
             seed_peers,
             shutdown_signal,
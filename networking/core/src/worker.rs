@@ -56,6 +56,7 @@ use crate::{
     global_ip::GlobalIp,
     handle::NetworkingRequest,
     notify::Notifiers,
+    peer_store::{epoch_time_now, SqlitePeerStore},
     relay_state::RelayState,
     MessageSpec,
     MessagingMode,
@@ -68,6 +69,17 @@ type ReplyTx<T> = oneshot::Sender<Result<T, NetworkingError>>;
 
 const PEER_ANNOUNCE_TOPIC: &str = "peer-announce";
 
+const BAN_EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The maximum number of peers for which invalid messages are counted at any one time
+const MAX_TRACKED_INVALID_MESSAGE_PEERS: usize = 1024;
+
+/// The number of invalid messages that a peer has sent since the start of the current window
+struct InvalidMessageCount {
+    count: u32,
+    window_start: Instant,
+}
+
 pub struct NetworkingWorker<TMsg>
 where
     TMsg: MessageSpec,
@@ -83,7 +95,9 @@ where
     pending_substream_requests: HashMap<StreamId, ReplyTx<NegotiatedSubstream<Substream>>>,
     pending_dial_requests: HashMap<PeerId, Vec<ReplyTx<()>>>,
     substream_notifiers: Notifiers<Substream>,
-    swarm: TariSwarm<ProstCodec<TMsg::Message>>,
+    swarm: TariSwarm<ProstCodec<TMsg::Message>, SqlitePeerStore>,
+    peer_store: SqlitePeerStore,
+    invalid_message_counts: HashMap<PeerId, InvalidMessageCount>,
     config: crate::Config,
     relays: RelayState,
     is_initial_bootstrap_complete: bool,
@@ -103,7 +117,8 @@ where
         rx_request: mpsc::Receiver<NetworkingRequest<TMsg>>,
         tx_events: broadcast::Sender<NetworkingEvent>,
         messaging_mode: MessagingMode<TMsg>,
-        swarm: TariSwarm<ProstCodec<TMsg::Message>>,
+        swarm: TariSwarm<ProstCodec<TMsg::Message>, SqlitePeerStore>,
+        peer_store: SqlitePeerStore,
         config: crate::Config,
         known_relay_nodes: Vec<(PeerId, Multiaddr)>,
         shutdown_signal: ShutdownSignal,
@@ -119,6 +134,8 @@ where
             pending_dial_requests: HashMap::new(),
             relays: RelayState::new(known_relay_nodes),
             swarm,
+            peer_store,
+            invalid_message_counts: HashMap::new(),
             config,
             is_initial_bootstrap_complete: false,
             has_sent_announce: false,
@@ -153,6 +170,9 @@ where
         }
 
         let mut check_connections_interval = time::interval(self.config.check_connections_interval);
+        let mut ban_expiry_interval = time::interval(BAN_EXPIRY_CHECK_INTERVAL);
+
+        self.restore_bans().await?;
 
         self.swarm
             .behaviour_mut()
@@ -174,6 +194,12 @@ where
                         error!(target: LOG_TARGET, "🚨 Failed to bootstrap: {}", err);
                     }
                 },
+                _ = ban_expiry_interval.tick() => {
+                    if let Err(err) = self.remove_expired_bans().await {
+                        error!(target: LOG_TARGET, "🚨 Failed to remove expired bans: {}", err);
+                    }
+                    self.remove_expired_invalid_message_counts();
+                },
 
                 _ = self.shutdown_signal.wait() => {
                     break;
@@ -341,11 +367,147 @@ where
                 info!(target: LOG_TARGET, "🧭 Setting want peers to {:?}", peers);
                 self.swarm.behaviour_mut().peer_sync.want_peers(peers).await?;
             },
+            NetworkingRequest::BanPeer {
+                peer_id,
+                reason,
+                ban_duration,
+                reply_tx,
+            } => {
+                let _ignore = reply_tx.send(self.ban_peer(peer_id, reason, ban_duration).await);
+            },
+            NetworkingRequest::UnbanPeer { peer_id, reply_tx } => {
+                let _ignore = reply_tx.send(self.unban_peer(peer_id).await);
+            },
+            NetworkingRequest::GetBannedPeers { reply_tx } => {
+                let _ignore = reply_tx.send(self.peer_store.get_banned_peers().await.map_err(Into::into));
+            },
+            NetworkingRequest::ReportInvalidMessage { peer_id, reason } => {
+                if let Err(err) = self.on_invalid_message(peer_id, reason).await {
+                    error!(target: LOG_TARGET, "🚨 Failed to ban peer {peer_id}: {err}");
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    async fn ban_peer(
+        &mut self,
+        peer_id: PeerId,
+        reason: String,
+        ban_duration: Option<Duration>,
+    ) -> Result<(), NetworkingError> {
+        if *self.swarm.local_peer_id() == peer_id {
+            return Err(NetworkingError::CannotBanLocalPeer);
         }
 
+        let ban_duration = ban_duration.unwrap_or(self.config.ban_duration);
+        self.peer_store
+            .ban_peer(peer_id, reason.clone(), epoch_time_now() + ban_duration)
+            .await?;
+        // Closes any existing connections and denies new connections to/from the peer
+        self.swarm.behaviour_mut().blocked_peers.block_peer(peer_id);
+        self.invalid_message_counts.remove(&peer_id);
+        shrink_hashmap_if_required(&mut self.invalid_message_counts);
+        warn!(target: LOG_TARGET, "⛔️ Banned peer {peer_id} for {ban_duration:.0?}: {reason}");
+        self.publish_event(NetworkingEvent::PeerBanned {
+            peer_id,
+            reason,
+            ban_duration,
+        });
         Ok(())
     }
 
+    async fn unban_peer(&mut self, peer_id: PeerId) -> Result<bool, NetworkingError> {
+        let was_banned = self.peer_store.unban_peer(peer_id).await?;
+        if was_banned {
+            self.swarm.behaviour_mut().blocked_peers.unblock_peer(peer_id);
+            info!(target: LOG_TARGET, "✅ Unbanned peer {peer_id}");
+            self.publish_event(NetworkingEvent::PeerUnbanned { peer_id });
+        }
+        Ok(was_banned)
+    }
+
+    /// Blocks all peers that were banned before the node was restarted
+    async fn restore_bans(&mut self) -> Result<(), NetworkingError> {
+        let banned_peers = self.peer_store.get_banned_peers().await?;
+        if !banned_peers.is_empty() {
+            info!(target: LOG_TARGET, "⛔️ Restoring {} peer ban(s)", banned_peers.len());
+        }
+        for banned in banned_peers {
+            self.swarm.behaviour_mut().blocked_peers.block_peer(banned.peer_id);
+        }
+        Ok(())
+    }
+
+    async fn remove_expired_bans(&mut self) -> Result<(), NetworkingError> {
+        for peer_id in self.peer_store.remove_expired_bans().await? {
+            self.swarm.behaviour_mut().blocked_peers.unblock_peer(peer_id);
+            info!(target: LOG_TARGET, "✅ Ban expired for peer {peer_id}");
+            self.publish_event(NetworkingEvent::PeerUnbanned { peer_id });
+        }
+        Ok(())
+    }
+
+    /// Counts an invalid message from the peer and bans the peer if it has sent too many invalid messages within the
+    /// configured window. Counts reset once the window has elapsed, so that occasional invalid messages from a
+    /// well-behaved peer do not eventually get it banned.
+    async fn on_invalid_message(&mut self, peer_id: PeerId, reason: String) -> Result<(), NetworkingError> {
+        let window = self.config.invalid_message_window;
+        if !self.invalid_message_counts.contains_key(&peer_id) &&
+            self.invalid_message_counts.len() >= MAX_TRACKED_INVALID_MESSAGE_PEERS
+        {
+            self.remove_expired_invalid_message_counts();
+            if self.invalid_message_counts.len() >= MAX_TRACKED_INVALID_MESSAGE_PEERS {
+                // Evict the peer with the oldest window to make room
+                let oldest = self
+                    .invalid_message_counts
+                    .iter()
+                    .min_by_key(|(_, c)| c.window_start)
+                    .map(|(peer_id, _)| *peer_id);
+                if let Some(oldest) = oldest {
+                    self.invalid_message_counts.remove(&oldest);
+                }
+            }
+        }
+
+        let now = Instant::now();
+        let entry = self
+            .invalid_message_counts
+            .entry(peer_id)
+            .or_insert(InvalidMessageCount {
+                count: 0,
+                window_start: now,
+            });
+        if now.duration_since(entry.window_start) >= window {
+            entry.count = 0;
+            entry.window_start = now;
+        }
+        entry.count += 1;
+        let count = entry.count;
+        warn!(
+            target: LOG_TARGET,
+            "🚨 Peer {peer_id} sent an invalid message ({count}/{}): {reason}",
+            self.config.max_invalid_messages
+        );
+        if count >= self.config.max_invalid_messages {
+            self.ban_peer(
+                peer_id,
+                format!("Sent {count} invalid message(s). Last error: {reason}"),
+                None,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    fn remove_expired_invalid_message_counts(&mut self) {
+        let window = self.config.invalid_message_window;
+        self.invalid_message_counts
+            .retain(|_, c| c.window_start.elapsed() < window);
+        shrink_hashmap_if_required(&mut self.invalid_message_counts);
+    }
+
     async fn bootstrap(&mut self) -> Result<(), NetworkingError> {
         if !self.is_initial_bootstrap_complete {
             self.swarm
@@ -381,7 +543,7 @@ where
 
     async fn on_swarm_event(
         &mut self,
-        event: SwarmEvent<TariNodeBehaviourEvent<ProstCodec<TMsg::Message>>>,
+        event: SwarmEvent<TariNodeBehaviourEvent<ProstCodec<TMsg::Message>, SqlitePeerStore>>,
     ) -> Result<(), NetworkingError> {
         match event {
             SwarmEvent::Behaviour(event) => self.on_behaviour_event(event).await?,
@@ -465,7 +627,7 @@ where
     #[allow(clippy::too_many_lines)]
     async fn on_behaviour_event(
         &mut self,
-        event: TariNodeBehaviourEvent<ProstCodec<TMsg::Message>>,
+        event: TariNodeBehaviourEvent<ProstCodec<TMsg::Message>, SqlitePeerStore>,
     ) -> Result<(), NetworkingError> {
         use TariNodeBehaviourEvent::*;
         match event {
@@ -559,6 +721,10 @@ where
                 // This is unreachable as connection-limits has no events
                 info!(target: LOG_TARGET, "ℹ️ ConnectionLimits event");
             },
+            BlockedPeers(_) => {
+                // This is unreachable as allow-block-list has no events
+                info!(target: LOG_TARGET, "ℹ️ BlockedPeers event");
+            },
             Mdns(event) => {
                 self.on_mdns_event(event)?;
             },
@@ -620,6 +786,8 @@ where
                         &propagation_source,
                         gossipsub::MessageAcceptance::Reject,
                     )?;
+                    self.on_invalid_message(propagation_source, format!("Invalid peer announcement: {err}"))
+                        .await?;
                     return Err(err.into());
                 },
                 // Some other internal error
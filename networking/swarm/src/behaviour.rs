@@ -7,6 +7,7 @@ use std::{
 };
 
 use libp2p::{
+    allow_block_list,
     autonat,
     connection_limits,
     connection_limits::ConnectionLimits,
@@ -27,7 +28,7 @@ use libp2p::{
 };
 use libp2p_messaging as messaging;
 use libp2p_peersync as peer_sync;
-use libp2p_peersync::store::MemoryPeerStore;
+use libp2p_peersync::store::PeerStore;
 use libp2p_substream as substream;
 
 use crate::{
@@ -36,12 +37,15 @@ use crate::{
 };
 
 #[derive(NetworkBehaviour)]
-pub struct TariNodeBehaviour<TCodec>
-where TCodec: messaging::Codec + Send + Clone + 'static
+pub struct TariNodeBehaviour<TCodec, TPeerStore>
+where
+    TCodec: messaging::Codec + Send + Clone + 'static,
+    TPeerStore: PeerStore,
 {
     pub ping: ping::Behaviour,
     pub dcutr: dcutr::Behaviour,
     pub connection_limits: connection_limits::Behaviour,
+    pub blocked_peers: allow_block_list::Behaviour<allow_block_list::BlockedPeers>,
 
     pub relay: Toggle<relay::Behaviour>,
     pub relay_client: relay::client::Behaviour,
@@ -49,7 +53,7 @@ where TCodec: messaging::Codec + Send + Clone + 'static
 
     pub identify: identify::Behaviour,
     pub mdns: Toggle<mdns::tokio::Behaviour>,
-    pub peer_sync: peer_sync::Behaviour<MemoryPeerStore>,
+    pub peer_sync: peer_sync::Behaviour<TPeerStore>,
 
     pub substream: substream::Behaviour,
     pub messaging: Toggle<messaging::Behaviour<TCodec>>,
@@ -67,13 +71,15 @@ pub fn is_supported_multiaddr(addr: &libp2p::Multiaddr) -> bool {
     })
 }
 
-pub fn create_swarm<TCodec>(
+pub fn create_swarm<TCodec, TPeerStore>(
     identity: Keypair,
     supported_protocols: HashSet<StreamProtocol>,
+    peer_store: TPeerStore,
     config: Config,
-) -> Result<Swarm<TariNodeBehaviour<TCodec>>, TariSwarmError>
+) -> Result<Swarm<TariNodeBehaviour<TCodec, TPeerStore>>, TariSwarmError>
 where
     TCodec: messaging::Codec + Clone + Send + 'static,
+    TPeerStore: PeerStore,
 {
     let swarm = SwarmBuilder::with_existing_identity(identity)
         .with_tokio()
@@ -150,8 +156,7 @@ where
             let autonat = autonat::Behaviour::new(local_peer_id, autonat::Config::default());
 
             // Peer sync
-            let peer_sync =
-                peer_sync::Behaviour::new(keypair.clone(), MemoryPeerStore::new(), peer_sync::Config::default());
+            let peer_sync = peer_sync::Behaviour::new(keypair.clone(), peer_store, peer_sync::Config::default());
 
             Ok(TariNodeBehaviour {
                 ping,
@@ -164,6 +169,7 @@ where
                 substream,
                 messaging: Toggle::from(messaging),
                 connection_limits,
+                blocked_peers: allow_block_list::Behaviour::default(),
                 mdns: Toggle::from(maybe_mdns),
                 peer_sync,
             })
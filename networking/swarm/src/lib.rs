@@ -11,7 +11,7 @@ pub use config::Config;
 pub use error::*;
 pub use protocol_version::*;
 
-pub type TariSwarm<TMsg> = libp2p::Swarm<TariNodeBehaviour<TMsg>>;
+pub type TariSwarm<TMsg, TPeerStore> = libp2p::Swarm<TariNodeBehaviour<TMsg, TPeerStore>>;
 
 pub use libp2p_messaging as messaging;
 pub use libp2p_peersync as peersync;